@@ -3,11 +3,10 @@
 use clap::{CommandFactory, Parser};
 use clap_complete::Shell;
 use regex::Regex;
-use std::fs;
-use std::io::{Read, Result};
-use zip::read::ZipArchive;
 
+use twitter_archive::reader::ArchiveReader;
 use twitter_archive::structs::direct_messages;
+use twitter_archive::Result;
 
 #[derive(Parser, Debug)]
 #[clap(author, version)]
@@ -56,7 +55,7 @@ struct Args {
 	pub build_completions: Option<Shell>,
 }
 
-///
+/// Print direct messages with content matching `--expression` found within `--input-file` archive
 fn main() -> Result<()> {
 	let args = Args::parse();
 
@@ -78,17 +77,9 @@ fn main() -> Result<()> {
 	let expression = args.expression.expect("Undefined value for: --expression");
 	let input_file = args.input_file.expect("Undefined value for: --input-file");
 
-	// Read "data/direct_messages.js" file from Zip archive into String buffer
-	let file_descriptor = fs::File::open(input_file)?;
-	let mut zip_archive = ZipArchive::new(file_descriptor)?;
-	let mut zip_file = zip_archive.by_name("data/direct-messages.js")?;
-	let mut buff = String::new();
-	zip_file.read_to_string(&mut buff)?;
-
-	// Clear JavaScript prefix and parse remaining text as JSON
-	let javascript = "window.YTD.direct_messages.part0 = ";
-	let json = buff.replacen(javascript, "", 1);
-	let data: Vec<direct_messages::DmConversationObject> = serde_json::from_str(&json).unwrap();
+	// Read and parse "data/direct-messages.js" file from Zip archive
+	let mut archive = ArchiveReader::open(input_file)?;
+	let data = archive.direct_messages()?;
 
 	// Do the search for Regex pattern and print matches thing!
 	let re = Regex::new(&expression).unwrap();
@@ -96,7 +87,7 @@ fn main() -> Result<()> {
 		let messages = &object_conversation.dm_conversation.messages;
 		/* Do stuff with each conversation and message */
 		for (index_message, object_message) in messages.iter().enumerate() {
-			let message = &object_message.message_create;
+			let direct_messages::Message::MessageCreate(message) = object_message else { continue };
 			let Some(_caps) = re.captures(&message.text) else { continue };
 
 			println!("{index_conversation} -- {index_message}");
@@ -3,11 +3,9 @@
 use clap::{CommandFactory, Parser};
 use clap_complete::Shell;
 use regex::Regex;
-use std::fs;
-use std::io::{Read, Result};
-use zip::read::ZipArchive;
 
-use twitter_archive::structs::tweets;
+use twitter_archive::reader::ArchiveReader;
+use twitter_archive::Result;
 
 #[derive(Parser, Debug)]
 #[clap(author, version)]
@@ -56,7 +54,7 @@ struct Args {
 	pub build_completions: Option<Shell>,
 }
 
-///
+/// Print tweets with content matching `--expression` found within `--input-file` archive
 fn main() -> Result<()> {
 	let args = Args::parse();
 
@@ -78,17 +76,9 @@ fn main() -> Result<()> {
 	let expression = args.expression.expect("Undefined value for: --expression");
 	let input_file = args.input_file.expect("Undefined value for: --input-file");
 
-	// Read "data/direct_messages.js" file from Zip archive into String buffer
-	let file_descriptor = fs::File::open(input_file)?;
-	let mut zip_archive = ZipArchive::new(file_descriptor)?;
-	let mut zip_file = zip_archive.by_name("data/tweets.js")?;
-	let mut buff = String::new();
-	zip_file.read_to_string(&mut buff)?;
-
-	// Clear JavaScript prefix and parse remaining text as JSON
-	let javascript = "window.YTD.tweets.part0 = ";
-	let json = buff.replacen(javascript, "", 1);
-	let data: Vec<tweets::TweetObject> = serde_json::from_str(&json).unwrap();
+	// Read and parse "data/tweets.js" file from Zip archive
+	let mut archive = ArchiveReader::open(input_file)?;
+	let data = archive.tweets()?;
 
 	// Do the search for Regex pattern and print matches thing!
 	let re = Regex::new(&expression).expect("Invalid regular expression: --expression");
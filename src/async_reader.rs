@@ -0,0 +1,679 @@
+#!/usr/bin/env rust
+
+//! Async counterpart of `reader::ArchiveReader`, enabled via `tokio` feature, so services that
+//! ingest uploaded archives do not block their run-time
+//!
+//! Zip archives keep their index at the end of the file, so uploads are first streamed to a
+//! temporary file asynchronously, and then opening, decompression, plus parsing happen on Tokio's
+//! blocking thread pool.  Archives are never held in memory whole.
+//!
+//! ## Example archive reader
+//!
+//! ```no_build
+//! use twitter_archive::async_reader::AsyncArchiveReader;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let archive = AsyncArchiveReader::open(input_file).await.expect("Unable to read --input-file");
+//!
+//!     for object in archive.tweets().await.expect("Unable to parse") {
+//!         println!("Created at: {}", object.tweet.created_at);
+//!     }
+//! }
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncRead, AsyncWriteExt};
+
+use crate::dm;
+use crate::reader::ArchiveReader;
+use crate::source::ZipSource;
+use crate::{Error, Result};
+
+use crate::structs::{
+	account, account_creation_ip, account_label, account_suspension, account_timezone,
+	ad_engagements, ad_free_article_visits, ad_impressions, ageinfo, app, block, branch_links,
+	catalog_item, commerce_catalog, community_note, community_note_rating,
+	community_note_tombstone, community_tweet, connected_application, contact, deleted_note_tweet,
+	deleted_tweet_headers, deleted_tweets, device_token, direct_message_group_headers,
+	direct_message_headers, direct_message_mute, direct_messages, direct_messages_group,
+	email_address_change, follower, following, grok_chat_item, ip_audit, key_registry, like,
+	lists_created, lists_member, lists_subscribed, manifest, moment, mute, ni_devices, note_tweet,
+	payment_transaction, periscope, personalization, phone_number, product_drop, product_set,
+	professional_data, profile, protected_history, reply_prompt, saved_search, screen_name_change,
+	shop_module, shopify_account, smartblock, spaces_metadata, sso, tweet_headers, tweetdeck,
+	tweets, twitter_article, twitter_article_metadata, twitter_circle, twitter_circle_member,
+	twitter_circle_tweet, user_link_clicks, verified,
+};
+
+/// Zip archive read from file, passed to `AsyncArchiveReader::with` callbacks
+pub type FileArchive = ArchiveReader<ZipSource<fs::File>>;
+
+/// Archive opened from path, or spooled to a temporary file from any `tokio::io::AsyncRead`, whose
+/// accessors parse on blocking threads
+///
+/// Cloning is cheap and clones share the same archive, which parses one file at a time.
+/// Temporary files of spooled uploads are removed once the last clone is dropped.
+///
+/// ## Example
+///
+/// ```
+/// use std::io::{Cursor, Write};
+/// use zip::write::{FileOptions, ZipWriter};
+///
+/// use twitter_archive::async_reader::AsyncArchiveReader;
+///
+/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+///   {
+///     "verified" : {
+///       "accountId" : "435455769",
+///       "verified" : false
+///     }
+///   }
+/// ]"#).unwrap();
+/// let upload = zip_writer.finish().unwrap().into_inner();
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// let data = runtime.block_on(async {
+///     let archive = AsyncArchiveReader::new(upload.as_slice()).await.unwrap();
+///     archive.verified().await.unwrap()
+/// });
+///
+/// assert_eq!(data.len(), 1);
+/// assert_eq!(data[0].verified.account_id, "435455769");
+///
+/// // Archives already on disk are opened in place
+/// let path = std::env::temp_dir().join(format!("twitter-archive-doc-{}.zip", std::process::id()));
+/// std::fs::write(&path, &upload).unwrap();
+///
+/// let data = runtime.block_on(async {
+///     let archive = AsyncArchiveReader::open(&path).await.unwrap();
+///     archive.verified().await.unwrap()
+/// });
+/// std::fs::remove_file(&path).unwrap();
+///
+/// assert_eq!(data[0].verified.account_id, "435455769");
+/// ```
+#[derive(Clone)]
+pub struct AsyncArchiveReader {
+	inner: Arc<Inner>,
+}
+
+/// Archive shared between clones, with fields dropped in order so file is closed before removal
+struct Inner {
+	archive: Mutex<FileArchive>,
+	_spool: Option<SpoolFile>,
+}
+
+impl AsyncArchiveReader {
+	/// Open `twitter-<DATE>-<UID>.zip` file found at `path`, on blocking thread pool
+	pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+		let path = path.as_ref().to_path_buf();
+		let archive = tokio::task::spawn_blocking(move || ArchiveReader::open(path))
+			.await
+			.map_err(join_error)??;
+
+		Ok(Self::from_parts(archive, None))
+	}
+
+	/// Stream everything from `reader`, such as an upload body, to a temporary file, then open it
+	/// as a zip archive
+	pub async fn new<R: AsyncRead + Unpin>(mut reader: R) -> Result<Self> {
+		let spool = SpoolFile::new();
+
+		let mut file = tokio::fs::File::create(&spool.path).await?;
+		tokio::io::copy(&mut reader, &mut file).await?;
+		file.flush().await?;
+		drop(file);
+
+		let path = spool.path.clone();
+		let archive = tokio::task::spawn_blocking(move || ArchiveReader::open(path))
+			.await
+			.map_err(join_error)??;
+
+		Ok(Self::from_parts(archive, Some(spool)))
+	}
+
+	fn from_parts(archive: FileArchive, spool: Option<SpoolFile>) -> Self {
+		Self {
+			inner: Arc::new(Inner {
+				archive: Mutex::new(archive),
+				_spool: spool,
+			}),
+		}
+	}
+
+	/// Run any `ArchiveReader` method on blocking thread pool, for example
+	/// `archive.with(|archive| archive.read_parts("YTD.like")).await`
+	pub async fn with<F, T>(&self, callback: F) -> Result<T>
+	where
+		F: FnOnce(&mut FileArchive) -> Result<T> + Send + 'static,
+		T: Send + 'static,
+	{
+		let inner = Arc::clone(&self.inner);
+		tokio::task::spawn_blocking(move || {
+			let mut archive = inner
+				.archive
+				.lock()
+				.map_err(|_| Error::from(io::Error::other("archive lock poisoned")))?;
+			callback(&mut archive)
+		})
+		.await
+		.map_err(join_error)?
+	}
+
+	/// Read full content of file within archive into a `String`
+	pub async fn read_to_string(&self, file_name: &str) -> Result<String> {
+		let file_name = file_name.to_string();
+		self.with(move |archive| archive.read_to_string(&file_name))
+			.await
+	}
+
+	/// Read and parse `data/account-creation-ip.js` file
+	pub async fn account_creation_ip(
+		&self,
+	) -> Result<Vec<account_creation_ip::AccountCreationIpObject>> {
+		self.with(|archive| archive.account_creation_ip()).await
+	}
+
+	/// Read and parse `data/account-label.js` file
+	pub async fn account_label(&self) -> Result<Vec<account_label::AccountLabelObject>> {
+		self.with(|archive| archive.account_label()).await
+	}
+
+	/// Read and parse `data/account-suspension.js` file
+	pub async fn account_suspension(
+		&self,
+	) -> Result<Vec<account_suspension::AccountSuspensionObject>> {
+		self.with(|archive| archive.account_suspension()).await
+	}
+
+	/// Read and parse `data/ad-free-article-visits.js` file
+	pub async fn ad_free_article_visits(
+		&self,
+	) -> Result<Vec<ad_free_article_visits::AdFreeArticleVisitObject>> {
+		self.with(|archive| archive.ad_free_article_visits()).await
+	}
+
+	/// Read and parse `data/ageinfo.js` file
+	pub async fn ageinfo(&self) -> Result<Vec<ageinfo::AgeinfoObject>> {
+		self.with(|archive| archive.ageinfo()).await
+	}
+
+	/// Read and parse `data/app.js` file
+	pub async fn app(&self) -> Result<Vec<app::AppObject>> {
+		self.with(|archive| archive.app()).await
+	}
+
+	/// Read and parse `data/branch-links.js` file
+	pub async fn branch_links(&self) -> Result<Vec<branch_links::BranchLinkObject>> {
+		self.with(|archive| archive.branch_links()).await
+	}
+
+	/// Read and parse `data/catalog-item.js` file
+	pub async fn catalog_item(&self) -> Result<Vec<catalog_item::CatalogItemObject>> {
+		self.with(|archive| archive.catalog_item()).await
+	}
+
+	/// Read and parse `data/commerce-catalog.js` file
+	pub async fn commerce_catalog(&self) -> Result<Vec<commerce_catalog::CommerceCatalogObject>> {
+		self.with(|archive| archive.commerce_catalog()).await
+	}
+
+	/// Read and parse `data/community-note.js` file
+	pub async fn community_note(&self) -> Result<Vec<community_note::CommunityNoteObject>> {
+		self.with(|archive| archive.community_note()).await
+	}
+
+	/// Read and parse `data/community-note-tombstone.js` file
+	pub async fn community_note_tombstone(
+		&self,
+	) -> Result<Vec<community_note_tombstone::CommunityNoteTombstoneObject>> {
+		self.with(|archive| archive.community_note_tombstone())
+			.await
+	}
+
+	/// Read and parse `data/community-tweet.js` file
+	pub async fn community_tweet(&self) -> Result<Vec<community_tweet::CommunityTweetObject>> {
+		self.with(|archive| archive.community_tweet()).await
+	}
+
+	/// Read and parse `data/contact.js` file
+	pub async fn contact(&self) -> Result<Vec<contact::ContactObject>> {
+		self.with(|archive| archive.contact()).await
+	}
+
+	/// Read and parse `data/deleted-note-tweet.js` file
+	pub async fn deleted_note_tweet(
+		&self,
+	) -> Result<Vec<deleted_note_tweet::DeletedNoteTweetObject>> {
+		self.with(|archive| archive.deleted_note_tweet()).await
+	}
+
+	/// Read and parse `data/direct-message-mute.js` file
+	pub async fn direct_message_mute(
+		&self,
+	) -> Result<Vec<direct_message_mute::DirectMessageMuteObject>> {
+		self.with(|archive| archive.direct_message_mute()).await
+	}
+
+	/// Read and parse `data/grok-chat-item.js` file
+	pub async fn grok_chat_item(&self) -> Result<Vec<grok_chat_item::GrokChatItemObject>> {
+		self.with(|archive| archive.grok_chat_item()).await
+	}
+
+	/// Read and parse `data/lists-created.js` file
+	pub async fn lists_created(&self) -> Result<Vec<lists_created::ListsCreatedObject>> {
+		self.with(|archive| archive.lists_created()).await
+	}
+
+	/// Read and parse `data/lists-subscribed.js` file
+	pub async fn lists_subscribed(&self) -> Result<Vec<lists_subscribed::ListsSubscribedObject>> {
+		self.with(|archive| archive.lists_subscribed()).await
+	}
+
+	/// Read and parse `data/manifest.js` file
+	pub async fn manifest(&self) -> Result<manifest::Manifest> {
+		self.with(|archive| archive.manifest()).await
+	}
+
+	/// Read and parse `data/account.js` file
+	pub async fn account(&self) -> Result<Vec<account::AccountObject>> {
+		self.with(|archive| archive.account()).await
+	}
+
+	/// Read and parse `data/account-timezone.js` file
+	pub async fn account_timezone(&self) -> Result<Vec<account_timezone::AccountTimezoneObject>> {
+		self.with(|archive| archive.account_timezone()).await
+	}
+
+	/// Read and parse `data/ad-engagements.js` file
+	pub async fn ad_engagements(&self) -> Result<Vec<ad_engagements::AdObject>> {
+		self.with(|archive| archive.ad_engagements()).await
+	}
+
+	/// Read and parse `data/ad-impressions.js` file
+	pub async fn ad_impressions(&self) -> Result<Vec<ad_impressions::AdObject>> {
+		self.with(|archive| archive.ad_impressions()).await
+	}
+
+	/// Read and parse `data/block.js` file
+	pub async fn block(&self) -> Result<Vec<block::BlockingObject>> {
+		self.with(|archive| archive.block()).await
+	}
+
+	/// Read and parse `data/community-note-rating.js` file
+	pub async fn community_note_rating(
+		&self,
+	) -> Result<Vec<community_note_rating::CommunityNoteRatingObject>> {
+		self.with(|archive| archive.community_note_rating()).await
+	}
+
+	/// Read and parse `data/connected-application.js` file
+	pub async fn connected_application(
+		&self,
+	) -> Result<Vec<connected_application::ConnectedApplicationObject>> {
+		self.with(|archive| archive.connected_application()).await
+	}
+
+	/// Read and parse `data/deleted-tweet-headers.js` file
+	pub async fn deleted_tweet_headers(&self) -> Result<Vec<deleted_tweet_headers::TweetObject>> {
+		self.with(|archive| archive.deleted_tweet_headers()).await
+	}
+
+	/// Read and parse `data/deleted-tweets.js` file
+	pub async fn deleted_tweets(&self) -> Result<Vec<tweets::TweetObject>> {
+		self.with(|archive| archive.deleted_tweets()).await
+	}
+
+	/// Read and parse `data/deleted-tweets.js` file, with `deleted_at` filled in from
+	/// `data/deleted-tweet-headers.js`
+	pub async fn deleted_tweets_with_headers(
+		&self,
+	) -> Result<Vec<deleted_tweets::DeletedTweetObject>> {
+		self.with(|archive| archive.deleted_tweets_with_headers())
+			.await
+	}
+
+	/// Read and parse `data/device-token.js` file
+	pub async fn device_token(&self) -> Result<Vec<device_token::DeviceTokenObject>> {
+		self.with(|archive| archive.device_token()).await
+	}
+
+	/// Read and parse `data/direct-message-group-headers.js` file
+	pub async fn direct_message_group_headers(
+		&self,
+	) -> Result<Vec<direct_message_group_headers::DmConversationObject>> {
+		self.with(|archive| archive.direct_message_group_headers())
+			.await
+	}
+
+	/// Read and parse `data/direct-message-headers.js` file
+	pub async fn direct_message_headers(
+		&self,
+	) -> Result<Vec<direct_message_headers::DmConversationObject>> {
+		self.with(|archive| archive.direct_message_headers()).await
+	}
+
+	/// Read and parse `data/direct-messages.js` file
+	pub async fn direct_messages(&self) -> Result<Vec<direct_messages::DmConversationObject>> {
+		self.with(|archive| archive.direct_messages()).await
+	}
+
+	/// Read and parse `data/direct-messages-group.js` file
+	pub async fn direct_messages_group(
+		&self,
+	) -> Result<Vec<direct_messages_group::DmConversationObject>> {
+		self.with(|archive| archive.direct_messages_group()).await
+	}
+
+	/// Read and parse `data/direct-messages.js` and `data/direct-messages-group.js` files into
+	/// conversations
+	pub async fn dm_conversations(&self) -> Result<Vec<dm::Conversation>> {
+		self.with(|archive| archive.dm_conversations()).await
+	}
+
+	/// Read and parse `data/email-address-change.js` file
+	pub async fn email_address_change(
+		&self,
+	) -> Result<Vec<email_address_change::EmailAddressChangeObject>> {
+		self.with(|archive| archive.email_address_change()).await
+	}
+
+	/// Read and parse `data/follower.js` file
+	pub async fn follower(&self) -> Result<Vec<follower::FollowerObject>> {
+		self.with(|archive| archive.follower()).await
+	}
+
+	/// Read and parse `data/following.js` file
+	pub async fn following(&self) -> Result<Vec<following::FollowingObject>> {
+		self.with(|archive| archive.following()).await
+	}
+
+	/// Read and parse `data/ip-audit.js` file
+	pub async fn ip_audit(&self) -> Result<Vec<ip_audit::IpAuditObject>> {
+		self.with(|archive| archive.ip_audit()).await
+	}
+
+	/// Read and parse `data/key-registry.js` file
+	pub async fn key_registry(&self) -> Result<Vec<key_registry::RegisteredDevicesObject>> {
+		self.with(|archive| archive.key_registry()).await
+	}
+
+	/// Read and parse `data/like.js` file
+	pub async fn like(&self) -> Result<Vec<like::LikeObject>> {
+		self.with(|archive| archive.like()).await
+	}
+
+	/// Read and parse `data/lists-member.js` file
+	pub async fn lists_member(&self) -> Result<Vec<lists_member::UserListInfoObject>> {
+		self.with(|archive| archive.lists_member()).await
+	}
+
+	/// Read and parse `data/moment.js` file
+	pub async fn moment(&self) -> Result<Vec<moment::MomentObject>> {
+		self.with(|archive| archive.moment()).await
+	}
+
+	/// Read and parse `data/mute.js` file
+	pub async fn mute(&self) -> Result<Vec<mute::MutingObject>> {
+		self.with(|archive| archive.mute()).await
+	}
+
+	/// Read and parse `data/ni-devices.js` file
+	pub async fn ni_devices(&self) -> Result<Vec<ni_devices::NiDeviceResponseObject>> {
+		self.with(|archive| archive.ni_devices()).await
+	}
+
+	/// Read and parse `data/note-tweet.js` file
+	pub async fn note_tweet(&self) -> Result<Vec<note_tweet::NoteTweetObject>> {
+		self.with(|archive| archive.note_tweet()).await
+	}
+
+	/// Read and parse `data/payment-transaction.js` file
+	pub async fn payment_transaction(
+		&self,
+	) -> Result<Vec<payment_transaction::PaymentTransactionObject>> {
+		self.with(|archive| archive.payment_transaction()).await
+	}
+
+	/// Read and parse `data/periscope-account-information.js` file
+	pub async fn periscope_account_information(
+		&self,
+	) -> Result<Vec<periscope::AccountInformationObject>> {
+		self.with(|archive| archive.periscope_account_information())
+			.await
+	}
+
+	/// Read and parse `data/periscope-ban-information.js` file
+	pub async fn periscope_ban_information(&self) -> Result<Vec<periscope::BanInformationObject>> {
+		self.with(|archive| archive.periscope_ban_information())
+			.await
+	}
+
+	/// Read and parse `data/periscope-broadcast-metadata.js` file
+	pub async fn periscope_broadcast_metadata(
+		&self,
+	) -> Result<Vec<periscope::BroadcastMetadataObject>> {
+		self.with(|archive| archive.periscope_broadcast_metadata())
+			.await
+	}
+
+	/// Read and parse `data/periscope-comments-made-by-user.js` file
+	pub async fn periscope_comments_made_by_user(
+		&self,
+	) -> Result<Vec<periscope::CommentsMadeByUserObject>> {
+		self.with(|archive| archive.periscope_comments_made_by_user())
+			.await
+	}
+
+	/// Read and parse `data/periscope-expired-broadcasts.js` file
+	pub async fn periscope_expired_broadcasts(
+		&self,
+	) -> Result<Vec<periscope::ExpiredBroadcastsObject>> {
+		self.with(|archive| archive.periscope_expired_broadcasts())
+			.await
+	}
+
+	/// Read and parse `data/periscope-followers.js` file
+	pub async fn periscope_followers(&self) -> Result<Vec<periscope::FollowersObject>> {
+		self.with(|archive| archive.periscope_followers()).await
+	}
+
+	/// Read and parse `data/periscope-profile-description.js` file
+	pub async fn periscope_profile_description(
+		&self,
+	) -> Result<Vec<periscope::ProfileDescriptionObject>> {
+		self.with(|archive| archive.periscope_profile_description())
+			.await
+	}
+
+	/// Read and parse `data/personalization.js` file
+	pub async fn personalization(&self) -> Result<Vec<personalization::P13nDataObject>> {
+		self.with(|archive| archive.personalization()).await
+	}
+
+	/// Read and parse `data/phone-number.js` file
+	pub async fn phone_number(&self) -> Result<Vec<phone_number::DeviceObject>> {
+		self.with(|archive| archive.phone_number()).await
+	}
+
+	/// Read and parse `data/product-drop.js` file
+	pub async fn product_drop(&self) -> Result<Vec<product_drop::ProductDropObject>> {
+		self.with(|archive| archive.product_drop()).await
+	}
+
+	/// Read and parse `data/product-set.js` file
+	pub async fn product_set(&self) -> Result<Vec<product_set::ProductSetObject>> {
+		self.with(|archive| archive.product_set()).await
+	}
+
+	/// Read and parse `data/professional-data.js` file
+	pub async fn professional_data(
+		&self,
+	) -> Result<Vec<professional_data::ProfessionalDataObject>> {
+		self.with(|archive| archive.professional_data()).await
+	}
+
+	/// Read and parse `data/profile.js` file
+	pub async fn profile(&self) -> Result<Vec<profile::ProfileObject>> {
+		self.with(|archive| archive.profile()).await
+	}
+
+	/// Read and parse `data/protected-history.js` file
+	pub async fn protected_history(
+		&self,
+	) -> Result<Vec<protected_history::ProtectedHistoryObject>> {
+		self.with(|archive| archive.protected_history()).await
+	}
+
+	/// Read and parse `data/reply-prompt.js` file
+	pub async fn reply_prompt(&self) -> Result<Vec<reply_prompt::ReplyPromptObject>> {
+		self.with(|archive| archive.reply_prompt()).await
+	}
+
+	/// Read and parse `data/saved-search.js` file
+	pub async fn saved_search(&self) -> Result<Vec<saved_search::SavedSearchObject>> {
+		self.with(|archive| archive.saved_search()).await
+	}
+
+	/// Read and parse `data/screen-name-change.js` file
+	pub async fn screen_name_change(
+		&self,
+	) -> Result<Vec<screen_name_change::ScreenNameChangeObject>> {
+		self.with(|archive| archive.screen_name_change()).await
+	}
+
+	/// Read and parse `data/shop-module.js` file
+	pub async fn shop_module(&self) -> Result<Vec<shop_module::ShopModuleObject>> {
+		self.with(|archive| archive.shop_module()).await
+	}
+
+	/// Read and parse `data/shopify-account.js` file
+	pub async fn shopify_account(&self) -> Result<Vec<shopify_account::ShopifyAccountObject>> {
+		self.with(|archive| archive.shopify_account()).await
+	}
+
+	/// Read and parse `data/smartblock.js` file
+	pub async fn smartblock(&self) -> Result<Vec<smartblock::SmartblockObject>> {
+		self.with(|archive| archive.smartblock()).await
+	}
+
+	/// Read and parse `data/spaces-metadata.js` file
+	pub async fn spaces_metadata(&self) -> Result<Vec<spaces_metadata::SpacesMetadataObject>> {
+		self.with(|archive| archive.spaces_metadata()).await
+	}
+
+	/// Read and parse `data/sso.js` file
+	pub async fn sso(&self) -> Result<Vec<sso::SsoObject>> {
+		self.with(|archive| archive.sso()).await
+	}
+
+	/// Read and parse `data/tweet-headers.js` file
+	pub async fn tweet_headers(&self) -> Result<Vec<tweet_headers::TweetObject>> {
+		self.with(|archive| archive.tweet_headers()).await
+	}
+
+	/// Read and parse `data/tweetdeck.js` file
+	pub async fn tweetdeck(&self) -> Result<Vec<tweetdeck::DeckObject>> {
+		self.with(|archive| archive.tweetdeck()).await
+	}
+
+	/// Read and parse `data/tweets.js` file
+	pub async fn tweets(&self) -> Result<Vec<tweets::TweetObject>> {
+		self.with(|archive| archive.tweets()).await
+	}
+
+	/// Read and parse `data/tweets.js` file, ordered oldest first
+	pub async fn tweets_sorted(&self) -> Result<Vec<tweets::TweetObject>> {
+		self.with(|archive| archive.tweets_sorted()).await
+	}
+
+	/// Read and parse `data/tweets.js` file, with truncated Tweets merged with their Notes
+	pub async fn tweets_with_notes(&self) -> Result<Vec<tweets::TweetObject>> {
+		self.with(|archive| archive.tweets_with_notes()).await
+	}
+
+	/// Read and parse `data/twitter-article.js` file
+	pub async fn twitter_article(&self) -> Result<Vec<twitter_article::TwitterArticleObject>> {
+		self.with(|archive| archive.twitter_article()).await
+	}
+
+	/// Read and parse `data/twitter-article-metadata.js` file
+	pub async fn twitter_article_metadata(
+		&self,
+	) -> Result<Vec<twitter_article_metadata::TwitterArticleMetadataObject>> {
+		self.with(|archive| archive.twitter_article_metadata())
+			.await
+	}
+
+	/// Read and parse `data/twitter-circle.js` file
+	pub async fn twitter_circle(&self) -> Result<Vec<twitter_circle::TwitterCircleObject>> {
+		self.with(|archive| archive.twitter_circle()).await
+	}
+
+	/// Read and parse `data/twitter-circle-member.js` file
+	pub async fn twitter_circle_member(
+		&self,
+	) -> Result<Vec<twitter_circle_member::TwitterCircleMemberObject>> {
+		self.with(|archive| archive.twitter_circle_member()).await
+	}
+
+	/// Read and parse `data/twitter-circle-tweet.js` file
+	pub async fn twitter_circle_tweet(
+		&self,
+	) -> Result<Vec<twitter_circle_tweet::TwitterCircleTweetObject>> {
+		self.with(|archive| archive.twitter_circle_tweet()).await
+	}
+
+	/// Read and parse `data/user-link-clicks.js` file
+	pub async fn user_link_clicks(&self) -> Result<Vec<user_link_clicks::UserLinkClickObject>> {
+		self.with(|archive| archive.user_link_clicks()).await
+	}
+
+	/// Read and parse `data/verified.js` file
+	pub async fn verified(&self) -> Result<Vec<verified::VerifiedObject>> {
+		self.with(|archive| archive.verified()).await
+	}
+}
+
+/// Blocking task panicked or was cancelled
+/// Temporary file holding spooled upload, removed when dropped
+struct SpoolFile {
+	path: PathBuf,
+}
+
+impl SpoolFile {
+	/// Unique path within `std::env::temp_dir`, file is created by caller
+	fn new() -> Self {
+		static COUNT: AtomicUsize = AtomicUsize::new(0);
+		let name = format!(
+			"twitter-archive-upload-{}-{}.zip",
+			std::process::id(),
+			COUNT.fetch_add(1, Ordering::Relaxed)
+		);
+		Self {
+			path: std::env::temp_dir().join(name),
+		}
+	}
+}
+
+impl Drop for SpoolFile {
+	fn drop(&mut self) {
+		let _ = fs::remove_file(&self.path);
+	}
+}
+
+fn join_error(error: tokio::task::JoinError) -> Error {
+	io::Error::other(error).into()
+}
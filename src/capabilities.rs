@@ -0,0 +1,252 @@
+#!/usr/bin/env rust
+
+//! Report which data types, files, and media directories are actually present within an archive,
+//! so tools can degrade gracefully when reading partial archives
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/manifest.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.__THAR_CONFIG = {
+//!   "archiveInfo": {
+//!     "isPartialArchive": true
+//!   },
+//!   "dataTypes": {
+//!     "like": {
+//!       "files": [
+//!         {
+//!           "fileName": "data/like.js",
+//!           "globalName": "YTD.like.part0",
+//!           "count": "2"
+//!         }
+//!       ]
+//!     },
+//!     "tweets": {
+//!       "files": [
+//!         {
+//!           "fileName": "data/tweets.js",
+//!           "globalName": "YTD.tweets.part0",
+//!           "count": "10"
+//!         }
+//!       ]
+//!     },
+//!     "tweetsMedia": {
+//!       "mediaDirectory": "data/tweets_media"
+//!     }
+//!   }
+//! }"#).unwrap();
+//! zip_writer.start_file("data/like.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(b"window.YTD.like.part0 = [ ]").unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! let capabilities = archive.capabilities().unwrap();
+//!
+//! assert_eq!(capabilities.is_partial_archive, Some(true));
+//! assert!(capabilities.has("like"));
+//! assert!(!capabilities.has("tweets"));
+//! assert!(!capabilities.has("tweetsMedia"));
+//! assert!(!capabilities.has("grokChatItem"));
+//!
+//! let tweets = &capabilities.data_types["tweets"];
+//! assert_eq!(tweets.files[0].count, 10);
+//! assert_eq!(tweets.files[0].present, false);
+//!
+//! assert_eq!(capabilities.files, vec!["data/like.js", "data/manifest.js"]);
+//! ```
+
+use std::collections::BTreeMap;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::structs::manifest;
+use crate::{Error, Result};
+
+/// Structured report returned by `ArchiveReader::capabilities`
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+	/// Value of manifest `archiveInfo.isPartialArchive`, `None` when manifest is missing
+	pub is_partial_archive: Option<bool>,
+
+	/// Data types keyed by manifest `dataTypes` name, such as `"tweets"` or `"tweetsMedia"`
+	///
+	/// When archive has no manifest, data types are guessed from `data/*.js` file names
+	pub data_types: BTreeMap<String, DataTypeCapability>,
+
+	/// Every file found within archive
+	pub files: Vec<String>,
+}
+
+/// Files, and/or media directory, described for one data type
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct DataTypeCapability {
+	/// Data files, with counts the manifest claims, and whether they exist
+	pub files: Vec<FileCapability>,
+
+	/// Directory of media files, such as `data/tweets_media`
+	pub media_directory: Option<String>,
+
+	/// At least one file exists within `media_directory`
+	pub media_directory_present: bool,
+}
+
+/// One data file pointer from manifest and whether it exists
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct FileCapability {
+	/// Relative path to file within archive
+	pub file_name: String,
+
+	/// JavaScript Object pointer data is assigned to
+	pub global_name: String,
+
+	/// Count of items manifest claims the file holds
+	pub count: usize,
+
+	/// File exists within archive
+	pub present: bool,
+}
+
+impl Capabilities {
+	/// Data type has at least one file, or media file, present within archive
+	pub fn has(&self, data_type: &str) -> bool {
+		self.data_types.get(data_type).is_some_and(|data_type| {
+			data_type.media_directory_present || data_type.files.iter().any(|file| file.present)
+		})
+	}
+}
+
+impl<S: Source> ArchiveReader<S> {
+	/// Report which data types have files, which files exist, and the counts manifest claims
+	///
+	/// Only `data/manifest.js` is parsed, data files are checked for existence but not read.
+	pub fn capabilities(&mut self) -> Result<Capabilities> {
+		let files = self.file_names();
+		let manifest = self.manifest_raw()?.clone();
+
+		let is_partial_archive = manifest
+			.get("archiveInfo")
+			.and_then(|archive_info| archive_info.get("isPartialArchive"))
+			.and_then(Value::as_bool);
+
+		let mut data_types = BTreeMap::new();
+		match manifest.get("dataTypes").and_then(Value::as_object) {
+			Some(described) => {
+				for (name, data_type) in described {
+					let listed = match data_type.get("files") {
+						Some(listed) => {
+							Vec::<manifest::File>::deserialize(listed).map_err(|error| {
+								Error::from(error).with_file_name("data/manifest.js")
+							})?
+						}
+						None => Vec::new(),
+					};
+					let media_directory = data_type
+						.get("mediaDirectory")
+						.and_then(Value::as_str)
+						.map(String::from);
+
+					data_types.insert(
+						name.clone(),
+						DataTypeCapability {
+							files: listed
+								.into_iter()
+								.map(|file| FileCapability {
+									present: files.contains(&file.file_name),
+									file_name: file.file_name,
+									global_name: file.global_name,
+									count: file.count,
+								})
+								.collect(),
+							media_directory_present: media_directory
+								.as_ref()
+								.is_some_and(|directory| has_files_within(&files, directory)),
+							media_directory,
+						},
+					);
+				}
+			}
+			None => {
+				for file_name in &files {
+					let Some(name) = file_name
+						.strip_prefix("data/")
+						.and_then(|name| name.strip_suffix(".js"))
+					else {
+						continue;
+					};
+					if name.contains('/') || name == "manifest" {
+						continue;
+					}
+
+					let (name, part) = match name.rsplit_once("-part") {
+						Some((name, part)) if part.chars().all(|c| c.is_ascii_digit()) => {
+							(name, part)
+						}
+						_ => (name, "0"),
+					};
+					let snake = name.replace('-', "_");
+
+					let data_type =
+						data_types
+							.entry(kebab_to_camel_case(name))
+							.or_insert_with(|| DataTypeCapability {
+								files: Vec::new(),
+								media_directory: None,
+								media_directory_present: false,
+							});
+					data_type.files.push(FileCapability {
+						file_name: file_name.clone(),
+						global_name: format!("YTD.{snake}.part{part}"),
+						count: 0,
+						present: true,
+					});
+				}
+			}
+		}
+
+		Ok(Capabilities {
+			is_partial_archive,
+			data_types,
+			files,
+		})
+	}
+}
+
+/// At least one file name is within `directory`
+fn has_files_within(files: &[String], directory: &str) -> bool {
+	let directory = directory.trim_end_matches('/');
+	files.iter().any(|file_name| {
+		file_name
+			.strip_prefix(directory)
+			.is_some_and(|rest| rest.starts_with('/'))
+	})
+}
+
+/// Convert `kebab-case` file names into `camelCase` keys of manifest `dataTypes`
+fn kebab_to_camel_case(name: &str) -> String {
+	let mut words = name.split('-');
+	let mut camel = words.next().unwrap_or_default().to_string();
+	for word in words {
+		let mut chars = word.chars();
+		if let Some(first) = chars.next() {
+			camel.extend(first.to_uppercase());
+			camel.push_str(chars.as_str());
+		}
+	}
+	camel
+}
@@ -0,0 +1,79 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between boolean like string values similar to
+//!
+//! ```json
+//! {
+//!   "protected": "true"
+//! }
+//! ```
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Deserializer};
+
+/// Convert `bool` type into JSON boolean like string
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::bool_like_string")]
+///     protected: bool,
+/// }
+///
+/// let data = Test { protected: true };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// assert_eq!(json_serialize, r#"{"protected":"true"}"#);
+/// ```
+pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(if *value { "true" } else { "false" })
+}
+
+/// Convert JSON boolean like string into `bool` type
+///
+/// Only exactly `"true"` and `"false"` are accepted
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::bool_like_string")]
+///     protected: bool,
+/// }
+///
+/// let data: Test = serde_json::from_str(r#"{ "protected": "false" }"#).unwrap();
+/// assert!(!data.protected);
+///
+/// assert!(serde_json::from_str::<Test>(r#"{ "protected": "yes" }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let value: String = Deserialize::deserialize(deserializer)?;
+	match value.as_str() {
+		"true" => Ok(true),
+		"false" => Ok(false),
+		_ => Err(serde::de::Error::invalid_value(
+			serde::de::Unexpected::Str(&value),
+			&r#""true" or "false""#,
+		)),
+	}
+}
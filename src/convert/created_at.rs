@@ -11,9 +11,10 @@
 //! See: https://serde.rs/custom-date-format.html
 
 use chrono::{DateTime, Utc};
-use serde::de;
 use serde::{Deserialize, Deserializer, Serializer};
 
+use crate::convert::date_error;
+
 /// Warning; this format string may be changed at the whims of Mr. Musk
 ///
 /// - %a -> Abbreviated day (e.g. Sun)
@@ -92,7 +93,7 @@ where
 	let value = String::deserialize(deserializer)?;
 
 	let date_time: DateTime<Utc> = DateTime::parse_from_str(&value, FORMAT)
-		.map_err(de::Error::custom)?
+		.map_err(|cause| date_error::invalid::<D::Error>(&value, FORMAT, cause))?
 		.into();
 
 	Ok(date_time)
@@ -0,0 +1,93 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between date-time stamp, keeping original UTC offset,
+//! from/to JSON value similar to
+//!
+//! ```json
+//! {
+//!   "created_at": "Sat Aug 12 09:10:37 -0700 2023"
+//! }
+//! ```
+//!
+//! Unlike `convert::created_at`, which normalizes to `DateTime<Utc>`, these functions keep the
+//! `FixedOffset` found within data, for tools that care about posting time-zone.
+//!
+//! Check `convert::created_at` for format details.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::convert::created_at::FORMAT;
+use crate::convert::date_error;
+
+/// Convert `DateTime` data structure into date time stamp string with original UTC offset
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset};
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::created_at::FORMAT;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::created_at_fixed_offset")]
+///     created_at: DateTime<FixedOffset>,
+/// }
+///
+/// let time = "Sat Aug 12 09:10:37 -0700 2023";
+///
+/// let data = Test {
+///     created_at: DateTime::parse_from_str(&time, FORMAT).unwrap(),
+/// };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// let json_expected = format!(r#"{{"created_at":"{time}"}}"#);
+///
+/// assert_eq!(json_serialize, json_expected);
+/// ```
+pub fn serialize<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let s = format!("{}", date.format(FORMAT));
+	serializer.serialize_str(&s)
+}
+
+/// Convert date time stamp string into `DateTime` data structure keeping original UTC offset
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset};
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::created_at_fixed_offset")]
+///     created_at: DateTime<FixedOffset>,
+/// }
+///
+/// let json = r#"{ "created_at": "Sat Aug 12 09:10:37 -0700 2023" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.created_at.offset().local_minus_utc(), -7 * 60 * 60);
+/// assert_eq!(data.created_at.format("%T").to_string(), "09:10:37");
+/// assert_eq!(data.created_at.naive_utc().format("%T").to_string(), "16:10:37");
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let value = String::deserialize(deserializer)?;
+
+	DateTime::parse_from_str(&value, FORMAT)
+		.map_err(|cause| date_error::invalid::<D::Error>(&value, FORMAT, cause))
+}
@@ -0,0 +1,19 @@
+#!/usr/bin/env rust
+
+//! Error construction shared by date time-stamp conversion modules, so failures name both the
+//! offending value and the format it was expected to match
+
+use std::fmt::Display;
+
+use serde::de;
+
+/// Build `serde` error similar to;
+///
+/// ```text
+/// invalid date time-stamp "2023-13-01", expected format "%FT%T.%3fZ": input is out of range
+/// ```
+pub(crate) fn invalid<E: de::Error>(value: &str, format: &str, cause: impl Display) -> E {
+	E::custom(format!(
+		"invalid date time-stamp {value:?}, expected format {format:?}: {cause}"
+	))
+}
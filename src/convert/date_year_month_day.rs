@@ -17,6 +17,8 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer, Serializer};
 
+use crate::convert::date_error;
+
 /// Warning; this format string may be changed at the whims of Mr. Musk
 ///
 /// Currently looks like: "2023-08-30T23:20:03.000Z"
@@ -100,6 +102,7 @@ where
 	D: Deserializer<'de>,
 {
 	let s = String::deserialize(deserializer)?;
-	let dt = NaiveDate::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)?;
+	let dt = NaiveDate::parse_from_str(&s, FORMAT)
+		.map_err(|cause| date_error::invalid::<D::Error>(&s, FORMAT, cause))?;
 	Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt.into(), Utc))
 }
@@ -16,6 +16,8 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Deserializer, Serializer};
 
+use crate::convert::date_error;
+
 /// Warning; this format string may be changed at the whims of Mr. Musk
 ///
 /// Currently looks like: "2023-06-05 17:00:52"
@@ -109,6 +111,7 @@ where
 	D: Deserializer<'de>,
 {
 	let s = String::deserialize(deserializer)?;
-	let dt = NaiveDateTime::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)?;
+	let dt = NaiveDateTime::parse_from_str(&s, FORMAT)
+		.map_err(|cause| date_error::invalid::<D::Error>(&s, FORMAT, cause))?;
 	Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
 }
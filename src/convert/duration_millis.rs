@@ -0,0 +1,89 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between `std::time::Duration` from/to millisecond
+//! strings similar to
+//!
+//! ```json
+//! {
+//!   "duration_millis": "30030"
+//! }
+//! ```
+//!
+//! Found within `extended_entities.media[].video_info` of Tweets with video or animated GIF media.
+
+use std::time::Duration;
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Deserializer};
+
+/// Convert `Duration` data structure into JSON millisecond string
+///
+/// Sub-millisecond precision is truncated.
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+/// struct Test {
+///     #[serde(with = "convert::duration_millis")]
+///     duration_millis: Duration,
+/// }
+///
+/// let data = Test { duration_millis: Duration::from_millis(30030) };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// assert_eq!(json_serialize, r#"{"duration_millis":"30030"}"#);
+/// ```
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&duration.as_millis().to_string())
+}
+
+/// Convert JSON millisecond string into `Duration` data structure
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+/// struct Test {
+///     #[serde(with = "convert::duration_millis")]
+///     duration_millis: Duration,
+/// }
+///
+/// let json = r#"{ "duration_millis": "30030" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.duration_millis, Duration::from_millis(30030));
+/// assert_eq!(data.duration_millis.as_secs(), 30);
+///
+/// assert!(serde_json::from_str::<Test>(r#"{ "duration_millis": "-1" }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let milliseconds: String = Deserialize::deserialize(deserializer)?;
+	milliseconds
+		.parse::<u64>()
+		.map(Duration::from_millis)
+		.map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,92 @@
+#!/usr/bin/env rust
+
+//! Parse E.164 phone numbers, such as
+//!
+//! ```json
+//! {
+//!   "phoneNumber": "+15551234567"
+//! }
+//! ```
+//!
+//! into country calling code and national number, so numbers may be normalized and compared
+//! across accounts.  Country calling codes are prefix free, so their length is decided by leading
+//! digits alone without need for a full table of assigned codes.
+
+use derive_more::Display;
+
+/// Maximum count of digits, country calling code included, allowed by E.164
+pub const MAX_DIGITS: usize = 15;
+
+/// Two digit country calling codes, all codes not listed here or starting with `1` or `7` are
+/// three digits long
+const TWO_DIGIT_CODES: &[u16] = &[
+	20, 27, 30, 31, 32, 33, 34, 36, 39, 40, 41, 43, 44, 45, 46, 47, 48, 49, 51, 52, 53, 54, 55, 56,
+	57, 58, 60, 61, 62, 63, 64, 65, 66, 81, 82, 84, 86, 90, 91, 92, 93, 94, 95, 98,
+];
+
+/// Phone number split into country calling code and national number
+///
+/// `Display` writes normalized E.164 form, e.g. `+15551234567`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Display)]
+#[display(fmt = "+{}{}", country_code, national_number)]
+pub struct PhoneNumber {
+	/// Country calling code without leading `+`, e.g. `1` for North America
+	pub country_code: u16,
+
+	/// Remaining digits, without separators
+	pub national_number: String,
+}
+
+/// Parse E.164 phone number, ignoring space, dash, dot, and parenthesis separators
+///
+/// Returns `None` for strings missing leading `+`, containing other characters, or holding too
+/// few or too many digits.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::e164;
+///
+/// let data = e164::parse("+15551234567").unwrap();
+/// assert_eq!(data.country_code, 1);
+/// assert_eq!(data.national_number, "5551234567");
+///
+/// let data = e164::parse("+44 (20) 7946-0958").unwrap();
+/// assert_eq!(data.country_code, 44);
+/// assert_eq!(data.to_string(), "+442079460958");
+///
+/// assert_eq!(e164::parse("+353 1 555 0100").unwrap().country_code, 353);
+///
+/// assert!(e164::parse("5551234567").is_none());
+/// assert!(e164::parse("+1555CALLNOW").is_none());
+/// ```
+pub fn parse(value: &str) -> Option<PhoneNumber> {
+	let digits = value.trim().strip_prefix('+')?;
+
+	let mut digits = digits
+		.chars()
+		.filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+		.collect::<String>();
+
+	if digits.len() > MAX_DIGITS || !digits.chars().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+
+	let code_length = match digits.get(..2)?.parse::<u16>().ok()? {
+		10..=19 | 70..=79 => 1,
+		code if TWO_DIGIT_CODES.contains(&code) => 2,
+		_ => 3,
+	};
+
+	if digits.len() <= code_length {
+		return None;
+	}
+
+	let national_number = digits.split_off(code_length);
+	let country_code = digits.parse().ok()?;
+
+	Some(PhoneNumber {
+		country_code,
+		national_number,
+	})
+}
@@ -0,0 +1,95 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between millisecond epoch time-stamp from/to JSON value
+//! similar to
+//!
+//! ```json
+//! {
+//!   "createdAt": "1692123456789"
+//! }
+//! ```
+//!
+//! Periscope and Spaces structs do not use this converter, because `data/periscope-*.js` and
+//! `data/spaces-metadata.js` write `createdAt`, `startedAt`, `endedAt`, and `expiresAt` as ISO
+//! 8601 strings, e.g. `"2023-01-01T00:05:00.000Z"`, which `convert::date_time_iso_8601` and
+//! `convert::optional_date_time_iso_8601` already parse.  This converter is for fields written as
+//! milliseconds since Unix epoch, such as those of newer data types read into custom structs via
+//! `ArchiveReader::load_as`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::convert::date_error;
+
+/// Description of expected format, used within error messages
+const FORMAT: &str = "milliseconds since Unix epoch";
+
+/// Convert `DateTime` data structure into millisecond epoch string
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Test {
+///     #[serde(with = "convert::epoch_millis")]
+///     created_at: DateTime<Utc>,
+/// }
+///
+/// let data = Test {
+///     created_at: DateTime::<Utc>::from_timestamp_millis(1692123456789).unwrap(),
+/// };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// assert_eq!(json_serialize, r#"{"createdAt":"1692123456789"}"#);
+/// ```
+pub fn serialize<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&date.timestamp_millis().to_string())
+}
+
+/// Convert millisecond epoch string into `DateTime` data structure
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Test {
+///     #[serde(with = "convert::epoch_millis")]
+///     created_at: DateTime<Utc>,
+/// }
+///
+/// let json = r#"{ "createdAt": "1692123456789" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.created_at.to_rfc3339(), "2023-08-15T18:17:36.789+00:00");
+///
+/// assert!(serde_json::from_str::<Test>(r#"{ "createdAt": "yesterday" }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let s = String::deserialize(deserializer)?;
+	let milliseconds = s
+		.parse::<i64>()
+		.map_err(|cause| date_error::invalid::<D::Error>(&s, FORMAT, cause))?;
+	DateTime::<Utc>::from_timestamp_millis(milliseconds)
+		.ok_or_else(|| date_error::invalid(&s, FORMAT, "input is out of range"))
+}
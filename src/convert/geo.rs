@@ -0,0 +1,232 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between `GeoPoint` from/to stringified latitude and
+//! longitude arrays similar to
+//!
+//! ```json
+//! {
+//!   "geo": {
+//!     "type": "Point",
+//!     "coordinates": ["37.7749", "-122.4194"]
+//!   }
+//! }
+//! ```
+//!
+//! Geo-tagged Tweets have both `geo.coordinates`, in latitude then longitude order, and
+//! `coordinates.coordinates`, in GeoJSON longitude then latitude order; use `convert::geo` for
+//! former and `convert::geo::long_lat` for latter.
+//!
+//! Note; values are re-serialized via `f64` formatting, so trailing zeros, e.g. `"37.70"`, are not
+//! preserved.
+
+use derive_more::Display;
+use serde::de::Error;
+use serde::ser::{SerializeTuple, Serializer};
+use serde::{Deserialize, Deserializer};
+
+/// Position on Earth in decimal degrees
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::geo::GeoPoint;
+///
+/// let point = GeoPoint::new(37.7749, -122.4194);
+///
+/// assert_eq!(point.to_string(), "37.7749,-122.4194");
+/// assert_eq!(point.lat_long(), [37.7749, -122.4194]);
+/// assert_eq!(point.long_lat(), [-122.4194, 37.7749]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Display)]
+#[display(fmt = "{},{}", latitude, longitude)]
+pub struct GeoPoint {
+	/// Degrees north of equator, negative for south
+	pub latitude: f64,
+
+	/// Degrees east of prime meridian, negative for west
+	pub longitude: f64,
+}
+
+impl GeoPoint {
+	/// Point from latitude and longitude
+	pub fn new(latitude: f64, longitude: f64) -> Self {
+		Self {
+			latitude,
+			longitude,
+		}
+	}
+
+	/// Latitude then longitude, as found within `geo.coordinates`
+	pub fn lat_long(&self) -> [f64; 2] {
+		[self.latitude, self.longitude]
+	}
+
+	/// Longitude then latitude, as found within GeoJSON `coordinates.coordinates`
+	pub fn long_lat(&self) -> [f64; 2] {
+		[self.longitude, self.latitude]
+	}
+
+	/// Parse latitude and longitude strings, returning `None` when either is not a number or is
+	/// out of range
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::convert::geo::GeoPoint;
+	///
+	/// assert_eq!(GeoPoint::parse("37.7749", "-122.4194"), Some(GeoPoint::new(37.7749, -122.4194)));
+	///
+	/// assert!(GeoPoint::parse("91", "0").is_none());
+	/// assert!(GeoPoint::parse("north", "0").is_none());
+	/// ```
+	pub fn parse(latitude: &str, longitude: &str) -> Option<Self> {
+		let latitude = latitude.trim().parse::<f64>().ok()?;
+		let longitude = longitude.trim().parse::<f64>().ok()?;
+
+		if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+			return None;
+		}
+
+		Some(Self::new(latitude, longitude))
+	}
+}
+
+/// Convert `GeoPoint` data structure into JSON array of latitude and longitude strings
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::geo::GeoPoint;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(with = "convert::geo")]
+///     coordinates: GeoPoint,
+/// }
+///
+/// let data = Test { coordinates: GeoPoint::new(37.7749, -122.4194) };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// assert_eq!(json_serialize, r#"{"coordinates":["37.7749","-122.4194"]}"#);
+/// ```
+pub fn serialize<S>(point: &GeoPoint, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serialize_pair(point.lat_long(), serializer)
+}
+
+/// Convert JSON array of latitude and longitude strings into `GeoPoint` data structure
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::geo::GeoPoint;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(with = "convert::geo")]
+///     coordinates: GeoPoint,
+/// }
+///
+/// let json = r#"{ "coordinates": ["37.7749", "-122.4194"] }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.coordinates.latitude, 37.7749);
+/// assert_eq!(data.coordinates.longitude, -122.4194);
+///
+/// assert!(serde_json::from_str::<Test>(r#"{ "coordinates": ["37.7749"] }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GeoPoint, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let [latitude, longitude] = deserialize_pair(deserializer)?;
+	point(&latitude, &longitude)
+}
+
+/// Same as parent module, though for GeoJSON longitude then latitude ordered arrays found within;
+///
+/// - `tweets[].tweet.coordinates.coordinates`
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::geo::GeoPoint;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(with = "convert::geo::long_lat")]
+///     coordinates: GeoPoint,
+/// }
+///
+/// let json = r#"{"coordinates":["-122.4194","37.7749"]}"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.coordinates, GeoPoint::new(37.7749, -122.4194));
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string(&data).unwrap(), json);
+/// ```
+pub mod long_lat {
+	use serde::{Deserializer, Serializer};
+
+	use super::GeoPoint;
+
+	/// Convert `GeoPoint` data structure into JSON array of longitude and latitude strings
+	pub fn serialize<S>(point: &GeoPoint, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		super::serialize_pair(point.long_lat(), serializer)
+	}
+
+	/// Convert JSON array of longitude and latitude strings into `GeoPoint` data structure
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<GeoPoint, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let [longitude, latitude] = super::deserialize_pair(deserializer)?;
+		super::point(&latitude, &longitude)
+	}
+}
+
+/// Write two numbers as array of strings
+fn serialize_pair<S>(pair: [f64; 2], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let mut tup = serializer.serialize_tuple(2)?;
+	tup.serialize_element(&pair[0].to_string())?;
+	tup.serialize_element(&pair[1].to_string())?;
+	tup.end()
+}
+
+/// Read array of exactly two strings
+fn deserialize_pair<'de, D>(deserializer: D) -> Result<[String; 2], D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let seq: Vec<String> = Deserialize::deserialize(deserializer)?;
+	seq.try_into()
+		.map_err(|_| Error::custom("Expected a sequence of length 2"))
+}
+
+/// Parse pair, reporting offending values on failure
+fn point<E: Error>(latitude: &str, longitude: &str) -> Result<GeoPoint, E> {
+	GeoPoint::parse(latitude, longitude).ok_or_else(|| {
+		E::custom(format!(
+			"invalid coordinates, latitude {latitude:?} and longitude {longitude:?}"
+		))
+	})
+}
@@ -0,0 +1,121 @@
+#!/usr/bin/env rust
+
+//! Decode HTML entities found within text, such as
+//!
+//! ```json
+//! {
+//!   "full_text": "Fish &amp; chips &lt;3"
+//! }
+//! ```
+//!
+//! Archives store text as Twitter served it to browsers, so `&`, `<`, and `>` are encoded.
+//! Decoding is left to accessor methods, e.g. `Tweet::full_text_decoded`, so stored fields stay
+//! byte-accurate for re-serialization.
+
+use std::borrow::Cow;
+
+/// Longest entity, between `&` and `;`, worth looking for
+const MAX_ENTITY_LENGTH: usize = 10;
+
+/// Decode named `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`, and numeric `&#39;` or `&#x27;`
+/// entities in single pass, so `&amp;lt;` becomes `&lt;` rather than `<`
+///
+/// Unknown or malformed entities are kept verbatim, and input without any `&` is borrowed as-is.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::html_entities;
+///
+/// assert_eq!(html_entities::decode("Fish &amp; chips &lt;3"), "Fish & chips <3");
+/// assert_eq!(html_entities::decode("&#39;quoted&#x27;"), "'quoted'");
+/// assert_eq!(html_entities::decode("&amp;lt;"), "&lt;");
+/// assert_eq!(html_entities::decode("AT&T &bogus;"), "AT&T &bogus;");
+/// ```
+pub fn decode(value: &str) -> Cow<'_, str> {
+	if !value.contains('&') {
+		return Cow::Borrowed(value);
+	}
+
+	let mut decoded = String::with_capacity(value.len());
+	let mut rest = value;
+
+	while let Some(start) = rest.find('&') {
+		decoded.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		let entity = rest[1..]
+			.char_indices()
+			.take(MAX_ENTITY_LENGTH + 1)
+			.find(|(_, c)| *c == ';')
+			.and_then(|(end, _)| Some((end, decode_entity(&rest[1..=end])?)));
+
+		match entity {
+			Some((end, c)) => {
+				decoded.push(c);
+				rest = &rest[end + 2..];
+			}
+			None => {
+				decoded.push('&');
+				rest = &rest[1..];
+			}
+		}
+	}
+
+	decoded.push_str(rest);
+
+	Cow::Owned(decoded)
+}
+
+/// Encode `&`, `<`, and `>` the same way archives do, which is inverse of `decode` for text
+/// Twitter stored, e.g. Note text being merged into `Tweet::full_text`
+///
+/// Input without any of those characters is borrowed as-is.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::html_entities;
+///
+/// assert_eq!(html_entities::encode("Fish & chips <3"), "Fish &amp; chips &lt;3");
+/// assert_eq!(html_entities::encode("\"quoted\""), "\"quoted\"");
+///
+/// let text = "AT&T &lt;3";
+/// assert_eq!(html_entities::decode(&html_entities::encode(text)), text);
+/// ```
+pub fn encode(value: &str) -> Cow<'_, str> {
+	if !value.contains(['&', '<', '>']) {
+		return Cow::Borrowed(value);
+	}
+
+	let mut encoded = String::with_capacity(value.len() + 8);
+	for c in value.chars() {
+		match c {
+			'&' => encoded.push_str("&amp;"),
+			'<' => encoded.push_str("&lt;"),
+			'>' => encoded.push_str("&gt;"),
+			_ => encoded.push(c),
+		}
+	}
+
+	Cow::Owned(encoded)
+}
+
+/// Character for entity name found between `&` and `;`
+fn decode_entity(name: &str) -> Option<char> {
+	match name {
+		"amp" => Some('&'),
+		"lt" => Some('<'),
+		"gt" => Some('>'),
+		"quot" => Some('"'),
+		"apos" => Some('\''),
+		_ => {
+			let number = name.strip_prefix('#')?;
+			let code = match number.strip_prefix(['x', 'X']) {
+				Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+				None => number.parse().ok()?,
+			};
+			char::from_u32(code)
+		}
+	}
+}
@@ -0,0 +1,96 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between `u64` from/to ID strings similar to
+//!
+//! ```json
+//! {
+//!   "id_str": "1690395372546301952"
+//! }
+//! ```
+//!
+//! IDs are stored as strings because JavaScript numbers lose precision above 2^53, though every
+//! Snowflake ID fits within `u64`.  Values that are not unsigned integers, or overflow `u64`,
+//! produce errors rather than panics, so legacy data fails gracefully.
+
+use std::num::ParseIntError;
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Deserializer};
+
+/// Parse ID string into `u64`, shared by `deserialize`, `snowflake::timestamp_from_str`, and
+/// numeric ordering of IDs by `query` functions
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::id_u64;
+///
+/// assert_eq!(id_u64::parse("1690395372546301952"), Ok(1690395372546301952));
+/// assert!(id_u64::parse("18446744073709551616").is_err());
+/// assert!(id_u64::parse("not-an-id").is_err());
+/// ```
+pub fn parse(id: &str) -> Result<u64, ParseIntError> {
+	id.parse()
+}
+
+/// Convert `u64` type into JSON ID string
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::id_u64")]
+///     id_str: u64,
+/// }
+///
+/// let data = Test { id_str: 1690395372546301952 };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// assert_eq!(json_serialize, r#"{"id_str":"1690395372546301952"}"#);
+/// ```
+pub fn serialize<S>(id: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&id.to_string())
+}
+
+/// Convert JSON ID string into `u64` type
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// struct Test {
+///     #[serde(with = "convert::id_u64")]
+///     id_str: u64,
+/// }
+///
+/// let json = r#"{ "id_str": "1690395372546301952" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.id_str, 1690395372546301952);
+///
+/// // Overflow and non-numeric values are errors
+/// assert!(serde_json::from_str::<Test>(r#"{ "id_str": "18446744073709551616" }"#).is_err());
+/// assert!(serde_json::from_str::<Test>(r#"{ "id_str": "[222]" }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let id: String = Deserialize::deserialize(deserializer)?;
+	parse(&id).map_err(|cause| serde::de::Error::custom(format!("invalid ID {id:?}: {cause}")))
+}
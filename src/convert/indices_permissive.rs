@@ -0,0 +1,107 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between `Vec<usize>` of any length from/to JSON value
+//! similar to
+//!
+//! ```json
+//! {
+//!   "indices": ["68", "419"]
+//! }
+//! ```
+//!
+//! Unlike `convert::indices` arrays of more, or fewer, than two values are accepted, because some
+//! entities within older archives have them and one malformed entity should not abort parsing of
+//! whole file.  Use `convert::indices_permissive::pair` to recover start and end where possible.
+
+use serde::de::Error;
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Deserializer};
+
+/// Convert `Vec<usize>` data structure into JSON array of number like strings
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+/// struct Test {
+///     #[serde(with = "convert::indices_permissive")]
+///     indices: Vec<usize>,
+/// }
+///
+/// let data = Test { indices: vec![68, 419, 420] };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// let json_expected = r#"{"indices":["68","419","420"]}"#;
+///
+/// assert_eq!(json_serialize, json_expected);
+/// ```
+pub fn serialize<S>(indices: &[usize], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let mut seq = serializer.serialize_seq(Some(indices.len()))?;
+	for index in indices {
+		seq.serialize_element(&index.to_string())?;
+	}
+	seq.end()
+}
+
+/// Convert JSON array of number like strings, of any length, into `Vec<usize>` data structure
+///
+/// ## Example
+///
+/// ```
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone, Display)]
+/// #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+/// struct Test {
+///     #[serde(with = "convert::indices_permissive")]
+///     indices: Vec<usize>,
+/// }
+///
+/// let json = r#"{ "indices": ["68"] }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.indices, vec![68]);
+///
+/// let json = r#"{ "indices": [] }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert!(data.indices.is_empty());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<usize>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let seq: Vec<String> = Deserialize::deserialize(deserializer)?;
+
+	seq.iter()
+		.map(|index| index.parse::<usize>().map_err(Error::custom))
+		.collect()
+}
+
+/// Start and end indices, when exactly two were found
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::indices_permissive::pair;
+///
+/// assert_eq!(pair(&[68, 419]), Some([68, 419]));
+/// assert_eq!(pair(&[68]), None);
+/// assert_eq!(pair(&[68, 419, 420]), None);
+/// ```
+pub fn pair(indices: &[usize]) -> Option<[usize; 2]> {
+	indices.try_into().ok()
+}
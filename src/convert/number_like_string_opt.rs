@@ -0,0 +1,95 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between optional number like string values similar to
+//!
+//! ```json
+//! {
+//!   "editsRemaining": "5"
+//! }
+//! ```
+//!
+//! Older archives sometimes store empty strings, or `null`, where a number is expected.
+//!
+//! Fields using these functions should also be marked `#[serde(default)]` so missing values
+//! de-serialize as `None`, and `#[serde(skip_serializing_if = "Option::is_none")]` so they are not
+//! re-serialized as `null`.
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Deserializer};
+
+/// Convert optional `usize` type into JSON number like string, or `null`
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::number_like_string_opt")]
+///     favorite_count: Option<usize>,
+/// }
+///
+/// let data = Test { favorite_count: Some(68419) };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"favorite_count":"68419"}"#);
+///
+/// let data = Test { favorite_count: None };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"favorite_count":null}"#);
+/// ```
+pub fn serialize<S>(value: &Option<usize>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match value {
+		Some(value) => serializer.serialize_some(&value.to_string()),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Convert optional JSON number like string into `usize` type
+///
+/// Empty strings and `null` both become `None`
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::number_like_string_opt")]
+///     favorite_count: Option<usize>,
+/// }
+///
+/// let data: Test = serde_json::from_str(r#"{ "favorite_count": "68419" }"#).unwrap();
+/// assert_eq!(data.favorite_count, Some(68419));
+///
+/// let data: Test = serde_json::from_str(r#"{ "favorite_count": "" }"#).unwrap();
+/// assert!(data.favorite_count.is_none());
+///
+/// let data: Test = serde_json::from_str(r#"{ "favorite_count": null }"#).unwrap();
+/// assert!(data.favorite_count.is_none());
+///
+/// let data: Test = serde_json::from_str("{}").unwrap();
+/// assert!(data.favorite_count.is_none());
+///
+/// assert!(serde_json::from_str::<Test>(r#"{ "favorite_count": "many" }"#).is_err());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match Option::<String>::deserialize(deserializer)? {
+		Some(number) if !number.is_empty() => number
+			.parse::<usize>()
+			.map(Some)
+			.map_err(serde::de::Error::custom),
+		_ => Ok(None),
+	}
+}
@@ -0,0 +1,110 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between optional date-time stamp from/to JSON value
+//! similar to
+//!
+//! ```json
+//! {
+//!   "deleted_at": "Wed Aug 30 23:20:03 +0000 2023"
+//! }
+//! ```
+//!
+//! Fields using these functions should also be marked `#[serde(default)]` so missing values
+//! de-serialize as `None`, and `#[serde(skip_serializing_if = "Option::is_none")]` so they are not
+//! re-serialized as `null`.
+//!
+//! Check `convert::created_at` for format details.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::convert::created_at::FORMAT;
+use crate::convert::date_error;
+
+/// Convert optional `DateTime` data structure into date time stamp string, or `null`
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::created_at::FORMAT;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_created_at")]
+///     deleted_at: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = "Wed Aug 30 23:20:03 +0000 2023";
+///
+/// let data = Test {
+///     deleted_at: Some(DateTime::parse_from_str(&time, FORMAT).unwrap().into()),
+/// };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// let json_expected = format!(r#"{{"deleted_at":"{time}"}}"#);
+///
+/// assert_eq!(json_serialize, json_expected);
+///
+/// let data = Test { deleted_at: None };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"deleted_at":null}"#);
+/// ```
+pub fn serialize<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match date {
+		Some(date) => serializer.serialize_some(&format!("{}", date.format(FORMAT))),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Convert optional date time stamp string into `DateTime` data structure
+///
+/// Empty strings and `null` both become `None`
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_created_at")]
+///     deleted_at: Option<DateTime<Utc>>,
+/// }
+///
+/// let json = r#"{ "deleted_at": "Wed Aug 30 23:20:03 +0000 2023" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.deleted_at.unwrap().format("%F %T").to_string(), "2023-08-30 23:20:03");
+///
+/// let data: Test = serde_json::from_str("{}").unwrap();
+///
+/// assert!(data.deleted_at.is_none());
+///
+/// let data: Test = serde_json::from_str(r#"{ "deleted_at": null }"#).unwrap();
+///
+/// assert!(data.deleted_at.is_none());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match Option::<String>::deserialize(deserializer)? {
+		Some(s) if !s.is_empty() => {
+			let date_time = DateTime::parse_from_str(&s, FORMAT)
+				.map_err(|cause| date_error::invalid::<D::Error>(&s, FORMAT, cause))?;
+			Ok(Some(date_time.into()))
+		}
+		_ => Ok(None),
+	}
+}
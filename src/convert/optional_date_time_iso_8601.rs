@@ -0,0 +1,115 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between optional date-time stamp from/to JSON value
+//! similar to
+//!
+//! ```json
+//! {
+//!   "removedAt": "2023-08-30T23:20:03.000Z"
+//! }
+//! ```
+//!
+//! Fields using these functions should also be marked `#[serde(default)]` so missing values
+//! de-serialize as `None`, and `#[serde(skip_serializing_if = "Option::is_none")]` so they are not
+//! re-serialized as `null`.
+//!
+//! Check `convert::date_time_iso_8601` for format details.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::convert::date_error;
+use crate::convert::date_time_iso_8601::FORMAT;
+
+/// Convert optional `DateTime` data structure into date time stamp string, or `null`
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+/// use derive_more::Display;
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_date_time_iso_8601")]
+///     removed_at: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = "2023-08-12T17:10:37.000Z";
+///
+/// let date_time = NaiveDateTime::parse_from_str(&time, FORMAT).unwrap();
+///
+/// let data = Test {
+///     removed_at: Some(DateTime::<Utc>::from_naive_utc_and_offset(date_time, Utc)),
+/// };
+///
+/// let json_serialize = serde_json::to_string(&data).unwrap();
+///
+/// let json_expected = format!(r#"{{"removedAt":"{time}"}}"#);
+///
+/// assert_eq!(json_serialize, json_expected);
+///
+/// let data = Test { removed_at: None };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"removedAt":null}"#);
+/// ```
+pub fn serialize<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match date {
+		Some(date) => serializer.serialize_some(&format!("{}", date.format(FORMAT))),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Convert optional date time stamp string into `DateTime` data structure
+///
+/// Empty strings and `null` both become `None`
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_date_time_iso_8601")]
+///     removed_at: Option<DateTime<Utc>>,
+/// }
+///
+/// let json = r#"{ "removedAt": "2023-08-12T17:10:37.000Z" }"#;
+/// let data: Test = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(data.removed_at.unwrap().format("%F").to_string(), "2023-08-12");
+///
+/// let data: Test = serde_json::from_str("{}").unwrap();
+///
+/// assert!(data.removed_at.is_none());
+///
+/// let data: Test = serde_json::from_str(r#"{ "removedAt": null }"#).unwrap();
+///
+/// assert!(data.removed_at.is_none());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match Option::<String>::deserialize(deserializer)? {
+		Some(s) if !s.is_empty() => {
+			let dt = NaiveDateTime::parse_from_str(&s, FORMAT)
+				.map_err(|cause| date_error::invalid::<D::Error>(&s, FORMAT, cause))?;
+			Ok(Some(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc)))
+		}
+		_ => Ok(None),
+	}
+}
@@ -0,0 +1,94 @@
+#!/usr/bin/env rust
+
+//! Functions to enable `serde` conversion between optional `std::time::Duration` from/to
+//! millisecond strings similar to
+//!
+//! ```json
+//! {
+//!   "duration_millis": "30030"
+//! }
+//! ```
+//!
+//! Fields using these functions should also be marked `#[serde(default)]` so missing values
+//! de-serialize as `None`, and `#[serde(skip_serializing_if = "Option::is_none")]` so they are not
+//! re-serialized as `null`.
+//!
+//! Check `convert::duration_millis` for details.
+
+use std::time::Duration;
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Deserializer};
+
+/// Convert optional `Duration` data structure into JSON millisecond string, or `null`
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_duration_millis")]
+///     duration_millis: Option<Duration>,
+/// }
+///
+/// let data = Test { duration_millis: Some(Duration::from_millis(30030)) };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"duration_millis":"30030"}"#);
+///
+/// let data = Test { duration_millis: None };
+///
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"duration_millis":null}"#);
+/// ```
+pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match duration {
+		Some(duration) => serializer.serialize_some(&duration.as_millis().to_string()),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Convert optional JSON millisecond string into `Duration` data structure
+///
+/// Empty strings and `null` both become `None`
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use serde::{Deserialize, Serialize};
+///
+/// use twitter_archive::convert;
+///
+/// #[derive(Deserialize, Serialize, Debug, Clone)]
+/// struct Test {
+///     #[serde(default, with = "convert::optional_duration_millis")]
+///     duration_millis: Option<Duration>,
+/// }
+///
+/// let data: Test = serde_json::from_str(r#"{ "duration_millis": "30030" }"#).unwrap();
+/// assert_eq!(data.duration_millis, Some(Duration::from_millis(30030)));
+///
+/// let data: Test = serde_json::from_str("{}").unwrap();
+/// assert!(data.duration_millis.is_none());
+/// ```
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match Option::<String>::deserialize(deserializer)? {
+		Some(milliseconds) if !milliseconds.is_empty() => milliseconds
+			.parse::<u64>()
+			.map(|milliseconds| Some(Duration::from_millis(milliseconds)))
+			.map_err(serde::de::Error::custom),
+		_ => Ok(None),
+	}
+}
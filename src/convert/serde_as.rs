@@ -0,0 +1,142 @@
+#!/usr/bin/env rust
+
+//! Helper types exposing `convert::*` modules to `serde_with`, so structs embedding archive
+//! values may be annotated with `#[serde_as(as = "...")]` instead of `#[serde(with = "...")]`
+//! path strings
+//!
+//! Being types, rather than module paths, these also compose with `serde_with` wrappers, e.g.
+//! `Option<CreatedAt>` or `Vec<NumberLikeString>`.
+//!
+//! ## Example
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! use twitter_archive::convert::serde_as::{CreatedAt, NumberLikeString};
+//!
+//! #[serde_as]
+//! #[derive(Deserialize, Serialize, Debug)]
+//! struct Test {
+//!     #[serde_as(as = "CreatedAt")]
+//!     created_at: DateTime<Utc>,
+//!
+//!     #[serde_as(as = "Vec<NumberLikeString>")]
+//!     counts: Vec<usize>,
+//! }
+//!
+//! let json = r#"{"created_at":"Sat Aug 12 16:10:37 +0000 2023","counts":["6","8"]}"#;
+//!
+//! let data: Test = serde_json::from_str(&json).unwrap();
+//!
+//! assert_eq!(data.created_at.format("%F").to_string(), "2023-08-12");
+//! assert_eq!(data.counts, vec![6, 8]);
+//!
+//! // Re-serialize is equivalent to original data
+//! assert_eq!(serde_json::to_string(&data).unwrap(), json);
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::convert;
+use crate::convert::geo::GeoPoint;
+
+/// Define unit `struct` forwarding `SerializeAs` and `DeserializeAs` to `convert::$module`, or
+/// nested module path such as `geo::long_lat`
+macro_rules! serde_as_adapter {
+	(
+		$(#[$meta:meta])*
+		$name:ident => $($module:ident)::+, $type:ty
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, Default)]
+		pub struct $name;
+
+		impl SerializeAs<$type> for $name {
+			fn serialize_as<S: Serializer>(source: &$type, serializer: S) -> Result<S::Ok, S::Error> {
+				convert::$($module)::+::serialize(source, serializer)
+			}
+		}
+
+		impl<'de> DeserializeAs<'de, $type> for $name {
+			fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<$type, D::Error> {
+				convert::$($module)::+::deserialize(deserializer)
+			}
+		}
+	};
+}
+
+serde_as_adapter! {
+	/// Same as `convert::bool_like_string`, for `bool` values
+	BoolLikeString => bool_like_string, bool
+}
+
+serde_as_adapter! {
+	/// Same as `convert::created_at`, for `DateTime<Utc>` values
+	CreatedAt => created_at, DateTime<Utc>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::created_at_fixed_offset`, for `DateTime<FixedOffset>` values
+	CreatedAtFixedOffset => created_at_fixed_offset, DateTime<FixedOffset>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::date_time_iso_8601`, for `DateTime<Utc>` values
+	DateTimeIso8601 => date_time_iso_8601, DateTime<Utc>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::date_year_month_day`, for `DateTime<Utc>` values
+	DateYearMonthDay => date_year_month_day, DateTime<Utc>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::date_year_month_day_hour_minute_second`, for `DateTime<Utc>` values
+	DateYearMonthDayHourMinuteSecond => date_year_month_day_hour_minute_second, DateTime<Utc>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::duration_millis`, for `Duration` values
+	DurationMillis => duration_millis, Duration
+}
+
+serde_as_adapter! {
+	/// Same as `convert::epoch_millis`, for `DateTime<Utc>` values
+	EpochMillis => epoch_millis, DateTime<Utc>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::geo`, for `GeoPoint` values in latitude then longitude order
+	Geo => geo, GeoPoint
+}
+
+serde_as_adapter! {
+	/// Same as `convert::geo::long_lat`, for `GeoPoint` values in longitude then latitude order
+	GeoLongLat => geo::long_lat, GeoPoint
+}
+
+serde_as_adapter! {
+	/// Same as `convert::id_u64`, for `u64` values
+	IdU64 => id_u64, u64
+}
+
+serde_as_adapter! {
+	/// Same as `convert::indices`, for `[usize; 2]` values
+	Indices => indices, [usize; 2]
+}
+
+serde_as_adapter! {
+	/// Same as `convert::indices_permissive`, for `Vec<usize>` values
+	IndicesPermissive => indices_permissive, Vec<usize>
+}
+
+serde_as_adapter! {
+	/// Same as `convert::number_like_string`, for `usize` values
+	NumberLikeString => number_like_string, usize
+}
@@ -0,0 +1,74 @@
+#!/usr/bin/env rust
+
+//! Functions to extract creation date time-stamp embedded within Snowflake IDs, such as
+//!
+//! ```json
+//! {
+//!   "id_str": "1690395372546301952"
+//! }
+//! ```
+//!
+//! Upper 41 bits of Snowflake IDs count milliseconds since `EPOCH_MILLISECONDS`, so date
+//! time-stamp is available even where only IDs were archived, e.g. `data/tweet-headers.js`, and
+//! can be used to double check `created_at` values.
+//!
+//! Note; `created_at` values are truncated to whole seconds, where Snowflake time-stamps keep
+//! milliseconds.
+
+use chrono::{DateTime, Utc};
+
+use crate::convert;
+
+/// Milliseconds between Unix epoch and Twitter epoch, `2010-11-04T01:42:54.657Z`
+pub const EPOCH_MILLISECONDS: i64 = 1_288_834_974_657;
+
+/// IDs below this predate Snowflake and encode no date time-stamp
+pub const FIRST_ID: u64 = 30_000_000_000;
+
+/// Number of low bits used for worker and sequence numbers
+const TIMESTAMP_SHIFT: u32 = 22;
+
+/// Extract creation date time-stamp from Snowflake ID
+///
+/// Returns `None` for IDs that predate Snowflake.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::snowflake;
+///
+/// let date_time = snowflake::timestamp(1690395372546301952).unwrap();
+///
+/// assert_eq!(date_time.to_rfc3339(), "2023-08-12T16:10:37.499+00:00");
+///
+/// // Legacy sequential IDs carry no time-stamp
+/// assert!(snowflake::timestamp(20).is_none());
+/// ```
+pub fn timestamp(id: u64) -> Option<DateTime<Utc>> {
+	if id < FIRST_ID {
+		return None;
+	}
+
+	let milliseconds = i64::try_from(id >> TIMESTAMP_SHIFT).ok()? + EPOCH_MILLISECONDS;
+
+	DateTime::<Utc>::from_timestamp_millis(milliseconds)
+}
+
+/// Same as `timestamp` but for IDs stored as strings, as they are within archives
+///
+/// Returns `None` for strings that are not unsigned integers, or IDs that predate Snowflake.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::snowflake;
+///
+/// let date_time = snowflake::timestamp_from_str("1347406193795411968").unwrap();
+///
+/// assert_eq!(date_time.format("%F %T").to_string(), "2021-01-08 04:54:04");
+///
+/// assert!(snowflake::timestamp_from_str("not-an-id").is_none());
+/// ```
+pub fn timestamp_from_str(id: &str) -> Option<DateTime<Utc>> {
+	timestamp(convert::id_u64::parse(id).ok()?)
+}
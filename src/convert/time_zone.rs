@@ -0,0 +1,196 @@
+#!/usr/bin/env rust
+
+//! Convert time-zone names found in `account_timezone[].accountTimezone.timeZone` into typed
+//! `chrono_tz::Tz` values, such as
+//!
+//! ```json
+//! {
+//!   "timeZone": "Arizona"
+//! }
+//! ```
+//!
+//! Twitter stores Ruby on Rails style friendly names, e.g. `Arizona` or `Pacific Time (US &
+//! Canada)`, rather than IANA names, so these are mapped via `RAILS_NAMES` with IANA names, e.g.
+//! `America/Phoenix`, accepted as-is.
+
+use chrono_tz::Tz;
+
+/// Ruby on Rails `ActiveSupport::TimeZone` friendly names paired with IANA time-zones
+pub const RAILS_NAMES: &[(&str, Tz)] = &[
+	("International Date Line West", Tz::Etc__GMTPlus12),
+	("Midway Island", Tz::Pacific__Midway),
+	("American Samoa", Tz::Pacific__Pago_Pago),
+	("Hawaii", Tz::Pacific__Honolulu),
+	("Alaska", Tz::America__Juneau),
+	("Pacific Time (US & Canada)", Tz::America__Los_Angeles),
+	("Tijuana", Tz::America__Tijuana),
+	("Mountain Time (US & Canada)", Tz::America__Denver),
+	("Arizona", Tz::America__Phoenix),
+	("Chihuahua", Tz::America__Chihuahua),
+	("Mazatlan", Tz::America__Mazatlan),
+	("Central Time (US & Canada)", Tz::America__Chicago),
+	("Saskatchewan", Tz::America__Regina),
+	("Guadalajara", Tz::America__Mexico_City),
+	("Mexico City", Tz::America__Mexico_City),
+	("Monterrey", Tz::America__Monterrey),
+	("Central America", Tz::America__Guatemala),
+	("Eastern Time (US & Canada)", Tz::America__New_York),
+	("Indiana (East)", Tz::America__Indiana__Indianapolis),
+	("Bogota", Tz::America__Bogota),
+	("Lima", Tz::America__Lima),
+	("Quito", Tz::America__Lima),
+	("Atlantic Time (Canada)", Tz::America__Halifax),
+	("Caracas", Tz::America__Caracas),
+	("La Paz", Tz::America__La_Paz),
+	("Santiago", Tz::America__Santiago),
+	("Newfoundland", Tz::America__St_Johns),
+	("Brasilia", Tz::America__Sao_Paulo),
+	("Buenos Aires", Tz::America__Argentina__Buenos_Aires),
+	("Montevideo", Tz::America__Montevideo),
+	("Georgetown", Tz::America__Guyana),
+	("Puerto Rico", Tz::America__Puerto_Rico),
+	("Greenland", Tz::America__Godthab),
+	("Mid-Atlantic", Tz::Atlantic__South_Georgia),
+	("Azores", Tz::Atlantic__Azores),
+	("Cape Verde Is.", Tz::Atlantic__Cape_Verde),
+	("Dublin", Tz::Europe__Dublin),
+	("Edinburgh", Tz::Europe__London),
+	("Lisbon", Tz::Europe__Lisbon),
+	("London", Tz::Europe__London),
+	("Casablanca", Tz::Africa__Casablanca),
+	("Monrovia", Tz::Africa__Monrovia),
+	("UTC", Tz::Etc__UTC),
+	("Belgrade", Tz::Europe__Belgrade),
+	("Bratislava", Tz::Europe__Bratislava),
+	("Budapest", Tz::Europe__Budapest),
+	("Ljubljana", Tz::Europe__Ljubljana),
+	("Prague", Tz::Europe__Prague),
+	("Sarajevo", Tz::Europe__Sarajevo),
+	("Skopje", Tz::Europe__Skopje),
+	("Warsaw", Tz::Europe__Warsaw),
+	("Zagreb", Tz::Europe__Zagreb),
+	("Brussels", Tz::Europe__Brussels),
+	("Copenhagen", Tz::Europe__Copenhagen),
+	("Madrid", Tz::Europe__Madrid),
+	("Paris", Tz::Europe__Paris),
+	("Amsterdam", Tz::Europe__Amsterdam),
+	("Berlin", Tz::Europe__Berlin),
+	("Bern", Tz::Europe__Zurich),
+	("Zurich", Tz::Europe__Zurich),
+	("Rome", Tz::Europe__Rome),
+	("Stockholm", Tz::Europe__Stockholm),
+	("Vienna", Tz::Europe__Vienna),
+	("West Central Africa", Tz::Africa__Algiers),
+	("Bucharest", Tz::Europe__Bucharest),
+	("Cairo", Tz::Africa__Cairo),
+	("Helsinki", Tz::Europe__Helsinki),
+	("Kyiv", Tz::Europe__Kiev),
+	("Riga", Tz::Europe__Riga),
+	("Sofia", Tz::Europe__Sofia),
+	("Tallinn", Tz::Europe__Tallinn),
+	("Vilnius", Tz::Europe__Vilnius),
+	("Athens", Tz::Europe__Athens),
+	("Istanbul", Tz::Europe__Istanbul),
+	("Minsk", Tz::Europe__Minsk),
+	("Jerusalem", Tz::Asia__Jerusalem),
+	("Harare", Tz::Africa__Harare),
+	("Pretoria", Tz::Africa__Johannesburg),
+	("Kaliningrad", Tz::Europe__Kaliningrad),
+	("Moscow", Tz::Europe__Moscow),
+	("St. Petersburg", Tz::Europe__Moscow),
+	("Volgograd", Tz::Europe__Volgograd),
+	("Samara", Tz::Europe__Samara),
+	("Kuwait", Tz::Asia__Kuwait),
+	("Riyadh", Tz::Asia__Riyadh),
+	("Nairobi", Tz::Africa__Nairobi),
+	("Baghdad", Tz::Asia__Baghdad),
+	("Tehran", Tz::Asia__Tehran),
+	("Abu Dhabi", Tz::Asia__Muscat),
+	("Muscat", Tz::Asia__Muscat),
+	("Baku", Tz::Asia__Baku),
+	("Tbilisi", Tz::Asia__Tbilisi),
+	("Yerevan", Tz::Asia__Yerevan),
+	("Kabul", Tz::Asia__Kabul),
+	("Ekaterinburg", Tz::Asia__Yekaterinburg),
+	("Islamabad", Tz::Asia__Karachi),
+	("Karachi", Tz::Asia__Karachi),
+	("Tashkent", Tz::Asia__Tashkent),
+	("Chennai", Tz::Asia__Kolkata),
+	("Kolkata", Tz::Asia__Kolkata),
+	("Mumbai", Tz::Asia__Kolkata),
+	("New Delhi", Tz::Asia__Kolkata),
+	("Kathmandu", Tz::Asia__Kathmandu),
+	("Astana", Tz::Asia__Dhaka),
+	("Dhaka", Tz::Asia__Dhaka),
+	("Sri Jayawardenepura", Tz::Asia__Colombo),
+	("Almaty", Tz::Asia__Almaty),
+	("Novosibirsk", Tz::Asia__Novosibirsk),
+	("Rangoon", Tz::Asia__Rangoon),
+	("Bangkok", Tz::Asia__Bangkok),
+	("Hanoi", Tz::Asia__Bangkok),
+	("Jakarta", Tz::Asia__Jakarta),
+	("Krasnoyarsk", Tz::Asia__Krasnoyarsk),
+	("Beijing", Tz::Asia__Shanghai),
+	("Chongqing", Tz::Asia__Chongqing),
+	("Hong Kong", Tz::Asia__Hong_Kong),
+	("Urumqi", Tz::Asia__Urumqi),
+	("Kuala Lumpur", Tz::Asia__Kuala_Lumpur),
+	("Singapore", Tz::Asia__Singapore),
+	("Taipei", Tz::Asia__Taipei),
+	("Perth", Tz::Australia__Perth),
+	("Irkutsk", Tz::Asia__Irkutsk),
+	("Ulaanbaatar", Tz::Asia__Ulaanbaatar),
+	("Seoul", Tz::Asia__Seoul),
+	("Osaka", Tz::Asia__Tokyo),
+	("Sapporo", Tz::Asia__Tokyo),
+	("Tokyo", Tz::Asia__Tokyo),
+	("Yakutsk", Tz::Asia__Yakutsk),
+	("Darwin", Tz::Australia__Darwin),
+	("Adelaide", Tz::Australia__Adelaide),
+	("Canberra", Tz::Australia__Melbourne),
+	("Melbourne", Tz::Australia__Melbourne),
+	("Sydney", Tz::Australia__Sydney),
+	("Brisbane", Tz::Australia__Brisbane),
+	("Hobart", Tz::Australia__Hobart),
+	("Vladivostok", Tz::Asia__Vladivostok),
+	("Guam", Tz::Pacific__Guam),
+	("Port Moresby", Tz::Pacific__Port_Moresby),
+	("Magadan", Tz::Asia__Magadan),
+	("Srednekolymsk", Tz::Asia__Srednekolymsk),
+	("Solomon Is.", Tz::Pacific__Guadalcanal),
+	("New Caledonia", Tz::Pacific__Noumea),
+	("Fiji", Tz::Pacific__Fiji),
+	("Kamchatka", Tz::Asia__Kamchatka),
+	("Marshall Is.", Tz::Pacific__Majuro),
+	("Auckland", Tz::Pacific__Auckland),
+	("Wellington", Tz::Pacific__Auckland),
+	("Nuku'alofa", Tz::Pacific__Tongatapu),
+	("Tokelau Is.", Tz::Pacific__Fakaofo),
+	("Chatham Is.", Tz::Pacific__Chatham),
+	("Samoa", Tz::Pacific__Apia),
+];
+
+/// Parse Rails friendly or IANA time-zone name
+///
+/// Returns `None` for names that are neither.
+///
+/// ## Example
+///
+/// ```
+/// use chrono_tz::Tz;
+///
+/// use twitter_archive::convert::time_zone;
+///
+/// assert_eq!(time_zone::parse("Arizona"), Some(Tz::America__Phoenix));
+/// assert_eq!(time_zone::parse("Pacific Time (US & Canada)"), Some(Tz::America__Los_Angeles));
+/// assert_eq!(time_zone::parse("Europe/Berlin"), Some(Tz::Europe__Berlin));
+///
+/// assert!(time_zone::parse("Middle Earth").is_none());
+/// ```
+pub fn parse(name: &str) -> Option<Tz> {
+	RAILS_NAMES
+		.iter()
+		.find(|(rails_name, _)| *rails_name == name)
+		.map(|(_, tz)| *tz)
+		.or_else(|| name.parse().ok())
+}
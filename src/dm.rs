@@ -0,0 +1,623 @@
+#!/usr/bin/env rust
+
+//! Direct Message conversations, one-to-one and group alike, with messages in order sent and
+//! participants resolved, instead of nested data structures of `structs::direct_messages` and
+//! `structs::direct_messages_group`
+//!
+//! Participants of one-to-one conversations come from `conversationId`, formatted as
+//! `<user_id>-<user_id>`, and from senders and recipients of messages.  Participants of group
+//! conversations come from senders, as well as users that joined or left.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::dm::Conversation;
+//! use twitter_archive::structs::direct_messages::DmConversationObject;
+//!
+//! let json = r#"{
+//!   "dmConversation": {
+//!     "conversationId": "111111111-222222222",
+//!     "messages": [
+//!       {
+//!         "messageCreate": {
+//!           "recipientId": "111111111",
+//!           "reactions": [],
+//!           "urls": [],
+//!           "text": "Fish &amp; chips?",
+//!           "mediaUrls": [],
+//!           "senderId": "222222222",
+//!           "id": "4444444444444444444",
+//!           "createdAt": "2023-08-12T17:12:00.000Z"
+//!         }
+//!       },
+//!       {
+//!         "messageCreate": {
+//!           "recipientId": "222222222",
+//!           "reactions": [],
+//!           "urls": [],
+//!           "text": "Salutations!",
+//!           "mediaUrls": [],
+//!           "senderId": "111111111",
+//!           "id": "3333333333333333333",
+//!           "createdAt": "2023-08-12T17:10:37.000Z"
+//!         }
+//!       }
+//!     ]
+//!   }
+//! }"#;
+//!
+//! let data: DmConversationObject = serde_json::from_str(&json).unwrap();
+//! let conversation = Conversation::from(data.dm_conversation);
+//!
+//! assert_eq!(conversation.conversation_id(), "111111111-222222222");
+//! assert!(!conversation.is_group());
+//! assert_eq!(conversation.participants().len(), 2);
+//! assert!(conversation.participants().contains("222222222"));
+//!
+//! // Messages are ordered oldest first
+//! let texts: Vec<String> = conversation
+//!     .messages()
+//!     .iter()
+//!     .map(|message| message.text_decoded().into_owned())
+//!     .collect();
+//! assert_eq!(texts, ["Salutations!", "Fish & chips?"]);
+//!
+//! assert_eq!(conversation.messages_from("222222222").count(), 1);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::convert;
+use crate::query;
+use crate::structs::url_entity::UrlEntity;
+use crate::structs::{
+	direct_message, direct_messages, direct_messages_group, key_registry, manifest,
+};
+
+/// One message of a conversation, shared by one-to-one and group conversations
+///
+/// Serializes with same keys, and date time-stamp format, as `messageCreate` entries of archives
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+	/// ID of message
+	pub id: String,
+
+	/// User ID of account that sent message
+	pub sender_id: String,
+
+	/// User ID of account that received message, which group conversations lack
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub recipient_id: Option<String>,
+
+	/// Content of message, with HTML entities such as `&amp;` left as is
+	pub text: String,
+
+	/// URLs of media attached to message
+	pub media_urls: Vec<String>,
+
+	/// Who, when, and what reactions were had about message
+	pub reactions: Vec<direct_message::MessageCreateReaction>,
+
+	/// Links found within `text`
+	pub urls: Vec<direct_message::MessageCreateUrl>,
+
+	/// Date time-stamp of when message was sent
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl Message {
+	/// Content of `text` with HTML entities, e.g. `&amp;`, decoded
+	pub fn text_decoded(&self) -> Cow<'_, str> {
+		convert::html_entities::decode(&self.text)
+	}
+
+	/// Links of `urls` as `UrlEntity`, shared with Tweets and Notes, so link analysis may cover
+	/// Direct Messages too
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::dm::Message;
+	/// use twitter_archive::structs::direct_messages_group::MessageCreate;
+	///
+	/// let json = r#"{
+	///   "reactions": [],
+	///   "urls": [
+	///     {
+	///       "url": "https://t.co/Yot7Ijm9vG",
+	///       "expanded": "https://github.com/S0AndS0/",
+	///       "display": "github.com/S0AndS0/"
+	///     }
+	///   ],
+	///   "text": "See https://t.co/Yot7Ijm9vG",
+	///   "mediaUrls": [],
+	///   "senderId": "111111111",
+	///   "id": "3333333333333333333",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }"#;
+	///
+	/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+	/// let message = Message::from(data);
+	///
+	/// let links = message.url_entities();
+	/// assert_eq!(links[0].expanded_url, "https://github.com/S0AndS0/");
+	/// assert_eq!(links[0].host(), Some("github.com".to_string()));
+	/// ```
+	pub fn url_entities(&self) -> Vec<UrlEntity> {
+		self.urls.iter().map(UrlEntity::from).collect()
+	}
+
+	/// Paths within archive, `<media_directory>/<message_id>-<file_name>`, where files of
+	/// `media_urls` are expected; in same order as `media_urls`, skipping URLs without file name
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::dm::Message;
+	/// use twitter_archive::structs::direct_messages::MessageCreate;
+	/// use twitter_archive::structs::manifest::MediaDirectory;
+	///
+	/// let json = r#"{
+	///   "recipientId": "222222222",
+	///   "reactions": [],
+	///   "urls": [],
+	///   "text": "Look! https://t.co/AbCdEfGhIj",
+	///   "mediaUrls": [
+	///     "https://ton.twitter.com/dm/3333333333333333333/5555555555555555555/AbCdEfGh.jpg",
+	///     "https://video.twimg.com/dm_video/6666666666666666666/vid/1280x720/IjKlMnOp.mp4?tag=1"
+	///   ],
+	///   "senderId": "111111111",
+	///   "id": "3333333333333333333",
+	///   "createdAt": "2023-08-12T17:10:37.000Z"
+	/// }"#;
+	///
+	/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+	/// let message = Message::from(data);
+	///
+	/// let media_directory: MediaDirectory =
+	///     serde_json::from_str(r#"{ "mediaDirectory": "data/direct_messages_media" }"#).unwrap();
+	///
+	/// assert_eq!(
+	///     message.media_file_names(&media_directory),
+	///     [
+	///         "data/direct_messages_media/3333333333333333333-AbCdEfGh.jpg",
+	///         "data/direct_messages_media/3333333333333333333-IjKlMnOp.mp4",
+	///     ]
+	/// );
+	/// ```
+	pub fn media_file_names(&self, media_directory: &manifest::MediaDirectory) -> Vec<String> {
+		self.media_urls
+			.iter()
+			.filter_map(|url| self.media_file_name(url, media_directory))
+			.collect()
+	}
+
+	/// Path within archive expected for media at `url`, `None` when `url` names no file
+	pub(crate) fn media_file_name(
+		&self,
+		url: &str,
+		media_directory: &manifest::MediaDirectory,
+	) -> Option<String> {
+		let directory = media_directory.media_directory.trim_end_matches('/');
+		media_file_name(url).map(|name| format!("{directory}/{}-{name}", self.id))
+	}
+}
+
+/// Media attached to a message, resolved to file within archive
+#[derive(Debug, Clone)]
+pub struct MediaFile {
+	/// URL listed within `mediaUrls` of message
+	pub url: String,
+
+	/// Path of file within archive, `None` when archive lacks it
+	pub file_name: Option<String>,
+
+	/// Devices from `data/key-registry.js` that were registered when message was sent, whose
+	/// identity keys encrypted copies of media were exchanged with
+	pub devices: Vec<key_registry::DeviceMetadata>,
+}
+
+impl From<direct_messages::MessageCreate> for Message {
+	fn from(message: direct_messages::MessageCreate) -> Self {
+		Self {
+			id: message.id,
+			sender_id: message.sender_id,
+			recipient_id: Some(message.recipient_id),
+			text: message.text,
+			media_urls: message.media_urls,
+			reactions: message.reactions.unwrap_or_default(),
+			urls: message.urls,
+			created_at: message.created_at,
+		}
+	}
+}
+
+impl From<direct_messages::WelcomeMessageCreate> for Message {
+	fn from(message: direct_messages::WelcomeMessageCreate) -> Self {
+		Self {
+			id: message.id,
+			sender_id: message.sender_id,
+			recipient_id: message.recipient_id,
+			text: message.text,
+			media_urls: message.media_urls,
+			reactions: message.reactions.unwrap_or_default(),
+			urls: message.urls,
+			created_at: message.created_at,
+		}
+	}
+}
+
+impl From<direct_messages_group::MessageCreate> for Message {
+	fn from(message: direct_messages_group::MessageCreate) -> Self {
+		Self {
+			id: message.id,
+			sender_id: message.sender_id,
+			recipient_id: None,
+			text: message.text,
+			media_urls: message.media_urls,
+			reactions: message.reactions.unwrap_or_default(),
+			urls: message.urls,
+			created_at: message.created_at,
+		}
+	}
+}
+
+/// Messages of one conversation, ordered oldest first, and user IDs of everyone taking part
+#[derive(Debug, Clone)]
+pub struct Conversation {
+	conversation_id: String,
+	is_group: bool,
+	messages: Vec<Message>,
+	participants: BTreeSet<String>,
+}
+
+impl Conversation {
+	/// ID of conversation, `<user_id>-<user_id>` for one-to-one conversations
+	pub fn conversation_id(&self) -> &str {
+		&self.conversation_id
+	}
+
+	/// Was conversation read from `data/direct-messages-group.js`
+	pub fn is_group(&self) -> bool {
+		self.is_group
+	}
+
+	/// Messages ordered by `created_at`, then by ID for messages sent within same millisecond
+	pub fn messages(&self) -> &[Message] {
+		&self.messages
+	}
+
+	/// User IDs of everyone that took part in conversation
+	pub fn participants(&self) -> &BTreeSet<String> {
+		&self.participants
+	}
+
+	/// Messages sent by `user_id`, oldest first
+	pub fn messages_from<'a>(&'a self, user_id: &'a str) -> impl Iterator<Item = &'a Message> {
+		self.messages
+			.iter()
+			.filter(move |message| message.sender_id == user_id)
+	}
+
+	/// Every reaction to messages of conversation, with message reacted to, ordered by when
+	/// reaction was made
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::dm::Conversation;
+	/// use twitter_archive::structs::direct_messages::DMConversation;
+	///
+	/// let json = r#"{
+	///   "conversationId": "111111111-222222222",
+	///   "messages": [
+	///     {
+	///       "messageCreate": {
+	///         "recipientId": "222222222",
+	///         "reactions": [
+	///           {
+	///             "senderId": "222222222",
+	///             "reactionKey": "funny",
+	///             "eventId": "6666666666666666666",
+	///             "createdAt": "2023-08-12T17:20:00.000Z"
+	///           },
+	///           {
+	///             "senderId": "111111111",
+	///             "reactionKey": "agree",
+	///             "eventId": "5555555555555555555",
+	///             "createdAt": "2023-08-12T17:15:00.000Z"
+	///           }
+	///         ],
+	///         "urls": [],
+	///         "text": "Salutations!",
+	///         "mediaUrls": [],
+	///         "senderId": "111111111",
+	///         "id": "3333333333333333333",
+	///         "createdAt": "2023-08-12T17:10:37.000Z"
+	///       }
+	///     }
+	///   ]
+	/// }"#;
+	///
+	/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+	/// let conversation = Conversation::from(data);
+	///
+	/// let history: Vec<(&str, &str, Option<&str>)> = conversation
+	///     .reactions()
+	///     .map(|(message, reaction)| {
+	///         (message.id.as_str(), reaction.sender_id.as_str(), reaction.emoji())
+	///     })
+	///     .collect();
+	///
+	/// assert_eq!(
+	///     history,
+	///     [
+	///         ("3333333333333333333", "111111111", Some("👍")),
+	///         ("3333333333333333333", "222222222", Some("😂")),
+	///     ]
+	/// );
+	/// ```
+	pub fn reactions(
+		&self,
+	) -> impl Iterator<Item = (&Message, &direct_message::MessageCreateReaction)> {
+		let mut reactions: Vec<(&Message, &direct_message::MessageCreateReaction)> = self
+			.messages
+			.iter()
+			.flat_map(|message| {
+				message
+					.reactions
+					.iter()
+					.map(move |reaction| (message, reaction))
+			})
+			.collect();
+		reactions.sort_by(|(_, a), (_, b)| {
+			a.created_at
+				.cmp(&b.created_at)
+				.then_with(|| query::compare_ids(&a.event_id, &b.event_id))
+		});
+		reactions.into_iter()
+	}
+
+	/// Readable transcript of conversation, one `[timestamp] sender_id: text` entry per message
+	/// oldest first, for backups or review outside of archive
+	///
+	/// Text has HTML entities decoded and shortened links expanded, lines after the first are
+	/// indented, and each attached media is replaced by `[Media: <file_name>]` placeholder.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::dm::Conversation;
+	/// use twitter_archive::structs::direct_messages::DMConversation;
+	///
+	/// let json = r#"{
+	///   "conversationId": "111111111-222222222",
+	///   "messages": [
+	///     {
+	///       "messageCreate": {
+	///         "recipientId": "111111111",
+	///         "reactions": [],
+	///         "urls": [
+	///           {
+	///             "url": "https://t.co/Yot7Ijm9vG",
+	///             "expanded": "https://example.com/fish",
+	///             "display": "example.com/fish"
+	///           }
+	///         ],
+	///         "text": "Fish &amp; chips?\nRecipe at https://t.co/Yot7Ijm9vG https://t.co/AbCdEfGhIj",
+	///         "mediaUrls": [
+	///           "https://ton.twitter.com/dm/4444444444444444444/5555555555555555555/AbCdEfGh.jpg"
+	///         ],
+	///         "senderId": "222222222",
+	///         "id": "4444444444444444444",
+	///         "createdAt": "2023-08-12T17:12:00.000Z"
+	///       }
+	///     },
+	///     {
+	///       "messageCreate": {
+	///         "recipientId": "222222222",
+	///         "reactions": [],
+	///         "urls": [],
+	///         "text": "Salutations!",
+	///         "mediaUrls": [],
+	///         "senderId": "111111111",
+	///         "id": "3333333333333333333",
+	///         "createdAt": "2023-08-12T17:10:37.000Z"
+	///       }
+	///     }
+	///   ]
+	/// }"#;
+	///
+	/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+	/// let conversation = Conversation::from(data);
+	///
+	/// assert_eq!(
+	///     conversation.to_text_log(),
+	///     [
+	///         "[2023-08-12 17:10:37 UTC] 111111111: Salutations!",
+	///         "[2023-08-12 17:12:00 UTC] 222222222: Fish & chips?",
+	///         "  Recipe at https://example.com/fish [Media: AbCdEfGh.jpg]",
+	///         "",
+	///     ].join("\n")
+	/// );
+	/// ```
+	pub fn to_text_log(&self) -> String {
+		let mut log = String::new();
+		for message in &self.messages {
+			let mut text = message.text_decoded().into_owned();
+
+			for url in &message.urls {
+				text = text.replace(&url.url, &url.expanded);
+			}
+			// Links to attached media are the only shortened links left without `urls` entry
+			if !message.media_urls.is_empty() {
+				let last_word = text.rsplit(char::is_whitespace).next().unwrap_or_default();
+				if last_word.starts_with("https://t.co/") {
+					text.truncate(text.len() - last_word.len());
+					text.truncate(text.trim_end().len());
+				}
+			}
+			for url in &message.media_urls {
+				let name = media_file_name(url).unwrap_or(url);
+				if !text.is_empty() {
+					text.push(' ');
+				}
+				text.push_str(&format!("[Media: {name}]"));
+			}
+
+			log.push_str(&format!(
+				"[{}] {}: {}\n",
+				message.created_at.format("%F %T UTC"),
+				message.sender_id,
+				text.trim_end().replace('\n', "\n  ")
+			));
+		}
+		log
+	}
+
+	/// Sort messages and gather participants from them
+	fn new(
+		conversation_id: String,
+		is_group: bool,
+		mut messages: Vec<Message>,
+		mut participants: BTreeSet<String>,
+	) -> Self {
+		messages.sort_by(|a, b| {
+			a.created_at
+				.cmp(&b.created_at)
+				.then_with(|| query::compare_ids(&a.id, &b.id))
+		});
+
+		for message in &messages {
+			participants.insert(message.sender_id.clone());
+			participants.extend(message.recipient_id.clone());
+		}
+
+		Self {
+			conversation_id,
+			is_group,
+			messages,
+			participants,
+		}
+	}
+}
+
+/// Welcome messages of business accounts are kept alongside other messages, while markers of
+/// deleted messages, and events of unknown kinds, are skipped
+impl From<direct_messages::DMConversation> for Conversation {
+	fn from(conversation: direct_messages::DMConversation) -> Self {
+		let participants = match conversation.conversation_id.split_once('-') {
+			Some((a, b)) if !a.is_empty() && !b.is_empty() => {
+				BTreeSet::from([a.to_string(), b.to_string()])
+			}
+			_ => BTreeSet::new(),
+		};
+
+		let messages = conversation
+			.messages
+			.into_iter()
+			.filter_map(|message| match message {
+				direct_messages::Message::MessageCreate(message) => Some(message.into()),
+				direct_messages::Message::WelcomeMessageCreate(message) => Some(message.into()),
+				direct_messages::Message::MessageDestroy(_)
+				| direct_messages::Message::Other(_) => None,
+			})
+			.collect();
+
+		Self::new(conversation.conversation_id, false, messages, participants)
+	}
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::dm::Conversation;
+/// use twitter_archive::structs::direct_messages_group::DmConversation;
+///
+/// let json = r#"{
+///   "conversationId": "6666666666666666666",
+///   "messages": [
+///     {
+///       "messageCreate": {
+///         "reactions": [],
+///         "urls": [],
+///         "text": "Sup!?",
+///         "mediaUrls": [],
+///         "senderId": "222222222",
+///         "id": "4444444444444444444",
+///         "createdAt": "2023-08-12T17:10:37.000Z"
+///       }
+///     },
+///     {
+///       "participantsLeave": {
+///         "userIds": [ "333333333" ],
+///         "createdAt": "2023-08-12T17:09:00.000Z"
+///       }
+///     },
+///     {
+///       "joinConversation": {
+///         "initiatingUserId": "111111111",
+///         "participantsSnapshot": [ "222222222", "333333333" ],
+///         "createdAt": "2023-08-12T17:00:00.000Z"
+///       }
+///     }
+///   ]
+/// }"#;
+///
+/// let data: DmConversation = serde_json::from_str(&json).unwrap();
+/// let conversation = Conversation::from(data);
+///
+/// assert!(conversation.is_group());
+/// assert_eq!(conversation.messages().len(), 1);
+/// assert_eq!(
+///     conversation.participants().iter().collect::<Vec<_>>(),
+///     ["111111111", "222222222", "333333333"]
+/// );
+/// ```
+impl From<direct_messages_group::DmConversation> for Conversation {
+	fn from(conversation: direct_messages_group::DmConversation) -> Self {
+		let mut participants = BTreeSet::new();
+		let mut messages = Vec::new();
+
+		for message in conversation.messages {
+			match message {
+				direct_messages_group::Message::MessageCreate(message) => {
+					messages.push(message.into());
+				}
+				direct_messages_group::Message::ParticipantsLeave(leave) => {
+					participants.extend(leave.user_ids);
+				}
+				direct_messages_group::Message::JoinConversation(join) => {
+					participants.insert(join.initiating_user_id);
+					participants.extend(join.participants_snapshot);
+				}
+				direct_messages_group::Message::ParticipantsJoin(join) => {
+					participants.insert(join.initiating_user_id);
+					participants.extend(join.user_ids);
+				}
+				direct_messages_group::Message::ConversationNameUpdate(update) => {
+					participants.insert(update.initiating_user_id);
+				}
+				direct_messages_group::Message::MessageDestroy(_)
+				| direct_messages_group::Message::Other(_) => {}
+			}
+		}
+
+		Self::new(conversation.conversation_id, true, messages, participants)
+	}
+}
+
+/// Last path segment of `url`, without query or fragment
+fn media_file_name(url: &str) -> Option<&str> {
+	url.split(['?', '#'])
+		.next()?
+		.rsplit('/')
+		.next()
+		.filter(|name| !name.is_empty())
+}
@@ -0,0 +1,157 @@
+#!/usr/bin/env rust
+
+//! Link every version of an edited Tweet found within an archive into one ordered chain
+//!
+//! Each version carries `edit_info.initial.editTweetIds`, listing IDs of all versions from first
+//! to latest, so versions sharing first ID belong to same chain.  Versions that were deleted, or
+//! are otherwise missing from archive, are skipped while their IDs remain listed.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::edit::EditChain;
+//! use twitter_archive::structs::tweets::Tweet;
+//!
+//! let json = r#"{
+//!   "edit_info": {
+//!     "initial": {
+//!       "editTweetIds": [ "1111111111111111111", "2222222222222222222" ],
+//!       "editableUntil": "2023-08-12T17:10:37.000Z",
+//!       "editsRemaining": "4",
+//!       "isEditEligible": true
+//!     }
+//!   },
+//!   "retweeted": false,
+//!   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!   "entities": {
+//!     "hashtags": [],
+//!     "symbols": [],
+//!     "user_mentions": [],
+//!     "urls": []
+//!   },
+//!   "display_text_range": [ "0", "11" ],
+//!   "favorite_count": "0",
+//!   "id_str": "1111111111111111111",
+//!   "truncated": false,
+//!   "retweet_count": "0",
+//!   "id": "1111111111111111111",
+//!   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!   "favorited": false,
+//!   "full_text": "Helo, world",
+//!   "lang": "en"
+//! }"#;
+//!
+//! let first: Tweet = serde_json::from_str(&json).unwrap();
+//!
+//! let mut second = first.clone();
+//! second.id_str = "2222222222222222222".to_string();
+//! second.full_text = "Hello, world".to_string();
+//!
+//! let mut unedited = first.clone();
+//! unedited.id_str = "3333333333333333333".to_string();
+//! unedited.edit_info.initial.edit_tweet_ids = vec![unedited.id_str.clone()];
+//!
+//! let tweets = vec![second, unedited, first];
+//! let chains = EditChain::collect(&tweets);
+//!
+//! assert_eq!(chains.len(), 1);
+//!
+//! let chain = &chains[0];
+//! assert_eq!(chain.initial_id(), "1111111111111111111");
+//! assert!(chain.is_complete());
+//!
+//! let texts: Vec<&str> = chain.versions().iter().map(|tweet| tweet.full_text.as_str()).collect();
+//! assert_eq!(texts, ["Helo, world", "Hello, world"]);
+//!
+//! // Consecutive versions, ready for diffing
+//! for (before, after) in chain.pairs() {
+//!     assert_ne!(before.full_text, after.full_text);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::structs::tweets::Tweet;
+
+/// Versions of one edited Tweet that are present within archive, ordered from first to latest
+#[derive(Debug)]
+pub struct EditChain<'t, T> {
+	ids: Vec<String>,
+	versions: Vec<&'t T>,
+}
+
+impl<'t, T: AsRef<Tweet>> EditChain<'t, T> {
+	/// Chains of every edited Tweet among `tweets`, which may be `Tweet`, `TweetObject`, or
+	/// references to either, ordered by first appearance of any version within `tweets`
+	///
+	/// Tweets that were never edited, with only their own ID listed, are left out.
+	pub fn collect(tweets: &'t [T]) -> Vec<Self> {
+		let by_id: HashMap<&str, &'t T> = tweets
+			.iter()
+			.map(|item| (item.as_ref().id_str.as_str(), item))
+			.collect();
+
+		let mut order: Vec<&str> = Vec::new();
+		let mut ids_by_initial: HashMap<&str, &[String]> = HashMap::new();
+		for item in tweets {
+			let ids = item.as_ref().edit_tweet_ids();
+			if ids.len() < 2 {
+				continue;
+			}
+
+			// Later versions may list more IDs than earlier ones, so keep longest listing
+			match ids_by_initial.get_mut(ids[0].as_str()) {
+				Some(known) if known.len() >= ids.len() => {}
+				Some(known) => *known = ids,
+				None => {
+					order.push(&ids[0]);
+					ids_by_initial.insert(&ids[0], ids);
+				}
+			}
+		}
+
+		order
+			.into_iter()
+			.map(|initial| {
+				let ids = ids_by_initial[initial];
+				Self {
+					ids: ids.to_vec(),
+					versions: ids
+						.iter()
+						.filter_map(|id| by_id.get(id.as_str()).copied())
+						.collect(),
+				}
+			})
+			.collect()
+	}
+
+	/// ID of first version, which Tweet was originally published with
+	pub fn initial_id(&self) -> &str {
+		&self.ids[0]
+	}
+
+	/// IDs of every version, from first to latest, including those missing from archive
+	pub fn ids(&self) -> &[String] {
+		&self.ids
+	}
+
+	/// Versions present within archive, from first to latest
+	pub fn versions(&self) -> &[&'t T] {
+		&self.versions
+	}
+
+	/// Latest version present within archive
+	pub fn latest(&self) -> Option<&'t T> {
+		self.versions.last().copied()
+	}
+
+	/// Is every listed version present within archive
+	pub fn is_complete(&self) -> bool {
+		self.versions.len() == self.ids.len()
+	}
+
+	/// Each version present within archive paired with next one, from first to latest
+	pub fn pairs(&self) -> impl Iterator<Item = (&'t T, &'t T)> + '_ {
+		self.versions.windows(2).map(|pair| (pair[0], pair[1]))
+	}
+}
@@ -0,0 +1,233 @@
+#!/usr/bin/env rust
+
+//! Single error type for reading and parsing archives, so consumers need not juggle
+//! `zip::result::ZipError`, `std::io::Error`, and `serde_json::Error` themselves
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::parse;
+//! use twitter_archive::structs::verified::VerifiedObject;
+//! use twitter_archive::Error;
+//!
+//! let javascript = r#"window.YTD.verified.part0 = [ { "verified" : { } } ]"#;
+//!
+//! match parse::from_js_str::<Vec<VerifiedObject>>(javascript) {
+//!     Err(Error::Json { offset: Some(offset), .. }) => assert_eq!(&javascript[offset..], "} } ]"),
+//!     _ => panic!("Expected JSON error"),
+//! }
+//! ```
+//!
+//! Errors noticed within nested data name the field path, and date time-stamp errors name both
+//! offending value and expected format
+//!
+//! ```
+//! use twitter_archive::parse;
+//! use twitter_archive::structs::ip_audit::IpAuditObject;
+//!
+//! let javascript = r#"window.YTD.ip_audit.part0 = [
+//!   { "ipAudit" : { "accountId" : "1", "createdAt" : "yesterday", "loginIp" : "127.0.0.1" } }
+//! ]"#;
+//!
+//! let error = parse::from_js_str::<Vec<IpAuditObject>>(javascript).unwrap_err();
+//!
+//! assert!(error.to_string().starts_with(
+//!     r#"[0].ipAudit.createdAt: invalid date time-stamp "yesterday", expected format "%FT%T.%3fZ""#
+//! ));
+//! ```
+
+use std::io;
+
+use thiserror::Error;
+
+/// Errors that may happen while reading or parsing archives
+///
+/// Variants that may be raised without knowing which file is being read carry an optional
+/// `file_name`, which `reader::ArchiveReader` fills in.  Matches need a wildcard arm, as features
+/// and later releases add variants.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+	/// Reading from file system, zip entry, or other `Read` implementation failed
+	#[error("{}{source}", prefix(.file_name))]
+	Io {
+		/// Relative path to file within archive, such as `data/tweets.js`
+		file_name: Option<String>,
+
+		/// Underlying error
+		source: io::Error,
+	},
+
+	/// Zip file is malformed, or encrypted entries were read without a password
+	#[error("{0}")]
+	Zip(#[source] zip::result::ZipError),
+
+	/// Password did not decrypt encrypted zip entry
+	#[error("{file_name}: invalid password for file in archive")]
+	InvalidPassword {
+		/// Relative path to file within archive, such as `data/tweets.js`
+		file_name: String,
+	},
+
+	/// Archive does not contain expected file
+	#[error("{file_name}: file not found within archive")]
+	MissingFile {
+		/// Relative path to file within archive, such as `data/tweets.js`
+		file_name: String,
+	},
+
+	/// JavaScript prefix, such as `window.YTD.tweets.part0 = `, did not match expected global name
+	#[error("{}expected JavaScript prelude similar to `window.{expected} = `", prefix(.file_name))]
+	PreludeMismatch {
+		/// Relative path to file within archive, such as `data/tweets.js`
+		file_name: Option<String>,
+
+		/// Global name data was expected to be assigned to
+		expected: String,
+	},
+
+	/// Data did not match shape of expected data structure
+	#[error("{}{}{source}{}", prefix(.file_name), prefix(.path), offset_suffix(.offset))]
+	Json {
+		/// Relative path to file within archive, such as `data/tweets.js`
+		file_name: Option<String>,
+
+		/// Bytes from start of file, including JavaScript prefix, where error was noticed
+		offset: Option<usize>,
+
+		/// Field path, such as `[3].tweet.created_at`, where error was noticed
+		path: Option<String>,
+
+		/// Underlying error
+		source: serde_json::Error,
+	},
+
+	/// Export was asked for a column that records of its type lack
+	#[error("unknown column `{column}`")]
+	UnknownColumn {
+		/// Name of column, such as `full_text`
+		column: String,
+	},
+
+	/// Creating, or writing to, SQLite database failed
+	#[cfg(feature = "sqlite")]
+	#[error("{0}")]
+	Sqlite(#[from] rusqlite::Error),
+
+	/// Building Arrow record batch failed
+	#[cfg(feature = "arrow")]
+	#[error("{0}")]
+	Arrow(#[from] arrow_schema::ArrowError),
+
+	/// Writing Parquet file failed
+	#[cfg(feature = "arrow")]
+	#[error("{0}")]
+	Parquet(#[from] parquet::errors::ParquetError),
+}
+
+/// Shortcut for `Result` with `twitter_archive::Error`
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Format optional file name, or field path, as `name: ` so it may lead error messages
+fn prefix(name: &Option<String>) -> String {
+	name.as_ref()
+		.map(|name| format!("{name}: "))
+		.unwrap_or_default()
+}
+
+/// Format optional byte offset as ` (byte offset N)` so it may trail error messages
+fn offset_suffix(offset: &Option<usize>) -> String {
+	offset
+		.map(|offset| format!(" (byte offset {offset})"))
+		.unwrap_or_default()
+}
+
+impl Error {
+	/// Attach file name to errors that do not yet know it
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::parse;
+	///
+	/// let error = parse::strip_prelude("window.YTD.tweets.part0 = [ ]", "YTD.like")
+	///     .unwrap_err()
+	///     .with_file_name("data/like.js");
+	///
+	/// assert_eq!(error.file_name(), Some("data/like.js"));
+	/// assert_eq!(
+	///     error.to_string(),
+	///     "data/like.js: expected JavaScript prelude similar to `window.YTD.like = `"
+	/// );
+	/// ```
+	pub fn with_file_name(mut self, name: &str) -> Self {
+		if let Self::Io { file_name, .. }
+		| Self::PreludeMismatch { file_name, .. }
+		| Self::Json { file_name, .. } = &mut self
+		{
+			file_name.get_or_insert_with(|| name.to_string());
+		}
+		self
+	}
+
+	/// Relative path to file within archive that error is about, if known
+	pub fn file_name(&self) -> Option<&str> {
+		match self {
+			Self::Io { file_name, .. }
+			| Self::PreludeMismatch { file_name, .. }
+			| Self::Json { file_name, .. } => file_name.as_deref(),
+			Self::MissingFile { file_name } | Self::InvalidPassword { file_name } => {
+				Some(file_name)
+			}
+			_ => None,
+		}
+	}
+}
+
+impl From<io::Error> for Error {
+	fn from(source: io::Error) -> Self {
+		Self::Io {
+			file_name: None,
+			source,
+		}
+	}
+}
+
+impl From<zip::result::ZipError> for Error {
+	fn from(source: zip::result::ZipError) -> Self {
+		match source {
+			zip::result::ZipError::Io(source) => source.into(),
+			source => Self::Zip(source),
+		}
+	}
+}
+
+impl From<serde_json::Error> for Error {
+	fn from(source: serde_json::Error) -> Self {
+		if source.is_io() {
+			return io::Error::from(source).into();
+		}
+		Self::Json {
+			file_name: None,
+			offset: None,
+			path: None,
+			source,
+		}
+	}
+}
+
+/// Allows `?` within functions returning `std::io::Result`
+impl From<Error> for io::Error {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::Io {
+				file_name: None,
+				source,
+			} => source,
+			Error::Io { ref source, .. } => io::Error::new(source.kind(), error),
+			Error::MissingFile { .. } => io::Error::new(io::ErrorKind::NotFound, error),
+			Error::UnknownColumn { .. } => io::Error::new(io::ErrorKind::InvalidInput, error),
+			error => io::Error::new(io::ErrorKind::InvalidData, error),
+		}
+	}
+}
@@ -0,0 +1,465 @@
+#!/usr/bin/env rust
+
+//! Convert Tweets, and threads of Tweets, into ActivityStreams 2.0 `Create` activities wrapping
+//! `Note` objects, as used by ActivityPub servers such as Mastodon
+//!
+//! Notes are attributed to `Options::actor`, with IDs formed as `<actor>/statuses/<TWEET_ID>`,
+//! addressed publicly with a copy to `<actor>/followers`, and link back to Twitter via `url`.
+//! Content is HTML with links expanded and link to attached media removed, media becomes
+//! `Document` attachments, and mentions and hashtags become `Mention` and `Hashtag` tags.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::export::activitystreams;
+//! use twitter_archive::structs::tweets::Tweet;
+//!
+//! let json = r#"{
+//!   "edit_info": {
+//!     "initial": {
+//!       "editTweetIds": [ "1111111111111111111" ],
+//!       "editableUntil": "2023-08-12T17:10:37.000Z",
+//!       "editsRemaining": "5",
+//!       "isEditEligible": true
+//!     }
+//!   },
+//!   "retweeted": false,
+//!   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!   "entities": {
+//!     "hashtags": [
+//!       {
+//!         "text": "rustlang",
+//!         "indices": [ "17", "26" ]
+//!       }
+//!     ],
+//!     "symbols": [],
+//!     "user_mentions": [],
+//!     "urls": []
+//!   },
+//!   "extended_entities": {
+//!     "media": [
+//!       {
+//!         "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+//!         "indices": [ "27", "50" ],
+//!         "url": "https://t.co/CCCCCCCCCC",
+//!         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "id_str": "3333333333333333333",
+//!         "id": "3333333333333333333",
+//!         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "sizes": {
+//!           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+//!           "small": { "w": "680", "h": "383", "resize": "fit" },
+//!           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+//!           "large": { "w": "1280", "h": "720", "resize": "fit" }
+//!         },
+//!         "type": "photo",
+//!         "display_url": "pic.twitter.com/CCCCCCCCCC"
+//!       }
+//!     ]
+//!   },
+//!   "display_text_range": [ "0", "26" ],
+//!   "favorite_count": "0",
+//!   "id_str": "1111111111111111111",
+//!   "truncated": false,
+//!   "retweet_count": "0",
+//!   "id": "1111111111111111111",
+//!   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!   "favorited": false,
+//!   "full_text": "Fish &amp; chips #rustlang https://t.co/CCCCCCCCCC",
+//!   "lang": "en"
+//! }"#;
+//!
+//! let data: Tweet = serde_json::from_str(&json).unwrap();
+//!
+//! let options = activitystreams::Options {
+//!     actor: "https://example.social/users/alice".to_string(),
+//!     media_directory: Some("media".to_string()),
+//! };
+//!
+//! let activity = activitystreams::create(&data, &options);
+//! assert_eq!(
+//!     serde_json::to_value(&activity).unwrap(),
+//!     serde_json::json!({
+//!         "@context": "https://www.w3.org/ns/activitystreams",
+//!         "id": "https://example.social/users/alice/statuses/1111111111111111111/activity",
+//!         "type": "Create",
+//!         "actor": "https://example.social/users/alice",
+//!         "published": "2023-08-12T16:10:37.000Z",
+//!         "to": [ "https://www.w3.org/ns/activitystreams#Public" ],
+//!         "cc": [ "https://example.social/users/alice/followers" ],
+//!         "object": {
+//!             "id": "https://example.social/users/alice/statuses/1111111111111111111",
+//!             "type": "Note",
+//!             "attributedTo": "https://example.social/users/alice",
+//!             "published": "2023-08-12T16:10:37.000Z",
+//!             "url": "https://twitter.com/i/web/status/1111111111111111111",
+//!             "content": "<p>Fish &amp; chips <a href=\"https://twitter.com/hashtag/rustlang\">#rustlang</a></p>",
+//!             "to": [ "https://www.w3.org/ns/activitystreams#Public" ],
+//!             "cc": [ "https://example.social/users/alice/followers" ],
+//!             "sensitive": false,
+//!             "tag": [
+//!                 {
+//!                     "type": "Hashtag",
+//!                     "href": "https://twitter.com/hashtag/rustlang",
+//!                     "name": "#rustlang"
+//!                 }
+//!             ],
+//!             "attachment": [
+//!                 {
+//!                     "type": "Document",
+//!                     "mediaType": "image/jpeg",
+//!                     "url": "media/1111111111111111111-F3UNnlRWoAAQXa_.jpg"
+//!                 }
+//!             ]
+//!         }
+//!     })
+//! );
+//! ```
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::render::html;
+use crate::structs::tweets::{MediaType, Tweet, TweetMedia};
+
+/// JSON-LD context of ActivityStreams 2.0 documents
+pub const CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+
+/// Special collection addressing activities and objects to everyone
+pub const PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+/// Choices for `activitystreams::note`, `activitystreams::create`, and `activitystreams::thread`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Options {
+	/// ID of account Notes are attributed to, e.g. `https://example.social/users/alice`
+	pub actor: String,
+
+	/// Directory attachment URLs point within, e.g. `media`, where `None` points to remote
+	/// `pbs.twimg.com` and `video.twimg.com` URLs instead
+	pub media_directory: Option<String>,
+}
+
+/// Activity announcing that `actor` created `object`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct Create {
+	/// JSON-LD context, set for standalone documents and left out when nested within collections
+	#[serde(rename = "@context", default, skip_serializing_if = "Option::is_none")]
+	pub context: Option<String>,
+
+	/// Note ID suffixed with `/activity`
+	pub id: String,
+
+	/// Always `Create`
+	#[serde(rename = "type")]
+	pub kind: String,
+
+	/// Same as `Options::actor`
+	pub actor: String,
+
+	/// Same as `Note::published`
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub published: DateTime<Utc>,
+
+	/// Same as `Note::to`
+	pub to: Vec<String>,
+
+	/// Same as `Note::cc`
+	pub cc: Vec<String>,
+
+	/// Note converted from Tweet
+	pub object: Note,
+}
+
+/// Short post converted from one Tweet
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Note {
+	/// `<actor>/statuses/<TWEET_ID>`
+	pub id: String,
+
+	/// Always `Note`
+	#[serde(rename = "type")]
+	pub kind: String,
+
+	/// Same as `Options::actor`
+	pub attributed_to: String,
+
+	/// When Tweet was created
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub published: DateTime<Utc>,
+
+	/// Link to original Tweet, see `Tweet::permalink`
+	pub url: String,
+
+	/// HTML of Tweet text with links expanded
+	pub content: String,
+
+	/// ID of Note when replying to Tweet converted alongside, otherwise link to Tweet on Twitter
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub in_reply_to: Option<String>,
+
+	/// Primary audience, the public collection
+	pub to: Vec<String>,
+
+	/// Secondary audience, followers of actor
+	pub cc: Vec<String>,
+
+	/// Same as `Tweet::possibly_sensitive`, where missing is `false`
+	pub sensitive: bool,
+
+	/// Mentions and hashtags
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub tag: Vec<Tag>,
+
+	/// Attached photos, videos, and animated GIFs
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub attachment: Vec<Attachment>,
+}
+
+/// Mention of account, or hashtag, within Note
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tag {
+	/// `Mention` or `Hashtag`
+	#[serde(rename = "type")]
+	pub kind: String,
+
+	/// Link to Twitter profile, or hashtag search
+	pub href: String,
+
+	/// Text as found within Tweet, e.g. `@EXAMPLE` or `#rustlang`
+	pub name: String,
+}
+
+/// Media file attached to Note
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+	/// Always `Document`
+	#[serde(rename = "type")]
+	pub kind: String,
+
+	/// MIME type, e.g. `image/jpeg` or `video/mp4`
+	pub media_type: String,
+
+	/// Path within `Options::media_directory`, or remote URL
+	pub url: String,
+}
+
+/// Convert `tweet` into Note, where replies point at Tweets on Twitter
+pub fn note(tweet: &Tweet, options: &Options) -> Note {
+	let in_reply_to = tweet
+		.in_reply_to_status_id_str
+		.as_ref()
+		.map(|id| format!("https://twitter.com/i/web/status/{id}"));
+
+	Note {
+		id: status_id(&tweet.id_str, options),
+		kind: "Note".to_string(),
+		attributed_to: options.actor.clone(),
+		published: tweet.created_at,
+		url: tweet.permalink(),
+		content: content(tweet),
+		in_reply_to,
+		to: vec![PUBLIC.to_string()],
+		cc: vec![followers(options)],
+		sensitive: tweet.possibly_sensitive.unwrap_or_default(),
+		tag: tags(tweet),
+		attachment: tweet
+			.media()
+			.iter()
+			.map(|media| attachment(tweet, media, options))
+			.collect(),
+	}
+}
+
+/// Convert `tweet` into Note wrapped by Create activity, see module documentation for example
+pub fn create(tweet: &Tweet, options: &Options) -> Create {
+	let mut activity = wrap(note(tweet, options));
+	activity.context = Some(CONTEXT.to_string());
+	activity
+}
+
+/// Convert `tweets`, in order given, into Create activities where replies to Tweets among
+/// `tweets` point at IDs of their Notes rather than at Twitter
+///
+/// Activities lack `@context`, so they may be nested within collections, such as outboxes.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::export::activitystreams;
+/// use twitter_archive::structs::tweets::Tweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [ "1111111111111111111" ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [ "0", "3" ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "1/2",
+///   "lang": "en"
+/// }"#;
+///
+/// let first: Tweet = serde_json::from_str(&json).unwrap();
+///
+/// let mut second = first.clone();
+/// second.id_str = "2222222222222222222".to_string();
+/// second.full_text = "2/2".to_string();
+/// second.in_reply_to_status_id_str = Some(first.id_str.clone());
+///
+/// // Reply to Tweet not within thread
+/// let mut other = first.clone();
+/// other.id_str = "4444444444444444444".to_string();
+/// other.in_reply_to_status_id_str = Some("9999999999999999999".to_string());
+///
+/// let options = activitystreams::Options {
+///     actor: "https://example.social/users/alice".to_string(),
+///     ..activitystreams::Options::default()
+/// };
+///
+/// let activities = activitystreams::thread(&[first, second, other], &options);
+///
+/// assert_eq!(activities.len(), 3);
+/// assert!(activities.iter().all(|activity| activity.context.is_none()));
+/// assert_eq!(activities[0].object.in_reply_to, None);
+/// assert_eq!(
+///     activities[1].object.in_reply_to.as_deref(),
+///     Some("https://example.social/users/alice/statuses/1111111111111111111")
+/// );
+/// assert_eq!(
+///     activities[2].object.in_reply_to.as_deref(),
+///     Some("https://twitter.com/i/web/status/9999999999999999999")
+/// );
+/// ```
+pub fn thread<T: AsRef<Tweet>>(tweets: &[T], options: &Options) -> Vec<Create> {
+	let ids: HashSet<&str> = tweets
+		.iter()
+		.map(|item| item.as_ref().id_str.as_str())
+		.collect();
+
+	tweets
+		.iter()
+		.map(|item| {
+			let tweet = item.as_ref();
+			let mut note = note(tweet, options);
+			if let Some(parent) = &tweet.in_reply_to_status_id_str {
+				if ids.contains(parent.as_str()) {
+					note.in_reply_to = Some(status_id(parent, options));
+				}
+			}
+			wrap(note)
+		})
+		.collect()
+}
+
+/// Create activity, without `@context`, sharing addressing of `note`
+fn wrap(note: Note) -> Create {
+	Create {
+		context: None,
+		id: format!("{}/activity", note.id),
+		kind: "Create".to_string(),
+		actor: note.attributed_to.clone(),
+		published: note.published,
+		to: note.to.clone(),
+		cc: note.cc.clone(),
+		object: note,
+	}
+}
+
+/// ID of Note converted from Tweet with `id`
+fn status_id(id: &str, options: &Options) -> String {
+	format!("{}/statuses/{id}", options.actor.trim_end_matches('/'))
+}
+
+fn followers(options: &Options) -> String {
+	format!("{}/followers", options.actor.trim_end_matches('/'))
+}
+
+/// Paragraphs of Tweet text as HTML, with link to attached media removed
+fn content(tweet: &Tweet) -> String {
+	let (body, _) = html::text_body(tweet, false);
+
+	body.trim()
+		.split("\n\n")
+		.map(str::trim)
+		.filter(|paragraph| !paragraph.is_empty())
+		.map(|paragraph| format!("<p>{}</p>", paragraph.replace('\n', "<br>")))
+		.collect()
+}
+
+fn tags(tweet: &Tweet) -> Vec<Tag> {
+	let mentions = tweet.entities.user_mentions.iter().map(|mention| Tag {
+		kind: "Mention".to_string(),
+		href: format!("https://twitter.com/{}", mention.screen_name),
+		name: format!("@{}", mention.screen_name),
+	});
+
+	let hashtags = tweet.entities.hashtags.iter().map(|hashtag| Tag {
+		kind: "Hashtag".to_string(),
+		href: format!("https://twitter.com/hashtag/{}", hashtag.text),
+		name: format!("#{}", hashtag.text),
+	});
+
+	mentions.chain(hashtags).collect()
+}
+
+fn attachment(tweet: &Tweet, media: &TweetMedia, options: &Options) -> Attachment {
+	let url = match (&options.media_directory, media.file_name()) {
+		(Some(directory), Some(file_name)) => format!(
+			"{}/{}-{file_name}",
+			directory.trim_end_matches('/'),
+			tweet.id_str
+		),
+		_ => media.file_url().to_string(),
+	};
+
+	Attachment {
+		kind: "Document".to_string(),
+		media_type: media_type(media).to_string(),
+		url,
+	}
+}
+
+/// MIME type of file archives store for `media`
+pub(crate) fn media_type(media: &TweetMedia) -> &'static str {
+	if matches!(media.media_type, MediaType::Video | MediaType::AnimatedGif) {
+		return "video/mp4";
+	}
+
+	let extension = media
+		.file_name()
+		.and_then(|name| name.rsplit_once('.'))
+		.map(|(_, extension)| extension.to_ascii_lowercase());
+	match extension.as_deref() {
+		Some("png") => "image/png",
+		Some("gif") => "image/gif",
+		Some("webp") => "image/webp",
+		Some("mp4") => "video/mp4",
+		_ => "image/jpeg",
+	}
+}
@@ -0,0 +1,466 @@
+#!/usr/bin/env rust
+
+//! Convert Tweets, Likes, ad impressions, and IP audit log-ins into Arrow record batches, or
+//! Parquet files, for columnar analysis and ingestion into data warehouses
+//!
+//! Date time-stamps become millisecond `Timestamp` columns within the UTC time-zone, counts become
+//! `UInt64`, and lists, such as `hashtags` of Tweets, become `List` columns rather than being
+//! joined into text as `export::csv` does.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::export::arrow;
+//! use twitter_archive::structs::like::LikeObject;
+//!
+//! let json = r#"[
+//!   {
+//!     "like": {
+//!       "tweetId": "1111111111111111111",
+//!       "fullText": "Fish &amp; chips",
+//!       "expandedUrl": "https://twitter.com/i/web/status/1111111111111111111"
+//!     }
+//!   },
+//!   {
+//!     "like": {
+//!       "tweetId": "2222222222222222222",
+//!       "expandedUrl": "https://twitter.com/i/web/status/2222222222222222222"
+//!     }
+//!   }
+//! ]"#;
+//!
+//! let data: Vec<LikeObject> = serde_json::from_str(&json).unwrap();
+//! let likes = data.iter().map(|object| &object.like);
+//!
+//! let batch = arrow::to_record_batch(likes.clone()).unwrap();
+//! assert_eq!(batch.num_rows(), 2);
+//! assert_eq!(batch.schema().field(1).name(), "full_text");
+//! assert_eq!(batch.column(1).null_count(), 1);
+//!
+//! let mut buffer = Vec::new();
+//! arrow::to_parquet(&mut buffer, likes).unwrap();
+//! assert!(buffer.starts_with(b"PAR1"));
+//! assert!(buffer.ends_with(b"PAR1"));
+//! ```
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::OffsetBufferBuilder;
+use arrow_array::{
+	ArrayRef, BooleanArray, ListArray, StringArray, StructArray, TimestampMillisecondArray,
+	UInt64Array,
+};
+use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+use chrono::{DateTime, Utc};
+use parquet::arrow::ArrowWriter;
+
+pub use arrow_array::RecordBatch;
+pub use arrow_schema::SchemaRef;
+
+use crate::structs::{ad, ip_audit, like, tweets};
+use crate::Result;
+
+/// Data that may be converted into rows of Arrow record batches
+pub trait Columnar {
+	/// Names and types of columns, shared by every record batch of this type
+	fn schema() -> SchemaRef;
+
+	/// One array per field of `schema`, holding values of `records` in order
+	fn columns(records: &[&Self]) -> Vec<ArrayRef>;
+}
+
+/// Convert `records` into one record batch
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::export::arrow;
+/// use twitter_archive::structs::ip_audit::IpAuditObject;
+///
+/// let json = r#"[
+///   {
+///     "ipAudit": {
+///       "accountId": "111111111",
+///       "createdAt": "2023-05-30T13:31:42.908Z",
+///       "loginIp": "127.0.0.1"
+///     }
+///   }
+/// ]"#;
+///
+/// let data: Vec<IpAuditObject> = serde_json::from_str(&json).unwrap();
+/// let batch = arrow::to_record_batch(data.iter().map(|object| &object.ip_audit)).unwrap();
+///
+/// let created_at = batch
+///     .column_by_name("created_at")
+///     .unwrap()
+///     .as_any()
+///     .downcast_ref::<arrow_array::TimestampMillisecondArray>()
+///     .unwrap();
+/// assert_eq!(created_at.value(0), 1685453502908);
+/// ```
+pub fn to_record_batch<'a, T, I>(records: I) -> Result<RecordBatch>
+where
+	T: Columnar + 'a,
+	I: IntoIterator<Item = &'a T>,
+{
+	let records: Vec<&T> = records.into_iter().collect();
+	Ok(RecordBatch::try_new(T::schema(), T::columns(&records))?)
+}
+
+/// Write `records` to `writer` as a Parquet file
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::export::arrow;
+/// use twitter_archive::structs::tweets::TweetObject;
+///
+/// let json = r#"[
+///   {
+///     "tweet": {
+///       "edit_info": {
+///         "initial": {
+///           "editTweetIds": [ "1690395372546301952" ],
+///           "editableUntil": "2023-08-12T17:10:37.000Z",
+///           "editsRemaining": "5",
+///           "isEditEligible": true
+///         }
+///       },
+///       "retweeted": false,
+///       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///       "entities": {
+///         "hashtags": [ { "text": "rust", "indices": [ "15", "20" ] } ],
+///         "symbols": [],
+///         "user_mentions": [],
+///         "urls": []
+///       },
+///       "display_text_range": [ "0", "20" ],
+///       "favorite_count": "3",
+///       "id_str": "1690395372546301952",
+///       "truncated": false,
+///       "retweet_count": "1",
+///       "id": "1690395372546301952",
+///       "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///       "favorited": false,
+///       "full_text": "Fish &amp; chips #rust",
+///       "lang": "en"
+///     }
+///   }
+/// ]"#;
+///
+/// let data: Vec<TweetObject> = serde_json::from_str(&json).unwrap();
+/// let tweets = data.iter().map(|object| &object.tweet);
+///
+/// let batch = arrow::to_record_batch(tweets.clone()).unwrap();
+/// assert_eq!(batch.num_columns(), 17);
+/// assert_eq!(batch.column_by_name("in_reply_to_status_id").unwrap().null_count(), 1);
+///
+/// let mut buffer = Vec::new();
+/// arrow::to_parquet(&mut buffer, tweets).unwrap();
+/// assert!(buffer.starts_with(b"PAR1"));
+/// ```
+pub fn to_parquet<'a, W, T, I>(writer: W, records: I) -> Result<()>
+where
+	W: Write + Send,
+	T: Columnar + 'a,
+	I: IntoIterator<Item = &'a T>,
+{
+	let batch = to_record_batch(records)?;
+	let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+	writer.write(&batch)?;
+	writer.close()?;
+	Ok(())
+}
+
+impl Columnar for tweets::Tweet {
+	fn schema() -> SchemaRef {
+		Arc::new(Schema::new(vec![
+			Field::new("id", DataType::Utf8, false),
+			Field::new("created_at", timestamp(), false),
+			Field::new("full_text", DataType::Utf8, false),
+			Field::new("lang", DataType::Utf8, false),
+			Field::new("kind", DataType::Utf8, false),
+			Field::new("source", DataType::Utf8, false),
+			Field::new("favorite_count", DataType::UInt64, false),
+			Field::new("retweet_count", DataType::UInt64, false),
+			Field::new("in_reply_to_status_id", DataType::Utf8, true),
+			Field::new("in_reply_to_user_id", DataType::Utf8, true),
+			Field::new("in_reply_to_screen_name", DataType::Utf8, true),
+			Field::new("hashtags", string_list(), false),
+			Field::new("user_mentions", string_list(), false),
+			Field::new("urls", string_list(), false),
+			Field::new("media_urls", string_list(), false),
+			Field::new("possibly_sensitive", DataType::Boolean, true),
+			Field::new("permalink", DataType::Utf8, false),
+		]))
+	}
+
+	fn columns(records: &[&Self]) -> Vec<ArrayRef> {
+		vec![
+			strings(records, |tweet| Some(tweet.id_str.clone())),
+			timestamps(records, |tweet| &tweet.created_at),
+			strings(records, |tweet| {
+				Some(tweet.full_text_decoded().into_owned())
+			}),
+			strings(records, |tweet| Some(tweet.lang.clone())),
+			strings(records, |tweet| Some(tweet.kind().to_string())),
+			strings(records, |tweet| Some(tweet.source_client_name())),
+			Arc::new(UInt64Array::from_iter_values(
+				records.iter().map(|tweet| tweet.favorite_count as u64),
+			)),
+			Arc::new(UInt64Array::from_iter_values(
+				records.iter().map(|tweet| tweet.retweet_count as u64),
+			)),
+			strings(records, |tweet| tweet.in_reply_to_status_id_str.clone()),
+			strings(records, |tweet| tweet.in_reply_to_user_id_str.clone()),
+			strings(records, |tweet| tweet.in_reply_to_screen_name.clone()),
+			string_lists(records, |tweet| {
+				tweet
+					.entities
+					.hashtags
+					.iter()
+					.map(|tag| tag.text.clone())
+					.collect()
+			}),
+			string_lists(records, |tweet| {
+				tweet
+					.entities
+					.user_mentions
+					.iter()
+					.map(|mention| mention.screen_name.clone())
+					.collect()
+			}),
+			string_lists(records, |tweet| {
+				tweet
+					.entities
+					.urls
+					.iter()
+					.map(|url| url.expanded_url.clone())
+					.collect()
+			}),
+			string_lists(records, |tweet| {
+				tweet
+					.media()
+					.iter()
+					.map(|media| media.file_url().to_string())
+					.collect()
+			}),
+			Arc::new(BooleanArray::from(
+				records
+					.iter()
+					.map(|tweet| tweet.possibly_sensitive)
+					.collect::<Vec<Option<bool>>>(),
+			)),
+			strings(records, |tweet| Some(tweet.permalink())),
+		]
+	}
+}
+
+impl Columnar for like::Like {
+	fn schema() -> SchemaRef {
+		Arc::new(Schema::new(vec![
+			Field::new("tweet_id", DataType::Utf8, false),
+			Field::new("full_text", DataType::Utf8, true),
+			Field::new("expanded_url", DataType::Utf8, false),
+		]))
+	}
+
+	fn columns(records: &[&Self]) -> Vec<ArrayRef> {
+		vec![
+			strings(records, |like| Some(like.tweet_id.clone())),
+			strings(records, |like| like.full_text.clone()),
+			strings(records, |like| Some(like.expanded_url.clone())),
+		]
+	}
+}
+
+/// Matched targeting criteria become a list of `targeting_type` and `targeting_value` structs
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::export::arrow;
+/// use twitter_archive::structs::ad::Impression;
+///
+/// let json = r#"[
+///   {
+///     "deviceInfo": { "osType": "Desktop" },
+///     "displayLocation": "TweetConversation",
+///     "advertiserInfo": { "advertiserName": "EXAMPLE", "screenName": "@EXAMPLE" },
+///     "matchedTargetingCriteria": [
+///       { "targetingType": "Follower look-alikes", "targetingValue": "@EXAMPLE" },
+///       { "targetingType": "Platforms", "targetingValue": "Desktop" }
+///     ],
+///     "impressionTime": "2023-08-12 16:10:37"
+///   },
+///   {
+///     "deviceInfo": { "osType": "Ios" },
+///     "displayLocation": "TimelineHome",
+///     "advertiserInfo": {},
+///     "impressionTime": "2023-08-13 08:00:00"
+///   }
+/// ]"#;
+///
+/// let data: Vec<Impression> = serde_json::from_str(&json).unwrap();
+/// let batch = arrow::to_record_batch(&data).unwrap();
+///
+/// assert_eq!(batch.num_rows(), 2);
+/// assert_eq!(batch.column_by_name("advertiser_name").unwrap().null_count(), 1);
+/// assert_eq!(batch.column_by_name("targeting_criteria").unwrap().to_data().child_data()[0].len(), 2);
+/// ```
+impl Columnar for ad::Impression {
+	fn schema() -> SchemaRef {
+		Arc::new(Schema::new(vec![
+			Field::new("impression_time", timestamp(), false),
+			Field::new("os_type", DataType::Utf8, false),
+			Field::new("display_location", DataType::Utf8, false),
+			Field::new("advertiser_name", DataType::Utf8, true),
+			Field::new("advertiser_screen_name", DataType::Utf8, true),
+			Field::new("promoted_tweet_id", DataType::Utf8, true),
+			Field::new("promoted_tweet_text", DataType::Utf8, true),
+			Field::new(
+				"targeting_criteria",
+				DataType::List(Arc::new(Field::new_list_field(
+					DataType::Struct(targeting_criteria_fields()),
+					true,
+				))),
+				false,
+			),
+		]))
+	}
+
+	fn columns(records: &[&Self]) -> Vec<ArrayRef> {
+		let mut offsets = OffsetBufferBuilder::new(records.len());
+		let mut targeting_types = Vec::new();
+		let mut targeting_values = Vec::new();
+		for impression in records {
+			let criteria = impression
+				.matched_targeting_criteria
+				.as_deref()
+				.unwrap_or_default();
+			offsets.push_length(criteria.len());
+			for criteria in criteria {
+				targeting_types.push(Some(criteria.targeting_type.clone()));
+				targeting_values.push(criteria.targeting_value.clone());
+			}
+		}
+		let targeting_criteria = StructArray::new(
+			targeting_criteria_fields(),
+			vec![
+				Arc::new(StringArray::from(targeting_types)),
+				Arc::new(StringArray::from(targeting_values)),
+			],
+			None,
+		);
+
+		vec![
+			timestamps(records, |impression| &impression.impression_time),
+			strings(records, |impression| {
+				Some(impression.device_info.os_type.as_str().to_string())
+			}),
+			strings(records, |impression| {
+				Some(impression.display_location.as_str().to_string())
+			}),
+			strings(records, |impression| {
+				impression.advertiser_info.advertiser_name.clone()
+			}),
+			strings(records, |impression| {
+				impression.advertiser_info.screen_name.clone()
+			}),
+			strings(records, |impression| {
+				impression
+					.promoted_tweet_info
+					.as_ref()
+					.map(|tweet| tweet.tweet_id.clone())
+			}),
+			strings(records, |impression| {
+				impression
+					.promoted_tweet_info
+					.as_ref()
+					.map(|tweet| tweet.tweet_text.clone())
+			}),
+			Arc::new(ListArray::new(
+				Arc::new(Field::new_list_field(
+					DataType::Struct(targeting_criteria_fields()),
+					true,
+				)),
+				offsets.finish(),
+				Arc::new(targeting_criteria),
+				None,
+			)),
+		]
+	}
+}
+
+impl Columnar for ip_audit::IpAudit {
+	fn schema() -> SchemaRef {
+		Arc::new(Schema::new(vec![
+			Field::new("account_id", DataType::Utf8, false),
+			Field::new("created_at", timestamp(), false),
+			Field::new("login_ip", DataType::Utf8, false),
+		]))
+	}
+
+	fn columns(records: &[&Self]) -> Vec<ArrayRef> {
+		vec![
+			strings(records, |audit| Some(audit.account_id.clone())),
+			timestamps(records, |audit| &audit.created_at),
+			strings(records, |audit| Some(audit.login_ip.to_string())),
+		]
+	}
+}
+
+/// Millisecond time-stamps within UTC time-zone
+fn timestamp() -> DataType {
+	DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+}
+
+/// List of nullable text values
+fn string_list() -> DataType {
+	DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, true)))
+}
+
+/// Fields of structs within `targeting_criteria` column of ad impressions
+fn targeting_criteria_fields() -> Fields {
+	Fields::from(vec![
+		Field::new("targeting_type", DataType::Utf8, false),
+		Field::new("targeting_value", DataType::Utf8, true),
+	])
+}
+
+fn strings<T>(records: &[&T], value: impl Fn(&T) -> Option<String>) -> ArrayRef {
+	Arc::new(StringArray::from(
+		records
+			.iter()
+			.map(|record| value(record))
+			.collect::<Vec<Option<String>>>(),
+	))
+}
+
+fn timestamps<T>(records: &[&T], value: impl Fn(&T) -> &DateTime<Utc>) -> ArrayRef {
+	Arc::new(
+		TimestampMillisecondArray::from_iter_values(
+			records
+				.iter()
+				.map(|record| value(record).timestamp_millis()),
+		)
+		.with_timezone("UTC"),
+	)
+}
+
+fn string_lists<T>(records: &[&T], values: impl Fn(&T) -> Vec<String>) -> ArrayRef {
+	let mut offsets = OffsetBufferBuilder::new(records.len());
+	let mut flattened = Vec::new();
+	for record in records {
+		let values = values(record);
+		offsets.push_length(values.len());
+		flattened.extend(values);
+	}
+	Arc::new(ListArray::new(
+		Arc::new(Field::new_list_field(DataType::Utf8, true)),
+		offsets.finish(),
+		Arc::new(StringArray::from(flattened)),
+		None,
+	))
+}
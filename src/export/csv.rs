@@ -0,0 +1,442 @@
+#!/usr/bin/env rust
+
+//! Write Tweets, Likes, followers, followings, blocks, mutes, Direct Messages, and ad impressions
+//! as CSV for spreadsheets, with nested data flattened into one column per value
+//!
+//! Columns holding lists, such as `hashtags` of Tweets, join values with a space, and date
+//! time-stamps are written as RFC 3339, e.g. `2023-08-12T16:10:37Z`.  Fields are quoted only when
+//! they contain commas, quotes, or line breaks, or when `Options::escape_formulas` guards them.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::export::csv;
+//! use twitter_archive::structs::like::LikeObject;
+//!
+//! let json = r#"[
+//!   {
+//!     "like": {
+//!       "tweetId": "1111111111111111111",
+//!       "fullText": "Fish, chips, and \"mushy\" peas",
+//!       "expandedUrl": "https://twitter.com/i/web/status/1111111111111111111"
+//!     }
+//!   },
+//!   {
+//!     "like": {
+//!       "tweetId": "2222222222222222222",
+//!       "expandedUrl": "https://twitter.com/i/web/status/2222222222222222222"
+//!     }
+//!   }
+//! ]"#;
+//!
+//! let data: Vec<LikeObject> = serde_json::from_str(&json).unwrap();
+//! let likes = data.iter().map(|object| &object.like);
+//!
+//! let mut buffer = Vec::new();
+//! csv::to_csv(&mut buffer, likes.clone(), &csv::Options::default()).unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(buffer).unwrap(),
+//!     [
+//!         "tweet_id,full_text,expanded_url",
+//!         r#"1111111111111111111,"Fish, chips, and ""mushy"" peas",https://twitter.com/i/web/status/1111111111111111111"#,
+//!         "2222222222222222222,,https://twitter.com/i/web/status/2222222222222222222",
+//!         "",
+//!     ].join("\n")
+//! );
+//!
+//! // Select, and order, columns by name
+//! let options = csv::Options {
+//!     columns: Some(vec!["expanded_url".to_string(), "tweet_id".to_string()]),
+//!     ..csv::Options::default()
+//! };
+//!
+//! let mut buffer = Vec::new();
+//! csv::to_csv(&mut buffer, likes.clone(), &options).unwrap();
+//! assert!(String::from_utf8(buffer).unwrap().starts_with("expanded_url,tweet_id\n"));
+//!
+//! // Names records lack are refused before anything is written
+//! let options = csv::Options {
+//!     columns: Some(vec!["favorite_count".to_string()]),
+//!     ..csv::Options::default()
+//! };
+//!
+//! let mut buffer = Vec::new();
+//! let error = csv::to_csv(&mut buffer, likes, &options).unwrap_err();
+//! assert_eq!(error.to_string(), "unknown column `favorite_count`");
+//! assert!(buffer.is_empty());
+//!
+//! // Formulas, even those hidden behind a leading tab, are escaped by default
+//! let mut like = data[0].like.clone();
+//! like.full_text = Some("\t=HYPERLINK(\"https://example.com\")".to_string());
+//!
+//! let options = csv::Options {
+//!     columns: Some(vec!["full_text".to_string()]),
+//!     ..csv::Options::default()
+//! };
+//!
+//! let mut buffer = Vec::new();
+//! csv::to_csv(&mut buffer, [&like], &options).unwrap();
+//! assert_eq!(
+//!     String::from_utf8(buffer).unwrap(),
+//!     "full_text\n\"'\t=HYPERLINK(\"\"https://example.com\"\")\"\n"
+//! );
+//! ```
+
+use std::io::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::query::MessageRef;
+use crate::structs::{ad, block, follow, like, mute, tweets};
+use crate::{Error, Result};
+
+/// Data that may be written as one CSV row
+pub trait Record {
+	/// Names of every column, in order written when `Options::columns` is `None`
+	const COLUMNS: &'static [&'static str];
+
+	/// Value of `column` flattened to text, empty for missing values and names not within
+	/// `COLUMNS`
+	fn field(&self, column: &str) -> String;
+}
+
+impl<T: Record + ?Sized> Record for &T {
+	const COLUMNS: &'static [&'static str] = T::COLUMNS;
+
+	fn field(&self, column: &str) -> String {
+		T::field(self, column)
+	}
+}
+
+/// Choices for `csv::to_csv`, where `Options::default()` writes every column and escapes formulas
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Options {
+	/// Columns to write, in order, where `None` writes every column of `Record::COLUMNS`
+	pub columns: Option<Vec<String>>,
+
+	/// Prefix fields starting with `=`, `+`, `-`, `@`, tab, or carriage return with `'`, so
+	/// spreadsheets do not evaluate them as formulas, e.g. replies starting with `@screen_name`
+	///
+	/// > Warning; archives hold text written by other accounts, such as Direct Messages and
+	/// > Likes, so disabling this lets anyone who messaged the archive owner run formulas in
+	/// > spreadsheets opening the output.  Only disable it for tools reading CSV as plain data.
+	pub escape_formulas: bool,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			columns: None,
+			escape_formulas: true,
+		}
+	}
+}
+
+/// Write header row, then one row per record, to `writer`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::dm::Conversation;
+/// use twitter_archive::export::csv;
+/// use twitter_archive::query::MessageFilter;
+/// use twitter_archive::structs::direct_messages::DMConversation;
+///
+/// let json = r#"{
+///   "conversationId": "111111111-222222222",
+///   "messages": [
+///     {
+///       "messageCreate": {
+///         "recipientId": "111111111",
+///         "reactions": [],
+///         "urls": [],
+///         "text": "@example Fish &amp; chips?\nOr pie",
+///         "mediaUrls": [],
+///         "senderId": "222222222",
+///         "id": "4444444444444444444",
+///         "createdAt": "2023-08-12T17:12:00.000Z"
+///       }
+///     }
+///   ]
+/// }"#;
+///
+/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+/// let conversations = vec![Conversation::from(data)];
+///
+/// let options = csv::Options {
+///     columns: Some(vec!["sender_id".to_string(), "text".to_string()]),
+///     escape_formulas: true,
+/// };
+///
+/// let mut buffer = Vec::new();
+/// csv::to_csv(&mut buffer, MessageFilter::new().search(&conversations), &options).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(buffer).unwrap(),
+///     "sender_id,text\n222222222,\"'@example Fish & chips?\nOr pie\"\n"
+/// );
+/// ```
+pub fn to_csv<W, I>(mut writer: W, records: I, options: &Options) -> Result<()>
+where
+	W: Write,
+	I: IntoIterator,
+	I::Item: Record,
+{
+	let columns: Vec<&str> = match &options.columns {
+		Some(columns) => columns.iter().map(String::as_str).collect(),
+		None => <I::Item as Record>::COLUMNS.to_vec(),
+	};
+	if let Some(column) = columns
+		.iter()
+		.find(|column| !<I::Item as Record>::COLUMNS.contains(column))
+	{
+		return Err(Error::UnknownColumn {
+			column: column.to_string(),
+		});
+	}
+
+	write_row(&mut writer, columns.iter().copied(), false)?;
+	for record in records {
+		let fields: Vec<String> = columns.iter().map(|column| record.field(column)).collect();
+		write_row(
+			&mut writer,
+			fields.iter().map(String::as_str),
+			options.escape_formulas,
+		)?;
+	}
+	writer.flush()?;
+	Ok(())
+}
+
+/// Write `fields` separated by commas, quoting those that need it
+fn write_row<'a, W: Write>(
+	writer: &mut W,
+	fields: impl Iterator<Item = &'a str>,
+	escape_formulas: bool,
+) -> Result<()> {
+	let mut row = String::new();
+	for (index, field) in fields.enumerate() {
+		if index > 0 {
+			row.push(',');
+		}
+
+		let formula = escape_formulas && field.starts_with(['=', '+', '-', '@', '\t', '\r']);
+		if formula || field.contains([',', '"', '\n', '\r']) {
+			row.push('"');
+			if formula {
+				row.push('\'');
+			}
+			row.push_str(&field.replace('"', "\"\""));
+			row.push('"');
+		} else {
+			row.push_str(field);
+		}
+	}
+	row.push('\n');
+	writer.write_all(row.as_bytes())?;
+	Ok(())
+}
+
+/// RFC 3339 time-stamp with second precision, e.g. `2023-08-12T16:10:37Z`
+fn date_time(date: &DateTime<Utc>) -> String {
+	date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+impl Record for tweets::Tweet {
+	const COLUMNS: &'static [&'static str] = &[
+		"id",
+		"created_at",
+		"full_text",
+		"lang",
+		"kind",
+		"source",
+		"favorite_count",
+		"retweet_count",
+		"in_reply_to_status_id",
+		"in_reply_to_user_id",
+		"in_reply_to_screen_name",
+		"hashtags",
+		"user_mentions",
+		"urls",
+		"media_urls",
+		"possibly_sensitive",
+		"permalink",
+	];
+
+	fn field(&self, column: &str) -> String {
+		match column {
+			"id" => self.id_str.clone(),
+			"created_at" => date_time(&self.created_at),
+			"full_text" => self.full_text_decoded().into_owned(),
+			"lang" => self.lang.clone(),
+			"kind" => self.kind().to_string(),
+			"source" => self.source_client_name(),
+			"favorite_count" => self.favorite_count.to_string(),
+			"retweet_count" => self.retweet_count.to_string(),
+			"in_reply_to_status_id" => self.in_reply_to_status_id_str.clone().unwrap_or_default(),
+			"in_reply_to_user_id" => self.in_reply_to_user_id_str.clone().unwrap_or_default(),
+			"in_reply_to_screen_name" => self.in_reply_to_screen_name.clone().unwrap_or_default(),
+			"hashtags" => join(self.entities.hashtags.iter().map(|tag| tag.text.as_str())),
+			"user_mentions" => join(
+				self.entities
+					.user_mentions
+					.iter()
+					.map(|mention| mention.screen_name.as_str()),
+			),
+			"urls" => join(
+				self.entities
+					.urls
+					.iter()
+					.map(|url| url.expanded_url.as_str()),
+			),
+			"media_urls" => join(self.media().iter().map(|media| media.file_url())),
+			"possibly_sensitive" => self
+				.possibly_sensitive
+				.map(|sensitive| sensitive.to_string())
+				.unwrap_or_default(),
+			"permalink" => self.permalink(),
+			_ => String::new(),
+		}
+	}
+}
+
+impl Record for like::Like {
+	const COLUMNS: &'static [&'static str] = &["tweet_id", "full_text", "expanded_url"];
+
+	fn field(&self, column: &str) -> String {
+		match column {
+			"tweet_id" => self.tweet_id.clone(),
+			"full_text" => self.full_text.clone().unwrap_or_default(),
+			"expanded_url" => self.expanded_url.clone(),
+			_ => String::new(),
+		}
+	}
+}
+
+/// Followers and followings alike
+impl Record for follow::Follow {
+	const COLUMNS: &'static [&'static str] = &["account_id", "user_link"];
+
+	fn field(&self, column: &str) -> String {
+		match column {
+			"account_id" => self.account_id.clone(),
+			"user_link" => self.user_link.clone(),
+			_ => String::new(),
+		}
+	}
+}
+
+impl Record for block::Blocking {
+	const COLUMNS: &'static [&'static str] = &["account_id", "user_link"];
+
+	fn field(&self, column: &str) -> String {
+		match column {
+			"account_id" => self.account_id.clone(),
+			"user_link" => self.user_link.clone(),
+			_ => String::new(),
+		}
+	}
+}
+
+impl Record for mute::Muting {
+	const COLUMNS: &'static [&'static str] = &["account_id", "user_link"];
+
+	fn field(&self, column: &str) -> String {
+		match column {
+			"account_id" => self.account_id.clone(),
+			"user_link" => self.user_link.clone(),
+			_ => String::new(),
+		}
+	}
+}
+
+/// Direct Messages, along with conversation they belong to, such as those found by
+/// `query::MessageFilter::search`
+impl Record for MessageRef<'_> {
+	const COLUMNS: &'static [&'static str] = &[
+		"conversation_id",
+		"id",
+		"created_at",
+		"sender_id",
+		"recipient_id",
+		"text",
+		"urls",
+		"media_urls",
+		"reactions",
+	];
+
+	fn field(&self, column: &str) -> String {
+		let message = self.message;
+		match column {
+			"conversation_id" => self.conversation.conversation_id().to_string(),
+			"id" => message.id.clone(),
+			"created_at" => date_time(&message.created_at),
+			"sender_id" => message.sender_id.clone(),
+			"recipient_id" => message.recipient_id.clone().unwrap_or_default(),
+			"text" => message.text_decoded().into_owned(),
+			"urls" => join(message.urls.iter().map(|url| url.expanded.as_str())),
+			"media_urls" => join(message.media_urls.iter().map(String::as_str)),
+			"reactions" => join(
+				message
+					.reactions
+					.iter()
+					.map(|reaction| reaction.emoji().unwrap_or(reaction.reaction_key.as_str())),
+			),
+			_ => String::new(),
+		}
+	}
+}
+
+/// Targeting criteria are written as `type: value` pairs separated by `; `
+impl Record for ad::Impression {
+	const COLUMNS: &'static [&'static str] = &[
+		"impression_time",
+		"os_type",
+		"display_location",
+		"advertiser_name",
+		"advertiser_screen_name",
+		"promoted_tweet_id",
+		"promoted_tweet_text",
+		"targeting_criteria",
+	];
+
+	fn field(&self, column: &str) -> String {
+		let tweet = self.promoted_tweet_info.as_ref();
+		match column {
+			"impression_time" => date_time(&self.impression_time),
+			"os_type" => self.device_info.os_type.as_str().to_string(),
+			"display_location" => self.display_location.as_str().to_string(),
+			"advertiser_name" => self
+				.advertiser_info
+				.advertiser_name
+				.clone()
+				.unwrap_or_default(),
+			"advertiser_screen_name" => {
+				self.advertiser_info.screen_name.clone().unwrap_or_default()
+			}
+			"promoted_tweet_id" => tweet
+				.map(|tweet| tweet.tweet_id.clone())
+				.unwrap_or_default(),
+			"promoted_tweet_text" => tweet
+				.map(|tweet| tweet.tweet_text.clone())
+				.unwrap_or_default(),
+			"targeting_criteria" => self
+				.matched_targeting_criteria
+				.iter()
+				.flatten()
+				.map(|criteria| match &criteria.targeting_value {
+					Some(value) => format!("{}: {value}", criteria.targeting_type),
+					None => criteria.targeting_type.clone(),
+				})
+				.collect::<Vec<String>>()
+				.join("; "),
+			_ => String::new(),
+		}
+	}
+}
+
+/// Join list values with a space
+fn join<'a>(values: impl Iterator<Item = &'a str>) -> String {
+	values.collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,130 @@
+#!/usr/bin/env rust
+
+//! Write Tweets, Likes, Direct Messages, or any other serializable records as JSON Lines, also
+//! known as NDJSON, one compact JSON object per line, for piping into `jq`, BigQuery, and log
+//! tooling
+//!
+//! Records are written as they are pulled from iterators, so pairing `jsonl::try_to_jsonl` with
+//! `ArchiveReader::stream_tweets` converts `data/tweets.js` without holding it in memory.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::export::jsonl;
+//! use twitter_archive::reader::ArchiveReader;
+//! use twitter_archive::structs::like::LikeObject;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/like.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.like.part0 = [
+//!   {
+//!     "like" : {
+//!       "tweetId" : "1111111111111111111",
+//!       "fullText" : "Fish &amp; chips",
+//!       "expandedUrl" : "https://twitter.com/i/web/status/1111111111111111111"
+//!     }
+//!   },
+//!   {
+//!     "like" : {
+//!       "tweetId" : "2222222222222222222",
+//!       "expandedUrl" : "https://twitter.com/i/web/status/2222222222222222222"
+//!     }
+//!   }
+//! ]"#).unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! let mut buffer = Vec::new();
+//! let likes = archive
+//!     .stream_file("data/like.js", "YTD.like")
+//!     .unwrap()
+//!     .map(|result| result.map(|object: LikeObject| object.like));
+//! jsonl::try_to_jsonl(&mut buffer, likes).unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(buffer).unwrap(),
+//!     [
+//!         r#"{"tweetId":"1111111111111111111","fullText":"Fish &amp; chips","expandedUrl":"https://twitter.com/i/web/status/1111111111111111111"}"#,
+//!         r#"{"tweetId":"2222222222222222222","expandedUrl":"https://twitter.com/i/web/status/2222222222222222222"}"#,
+//!         "",
+//!     ].join("\n")
+//! );
+//! ```
+
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::Result;
+
+/// Write each of `records` as one line of JSON to `writer`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::dm::Conversation;
+/// use twitter_archive::export::jsonl;
+/// use twitter_archive::query::MessageFilter;
+/// use twitter_archive::structs::direct_messages::DMConversation;
+///
+/// let json = r#"{
+///   "conversationId": "111111111-222222222",
+///   "messages": [
+///     {
+///       "messageCreate": {
+///         "recipientId": "111111111",
+///         "reactions": [],
+///         "urls": [],
+///         "text": "Fish &amp; chips?\nOr pie",
+///         "mediaUrls": [],
+///         "senderId": "222222222",
+///         "id": "4444444444444444444",
+///         "createdAt": "2023-08-12T17:12:00.000Z"
+///       }
+///     }
+///   ]
+/// }"#;
+///
+/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+/// let conversations = vec![Conversation::from(data)];
+///
+/// let mut buffer = Vec::new();
+/// jsonl::to_jsonl(&mut buffer, MessageFilter::new().search(&conversations)).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(buffer).unwrap(),
+///     concat!(
+///         r#"{"conversationId":"111111111-222222222","id":"4444444444444444444","#,
+///         r#""senderId":"222222222","recipientId":"111111111","text":"Fish &amp; chips?\nOr pie","#,
+///         r#""mediaUrls":[],"reactions":[],"urls":[],"createdAt":"2023-08-12T17:12:00.000Z"}"#,
+///         "\n",
+///     )
+/// );
+/// ```
+pub fn to_jsonl<W, I>(writer: W, records: I) -> Result<()>
+where
+	W: Write,
+	I: IntoIterator,
+	I::Item: Serialize,
+{
+	try_to_jsonl(writer, records.into_iter().map(Ok))
+}
+
+/// Write each of `records` as one line of JSON to `writer`, stopping at first error, such as
+/// those yielded by `parse::JsArrayStream` for malformed data
+pub fn try_to_jsonl<W, I, T>(mut writer: W, records: I) -> Result<()>
+where
+	W: Write,
+	I: IntoIterator<Item = Result<T>>,
+	T: Serialize,
+{
+	for record in records {
+		serde_json::to_writer(&mut writer, &record?)?;
+		writer.write_all(b"\n")?;
+	}
+	writer.flush()?;
+	Ok(())
+}
@@ -0,0 +1,363 @@
+#!/usr/bin/env rust
+
+//! Convert Tweets into the layout of Mastodon account archives, `outbox.json` plus files within
+//! `media_attachments/files/`, which Mastodon archive import tools read to re-post history
+//!
+//! Retweets are left out, since they are posts of other accounts, and remaining Tweets are
+//! ordered oldest first as `Create` activities from `export::activitystreams`, so replies within
+//! threads point at Notes of the outbox.  Attachments point at
+//! `/media_attachments/files/<TWEET_ID>-<FILE_NAME>` when the archive holds their file, listed by
+//! `Export::media`, otherwise at remote `pbs.twimg.com` and `video.twimg.com` URLs.
+//!
+//! `mastodon::to_directory` writes everything, including `media_manifest.json`, with one call.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::export::mastodon;
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/tweets.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.tweets.part0 = [
+//!   {
+//!     "tweet": {
+//!       "edit_info": {
+//!         "initial": {
+//!           "editTweetIds": [ "2222222222222222222" ],
+//!           "editableUntil": "2023-08-12T18:10:37.000Z",
+//!           "editsRemaining": "5",
+//!           "isEditEligible": true
+//!         }
+//!       },
+//!       "retweeted": false,
+//!       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities": { "hashtags": [], "symbols": [], "user_mentions": [], "urls": [] },
+//!       "extended_entities": {
+//!         "media": [
+//!           {
+//!             "expanded_url": "https://twitter.com/EXAMPLE/status/2222222222222222222/photo/1",
+//!             "indices": [ "4", "27" ],
+//!             "url": "https://t.co/CCCCCCCCCC",
+//!             "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!             "id_str": "3333333333333333333",
+//!             "id": "3333333333333333333",
+//!             "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!             "sizes": {
+//!               "thumb": { "w": "150", "h": "150", "resize": "crop" },
+//!               "small": { "w": "680", "h": "383", "resize": "fit" },
+//!               "medium": { "w": "1200", "h": "675", "resize": "fit" },
+//!               "large": { "w": "1280", "h": "720", "resize": "fit" }
+//!             },
+//!             "type": "photo",
+//!             "display_url": "pic.twitter.com/CCCCCCCCCC"
+//!           }
+//!         ]
+//!       },
+//!       "display_text_range": [ "0", "3" ],
+//!       "favorite_count": "0",
+//!       "id_str": "2222222222222222222",
+//!       "in_reply_to_status_id_str": "1111111111111111111",
+//!       "truncated": false,
+//!       "retweet_count": "0",
+//!       "id": "2222222222222222222",
+//!       "created_at": "Sat Aug 12 17:10:37 +0000 2023",
+//!       "favorited": false,
+//!       "full_text": "2/2 https://t.co/CCCCCCCCCC",
+//!       "lang": "en"
+//!     }
+//!   },
+//!   {
+//!     "tweet": {
+//!       "edit_info": {
+//!         "initial": {
+//!           "editTweetIds": [ "1111111111111111111" ],
+//!           "editableUntil": "2023-08-12T17:10:37.000Z",
+//!           "editsRemaining": "5",
+//!           "isEditEligible": true
+//!         }
+//!       },
+//!       "retweeted": false,
+//!       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities": { "hashtags": [], "symbols": [], "user_mentions": [], "urls": [] },
+//!       "display_text_range": [ "0", "3" ],
+//!       "favorite_count": "0",
+//!       "id_str": "1111111111111111111",
+//!       "truncated": false,
+//!       "retweet_count": "0",
+//!       "id": "1111111111111111111",
+//!       "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!       "favorited": false,
+//!       "full_text": "1/2",
+//!       "lang": "en"
+//!     }
+//!   }
+//! ]"#).unwrap();
+//! zip_writer
+//!     .start_file("data/tweets_media/2222222222222222222-F3UNnlRWoAAQXa_.jpg", FileOptions::default())
+//!     .unwrap();
+//! zip_writer.write_all(b"JPEG").unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! let export = mastodon::export(&mut archive, "https://example.social/users/alice").unwrap();
+//!
+//! let items = &export.outbox.ordered_items;
+//! assert_eq!(export.outbox.total_items, 2);
+//! assert_eq!(items[0].object.content, "<p>1/2</p>");
+//! assert_eq!(items[1].object.in_reply_to, Some(items[0].object.id.clone()));
+//! assert_eq!(
+//!     items[1].object.attachment[0].url,
+//!     "/media_attachments/files/2222222222222222222-F3UNnlRWoAAQXa_.jpg"
+//! );
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&export.media).unwrap(),
+//!     serde_json::json!([
+//!         {
+//!             "tweetId": "2222222222222222222",
+//!             "source": "data/tweets_media/2222222222222222222-F3UNnlRWoAAQXa_.jpg",
+//!             "path": "media_attachments/files/2222222222222222222-F3UNnlRWoAAQXa_.jpg",
+//!             "mediaType": "image/jpeg"
+//!         }
+//!     ])
+//! );
+//! ```
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::export::activitystreams::{self, Create};
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::structs::tweets::{Tweet, TweetKind};
+use crate::{Error, Result};
+
+/// Directory, relative to root of Mastodon archive, holding media files
+pub const MEDIA_DIRECTORY: &str = "media_attachments/files";
+
+/// Outbox and media of Mastodon archive, see `mastodon::export`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+	/// Content of `outbox.json`
+	pub outbox: Outbox,
+
+	/// Content of `media_manifest.json`, one entry per media file archive holds
+	pub media: Vec<MediaFile>,
+}
+
+/// Collection of every Create activity, as found within `outbox.json` of Mastodon archives
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Outbox {
+	/// Always `https://www.w3.org/ns/activitystreams`
+	#[serde(rename = "@context")]
+	pub context: String,
+
+	/// Always `outbox.json`
+	pub id: String,
+
+	/// Always `OrderedCollection`
+	#[serde(rename = "type")]
+	pub kind: String,
+
+	/// Number of `ordered_items`
+	pub total_items: usize,
+
+	/// Activities, oldest first
+	pub ordered_items: Vec<Create>,
+}
+
+/// Media file to copy from Twitter archive into Mastodon archive
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct MediaFile {
+	/// ID of Tweet media is attached to
+	pub tweet_id: String,
+
+	/// Path within Twitter archive, e.g. `data/tweets_media/<TWEET_ID>-<FILE_NAME>`
+	pub source: String,
+
+	/// Path within Mastodon archive, e.g. `media_attachments/files/<TWEET_ID>-<FILE_NAME>`
+	pub path: String,
+
+	/// MIME type, e.g. `image/jpeg` or `video/mp4`
+	pub media_type: String,
+}
+
+/// Convert Tweets of `archive` into outbox of Notes attributed to `actor`, e.g.
+/// `https://example.social/users/alice`, and list media files to copy alongside
+pub fn export<S: Source>(archive: &mut ArchiveReader<S>, actor: &str) -> Result<Export> {
+	let mut tweets: Vec<Tweet> = archive
+		.tweets()?
+		.into_iter()
+		.map(|object| object.tweet)
+		.filter(|tweet| tweet.kind() != TweetKind::Retweet)
+		.collect();
+	tweets.sort_by_key(|tweet| tweet.created_at);
+
+	let options = activitystreams::Options {
+		actor: actor.to_string(),
+		media_directory: Some(format!("/{MEDIA_DIRECTORY}")),
+	};
+	let mut ordered_items = activitystreams::thread(&tweets, &options);
+
+	let tweets_media_directory = archive.tweets_media_directory()?;
+	let mut media = Vec::new();
+	for (tweet, activity) in tweets.iter().zip(&mut ordered_items) {
+		for (item, attachment) in tweet.media().iter().zip(&mut activity.object.attachment) {
+			let source = item
+				.file_name()
+				.map(|file_name| format!("{tweets_media_directory}/{}-{file_name}", tweet.id_str))
+				.filter(|source| archive.contains(source));
+
+			match source {
+				Some(source) => media.push(MediaFile {
+					tweet_id: tweet.id_str.clone(),
+					path: attachment.url.trim_start_matches('/').to_string(),
+					source,
+					media_type: attachment.media_type.clone(),
+				}),
+				None => attachment.url = item.file_url().to_string(),
+			}
+		}
+	}
+
+	Ok(Export {
+		outbox: Outbox {
+			context: activitystreams::CONTEXT.to_string(),
+			id: "outbox.json".to_string(),
+			kind: "OrderedCollection".to_string(),
+			total_items: ordered_items.len(),
+			ordered_items,
+		},
+		media,
+	})
+}
+
+/// Export `archive`, as `mastodon::export` does, into `directory` by writing `outbox.json` and
+/// `media_manifest.json`, then copying media files into `media_attachments/files/`
+///
+/// ## Example
+///
+/// ```
+/// use std::fs;
+/// use std::io::{Cursor, Write};
+/// use zip::write::{FileOptions, ZipWriter};
+///
+/// use twitter_archive::export::mastodon;
+/// use twitter_archive::reader::ArchiveReader;
+///
+/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+/// zip_writer.start_file("data/tweets.js", FileOptions::default()).unwrap();
+/// zip_writer.write_all(br#"window.YTD.tweets.part0 = [
+///   {
+///     "tweet": {
+///       "edit_info": {
+///         "initial": {
+///           "editTweetIds": [ "1111111111111111111" ],
+///           "editableUntil": "2023-08-12T17:10:37.000Z",
+///           "editsRemaining": "5",
+///           "isEditEligible": true
+///         }
+///       },
+///       "retweeted": false,
+///       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///       "entities": { "hashtags": [], "symbols": [], "user_mentions": [], "urls": [] },
+///       "extended_entities": {
+///         "media": [
+///           {
+///             "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+///             "indices": [ "13", "36" ],
+///             "url": "https://t.co/CCCCCCCCCC",
+///             "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.png",
+///             "id_str": "3333333333333333333",
+///             "id": "3333333333333333333",
+///             "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.png",
+///             "sizes": {
+///               "thumb": { "w": "150", "h": "150", "resize": "crop" },
+///               "small": { "w": "680", "h": "383", "resize": "fit" },
+///               "medium": { "w": "1200", "h": "675", "resize": "fit" },
+///               "large": { "w": "1280", "h": "720", "resize": "fit" }
+///             },
+///             "type": "photo",
+///             "display_url": "pic.twitter.com/CCCCCCCCCC"
+///           }
+///         ]
+///       },
+///       "display_text_range": [ "0", "12" ],
+///       "favorite_count": "0",
+///       "id_str": "1111111111111111111",
+///       "truncated": false,
+///       "retweet_count": "0",
+///       "id": "1111111111111111111",
+///       "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///       "favorited": false,
+///       "full_text": "Fish &amp; chips https://t.co/CCCCCCCCCC",
+///       "lang": "en"
+///     }
+///   }
+/// ]"#).unwrap();
+/// zip_writer
+///     .start_file("data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.png", FileOptions::default())
+///     .unwrap();
+/// zip_writer.write_all(b"PNG").unwrap();
+///
+/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+///
+/// let root = std::env::temp_dir().join(format!("twitter-archive-mastodon-{}", std::process::id()));
+/// let export = mastodon::to_directory(&mut archive, "https://example.social/users/alice", &root).unwrap();
+///
+/// assert_eq!(export.media[0].media_type, "image/png");
+/// assert_eq!(
+///     fs::read(root.join("media_attachments/files/1111111111111111111-F3UNnlRWoAAQXa_.png")).unwrap(),
+///     b"PNG"
+/// );
+///
+/// let outbox: mastodon::Outbox =
+///     serde_json::from_slice(&fs::read(root.join("outbox.json")).unwrap()).unwrap();
+/// assert_eq!(outbox, export.outbox);
+/// assert!(root.join("media_manifest.json").exists());
+///
+/// fs::remove_dir_all(&root).unwrap();
+/// ```
+pub fn to_directory<S, P>(
+	archive: &mut ArchiveReader<S>,
+	actor: &str,
+	directory: P,
+) -> Result<Export>
+where
+	S: Source,
+	P: AsRef<Path>,
+{
+	let directory = directory.as_ref();
+	let export = export(archive, actor)?;
+
+	fs::create_dir_all(directory.join(MEDIA_DIRECTORY))?;
+
+	let mut outbox = io::BufWriter::new(fs::File::create(directory.join("outbox.json"))?);
+	serde_json::to_writer(&mut outbox, &export.outbox)?;
+	outbox.flush()?;
+
+	let mut manifest = io::BufWriter::new(fs::File::create(directory.join("media_manifest.json"))?);
+	serde_json::to_writer_pretty(&mut manifest, &export.media)?;
+	manifest.flush()?;
+
+	for file in &export.media {
+		let mut reader = archive.open_file(&file.source)?;
+		let mut writer = fs::File::create(directory.join(&file.path))?;
+		io::copy(&mut reader, &mut writer)
+			.map_err(|error| Error::from(error).with_file_name(&file.source))?;
+	}
+
+	Ok(export)
+}
@@ -0,0 +1,557 @@
+#!/usr/bin/env rust
+
+//! Write whole archive into a normalized SQLite database, for SQL analysis of one's history
+//!
+//! Tables created are;
+//!
+//! - `tweets`, with `tweet_entities` holding hashtags, symbols, mentions, and links, and
+//!   `tweet_media` holding attached photos, videos, and animated GIFs
+//! - `dm_conversations`, with `dm_participants` and `dms` holding messages, and `dm_media`
+//!   holding URLs of media attached to messages
+//! - `followers`, `following`, `blocks`, `mutes`, and `likes`
+//! - `ad_impressions`, with `ad_targeting` holding matched targeting criteria, and
+//!   `ad_engagements` holding one row per engagement with ads
+//!
+//! Date time-stamps are stored as RFC 3339 text, e.g. `2023-08-12T16:10:37Z`, which SQLite date
+//! and time functions understand, and data types the archive lacks leave their tables empty.
+//! Tweets, conversations, messages, accounts, and Likes the archive lists more than once are
+//! stored once, keeping first listed, so e.g. a Tweet repeated across `tweets-part<N>.js` files
+//! does not abort the export.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::export::sqlite;
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/follower.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.follower.part0 = [
+//!   {
+//!     "follower" : {
+//!       "accountId" : "1111111111111111111",
+//!       "userLink" : "https://twitter.com/intent/user?user_id=1111111111111111111"
+//!     }
+//!   }
+//! ]"#).unwrap();
+//! zip_writer.start_file("data/like.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.like.part0 = [
+//!   {
+//!     "like" : {
+//!       "tweetId" : "2222222222222222222",
+//!       "fullText" : "Fish &amp; chips",
+//!       "expandedUrl" : "https://twitter.com/i/web/status/2222222222222222222"
+//!     }
+//!   },
+//!   {
+//!     "like" : {
+//!       "tweetId" : "2222222222222222222",
+//!       "fullText" : "Fish &amp; chips",
+//!       "expandedUrl" : "https://twitter.com/i/web/status/2222222222222222222"
+//!     }
+//!   }
+//! ]"#).unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! let mut connection = sqlite::Connection::open_in_memory().unwrap();
+//! sqlite::to_sqlite(&mut archive, &mut connection).unwrap();
+//!
+//! let follower: String = connection
+//!     .query_row("SELECT account_id FROM followers", [], |row| row.get(0))
+//!     .unwrap();
+//! assert_eq!(follower, "1111111111111111111");
+//!
+//! // Like listed twice is stored once
+//! let likes: i64 = connection
+//!     .query_row("SELECT COUNT(*) FROM likes WHERE full_text LIKE '%chips%'", [], |row| row.get(0))
+//!     .unwrap();
+//! assert_eq!(likes, 1);
+//!
+//! // Tables of data types archive lacks exist, but are empty
+//! let tweets: i64 = connection
+//!     .query_row("SELECT COUNT(*) FROM tweets", [], |row| row.get(0))
+//!     .unwrap();
+//! assert_eq!(tweets, 0);
+//! ```
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Transaction};
+
+pub use rusqlite::Connection;
+
+use crate::convert::indices_permissive;
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::structs::{ad, tweets};
+use crate::{Error, Result};
+
+/// Statements creating every table, and indexes for joins between them
+const SCHEMA: &str = "
+CREATE TABLE tweets (
+	id TEXT PRIMARY KEY,
+	created_at TEXT NOT NULL,
+	full_text TEXT NOT NULL,
+	lang TEXT NOT NULL,
+	kind TEXT NOT NULL,
+	source TEXT NOT NULL,
+	favorite_count INTEGER NOT NULL,
+	retweet_count INTEGER NOT NULL,
+	in_reply_to_status_id TEXT,
+	in_reply_to_user_id TEXT,
+	in_reply_to_screen_name TEXT,
+	possibly_sensitive INTEGER
+);
+
+CREATE TABLE tweet_entities (
+	tweet_id TEXT NOT NULL REFERENCES tweets (id),
+	kind TEXT NOT NULL,
+	text TEXT NOT NULL,
+	user_id TEXT,
+	start_index INTEGER,
+	end_index INTEGER
+);
+CREATE INDEX tweet_entities_tweet_id ON tweet_entities (tweet_id);
+
+CREATE TABLE tweet_media (
+	id TEXT NOT NULL,
+	tweet_id TEXT NOT NULL REFERENCES tweets (id),
+	media_type TEXT NOT NULL,
+	url TEXT NOT NULL,
+	PRIMARY KEY (tweet_id, id)
+);
+
+CREATE TABLE dm_conversations (
+	id TEXT PRIMARY KEY,
+	is_group INTEGER NOT NULL
+);
+
+CREATE TABLE dm_participants (
+	conversation_id TEXT NOT NULL REFERENCES dm_conversations (id),
+	user_id TEXT NOT NULL,
+	PRIMARY KEY (conversation_id, user_id)
+);
+
+CREATE TABLE dms (
+	id TEXT NOT NULL,
+	conversation_id TEXT NOT NULL REFERENCES dm_conversations (id),
+	created_at TEXT NOT NULL,
+	sender_id TEXT NOT NULL,
+	recipient_id TEXT,
+	text TEXT NOT NULL,
+	PRIMARY KEY (conversation_id, id)
+);
+CREATE INDEX dms_conversation_id ON dms (conversation_id);
+
+CREATE TABLE dm_media (
+	message_id TEXT NOT NULL,
+	url TEXT NOT NULL
+);
+
+CREATE TABLE followers (
+	account_id TEXT PRIMARY KEY,
+	user_link TEXT NOT NULL
+);
+
+CREATE TABLE following (
+	account_id TEXT PRIMARY KEY,
+	user_link TEXT NOT NULL
+);
+
+CREATE TABLE blocks (
+	account_id TEXT PRIMARY KEY,
+	user_link TEXT NOT NULL
+);
+
+CREATE TABLE mutes (
+	account_id TEXT PRIMARY KEY,
+	user_link TEXT NOT NULL
+);
+
+CREATE TABLE likes (
+	tweet_id TEXT PRIMARY KEY,
+	full_text TEXT,
+	expanded_url TEXT NOT NULL
+);
+
+CREATE TABLE ad_impressions (
+	id INTEGER PRIMARY KEY,
+	impression_time TEXT NOT NULL,
+	os_type TEXT NOT NULL,
+	display_location TEXT NOT NULL,
+	advertiser_name TEXT,
+	advertiser_screen_name TEXT,
+	promoted_tweet_id TEXT,
+	promoted_tweet_text TEXT
+);
+
+CREATE TABLE ad_targeting (
+	impression_id INTEGER NOT NULL REFERENCES ad_impressions (id),
+	targeting_type TEXT NOT NULL,
+	targeting_value TEXT
+);
+CREATE INDEX ad_targeting_impression_id ON ad_targeting (impression_id);
+
+CREATE TABLE ad_engagements (
+	impression_time TEXT NOT NULL,
+	advertiser_name TEXT,
+	advertiser_screen_name TEXT,
+	promoted_tweet_id TEXT,
+	engagement_time TEXT NOT NULL,
+	engagement_type TEXT NOT NULL
+);
+";
+
+/// Create tables listed by module documentation within `connection`, which must not have them
+/// yet, and fill them from `archive` within one transaction
+///
+/// ## Example
+///
+/// ```
+/// use std::io::{Cursor, Write};
+/// use zip::write::{FileOptions, ZipWriter};
+///
+/// use twitter_archive::export::sqlite;
+/// use twitter_archive::reader::ArchiveReader;
+///
+/// let tweet = r##"{
+///   "tweet" : {
+///     "edit_info" : {
+///       "initial" : {
+///         "editTweetIds" : [ "1111111111111111111" ],
+///         "editableUntil" : "2023-08-12T17:10:37.000Z",
+///         "editsRemaining" : "5",
+///         "isEditEligible" : true
+///       }
+///     },
+///     "retweeted" : false,
+///     "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///     "entities" : {
+///       "hashtags" : [ { "text" : "fish", "indices" : [ "0", "5" ] } ],
+///       "symbols" : [ ],
+///       "user_mentions" : [ ],
+///       "urls" : [ ]
+///     },
+///     "display_text_range" : [ "0", "5" ],
+///     "favorite_count" : "0",
+///     "id_str" : "1111111111111111111",
+///     "truncated" : false,
+///     "retweet_count" : "0",
+///     "id" : "1111111111111111111",
+///     "created_at" : "Sat Aug 12 16:10:37 +0000 2023",
+///     "favorited" : false,
+///     "full_text" : "#fish",
+///     "lang" : "en"
+///   }
+/// }"##;
+///
+/// let conversation = r#"{
+///   "dmConversation" : {
+///     "conversationId" : "111111111-222222222",
+///     "messages" : [
+///       {
+///         "messageCreate" : {
+///           "recipientId" : "111111111",
+///           "reactions" : [ ],
+///           "urls" : [ ],
+///           "text" : "Chips?",
+///           "mediaUrls" : [ ],
+///           "senderId" : "222222222",
+///           "id" : "3333333333333333333",
+///           "createdAt" : "2023-08-12T17:12:00.000Z"
+///         }
+///       }
+///     ]
+///   }
+/// }"#;
+///
+/// // Tweet and conversation are each listed twice
+/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+/// zip_writer.start_file("data/tweets.js", FileOptions::default()).unwrap();
+/// write!(zip_writer, "window.YTD.tweets.part0 = [{tweet}, {tweet}]").unwrap();
+/// zip_writer.start_file("data/direct-messages.js", FileOptions::default()).unwrap();
+/// write!(zip_writer, "window.YTD.direct_messages.part0 = [{conversation}, {conversation}]").unwrap();
+///
+/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+///
+/// let mut connection = sqlite::Connection::open_in_memory().unwrap();
+/// sqlite::to_sqlite(&mut archive, &mut connection).unwrap();
+///
+/// let count = |table: &str| -> i64 {
+///     connection
+///         .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0))
+///         .unwrap()
+/// };
+///
+/// assert_eq!(count("tweets"), 1);
+/// assert_eq!(count("tweet_entities"), 1);
+/// assert_eq!(count("dm_conversations"), 1);
+/// assert_eq!(count("dm_participants"), 2);
+/// assert_eq!(count("dms"), 1);
+/// ```
+pub fn to_sqlite<S: Source>(
+	archive: &mut ArchiveReader<S>,
+	connection: &mut Connection,
+) -> Result<()> {
+	let transaction = connection.transaction()?;
+	transaction.execute_batch(SCHEMA)?;
+
+	insert_tweets(&transaction, archive)?;
+	insert_dms(&transaction, archive)?;
+	insert_accounts(&transaction, archive)?;
+	insert_likes(&transaction, archive)?;
+	insert_ads(&transaction, archive)?;
+
+	transaction.commit()?;
+	Ok(())
+}
+
+fn insert_tweets<S: Source>(
+	transaction: &Transaction,
+	archive: &mut ArchiveReader<S>,
+) -> Result<()> {
+	let mut tweets = transaction.prepare(
+		"INSERT OR IGNORE INTO tweets VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+	)?;
+	let mut entities =
+		transaction.prepare("INSERT INTO tweet_entities VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+	let mut media =
+		transaction.prepare("INSERT OR IGNORE INTO tweet_media VALUES (?1, ?2, ?3, ?4)")?;
+
+	for object in optional(archive.tweets())? {
+		let tweet = &object.tweet;
+		let inserted = tweets.execute(params![
+			tweet.id_str,
+			date_time(&tweet.created_at),
+			tweet.full_text_decoded(),
+			tweet.lang,
+			tweet.kind().to_string(),
+			tweet.source_client_name(),
+			tweet.favorite_count,
+			tweet.retweet_count,
+			tweet.in_reply_to_status_id_str,
+			tweet.in_reply_to_user_id_str,
+			tweet.in_reply_to_screen_name,
+			tweet.possibly_sensitive,
+		])?;
+		// Repeated Tweet already has its entities and media
+		if inserted == 0 {
+			continue;
+		}
+
+		let tweets::TweetEntities {
+			hashtags,
+			symbols,
+			user_mentions,
+			urls,
+			..
+		} = &tweet.entities;
+		for (kind, entries) in [("hashtag", hashtags), ("symbol", symbols)] {
+			for entry in entries {
+				let [start, end] = index_pair(&entry.indices);
+				entities.execute(params![
+					tweet.id_str,
+					kind,
+					entry.text,
+					None::<&str>,
+					start,
+					end
+				])?;
+			}
+		}
+		for mention in user_mentions {
+			let [start, end] = index_pair(&mention.indices);
+			entities.execute(params![
+				tweet.id_str,
+				"mention",
+				mention.screen_name,
+				mention.id_str,
+				start,
+				end
+			])?;
+		}
+		for url in urls {
+			let [start, end] = index_pair(&url.indices);
+			entities.execute(params![
+				tweet.id_str,
+				"url",
+				url.expanded_url,
+				None::<&str>,
+				start,
+				end
+			])?;
+		}
+
+		for item in tweet.media() {
+			media.execute(params![
+				item.id_str,
+				tweet.id_str,
+				item.media_type.as_str(),
+				item.file_url()
+			])?;
+		}
+	}
+	Ok(())
+}
+
+fn insert_dms<S: Source>(transaction: &Transaction, archive: &mut ArchiveReader<S>) -> Result<()> {
+	let mut conversations =
+		transaction.prepare("INSERT OR IGNORE INTO dm_conversations VALUES (?1, ?2)")?;
+	let mut participants =
+		transaction.prepare("INSERT OR IGNORE INTO dm_participants VALUES (?1, ?2)")?;
+	let mut messages =
+		transaction.prepare("INSERT OR IGNORE INTO dms VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+	let mut media = transaction.prepare("INSERT INTO dm_media VALUES (?1, ?2)")?;
+
+	for conversation in archive.dm_conversations()? {
+		let conversation_id = conversation.conversation_id();
+		conversations.execute(params![conversation_id, conversation.is_group()])?;
+
+		for user_id in conversation.participants() {
+			participants.execute(params![conversation_id, user_id])?;
+		}
+
+		// Conversations listed more than once may each hold different messages, so only
+		// repeated messages are skipped
+		for message in conversation.messages() {
+			let inserted = messages.execute(params![
+				message.id,
+				conversation_id,
+				date_time(&message.created_at),
+				message.sender_id,
+				message.recipient_id,
+				message.text_decoded(),
+			])?;
+			// Repeated message already has its media
+			if inserted == 0 {
+				continue;
+			}
+			for url in &message.media_urls {
+				media.execute(params![message.id, url])?;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Followers, followings, blocks, and mutes
+fn insert_accounts<S: Source>(
+	transaction: &Transaction,
+	archive: &mut ArchiveReader<S>,
+) -> Result<()> {
+	let mut followers = transaction.prepare("INSERT OR IGNORE INTO followers VALUES (?1, ?2)")?;
+	for object in optional(archive.follower())? {
+		followers.execute(params![
+			object.follower.account_id,
+			object.follower.user_link
+		])?;
+	}
+
+	let mut following = transaction.prepare("INSERT OR IGNORE INTO following VALUES (?1, ?2)")?;
+	for object in optional(archive.following())? {
+		following.execute(params![
+			object.following.account_id,
+			object.following.user_link
+		])?;
+	}
+
+	let mut blocks = transaction.prepare("INSERT OR IGNORE INTO blocks VALUES (?1, ?2)")?;
+	for object in optional(archive.block())? {
+		blocks.execute(params![
+			object.blocking.account_id,
+			object.blocking.user_link
+		])?;
+	}
+
+	let mut mutes = transaction.prepare("INSERT OR IGNORE INTO mutes VALUES (?1, ?2)")?;
+	for object in optional(archive.mute())? {
+		mutes.execute(params![object.muting.account_id, object.muting.user_link])?;
+	}
+	Ok(())
+}
+
+fn insert_likes<S: Source>(
+	transaction: &Transaction,
+	archive: &mut ArchiveReader<S>,
+) -> Result<()> {
+	let mut likes = transaction.prepare("INSERT OR IGNORE INTO likes VALUES (?1, ?2, ?3)")?;
+	for object in optional(archive.like())? {
+		let like = &object.like;
+		likes.execute(params![like.tweet_id, like.full_text, like.expanded_url])?;
+	}
+	Ok(())
+}
+
+fn insert_ads<S: Source>(transaction: &Transaction, archive: &mut ArchiveReader<S>) -> Result<()> {
+	let mut impressions = transaction.prepare(
+		"INSERT INTO ad_impressions (impression_time, os_type, display_location, advertiser_name,
+			advertiser_screen_name, promoted_tweet_id, promoted_tweet_text)
+		VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+	)?;
+	let mut targeting = transaction.prepare("INSERT INTO ad_targeting VALUES (?1, ?2, ?3)")?;
+
+	for object in optional(archive.ad_impressions())? {
+		for impression in &object.ad.ads_user_data.ad_impressions.impressions {
+			let tweet = impression.promoted_tweet_info.as_ref();
+			impressions.execute(params![
+				date_time(&impression.impression_time),
+				impression.device_info.os_type.as_str(),
+				impression.display_location.as_str(),
+				impression.advertiser_info.advertiser_name,
+				impression.advertiser_info.screen_name,
+				tweet.map(|tweet| &tweet.tweet_id),
+				tweet.map(|tweet| &tweet.tweet_text),
+			])?;
+
+			let impression_id = transaction.last_insert_rowid();
+			for criteria in impression.matched_targeting_criteria.iter().flatten() {
+				targeting.execute(params![
+					impression_id,
+					criteria.targeting_type,
+					criteria.targeting_value
+				])?;
+			}
+		}
+	}
+
+	let mut engagements =
+		transaction.prepare("INSERT INTO ad_engagements VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+	for object in optional(archive.ad_engagements())? {
+		for engagement in &object.ad.ads_user_data.ad_engagements.engagements {
+			let impression: &ad::Impression = &engagement.impression_attributes;
+			let tweet = impression.promoted_tweet_info.as_ref();
+			for attributes in &engagement.engagement_attributes {
+				engagements.execute(params![
+					date_time(&impression.impression_time),
+					impression.advertiser_info.advertiser_name,
+					impression.advertiser_info.screen_name,
+					tweet.map(|tweet| &tweet.tweet_id),
+					date_time(&attributes.engagement_time),
+					attributes.engagement_type.as_str(),
+				])?;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Data read from archive, or nothing when archive lacks its files
+fn optional<T>(result: Result<Vec<T>>) -> Result<Vec<T>> {
+	match result {
+		Err(Error::MissingFile { .. }) => Ok(Vec::new()),
+		result => result,
+	}
+}
+
+/// Start and end of entity, both `NULL` when archive lists other than two indices
+fn index_pair(indices: &[usize]) -> [Option<usize>; 2] {
+	indices_permissive::pair(indices).map_or([None, None], |[start, end]| [Some(start), Some(end)])
+}
+
+/// RFC 3339 time-stamp with second precision, e.g. `2023-08-12T16:10:37Z`
+fn date_time(date: &DateTime<Utc>) -> String {
+	date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
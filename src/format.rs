@@ -0,0 +1,228 @@
+#!/usr/bin/env rust
+
+//! Serialize data structures back into the same JSON formatting Twitter uses within archives,
+//! similar to;
+//!
+//! ```javascript
+//! window.YTD.verified.part0 = [
+//!   {
+//!     "verified" : {
+//!       "accountId" : "111111111",
+//!       "verified" : false
+//!     }
+//!   }
+//! ]
+//! ```
+//!
+//! Where `serde_json::to_string_pretty` writes `"key": value` and `[]`, archives have
+//! `"key" : value` and `[ ]`, so regenerated files would not diff cleanly against originals.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::{format, parse};
+//! use twitter_archive::structs::tweets::TweetObject;
+//!
+//! let javascript = r#"window.YTD.tweets.part0 = [
+//!   {
+//!     "tweet" : {
+//!       "edit_info" : {
+//!         "initial" : {
+//!           "editTweetIds" : [
+//!             "1690395372546301952"
+//!           ],
+//!           "editableUntil" : "2023-08-12T17:10:37.000Z",
+//!           "editsRemaining" : "5",
+//!           "isEditEligible" : true
+//!         }
+//!       },
+//!       "retweeted" : false,
+//!       "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities" : {
+//!         "hashtags" : [ ],
+//!         "symbols" : [ ],
+//!         "user_mentions" : [ ],
+//!         "urls" : [ ]
+//!       },
+//!       "display_text_range" : [
+//!         "0",
+//!         "2"
+//!       ],
+//!       "favorite_count" : "0",
+//!       "id_str" : "1690395372546301952",
+//!       "truncated" : false,
+//!       "retweet_count" : "0",
+//!       "id" : "1690395372546301952",
+//!       "created_at" : "Sat Aug 12 16:10:37 +0000 2023",
+//!       "favorited" : false,
+//!       "full_text" : "Hi",
+//!       "lang" : "en"
+//!     }
+//!   }
+//! ]"#;
+//!
+//! let data: Vec<TweetObject> = parse::from_js_str(&javascript).unwrap();
+//!
+//! // Re-serialize is byte-for-byte equivalent to original data
+//! assert_eq!(format::to_js_string(&data).unwrap(), javascript);
+//! ```
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+use serde_json::ser::Formatter;
+
+use crate::parse::FromJsStr;
+use crate::Result;
+
+/// `serde_json` formatter reproducing archive style; two space indentation, `" : "` between keys
+/// and values, one array element per line, and `[ ]` or `{ }` for empty arrays and objects
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveFormatter {
+	current_indent: usize,
+	has_value: bool,
+}
+
+impl ArchiveFormatter {
+	/// Formatter starting at zero indentation
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn begin<W: ?Sized + Write>(&mut self, writer: &mut W, open: &[u8]) -> io::Result<()> {
+		self.current_indent += 1;
+		self.has_value = false;
+		writer.write_all(open)
+	}
+
+	fn end<W: ?Sized + Write>(&mut self, writer: &mut W, close: &[u8]) -> io::Result<()> {
+		self.current_indent -= 1;
+
+		if self.has_value {
+			writer.write_all(b"\n")?;
+			indent(writer, self.current_indent)?;
+		} else {
+			writer.write_all(b" ")?;
+		}
+
+		writer.write_all(close)
+	}
+
+	fn begin_value<W: ?Sized + Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+		writer.write_all(if first { b"\n" } else { b",\n" })?;
+		indent(writer, self.current_indent)
+	}
+}
+
+impl Formatter for ArchiveFormatter {
+	fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		self.begin(writer, b"[")
+	}
+
+	fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		self.end(writer, b"]")
+	}
+
+	fn begin_array_value<W: ?Sized + Write>(
+		&mut self,
+		writer: &mut W,
+		first: bool,
+	) -> io::Result<()> {
+		self.begin_value(writer, first)
+	}
+
+	fn end_array_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+		self.has_value = true;
+		Ok(())
+	}
+
+	fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		self.begin(writer, b"{")
+	}
+
+	fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		self.end(writer, b"}")
+	}
+
+	fn begin_object_key<W: ?Sized + Write>(
+		&mut self,
+		writer: &mut W,
+		first: bool,
+	) -> io::Result<()> {
+		self.begin_value(writer, first)
+	}
+
+	fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		writer.write_all(b" : ")
+	}
+
+	fn end_object_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+		self.has_value = true;
+		Ok(())
+	}
+}
+
+/// Write two spaces per indentation level
+fn indent<W: ?Sized + Write>(writer: &mut W, level: usize) -> io::Result<()> {
+	for _ in 0..level {
+		writer.write_all(b"  ")?;
+	}
+	Ok(())
+}
+
+/// Serialize data as JSON formatted the way Twitter formats archives
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::format;
+/// use twitter_archive::structs::verified::VerifiedObject;
+///
+/// let json = r#"{
+///   "verified" : {
+///     "accountId" : "111111111",
+///     "verified" : false
+///   }
+/// }"#;
+///
+/// let data: VerifiedObject = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(format::to_archive_string(&data).unwrap(), json);
+/// ```
+pub fn to_archive_string<T: ?Sized + Serialize>(value: &T) -> Result<String> {
+	let mut buff = Vec::new();
+	to_archive_writer(&mut buff, value)?;
+	Ok(String::from_utf8(buff).expect("serde_json writes valid UTF-8"))
+}
+
+/// Same as `to_archive_string` though writing into any `Write` implementation
+pub fn to_archive_writer<W: Write, T: ?Sized + Serialize>(writer: W, value: &T) -> Result<()> {
+	let mut serializer = serde_json::Serializer::with_formatter(writer, ArchiveFormatter::new());
+	value.serialize(&mut serializer)?;
+	Ok(())
+}
+
+/// Serialize data as JavaScript source, including `window.<GLOBAL_NAME>.part0 = ` prefix, that
+/// may replace original file within archive
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::format;
+/// use twitter_archive::structs::verified::VerifiedObject;
+///
+/// let data: Vec<VerifiedObject> = Vec::new();
+///
+/// assert_eq!(format::to_js_string(&data).unwrap(), "window.YTD.verified.part0 = [ ]");
+/// ```
+pub fn to_js_string<T: FromJsStr + Serialize>(value: &T) -> Result<String> {
+	let json = to_archive_string(value)?;
+
+	let part = if T::GLOBAL_NAME.starts_with("YTD.") {
+		".part0"
+	} else {
+		""
+	};
+
+	Ok(format!("window.{}{part} = {json}", T::GLOBAL_NAME))
+}
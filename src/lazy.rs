@@ -0,0 +1,643 @@
+#!/usr/bin/env rust
+
+//! Cache typed data so each file within an archive is parsed at most once
+//!
+//! ## Example lazy archive
+//!
+//! ```no_build
+//! use twitter_archive::lazy::LazyArchive;
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let archive = LazyArchive::new(ArchiveReader::open(input_file).expect("Unable to read --input-file"));
+//!
+//!     // First call parses `data/tweets.js`, following calls are cheap
+//!     println!("Tweets: {}", archive.tweets().unwrap().len());
+//!     println!("Likes: {}", archive.like().unwrap().len());
+//!     println!("Likes per Tweet: {}", archive.like().unwrap().len() / archive.tweets().unwrap().len());
+//! }
+//! ```
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+
+use crate::parse::FromJsStr;
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::Result;
+
+use crate::structs::{
+	account, account_creation_ip, account_label, account_suspension, account_timezone,
+	ad_engagements, ad_free_article_visits, ad_impressions, ageinfo, app, block, branch_links,
+	catalog_item, commerce_catalog, community_note, community_note_rating,
+	community_note_tombstone, community_tweet, connected_application, contact, deleted_note_tweet,
+	deleted_tweet_headers, device_token, direct_message_group_headers, direct_message_headers,
+	direct_message_mute, direct_messages, direct_messages_group, email_address_change, follower,
+	following, grok_chat_item, ip_audit, key_registry, like, lists_created, lists_member,
+	lists_subscribed, moment, mute, ni_devices, note_tweet, payment_transaction, periscope,
+	personalization, phone_number, product_drop, product_set, professional_data, profile,
+	protected_history, reply_prompt, saved_search, screen_name_change, shop_module,
+	shopify_account, smartblock, spaces_metadata, sso, tweet_headers, tweetdeck, tweets,
+	twitter_article, twitter_article_metadata, twitter_circle, twitter_circle_member,
+	twitter_circle_tweet, user_link_clicks, verified,
+};
+
+/// Wraps `ArchiveReader` and caches parsed data, keyed by global name and type, so accessors may
+/// be called repeatedly without re-reading files
+///
+/// Results are shared via `Rc` so data from several files may be held at the same time.
+///
+/// ## Example
+///
+/// ```
+/// use std::io::{Cursor, Write};
+/// use std::rc::Rc;
+/// use zip::write::{FileOptions, ZipWriter};
+///
+/// use twitter_archive::lazy::LazyArchive;
+/// use twitter_archive::reader::ArchiveReader;
+///
+/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+///   { "verified" : { "accountId" : "111", "verified" : false } }
+/// ]"#).unwrap();
+///
+/// let archive = LazyArchive::new(ArchiveReader::new(zip_writer.finish().unwrap()).unwrap());
+///
+/// let first = archive.verified().unwrap();
+/// let second = archive.verified().unwrap();
+///
+/// assert_eq!(first[0].verified.account_id, "111");
+/// assert!(Rc::ptr_eq(&first, &second));
+///
+/// // Missing files are not cached
+/// assert!(archive.tweets().is_err());
+/// assert!(archive.tweets().is_err());
+/// ```
+pub struct LazyArchive<S> {
+	reader: RefCell<ArchiveReader<S>>,
+	cache: RefCell<HashMap<(String, TypeId), Rc<dyn Any>>>,
+}
+
+impl<S: Source> LazyArchive<S> {
+	/// Wrap reader, nothing is parsed until first access
+	pub fn new(reader: ArchiveReader<S>) -> Self {
+		Self {
+			reader: RefCell::new(reader),
+			cache: RefCell::new(HashMap::new()),
+		}
+	}
+
+	/// Unwrap reader, dropping cached data
+	pub fn into_inner(self) -> ArchiveReader<S> {
+		self.reader.into_inner()
+	}
+
+	/// Drop all cached data, useful to release memory after analysis of a data type is done
+	pub fn clear(&self) {
+		self.cache.borrow_mut().clear();
+	}
+
+	/// Parse, at most once, every part of data for structures that know their own global name
+	///
+	/// Data is cached per type, so accessors reading one file as different types each parse it
+	/// once.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use std::rc::Rc;
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::lazy::LazyArchive;
+	/// use twitter_archive::reader::ArchiveReader;
+	/// use twitter_archive::structs::{deleted_tweets, tweets};
+	///
+	/// let tweet = r##"{
+	///   "tweet" : {
+	///     "edit_info" : {
+	///       "initial" : {
+	///         "editTweetIds" : [ "1111111111111111111" ],
+	///         "editableUntil" : "2023-08-12T17:10:37.000Z",
+	///         "editsRemaining" : "5",
+	///         "isEditEligible" : true
+	///       }
+	///     },
+	///     "retweeted" : false,
+	///     "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///     "entities" : { "hashtags" : [ ], "symbols" : [ ], "user_mentions" : [ ], "urls" : [ ] },
+	///     "display_text_range" : [ "0", "4" ],
+	///     "favorite_count" : "0",
+	///     "id_str" : "1111111111111111111",
+	///     "truncated" : false,
+	///     "retweet_count" : "0",
+	///     "id" : "1111111111111111111",
+	///     "created_at" : "Sat Aug 12 16:10:37 +0000 2023",
+	///     "favorited" : false,
+	///     "full_text" : "Fish",
+	///     "lang" : "en"
+	///   }
+	/// }"##;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/tweets.js", FileOptions::default()).unwrap();
+	/// write!(zip_writer, "window.YTD.tweets.part0 = [{tweet}]").unwrap();
+	/// zip_writer.start_file("data/deleted-tweets.js", FileOptions::default()).unwrap();
+	/// write!(zip_writer, "window.YTD.deleted_tweets.part0 = [{tweet}]").unwrap();
+	///
+	/// let archive = LazyArchive::new(ArchiveReader::new(zip_writer.finish().unwrap()).unwrap());
+	///
+	/// // Same file read as two types is cached once for each
+	/// let plain = archive.deleted_tweets().unwrap();
+	/// let detailed = archive.get::<deleted_tweets::DeletedTweetObject>().unwrap();
+	/// assert!(Rc::ptr_eq(&plain, &archive.deleted_tweets().unwrap()));
+	/// assert!(Rc::ptr_eq(&detailed, &archive.get().unwrap()));
+	///
+	/// // Tweets read generically share cache with `LazyArchive::tweets`
+	/// let generic = archive.get::<tweets::TweetObject>().unwrap();
+	/// assert!(Rc::ptr_eq(&generic, &archive.tweets().unwrap()));
+	/// ```
+	pub fn get<T>(&self) -> Result<Rc<Vec<T>>>
+	where
+		Vec<T>: FromJsStr,
+		T: DeserializeOwned + 'static,
+	{
+		self.get_with_global_name(<Vec<T>>::GLOBAL_NAME)
+	}
+
+	/// Parse, at most once, every part of data assigned to `global_name`
+	///
+	/// Tweets are read via `ArchiveReader::tweets`, so `get::<tweets::TweetObject>()` and
+	/// `LazyArchive::tweets` share one cache entry and both understand legacy archives.
+	pub fn get_with_global_name<T>(&self, global_name: &str) -> Result<Rc<Vec<T>>>
+	where
+		T: DeserializeOwned + 'static,
+	{
+		let global_name = global_name.strip_prefix("window.").unwrap_or(global_name);
+		if global_name == <Vec<tweets::TweetObject>>::GLOBAL_NAME
+			&& TypeId::of::<T>() == TypeId::of::<tweets::TweetObject>()
+		{
+			let data: Rc<dyn Any> = self.tweets()?;
+			return Ok(data
+				.downcast()
+				.unwrap_or_else(|_| unreachable!("`T` is `tweets::TweetObject`")));
+		}
+
+		self.get_or_load(global_name, |reader| reader.read_parts(global_name))
+	}
+
+	/// Return data cached under `global_name` and type `T`, or cache what `load` returns
+	fn get_or_load<T, F>(&self, global_name: &str, load: F) -> Result<Rc<Vec<T>>>
+	where
+		T: 'static,
+		F: FnOnce(&mut ArchiveReader<S>) -> Result<Vec<T>>,
+	{
+		let key = (global_name.to_string(), TypeId::of::<T>());
+		if let Some(cached) = self.cache.borrow().get(&key) {
+			return Ok(Rc::clone(cached)
+				.downcast()
+				.unwrap_or_else(|_| unreachable!("cache is keyed by type")));
+		}
+
+		let data: Rc<Vec<T>> = Rc::new(load(&mut self.reader.borrow_mut())?);
+		self.cache
+			.borrow_mut()
+			.insert(key, Rc::clone(&data) as Rc<dyn Any>);
+		Ok(data)
+	}
+
+	/// Parse, at most once, `data/account.js` file
+	pub fn account(&self) -> Result<Rc<Vec<account::AccountObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/account-creation-ip.js` file
+	pub fn account_creation_ip(
+		&self,
+	) -> Result<Rc<Vec<account_creation_ip::AccountCreationIpObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/account-label.js` file
+	pub fn account_label(&self) -> Result<Rc<Vec<account_label::AccountLabelObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/account-suspension.js` file
+	pub fn account_suspension(
+		&self,
+	) -> Result<Rc<Vec<account_suspension::AccountSuspensionObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/account-timezone.js` file
+	pub fn account_timezone(&self) -> Result<Rc<Vec<account_timezone::AccountTimezoneObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ad-engagements.js` file
+	pub fn ad_engagements(&self) -> Result<Rc<Vec<ad_engagements::AdObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ad-free-article-visits.js` file
+	pub fn ad_free_article_visits(
+		&self,
+	) -> Result<Rc<Vec<ad_free_article_visits::AdFreeArticleVisitObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ad-impressions.js` file
+	pub fn ad_impressions(&self) -> Result<Rc<Vec<ad_impressions::AdObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ageinfo.js` file
+	pub fn ageinfo(&self) -> Result<Rc<Vec<ageinfo::AgeinfoObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/app.js` file
+	pub fn app(&self) -> Result<Rc<Vec<app::AppObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/block.js` file
+	pub fn block(&self) -> Result<Rc<Vec<block::BlockingObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/branch-links.js` file
+	pub fn branch_links(&self) -> Result<Rc<Vec<branch_links::BranchLinkObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/catalog-item.js` file
+	pub fn catalog_item(&self) -> Result<Rc<Vec<catalog_item::CatalogItemObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/commerce-catalog.js` file
+	pub fn commerce_catalog(&self) -> Result<Rc<Vec<commerce_catalog::CommerceCatalogObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/community-note.js` file
+	pub fn community_note(&self) -> Result<Rc<Vec<community_note::CommunityNoteObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/community-note-rating.js` file
+	pub fn community_note_rating(
+		&self,
+	) -> Result<Rc<Vec<community_note_rating::CommunityNoteRatingObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/community-note-tombstone.js` file
+	pub fn community_note_tombstone(
+		&self,
+	) -> Result<Rc<Vec<community_note_tombstone::CommunityNoteTombstoneObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/community-tweet.js` file
+	pub fn community_tweet(&self) -> Result<Rc<Vec<community_tweet::CommunityTweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/connected-application.js` file
+	pub fn connected_application(
+		&self,
+	) -> Result<Rc<Vec<connected_application::ConnectedApplicationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/contact.js` file
+	pub fn contact(&self) -> Result<Rc<Vec<contact::ContactObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/deleted-note-tweet.js` file
+	pub fn deleted_note_tweet(
+		&self,
+	) -> Result<Rc<Vec<deleted_note_tweet::DeletedNoteTweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/deleted-tweet-headers.js` file
+	pub fn deleted_tweet_headers(&self) -> Result<Rc<Vec<deleted_tweet_headers::TweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/deleted-tweets.js` file
+	pub fn deleted_tweets(&self) -> Result<Rc<Vec<tweets::TweetObject>>> {
+		self.get_with_global_name("YTD.deleted_tweets")
+	}
+
+	/// Parse, at most once, `data/device-token.js` file
+	pub fn device_token(&self) -> Result<Rc<Vec<device_token::DeviceTokenObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/direct-message-group-headers.js` file
+	pub fn direct_message_group_headers(
+		&self,
+	) -> Result<Rc<Vec<direct_message_group_headers::DmConversationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/direct-message-headers.js` file
+	pub fn direct_message_headers(
+		&self,
+	) -> Result<Rc<Vec<direct_message_headers::DmConversationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/direct-message-mute.js` file
+	pub fn direct_message_mute(
+		&self,
+	) -> Result<Rc<Vec<direct_message_mute::DirectMessageMuteObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/direct-messages.js` file
+	pub fn direct_messages(&self) -> Result<Rc<Vec<direct_messages::DmConversationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/direct-messages-group.js` file
+	pub fn direct_messages_group(
+		&self,
+	) -> Result<Rc<Vec<direct_messages_group::DmConversationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/email-address-change.js` file
+	pub fn email_address_change(
+		&self,
+	) -> Result<Rc<Vec<email_address_change::EmailAddressChangeObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/follower.js` file
+	pub fn follower(&self) -> Result<Rc<Vec<follower::FollowerObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/following.js` file
+	pub fn following(&self) -> Result<Rc<Vec<following::FollowingObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/grok-chat-item.js` file
+	pub fn grok_chat_item(&self) -> Result<Rc<Vec<grok_chat_item::GrokChatItemObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ip-audit.js` file
+	pub fn ip_audit(&self) -> Result<Rc<Vec<ip_audit::IpAuditObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/key-registry.js` file
+	pub fn key_registry(&self) -> Result<Rc<Vec<key_registry::RegisteredDevicesObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/like.js` file
+	pub fn like(&self) -> Result<Rc<Vec<like::LikeObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/lists-created.js` file
+	pub fn lists_created(&self) -> Result<Rc<Vec<lists_created::ListsCreatedObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/lists-member.js` file
+	pub fn lists_member(&self) -> Result<Rc<Vec<lists_member::UserListInfoObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/lists-subscribed.js` file
+	pub fn lists_subscribed(&self) -> Result<Rc<Vec<lists_subscribed::ListsSubscribedObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/moment.js` file
+	pub fn moment(&self) -> Result<Rc<Vec<moment::MomentObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/mute.js` file
+	pub fn mute(&self) -> Result<Rc<Vec<mute::MutingObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/ni-devices.js` file
+	pub fn ni_devices(&self) -> Result<Rc<Vec<ni_devices::NiDeviceResponseObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/note-tweet.js` file
+	pub fn note_tweet(&self) -> Result<Rc<Vec<note_tweet::NoteTweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/payment-transaction.js` file
+	pub fn payment_transaction(
+		&self,
+	) -> Result<Rc<Vec<payment_transaction::PaymentTransactionObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-account-information.js` file
+	pub fn periscope_account_information(
+		&self,
+	) -> Result<Rc<Vec<periscope::AccountInformationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-ban-information.js` file
+	pub fn periscope_ban_information(&self) -> Result<Rc<Vec<periscope::BanInformationObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-broadcast-metadata.js` file
+	pub fn periscope_broadcast_metadata(
+		&self,
+	) -> Result<Rc<Vec<periscope::BroadcastMetadataObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-comments-made-by-user.js` file
+	pub fn periscope_comments_made_by_user(
+		&self,
+	) -> Result<Rc<Vec<periscope::CommentsMadeByUserObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-expired-broadcasts.js` file
+	pub fn periscope_expired_broadcasts(
+		&self,
+	) -> Result<Rc<Vec<periscope::ExpiredBroadcastsObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-followers.js` file
+	pub fn periscope_followers(&self) -> Result<Rc<Vec<periscope::FollowersObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/periscope-profile-description.js` file
+	pub fn periscope_profile_description(
+		&self,
+	) -> Result<Rc<Vec<periscope::ProfileDescriptionObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/personalization.js` file
+	pub fn personalization(&self) -> Result<Rc<Vec<personalization::P13nDataObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/phone-number.js` file
+	pub fn phone_number(&self) -> Result<Rc<Vec<phone_number::DeviceObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/product-drop.js` file
+	pub fn product_drop(&self) -> Result<Rc<Vec<product_drop::ProductDropObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/product-set.js` file
+	pub fn product_set(&self) -> Result<Rc<Vec<product_set::ProductSetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/professional-data.js` file
+	pub fn professional_data(&self) -> Result<Rc<Vec<professional_data::ProfessionalDataObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/profile.js` file
+	pub fn profile(&self) -> Result<Rc<Vec<profile::ProfileObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/protected-history.js` file
+	pub fn protected_history(&self) -> Result<Rc<Vec<protected_history::ProtectedHistoryObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/reply-prompt.js` file
+	pub fn reply_prompt(&self) -> Result<Rc<Vec<reply_prompt::ReplyPromptObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/saved-search.js` file
+	pub fn saved_search(&self) -> Result<Rc<Vec<saved_search::SavedSearchObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/screen-name-change.js` file
+	pub fn screen_name_change(
+		&self,
+	) -> Result<Rc<Vec<screen_name_change::ScreenNameChangeObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/shop-module.js` file
+	pub fn shop_module(&self) -> Result<Rc<Vec<shop_module::ShopModuleObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/shopify-account.js` file
+	pub fn shopify_account(&self) -> Result<Rc<Vec<shopify_account::ShopifyAccountObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/smartblock.js` file
+	pub fn smartblock(&self) -> Result<Rc<Vec<smartblock::SmartblockObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/spaces-metadata.js` file
+	pub fn spaces_metadata(&self) -> Result<Rc<Vec<spaces_metadata::SpacesMetadataObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/sso.js` file
+	pub fn sso(&self) -> Result<Rc<Vec<sso::SsoObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/tweet-headers.js` file
+	pub fn tweet_headers(&self) -> Result<Rc<Vec<tweet_headers::TweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/tweetdeck.js` file
+	pub fn tweetdeck(&self) -> Result<Rc<Vec<tweetdeck::DeckObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/tweets.js` file
+	pub fn tweets(&self) -> Result<Rc<Vec<tweets::TweetObject>>> {
+		self.get_or_load(
+			<Vec<tweets::TweetObject>>::GLOBAL_NAME,
+			ArchiveReader::tweets,
+		)
+	}
+
+	/// Parse, at most once, `data/twitter-article.js` file
+	pub fn twitter_article(&self) -> Result<Rc<Vec<twitter_article::TwitterArticleObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/twitter-article-metadata.js` file
+	pub fn twitter_article_metadata(
+		&self,
+	) -> Result<Rc<Vec<twitter_article_metadata::TwitterArticleMetadataObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/twitter-circle.js` file
+	pub fn twitter_circle(&self) -> Result<Rc<Vec<twitter_circle::TwitterCircleObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/twitter-circle-member.js` file
+	pub fn twitter_circle_member(
+		&self,
+	) -> Result<Rc<Vec<twitter_circle_member::TwitterCircleMemberObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/twitter-circle-tweet.js` file
+	pub fn twitter_circle_tweet(
+		&self,
+	) -> Result<Rc<Vec<twitter_circle_tweet::TwitterCircleTweetObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/user-link-clicks.js` file
+	pub fn user_link_clicks(&self) -> Result<Rc<Vec<user_link_clicks::UserLinkClickObject>>> {
+		self.get()
+	}
+
+	/// Parse, at most once, `data/verified.js` file
+	pub fn verified(&self) -> Result<Rc<Vec<verified::VerifiedObject>>> {
+		self.get()
+	}
+}
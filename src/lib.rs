@@ -6,11 +6,21 @@
 #![deny(clippy::all)]
 #![deny(missing_docs)]
 
+#[macro_use]
+mod macros;
+
 /// Various functions for facilitating conversion between JSON and Rust values
 pub mod convert {
+	/// Convert Rust `bool` type to/from `"true"` and `"false"` strings
+	pub mod bool_like_string;
+
 	/// Convert Rust `DateTime` type to/from `tweets[].tweet.created_at` string
 	pub mod created_at;
 
+	/// Convert Rust `DateTime<FixedOffset>` type, keeping original UTC offset, to/from
+	/// `tweets[].tweet.created_at` string
+	pub mod created_at_fixed_offset;
+
 	/// Convert Rust `DateTime` type to/from strings found in;
 	///
 	/// - `tweets[].tweet.edit_info.initial.editableUntil`
@@ -28,16 +38,97 @@ pub mod convert {
 	/// - `ad_impressions[].ad.adsUserData.adImpressions.impressions[].impressionTime`
 	pub mod date_year_month_day_hour_minute_second;
 
-	/// Convert Rust `[usize; 2]` type to/from array of strings found mostly within;
+	/// Error construction shared by date time-stamp conversion modules
+	mod date_error;
+
+	/// Convert Rust `Duration` type to/from millisecond strings found in;
+	///
+	/// - `tweets[].tweet.extended_entities.media[].video_info.duration_millis`
+	pub mod duration_millis;
+
+	/// Parse E.164 phone numbers found in;
+	///
+	/// - `phone_number[].device.phoneNumber`
+	/// - `ni_devices[].niDeviceResponse.messagingDevice.phoneNumber`
+	#[cfg(feature = "e164")]
+	pub mod e164;
+
+	/// Convert Rust `DateTime` type to/from millisecond epoch strings, for custom structs of data
+	/// types this crate does not yet describe; Periscope and Spaces time-stamps are ISO 8601
+	pub mod epoch_millis;
+
+	/// Convert `GeoPoint` type to/from stringified coordinate arrays found in;
+	///
+	/// - `tweets[].tweet.geo.coordinates`
+	/// - `tweets[].tweet.coordinates.coordinates`
+	pub mod geo;
+
+	/// Decode, and encode, HTML entities within strings found in;
+	///
+	/// - `tweets[].tweet.full_text`
+	/// - `profile[].profile.description.bio`
+	/// - `direct_messages[].dmConversation.messages[].messageCreate.text`
+	pub mod html_entities;
+
+	/// Convert Rust `u64` type to/from ID strings found in;
+	///
+	/// - `tweets[].tweet.id_str`
+	/// - `like[].like.tweetId`
+	///
+	/// Also orders IDs numerically for `query` sorting, and decodes `convert::snowflake` time-stamps
+	pub mod id_u64;
+
+	/// Convert Rust `[usize; 2]` type to/from array of strings found within;
+	///
+	/// - `tweets[].tweet.display_text_range`
+	/// - `tweets[].tweet.extended_entities.media[].video_info.aspect_ratio`
+	pub mod indices;
+
+	/// Convert Rust `Vec<usize>` type to/from array of strings, of any length, found within;
 	///
 	/// - `tweets[].tweet.entities.hashtags[].indices`
 	/// - `tweets[].tweet.entities.symbols[].indices`
 	/// - `tweets[].tweet.entities.user_mentions[].indices`
 	/// - `tweets[].tweet.entities.urls[].indices`
-	pub mod indices;
+	/// - `tweets[].tweet.entities.media[].indices`
+	pub mod indices_permissive;
 
 	/// Convert Rust `usize` type to/from strings unlikely to overflow `usize::MAX`
 	pub mod number_like_string;
+
+	/// Convert Rust `Option<usize>` type to/from strings that may be empty or `null`
+	pub mod number_like_string_opt;
+
+	/// Convert Rust `Option<DateTime>` type to/from optional strings found in;
+	///
+	/// - `deleted_tweets[].tweet.deleted_at`
+	pub mod optional_created_at;
+
+	/// Convert Rust `Option<DateTime>` type to/from optional strings found in;
+	///
+	/// - `account_label[].accountLabel.removedAt`
+	pub mod optional_date_time_iso_8601;
+
+	/// Convert Rust `Option<Duration>` type to/from optional millisecond strings found in;
+	///
+	/// - `tweets[].tweet.extended_entities.media[].video_info.duration_millis`
+	pub mod optional_duration_millis;
+
+	/// `serde_with` helper types for use within `#[serde_as]` annotated structs
+	#[cfg(feature = "serde_with")]
+	pub mod serde_as;
+
+	/// Extract creation date time-stamp embedded within Snowflake IDs found in;
+	///
+	/// - `tweets[].tweet.id_str`
+	/// - `tweet_headers[].tweet.tweet_id`
+	pub mod snowflake;
+
+	/// Convert `chrono_tz::Tz` type from strings found in;
+	///
+	/// - `account_timezone[].accountTimezone.timeZone`
+	#[cfg(feature = "chrono-tz")]
+	pub mod time_zone;
 }
 
 /// Data structures that allow `serde` to better understand Mr. Musk's vision
@@ -45,6 +136,15 @@ pub mod structs {
 	/// Describe data within `twitter-<uuid>.zip:data/manifest.js` file
 	pub mod manifest;
 
+	/// Describe data within `twitter-<uuid>.zip:data/account-creation-ip.js` file
+	pub mod account_creation_ip;
+
+	/// Describe data within `twitter-<uuid>.zip:data/account-label.js` file
+	pub mod account_label;
+
+	/// Describe data within `twitter-<uuid>.zip:data/account-suspension.js` file
+	pub mod account_suspension;
+
 	/// Describe data within `twitter-<uuid>.zip:data/account-timezone.js` file
 	pub mod account_timezone;
 
@@ -60,21 +160,57 @@ pub mod structs {
 	/// Describe data within `twitter-<uuid>.zip:data/ad-engagements.js` file
 	pub mod ad_engagements;
 
+	/// Describe data within `twitter-<uuid>.zip:data/ad-free-article-visits.js` file
+	pub mod ad_free_article_visits;
+
 	/// Describe data within `twitter-<uuid>.zip:data/ad-impressions.js` file
 	pub mod ad_impressions;
 
+	/// Describe data within `twitter-<uuid>.zip:data/ageinfo.js` file
+	pub mod ageinfo;
+
+	/// Describe data within `twitter-<uuid>.zip:data/app.js` file
+	pub mod app;
+
 	/// Describe data within `twitter-<uuid>.zip:data/block.js` file
 	pub mod block;
 
+	/// Describe data within `twitter-<uuid>.zip:data/branch-links.js` file
+	pub mod branch_links;
+
+	/// Describe data within `twitter-<uuid>.zip:data/catalog-item.js` file
+	pub mod catalog_item;
+
+	/// Describe data within `twitter-<uuid>.zip:data/commerce-catalog.js` file
+	pub mod commerce_catalog;
+
+	/// Describe data within `twitter-<uuid>.zip:data/community-note.js` file
+	pub mod community_note;
+
 	/// Describe data within `twitter-<uuid>.zip:data/community-note-rating.js` file
 	pub mod community_note_rating;
 
+	/// Describe data within `twitter-<uuid>.zip:data/community-note-tombstone.js` file
+	pub mod community_note_tombstone;
+
+	/// Describe data within `twitter-<uuid>.zip:data/community-tweet.js` file
+	pub mod community_tweet;
+
 	/// Describe data within `twitter-<uuid>.zip:data/connected-application.js` file
 	pub mod connected_application;
 
+	/// Describe data within `twitter-<uuid>.zip:data/contact.js` file
+	pub mod contact;
+
+	/// Describe data within `twitter-<uuid>.zip:data/deleted-note-tweet.js` file
+	pub mod deleted_note_tweet;
+
 	/// Describe data within `twitter-<uuid>.zip:data/deleted-tweet-headers.js` file
 	pub mod deleted_tweet_headers;
 
+	/// Describe data within `twitter-<uuid>.zip:data/deleted-tweets.js` file
+	pub mod deleted_tweets;
+
 	/// Describe data within `twitter-<uuid>.zip:data/device-token.js` file
 	pub mod device_token;
 
@@ -90,6 +226,9 @@ pub mod structs {
 	/// - `twitter-<uuid>.zip:data/direct-messages-group.js`
 	pub mod direct_message;
 
+	/// Describe data within `twitter-<uuid>.zip:data/direct-message-mute.js` file
+	pub mod direct_message_mute;
+
 	/// Describe data within `twitter-<uuid>.zip:data/direct-messages.js` file
 	pub mod direct_messages;
 
@@ -111,6 +250,9 @@ pub mod structs {
 	/// Describe data within `twitter-<uuid>.zip:data/following.js` file
 	pub mod following;
 
+	/// Describe data within `twitter-<uuid>.zip:data/grok-chat-item.js` file
+	pub mod grok_chat_item;
+
 	/// Describe data within `twitter-<uuid>.zip:data/ip-audit.js` file
 	pub mod ip_audit;
 
@@ -120,27 +262,85 @@ pub mod structs {
 	/// Describe data within `twitter-<uuid>.zip:data/like.js` file
 	pub mod like;
 
+	/// Describe entries common between;
+	///
+	/// - `twitter-<uuid>.zip:data/lists-created.js`
+	/// - `twitter-<uuid>.zip:data/lists-member.js`
+	/// - `twitter-<uuid>.zip:data/lists-subscribed.js`
+	pub mod list;
+
+	/// Describe data within `twitter-<uuid>.zip:data/lists-created.js` file
+	pub mod lists_created;
+
 	/// Describe data within `twitter-<uuid>.zip:data/lists-member.js` file
 	pub mod lists_member;
 
+	/// Describe data within `twitter-<uuid>.zip:data/lists-subscribed.js` file
+	pub mod lists_subscribed;
+
+	/// Describe data within `twitter-<uuid>.zip:data/moment.js` file
+	pub mod moment;
+
 	/// Describe data within `twitter-<uuid>.zip:data/mute.js` file
 	pub mod mute;
 
 	/// Describe data within `twitter-<uuid>.zip:data/ni-devices.js` file
 	pub mod ni_devices;
 
+	/// Describe data within `twitter-<uuid>.zip:data/note-tweet.js` file
+	pub mod note_tweet;
+
+	/// Describe data within `twitter-<uuid>.zip:data/payment-transaction.js` file
+	pub mod payment_transaction;
+
+	/// Describe data within `twitter-<uuid>.zip:data/periscope-*.js` files
+	pub mod periscope;
+
 	/// Describe data within `twitter-<uuid>.zip:data/personalization.js` file
 	pub mod personalization;
 
 	/// Describe data within `twitter-<uuid>.zip:data/phone-number.js` file
 	pub mod phone_number;
 
+	/// Describe data within `twitter-<uuid>.zip:data/product-drop.js` file
+	pub mod product_drop;
+
+	/// Describe data within `twitter-<uuid>.zip:data/product-set.js` file
+	pub mod product_set;
+
+	/// Describe data within `twitter-<uuid>.zip:data/professional-data.js` file
+	pub mod professional_data;
+
 	/// Describe data within `twitter-<uuid>.zip:data/profile.js` file
 	pub mod profile;
 
+	/// Describe data within `twitter-<uuid>.zip:data/protected-history.js` file
+	pub mod protected_history;
+
+	/// Describe data within `twitter-<uuid>.zip:data/reply-prompt.js` file
+	pub mod reply_prompt;
+
+	/// Describe data within `twitter-<uuid>.zip:data/saved-search.js` file
+	pub mod saved_search;
+
 	/// Describe data within `twitter-<uuid>.zip:data/screen-name-change.js` file
 	pub mod screen_name_change;
 
+	/// Describe data within `twitter-<uuid>.zip:data/shop-module.js` file
+	pub mod shop_module;
+
+	/// Describe data within `twitter-<uuid>.zip:data/shopify-account.js` file
+	pub mod shopify_account;
+
+	/// Describe data within `twitter-<uuid>.zip:data/smartblock.js` file
+	pub mod smartblock;
+
+	/// Describe data within `twitter-<uuid>.zip:data/spaces-metadata.js` file
+	pub mod spaces_metadata;
+
+	/// Describe data within `twitter-<uuid>.zip:data/sso.js` file
+	pub mod sso;
+
 	/// Describe data within `twitter-<uuid>.zip:data/tweets.js` file
 	pub mod tweets;
 
@@ -150,9 +350,114 @@ pub mod structs {
 	/// Describe data within `twitter-<uuid>.zip:data/tweetdeck.js` file
 	pub mod tweetdeck;
 
+	/// Describe data within `twitter-<uuid>.zip:data/twitter-article.js` file
+	pub mod twitter_article;
+
+	/// Describe data within `twitter-<uuid>.zip:data/twitter-article-metadata.js` file
+	pub mod twitter_article_metadata;
+
 	/// Describe data within `twitter-<uuid>.zip:data/twitter-circle.js` file
 	pub mod twitter_circle;
 
+	/// Describe data within `twitter-<uuid>.zip:data/twitter-circle-member.js` file
+	pub mod twitter_circle_member;
+
+	/// Describe data within `twitter-<uuid>.zip:data/twitter-circle-tweet.js` file
+	pub mod twitter_circle_tweet;
+
+	/// Describe link entries common between;
+	///
+	/// - `twitter-<uuid>.zip:data/tweets.js`
+	/// - `twitter-<uuid>.zip:data/note-tweet.js`
+	/// - `twitter-<uuid>.zip:data/direct-messages.js`
+	/// - `twitter-<uuid>.zip:data/direct-messages-group.js`
+	pub mod url_entity;
+
+	/// Describe data within `twitter-<uuid>.zip:data/user-link-clicks.js` file
+	pub mod user_link_clicks;
+
 	/// Describe data within `twitter-<uuid>.zip:data/verified.js` file
 	pub mod verified;
 }
+
+/// Error type shared by reading and parsing functions
+pub mod error;
+pub use error::{Error, Result};
+
+/// Parse JavaScript data files into Rust data structures
+pub mod parse;
+
+/// Serialize Rust data structures back into archive JSON formatting
+pub mod format;
+
+/// Render Tweets into formats suited to exports and viewers
+pub mod render {
+	/// Render Tweets, and threads of Tweets, as sanitized HTML
+	pub mod html;
+
+	/// Render Tweets, and threads of Tweets, as Markdown
+	pub mod markdown;
+}
+
+/// Write archive data into interchange formats suited to other tools
+pub mod export {
+	/// Convert Tweets, and threads of Tweets, into ActivityStreams 2.0 `Create` activities and
+	/// `Note` objects
+	pub mod activitystreams;
+
+	/// Convert Tweets, Likes, ad impressions, and IP audit log-ins into Arrow record batches and
+	/// Parquet files
+	#[cfg(feature = "arrow")]
+	pub mod arrow;
+
+	/// Write list-like data, such as Tweets, Likes, or Direct Messages, as CSV
+	pub mod csv;
+
+	/// Write records, such as Tweets, Likes, or Direct Messages, as JSON Lines
+	pub mod jsonl;
+
+	/// Convert Tweets into `outbox.json` and media of Mastodon account archives
+	pub mod mastodon;
+
+	/// Write whole archive into normalized SQLite database
+	#[cfg(feature = "sqlite")]
+	pub mod sqlite;
+}
+
+/// Read typed data from `twitter-<DATE>-<UID>.zip` archives
+pub mod reader;
+
+/// Zip file and extracted directory storage forms for archives
+pub mod source;
+
+/// Parse each data file at most once and cache results
+pub mod lazy;
+
+/// Report data types and files present within partial archives
+pub mod capabilities;
+
+/// Cross-check archive contents against manifest, and Direct Message headers against messages
+pub mod validate;
+
+/// Detect generation of archive exports
+pub mod version;
+
+/// Filter Tweets by date, language, kind, hashtag, mention, or text, sort them by time, and search
+/// Direct Messages
+pub mod query;
+
+/// Find Tweet that started thread of each reply within archive
+pub mod thread;
+
+/// Link versions of each edited Tweet within archive in order of editing
+pub mod edit;
+
+/// Direct Message conversations with ordered messages and resolved participants
+pub mod dm;
+
+/// Progress reporting while loading large archives
+pub mod progress;
+
+/// Async reading of `twitter-<DATE>-<UID>.zip` archives within Tokio run-times
+#[cfg(feature = "tokio")]
+pub mod async_reader;
@@ -0,0 +1,120 @@
+#!/usr/bin/env rust
+
+//! Macros shared by data structure definitions
+
+/// Define an `enum` for string values that take a mostly closed set of values
+///
+/// Each variant is paired with the exact string found within archives, and an `Other(String)`
+/// variant is appended to keep values unknown at time of writing.  Serialization goes through
+/// `String` so re-serializing is equivalent to original data, regardless of variant.
+///
+/// Generated `enum` also gets `as_str`, `Display`, `From<String>`, `From<&str>`, and comparisons
+/// against string slices, so existing code comparing against strings keeps working.
+///
+/// Fallback variant may be renamed, and documented, within parentheses after `enum` name, e.g.
+/// `pub enum Gender(/// Self-described  Custom) { ... }`
+macro_rules! string_enum {
+	(
+		$(#[$enum_meta:meta])*
+		pub enum $name:ident {
+			$(
+				$(#[$variant_meta:meta])*
+				$variant:ident => $value:literal,
+			)*
+		}
+	) => {
+		string_enum! {
+			$(#[$enum_meta])*
+			pub enum $name(
+				/// Value unknown at time of writing, kept verbatim
+				Other
+			) {
+				$(
+					$(#[$variant_meta])*
+					$variant => $value,
+				)*
+			}
+		}
+	};
+
+	(
+		$(#[$enum_meta:meta])*
+		pub enum $name:ident(
+			$(#[$other_meta:meta])*
+			$other:ident
+		) {
+			$(
+				$(#[$variant_meta:meta])*
+				$variant:ident => $value:literal,
+			)*
+		}
+	) => {
+		$(#[$enum_meta])*
+		#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+		#[serde(from = "String", into = "String")]
+		pub enum $name {
+			$(
+				$(#[$variant_meta])*
+				$variant,
+			)*
+
+			$(#[$other_meta])*
+			$other(String),
+		}
+
+		impl $name {
+			/// String as found within archive
+			pub fn as_str(&self) -> &str {
+				match self {
+					$( Self::$variant => $value, )*
+					Self::$other(value) => value,
+				}
+			}
+		}
+
+		impl From<&str> for $name {
+			fn from(value: &str) -> Self {
+				match value {
+					$( $value => Self::$variant, )*
+					_ => Self::$other(value.to_string()),
+				}
+			}
+		}
+
+		impl From<String> for $name {
+			fn from(value: String) -> Self {
+				match value.as_str() {
+					$( $value => Self::$variant, )*
+					_ => Self::$other(value),
+				}
+			}
+		}
+
+		impl From<$name> for String {
+			fn from(value: $name) -> Self {
+				match value {
+					$name::$other(value) => value,
+					value => value.as_str().to_string(),
+				}
+			}
+		}
+
+		impl std::fmt::Display for $name {
+			fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str(self.as_str())
+			}
+		}
+
+		impl PartialEq<str> for $name {
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		impl PartialEq<&str> for $name {
+			fn eq(&self, other: &&str) -> bool {
+				self.as_str() == *other
+			}
+		}
+	};
+}
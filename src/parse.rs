@@ -0,0 +1,620 @@
+#!/usr/bin/env rust
+
+//! Functions to parse JavaScript files found within `twitter-<DATE>-<UID>.zip:data/` directory
+//!
+//! Each data file assigns a JSON value to a global name, similar to;
+//!
+//! ```javascript
+//! window.YTD.tweets.part0 = [
+//!   { "tweet": { } }
+//! ]
+//! ```
+//!
+//! ... or for `data/manifest.js` file;
+//!
+//! ```javascript
+//! window.__THAR_CONFIG = {
+//!   "userInfo": { }
+//! }
+//! ```
+//!
+//! Rather than hard-coding `buff.replacen("window.YTD.tweets.part0 = ", "", 1)`, which silently
+//! breaks when spacing changes, prefer `parse::from_js_str` which tolerates whitespace variations,
+//! any `.partN` suffix, and trailing semicolons.
+//!
+//! ## Example file reader for `data/tweets.js`
+//!
+//! ```no_build
+//! use std::io::Read;
+//! use std::{fs, path};
+//! use zip::read::ZipArchive;
+//!
+//! use twitter_archive::parse;
+//! use twitter_archive::structs::tweets;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let file_descriptor = fs::File::open(input_file).expect("Unable to read --input-file");
+//!     let mut zip_archive = ZipArchive::new(file_descriptor).unwrap();
+//!     let mut zip_file = zip_archive.by_name("data/tweets.js").unwrap();
+//!     let mut buff = String::new();
+//!     zip_file.read_to_string(&mut buff).unwrap();
+//!
+//!     let data: Vec<tweets::TweetObject> = parse::from_js_str(&buff).expect("Unable to parse");
+//! }
+//! ```
+
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use serde::de::{self, DeserializeOwned};
+
+use crate::structs::tweets::TweetObject;
+use crate::{Error, Result};
+
+/// Data structures that know which JavaScript global name their file content is assigned to
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse::FromJsStr;
+/// use twitter_archive::structs::{manifest, tweets};
+///
+/// assert_eq!(<Vec<tweets::TweetObject>>::GLOBAL_NAME, "YTD.tweets");
+/// assert_eq!(manifest::Manifest::GLOBAL_NAME, "__THAR_CONFIG");
+/// ```
+pub trait FromJsStr: DeserializeOwned {
+	/// Name, without `window.` prefix nor `.partN` suffix, that data is assigned to
+	const GLOBAL_NAME: &'static str;
+}
+
+/// Remove `window.<GLOBAL_NAME>.partN = ` prefix, and trailing semicolon, from JavaScript source
+///
+/// The `global_name` may optionally include `window.` prefix and/or `.partN` suffix, when it does
+/// not include a `.partN` suffix then any part number is accepted.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse::strip_prelude;
+///
+/// let javascript = "window.YTD.verified.part0 = [ ]";
+/// assert_eq!(strip_prelude(javascript, "YTD.verified").unwrap(), "[ ]");
+///
+/// let javascript = "\n window.YTD.verified.part1=[];\n";
+/// assert_eq!(strip_prelude(javascript, "YTD.verified").unwrap(), "[]");
+///
+/// let javascript = "window.YTD.verified.part0 = [ ]";
+/// assert_eq!(strip_prelude(javascript, "window.YTD.verified.part0").unwrap(), "[ ]");
+///
+/// let javascript = "window.__THAR_CONFIG = { }";
+/// assert_eq!(strip_prelude(javascript, "__THAR_CONFIG").unwrap(), "{ }");
+///
+/// // Names must match exactly
+/// assert!(strip_prelude("window.YTD.verified.part0 = [ ]", "YTD.verified.part1").is_err());
+/// assert!(strip_prelude("window.YTD.tweets.part0 = [ ]", "YTD.tweet").is_err());
+/// ```
+pub fn strip_prelude<'a>(js: &'a str, global_name: &str) -> Result<&'a str> {
+	let global_name = global_name.strip_prefix("window.").unwrap_or(global_name);
+
+	let error = || prelude_mismatch(global_name);
+
+	let rest = js.trim_start_matches(|c: char| c.is_whitespace() || c == '\u{feff}');
+	let rest = rest.strip_prefix("window.").unwrap_or(rest);
+	let mut rest = rest.strip_prefix(global_name).ok_or_else(error)?;
+
+	if let Some(part) = rest.strip_prefix(".part") {
+		if global_name.contains(".part") {
+			return Err(error());
+		}
+		rest = part.trim_start_matches(|c: char| c.is_ascii_digit());
+	}
+
+	let rest = rest.trim_start().strip_prefix('=').ok_or_else(error)?;
+
+	let rest = rest.trim_end();
+	let rest = rest.strip_suffix(';').unwrap_or(rest);
+
+	Ok(rest.trim())
+}
+
+/// Parse JavaScript source, such as `data/tweets.js` content, into data structure that knows its
+/// own global name
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse;
+/// use twitter_archive::structs::verified::VerifiedObject;
+///
+/// let javascript = r#"window.YTD.verified.part0 = [
+///   {
+///     "verified" : {
+///       "accountId" : "435455769",
+///       "verified" : false
+///     }
+///   }
+/// ];"#;
+///
+/// let data: Vec<VerifiedObject> = parse::from_js_str(&javascript).unwrap();
+///
+/// assert_eq!(data.len(), 1);
+/// assert_eq!(data[0].verified.account_id, "435455769");
+/// assert_eq!(data[0].verified.verified, false);
+/// ```
+pub fn from_js_str<T: FromJsStr>(js: &str) -> Result<T> {
+	from_js_str_with_global_name(js, T::GLOBAL_NAME)
+}
+
+/// Parse JavaScript source where global name is only known at run-time, for example
+/// `data/deleted-tweets.js` content shares data structures with `data/tweets.js`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse;
+/// use twitter_archive::structs::tweets::TweetObject;
+///
+/// let javascript = "window.YTD.deleted_tweets.part0 = [ ]";
+///
+/// let data: Vec<TweetObject> = parse::from_js_str_with_global_name(&javascript, "YTD.deleted_tweets").unwrap();
+///
+/// assert_eq!(data.len(), 0);
+/// ```
+pub fn from_js_str_with_global_name<T: DeserializeOwned>(js: &str, global_name: &str) -> Result<T> {
+	let json = strip_prelude(js, global_name)?;
+	let prelude_length = json.as_ptr() as usize - js.as_ptr() as usize;
+	let error = |source| json_error(source, prelude_length, json.as_bytes());
+
+	let mut deserializer = serde_json::Deserializer::from_str(json);
+	let value = deserialize_with_path(&mut deserializer)
+		.map_err(|(path, source)| with_path(error(source), path))?;
+	deserializer.end().map_err(error)?;
+
+	Ok(value)
+}
+
+/// Iterator that parses one element at a time from JavaScript source assigning a JSON array to a
+/// global name, so large files such as `data/tweets.js` need not be held in memory all at once
+///
+/// Returned by `parse::stream_js` and `parse::stream_tweets`, after any error the iterator ends.
+pub struct JsArrayStream<R, T> {
+	reader: BufReader<R>,
+	global_name: String,
+	position: usize,
+	started: bool,
+	finished: bool,
+	item: PhantomData<T>,
+}
+
+/// Stream elements of JavaScript source where global name is only known at run-time
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse;
+/// use twitter_archive::structs::verified::VerifiedObject;
+///
+/// let javascript = r#"window.YTD.verified.part0 = [
+///   { "verified" : { "accountId" : "111", "verified" : false } },
+///   { "verified" : { "accountId" : "[222]", "verified" : true } }
+/// ];"#;
+///
+/// let mut stream = parse::stream_js::<VerifiedObject, _>(javascript.as_bytes(), "YTD.verified");
+///
+/// assert_eq!(stream.next().unwrap().unwrap().verified.account_id, "111");
+/// assert_eq!(stream.next().unwrap().unwrap().verified.account_id, "[222]");
+/// assert!(stream.next().is_none());
+///
+/// // Mismatched global names are reported once
+/// let mut stream = parse::stream_js::<VerifiedObject, _>(javascript.as_bytes(), "YTD.tweets");
+/// assert!(stream.next().unwrap().is_err());
+/// assert!(stream.next().is_none());
+/// ```
+pub fn stream_js<T: DeserializeOwned, R: Read>(
+	reader: R,
+	global_name: &str,
+) -> JsArrayStream<R, T> {
+	JsArrayStream {
+		reader: BufReader::new(reader),
+		global_name: global_name.to_string(),
+		position: 0,
+		started: false,
+		finished: false,
+		item: PhantomData,
+	}
+}
+
+/// Stream Tweets from `data/tweets.js` content, each Tweet may be processed and dropped before
+/// the next is parsed
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse;
+///
+/// let javascript = r#"window.YTD.tweets.part0 = [
+///   {
+///     "tweet" : {
+///       "edit_info" : {
+///         "initial" : {
+///           "editTweetIds" : [ "1111111111111111111" ],
+///           "editableUntil" : "2020-01-01T00:00:00.000Z",
+///           "editsRemaining" : "5",
+///           "isEditEligible" : false
+///         }
+///       },
+///       "retweeted" : false,
+///       "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///       "entities" : {
+///         "hashtags" : [ ],
+///         "symbols" : [ ],
+///         "user_mentions" : [ ],
+///         "urls" : [ ]
+///       },
+///       "display_text_range" : [ "0", "14" ],
+///       "favorite_count" : "0",
+///       "id_str" : "1111111111111111111",
+///       "truncated" : false,
+///       "retweet_count" : "0",
+///       "id" : "1111111111111111111",
+///       "created_at" : "Wed Jan 01 00:00:00 +0000 2020",
+///       "favorited" : false,
+///       "full_text" : "Hello, { world",
+///       "lang" : "en"
+///     }
+///   }
+/// ]"#;
+///
+/// let mut count = 0;
+/// for result in parse::stream_tweets(javascript.as_bytes()) {
+///     let object = result.unwrap();
+///     assert_eq!(object.tweet.full_text, "Hello, { world");
+///     count += 1;
+/// }
+///
+/// assert_eq!(count, 1);
+/// ```
+pub fn stream_tweets<R: Read>(reader: R) -> JsArrayStream<R, TweetObject> {
+	stream_js(reader, "YTD.tweets")
+}
+
+impl<R: Read, T: DeserializeOwned> JsArrayStream<R, T> {
+	/// Look at next byte without consuming it
+	fn peek(&mut self) -> Result<Option<u8>> {
+		Ok(self.reader.fill_buf()?.first().copied())
+	}
+
+	/// Consume next byte, reaching end of input is an error
+	fn next_byte(&mut self) -> Result<u8> {
+		let byte = self
+			.peek()?
+			.ok_or_else(|| syntax_error("unterminated JavaScript array", self.position))?;
+		self.reader.consume(1);
+		self.position += 1;
+		Ok(byte)
+	}
+
+	/// Consume whitespace and return following byte
+	fn next_non_whitespace(&mut self) -> Result<u8> {
+		loop {
+			let byte = self.next_byte()?;
+			if !byte.is_ascii_whitespace() {
+				return Ok(byte);
+			}
+		}
+	}
+
+	/// Check `window.<GLOBAL_NAME>.partN = [` prefix
+	fn read_prelude(&mut self) -> Result<()> {
+		self.position += consume_prelude(&mut self.reader, &self.global_name)?;
+
+		match self.next_non_whitespace()? {
+			b'[' => Ok(()),
+			_ => Err(syntax_error("expected JavaScript array", self.position)),
+		}
+	}
+
+	/// Collect bytes of next array element, and offset of its first byte, or `None` when end of
+	/// array is reached
+	fn read_element(&mut self) -> Result<Option<(usize, Vec<u8>)>> {
+		let mut byte = self.next_non_whitespace()?;
+		if !self.started {
+			self.started = true;
+			if byte == b']' {
+				return Ok(None);
+			}
+		} else {
+			match byte {
+				b',' => byte = self.next_non_whitespace()?,
+				b']' => return Ok(None),
+				_ => {
+					return Err(syntax_error(
+						"expected `,` or `]` between array elements",
+						self.position,
+					))
+				}
+			}
+		}
+
+		let offset = self.position - 1;
+		let mut buff = vec![byte];
+		let mut depth: usize = 0;
+		let mut in_string = false;
+		let mut escaped = false;
+		loop {
+			if in_string {
+				if escaped {
+					escaped = false;
+				} else if byte == b'\\' {
+					escaped = true;
+				} else if byte == b'"' {
+					in_string = false;
+				}
+			} else {
+				match byte {
+					b'"' => in_string = true,
+					b'{' | b'[' => depth += 1,
+					b'}' | b']' => {
+						depth = depth.checked_sub(1).ok_or_else(|| {
+							syntax_error("unbalanced JSON brackets", self.position)
+						})?;
+					}
+					_ => {}
+				}
+			}
+
+			if depth == 0 && !in_string {
+				match self.peek()? {
+					Some(next) if next == b',' || next == b']' || next.is_ascii_whitespace() => {
+						return Ok(Some((offset, buff)));
+					}
+					None => return Ok(Some((offset, buff))),
+					_ => {}
+				}
+			}
+
+			byte = self.next_byte()?;
+			buff.push(byte);
+		}
+	}
+}
+
+impl<R: Read, T: DeserializeOwned> Iterator for JsArrayStream<R, T> {
+	type Item = Result<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+
+		if !self.started {
+			if let Err(error) = self.read_prelude() {
+				self.finished = true;
+				return Some(Err(error));
+			}
+		}
+
+		let result = match self.read_element() {
+			Ok(Some((offset, buff))) => {
+				let mut deserializer = serde_json::Deserializer::from_slice(&buff);
+				deserialize_with_path(&mut deserializer)
+					.map_err(|(path, source)| with_path(json_error(source, offset, &buff), path))
+					.and_then(|value| {
+						deserializer
+							.end()
+							.map_err(|source| json_error(source, offset, &buff))?;
+						Ok(value)
+					})
+			}
+			Ok(None) => {
+				self.finished = true;
+				return None;
+			}
+			Err(error) => Err(error),
+		};
+
+		if result.is_err() {
+			self.finished = true;
+		}
+		Some(result)
+	}
+}
+
+/// Reader that checks and skips `window.<GLOBAL_NAME>.partN = ` prefix, passing remaining bytes
+/// through, so JSON may be deserialized without first buffering whole files into a `String`
+///
+/// ## Example
+///
+/// ```
+/// use std::io::Read;
+///
+/// use twitter_archive::parse::PreludeReader;
+///
+/// let javascript = "window.YTD.verified.part0 = [ ]";
+///
+/// let mut buff = String::new();
+/// PreludeReader::new(javascript.as_bytes(), "YTD.verified").read_to_string(&mut buff).unwrap();
+///
+/// assert_eq!(buff, " [ ]");
+/// ```
+pub struct PreludeReader<R> {
+	reader: BufReader<R>,
+	global_name: String,
+	position: usize,
+	checked: bool,
+}
+
+impl<R: Read> PreludeReader<R> {
+	/// Wrap `reader` expecting data to be assigned to `global_name`
+	pub fn new(reader: R, global_name: &str) -> Self {
+		Self {
+			reader: BufReader::new(reader),
+			global_name: global_name.to_string(),
+			position: 0,
+			checked: false,
+		}
+	}
+
+	/// Bytes consumed from wrapped reader so far, including prefix
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Consume and check prefix, if not already done
+	pub fn check_prelude(&mut self) -> Result<()> {
+		if !self.checked {
+			self.position += consume_prelude(&mut self.reader, &self.global_name)?;
+			self.checked = true;
+		}
+		Ok(())
+	}
+}
+
+impl<R: Read> Read for PreludeReader<R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.check_prelude()?;
+		let length = self.reader.read(buf)?;
+		self.position += length;
+		Ok(length)
+	}
+}
+
+/// Deserialize from any `Read`, such as a `zip::read::ZipFile`, data structure that knows its own
+/// global name
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::parse;
+/// use twitter_archive::structs::verified::VerifiedObject;
+///
+/// let javascript = r#"window.YTD.verified.part0 = [
+///   {
+///     "verified" : {
+///       "accountId" : "435455769",
+///       "verified" : false
+///     }
+///   }
+/// ];"#;
+///
+/// let data: Vec<VerifiedObject> = parse::from_js_reader(javascript.as_bytes()).unwrap();
+///
+/// assert_eq!(data.len(), 1);
+/// assert_eq!(data[0].verified.account_id, "435455769");
+///
+/// let error = parse::from_js_reader::<Vec<VerifiedObject>, _>("window.YTD.tweets.part0 = []".as_bytes());
+/// assert!(matches!(error, Err(twitter_archive::Error::PreludeMismatch { .. })));
+/// ```
+pub fn from_js_reader<T: FromJsStr, R: Read>(reader: R) -> Result<T> {
+	from_js_reader_with_global_name(reader, T::GLOBAL_NAME)
+}
+
+/// Deserialize from any `Read` where global name is only known at run-time
+pub fn from_js_reader_with_global_name<T: DeserializeOwned, R: Read>(
+	reader: R,
+	global_name: &str,
+) -> Result<T> {
+	let mut reader = PreludeReader::new(reader, global_name);
+	reader.check_prelude()?;
+
+	let mut deserializer = serde_json::Deserializer::from_reader(&mut reader);
+	// Note; `Deserializer::end` is intentionally not called so trailing `;` is tolerated
+	let result = deserialize_with_path(&mut deserializer);
+
+	result.map_err(|(path, source)| match Error::from(source) {
+		Error::Json {
+			file_name, source, ..
+		} => Error::Json {
+			file_name,
+			offset: Some(reader.position()),
+			path,
+			source,
+		},
+		error => error,
+	})
+}
+
+/// Deserialize while tracking field path, such as `[3].tweet.created_at`, so errors may report
+/// where within data structure they were noticed
+fn deserialize_with_path<'de, T, D>(
+	deserializer: D,
+) -> std::result::Result<T, (Option<String>, D::Error)>
+where
+	T: de::Deserialize<'de>,
+	D: de::Deserializer<'de>,
+{
+	serde_path_to_error::deserialize(deserializer).map_err(|error| {
+		let path = error.path().to_string();
+		let path = (path != ".").then_some(path);
+		(path, error.into_inner())
+	})
+}
+
+/// Attach field path to JSON errors
+fn with_path(mut error: Error, path: Option<String>) -> Error {
+	if let Error::Json { path: old_path, .. } = &mut error {
+		*old_path = path;
+	}
+	error
+}
+
+/// Consume bytes up to, and including, `=` and check they match `global_name`, returning number
+/// of bytes consumed
+fn consume_prelude<R: BufRead>(reader: &mut R, global_name: &str) -> Result<usize> {
+	let mut prelude = Vec::new();
+	loop {
+		let byte = match reader.fill_buf()?.first() {
+			Some(byte) => *byte,
+			None => return Err(prelude_mismatch(global_name)),
+		};
+		reader.consume(1);
+		prelude.push(byte);
+		if byte == b'=' {
+			break;
+		}
+		if prelude.len() > 256 {
+			return Err(prelude_mismatch(global_name));
+		}
+	}
+
+	let length = prelude.len();
+	let prelude = String::from_utf8(prelude).map_err(|_| prelude_mismatch(global_name))?;
+	strip_prelude(&prelude, global_name)?;
+	Ok(length)
+}
+
+/// Error for JavaScript prefix not assigning data to `global_name`
+fn prelude_mismatch(global_name: &str) -> Error {
+	Error::PreludeMismatch {
+		file_name: None,
+		expected: global_name.to_string(),
+	}
+}
+
+/// Error for malformed JavaScript array noticed at `offset`
+fn syntax_error(message: &str, offset: usize) -> Error {
+	Error::Json {
+		file_name: None,
+		offset: Some(offset),
+		path: None,
+		source: de::Error::custom(message),
+	}
+}
+
+/// Convert `serde_json` line and column, within `json` starting at `offset`, into byte offset
+fn json_error(source: serde_json::Error, offset: usize, json: &[u8]) -> Error {
+	let line_start: usize = json
+		.split_inclusive(|byte| *byte == b'\n')
+		.take(source.line().saturating_sub(1))
+		.map(<[u8]>::len)
+		.sum();
+
+	Error::Json {
+		file_name: None,
+		offset: Some(offset + line_start + source.column().saturating_sub(1)),
+		path: None,
+		source,
+	}
+}
@@ -0,0 +1,139 @@
+#!/usr/bin/env rust
+
+//! Progress reporting hooks, so GUI/CLI consumers can show progress bars while large archives load
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use std::sync::{Arc, Mutex};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::progress::Progress;
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.verified.part0 = [
+//!   { "verified" : { "accountId" : "111", "verified" : false } },
+//!   { "verified" : { "accountId" : "222", "verified" : true } }
+//! ]"#).unwrap();
+//!
+//! let events: Arc<Mutex<Vec<Progress>>> = Arc::new(Mutex::new(Vec::new()));
+//! let sink_events = Arc::clone(&events);
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap())
+//!     .unwrap()
+//!     .with_progress_sink(move |progress: &Progress| {
+//!         sink_events.lock().unwrap().push(progress.clone());
+//!     });
+//!
+//! archive.verified().unwrap();
+//!
+//! let events = events.lock().unwrap();
+//! let last = events.last().unwrap();
+//! assert_eq!(last.file_name, "data/verified.js");
+//! assert_eq!(last.entries_parsed, 2);
+//! assert_eq!(Some(last.bytes_read), last.total_bytes);
+//! ```
+
+use std::io::{self, Read};
+
+/// Bytes read, entries parsed, and current file while loading data
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+	/// Relative path to file within archive currently being read, such as `data/tweets.js`
+	pub file_name: String,
+
+	/// Bytes of current file read so far
+	pub bytes_read: u64,
+
+	/// Uncompressed size of current file, when known
+	pub total_bytes: Option<u64>,
+
+	/// Items parsed so far for current data type, across every `data/<name>-partN.js` file
+	pub entries_parsed: usize,
+}
+
+/// Receives progress updates from `reader::ArchiveReader`
+///
+/// Implemented for any `FnMut(&Progress)` closure, so most consumers need not implement it
+/// themselves.
+pub trait ProgressSink {
+	/// Called as bytes are read, at most once per 64 KiB, and after each file is parsed
+	fn on_progress(&mut self, progress: &Progress);
+}
+
+impl<F: FnMut(&Progress)> ProgressSink for F {
+	fn on_progress(&mut self, progress: &Progress) {
+		self(progress)
+	}
+}
+
+/// Sink plus latest progress, held by `reader::ArchiveReader`
+pub(crate) struct ProgressState {
+	sink: Box<dyn ProgressSink + Send>,
+	progress: Progress,
+}
+
+impl ProgressState {
+	/// Start tracking progress with `sink`
+	pub(crate) fn new<P: ProgressSink + Send + 'static>(sink: P) -> Self {
+		Self {
+			sink: Box::new(sink),
+			progress: Progress::default(),
+		}
+	}
+
+	/// Reset entry count before reading a data type
+	pub(crate) fn start_data_type(&mut self) {
+		self.progress.entries_parsed = 0;
+	}
+
+	/// Reset byte counts before reading a file
+	pub(crate) fn start_file(&mut self, file_name: &str, total_bytes: Option<u64>) {
+		self.progress.file_name = file_name.to_string();
+		self.progress.bytes_read = 0;
+		self.progress.total_bytes = total_bytes;
+	}
+
+	/// Count parsed items and notify sink
+	pub(crate) fn finish_file(&mut self, entries: usize) {
+		self.progress.entries_parsed += entries;
+		self.sink.on_progress(&self.progress);
+	}
+}
+
+/// Counts bytes passing through and notifies sink
+pub(crate) struct ProgressReader<'a, R> {
+	reader: R,
+	state: &'a mut ProgressState,
+	unreported: u64,
+}
+
+impl<'a, R: Read> ProgressReader<'a, R> {
+	/// Bytes read between notifications
+	const INTERVAL: u64 = 64 * 1024;
+
+	/// Wrap `reader`, counting towards file most recently passed to `ProgressState::start_file`
+	pub(crate) fn new(reader: R, state: &'a mut ProgressState) -> Self {
+		Self {
+			reader,
+			state,
+			unreported: 0,
+		}
+	}
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let length = self.reader.read(buf)?;
+		self.state.progress.bytes_read += length as u64;
+		self.unreported += length as u64;
+		if self.unreported >= Self::INTERVAL || (length == 0 && self.unreported > 0) {
+			self.unreported = 0;
+			self.state.sink.on_progress(&self.state.progress);
+		}
+		Ok(length)
+	}
+}
@@ -0,0 +1,474 @@
+#!/usr/bin/env rust
+
+//! Filter Tweets by date, language, kind, hashtag, mention, or text without hand-written closures,
+//! and order them chronologically; as well as search Direct Messages by participant, date, or text
+//!
+//! Every condition added to a `TweetFilter` must hold for a Tweet to match, and filters work with
+//! any iterator of `Tweet` or `TweetObject` values, or references to either.  `MessageFilter`
+//! likewise searches `dm::Conversation` messages.
+//!
+//! ## Example
+//!
+//! ```
+//! use chrono::{TimeZone, Utc};
+//!
+//! use twitter_archive::query::TweetFilter;
+//! use twitter_archive::structs::tweets::TweetObject;
+//!
+//! let json = r#"[
+//!   {
+//!     "tweet": {
+//!       "edit_info": {
+//!         "initial": {
+//!           "editTweetIds": [ "1111111111111111111" ],
+//!           "editableUntil": "2023-08-12T17:10:37.000Z",
+//!           "editsRemaining": "5",
+//!           "isEditEligible": true
+//!         }
+//!       },
+//!       "retweeted": false,
+//!       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities": {
+//!         "hashtags": [
+//!           {
+//!             "text": "RustLang",
+//!             "indices": [ "15", "24" ]
+//!           }
+//!         ],
+//!         "symbols": [],
+//!         "user_mentions": [
+//!           {
+//!             "name": "Example",
+//!             "screen_name": "EXAMPLE",
+//!             "indices": [ "0", "8" ],
+//!             "id_str": "222222222",
+//!             "id": "222222222"
+//!           }
+//!         ],
+//!         "urls": []
+//!       },
+//!       "display_text_range": [ "0", "24" ],
+//!       "favorite_count": "0",
+//!       "id_str": "1111111111111111111",
+//!       "truncated": false,
+//!       "retweet_count": "0",
+//!       "id": "1111111111111111111",
+//!       "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!       "favorited": false,
+//!       "full_text": "@EXAMPLE Ferris #RustLang",
+//!       "lang": "en"
+//!     }
+//!   }
+//! ]"#;
+//!
+//! let tweets: Vec<TweetObject> = serde_json::from_str(&json).unwrap();
+//!
+//! let filter = TweetFilter::new()
+//!     .after(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+//!     .lang("en")
+//!     .hashtag("#rustlang")
+//!     .mentions("@example");
+//!
+//! assert_eq!(filter.filter(&tweets).count(), 1);
+//!
+//! // Conditions add up, so any one not holding excludes Tweet
+//! assert_eq!(filter.clone().contains("crab").filter(&tweets).count(), 0);
+//! assert_eq!(filter.clone().lang("de").filter(&tweets).count(), 0);
+//! ```
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+
+use crate::convert::id_u64;
+use crate::dm::{Conversation, Message};
+use crate::structs::tweets::{Tweet, TweetKind};
+
+/// Conditions, added via builder methods, that Tweets must all meet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TweetFilter {
+	after: Option<DateTime<Utc>>,
+	before: Option<DateTime<Utc>>,
+	lang: Option<String>,
+	kinds: Vec<TweetKind>,
+	hashtags: Vec<String>,
+	mentions: Vec<String>,
+	contains: Vec<String>,
+}
+
+impl TweetFilter {
+	/// Filter that matches every Tweet until conditions are added
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Keep Tweets created strictly after `date`
+	pub fn after(mut self, date: DateTime<Utc>) -> Self {
+		self.after = Some(date);
+		self
+	}
+
+	/// Keep Tweets created strictly before `date`
+	pub fn before(mut self, date: DateTime<Utc>) -> Self {
+		self.before = Some(date);
+		self
+	}
+
+	/// Keep Tweets with `lang` code, compared ignoring ASCII case, replacing any earlier code
+	pub fn lang(mut self, lang: &str) -> Self {
+		self.lang = Some(lang.to_string());
+		self
+	}
+
+	/// Keep Tweets of `kind`, where calling more than once keeps Tweets of any given kind
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::query::TweetFilter;
+	/// use twitter_archive::structs::tweets::TweetKind;
+	///
+	/// let filter = TweetFilter::new().kind(TweetKind::Original).kind(TweetKind::Quote);
+	///
+	/// assert_ne!(filter, TweetFilter::new());
+	/// ```
+	pub fn kind(mut self, kind: TweetKind) -> Self {
+		self.kinds.push(kind);
+		self
+	}
+
+	/// Keep Tweets tagged with `hashtag`, with or without leading `#`, compared ignoring case
+	pub fn hashtag(mut self, hashtag: &str) -> Self {
+		let hashtag = hashtag.strip_prefix(['#', '＃']).unwrap_or(hashtag);
+		self.hashtags.push(hashtag.to_lowercase());
+		self
+	}
+
+	/// Keep Tweets mentioning `screen_name`, with or without leading `@`, compared ignoring case
+	pub fn mentions(mut self, screen_name: &str) -> Self {
+		let screen_name = screen_name.strip_prefix('@').unwrap_or(screen_name);
+		self.mentions.push(screen_name.to_lowercase());
+		self
+	}
+
+	/// Keep Tweets whose decoded `full_text` contains `text`, compared ignoring case
+	pub fn contains(mut self, text: &str) -> Self {
+		self.contains.push(text.to_lowercase());
+		self
+	}
+
+	/// Does `tweet` meet every condition
+	pub fn matches(&self, tweet: &Tweet) -> bool {
+		if self.after.is_some_and(|after| tweet.created_at <= after)
+			|| self.before.is_some_and(|before| tweet.created_at >= before)
+		{
+			return false;
+		}
+
+		if let Some(lang) = &self.lang {
+			if !tweet.lang.eq_ignore_ascii_case(lang) {
+				return false;
+			}
+		}
+
+		if !self.kinds.is_empty() && !self.kinds.contains(&tweet.kind()) {
+			return false;
+		}
+
+		let has_hashtags = self.hashtags.iter().all(|wanted| {
+			tweet
+				.entities
+				.hashtags
+				.iter()
+				.any(|hashtag| hashtag.text.to_lowercase() == *wanted)
+		});
+		let has_mentions = self.mentions.iter().all(|wanted| {
+			tweet
+				.entities
+				.user_mentions
+				.iter()
+				.any(|mention| mention.screen_name.to_lowercase() == *wanted)
+		});
+		if !has_hashtags || !has_mentions {
+			return false;
+		}
+
+		if !self.contains.is_empty() {
+			let text = tweet.full_text_decoded().to_lowercase();
+			if !self.contains.iter().all(|wanted| text.contains(wanted)) {
+				return false;
+			}
+		}
+
+		true
+	}
+
+	/// Lazily keep items of `tweets` that match, where items may be `Tweet`, `TweetObject`, or
+	/// references to either
+	pub fn filter<'a, I>(&'a self, tweets: I) -> impl Iterator<Item = I::Item> + 'a
+	where
+		I: IntoIterator,
+		I::IntoIter: 'a,
+		I::Item: AsRef<Tweet>,
+	{
+		tweets
+			.into_iter()
+			.filter(move |item| self.matches(item.as_ref()))
+	}
+}
+
+/// Conditions, added via builder methods, that Direct Messages must all meet
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// use twitter_archive::dm::Conversation;
+/// use twitter_archive::query::MessageFilter;
+/// use twitter_archive::structs::direct_messages::DMConversation;
+///
+/// let json = r#"{
+///   "conversationId": "111111111-222222222",
+///   "messages": [
+///     {
+///       "messageCreate": {
+///         "recipientId": "111111111",
+///         "reactions": [],
+///         "urls": [],
+///         "text": "Fish &amp; chips?",
+///         "mediaUrls": [],
+///         "senderId": "222222222",
+///         "id": "4444444444444444444",
+///         "createdAt": "2023-08-12T17:12:00.000Z"
+///       }
+///     },
+///     {
+///       "messageCreate": {
+///         "recipientId": "222222222",
+///         "reactions": [],
+///         "urls": [],
+///         "text": "Salutations!",
+///         "mediaUrls": [],
+///         "senderId": "111111111",
+///         "id": "3333333333333333333",
+///         "createdAt": "2023-08-12T17:10:37.000Z"
+///       }
+///     }
+///   ]
+/// }"#;
+///
+/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+/// let conversations = vec![Conversation::from(data)];
+///
+/// let filter = MessageFilter::new()
+///     .participant("222222222")
+///     .after(Utc.with_ymd_and_hms(2023, 8, 12, 17, 11, 0).unwrap())
+///     .contains("CHIPS");
+///
+/// let found: Vec<&str> = filter
+///     .search(&conversations)
+///     .map(|found| found.message.id.as_str())
+///     .collect();
+/// assert_eq!(found, ["4444444444444444444"]);
+///
+/// // Keywords match decoded text, and conditions add up
+/// assert_eq!(filter.clone().contains("&amp;").search(&conversations).count(), 0);
+/// assert_eq!(filter.clone().sender("111111111").search(&conversations).count(), 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageFilter {
+	after: Option<DateTime<Utc>>,
+	before: Option<DateTime<Utc>>,
+	participants: Vec<String>,
+	senders: Vec<String>,
+	contains: Vec<String>,
+}
+
+/// Message found by `MessageFilter::search`, along with conversation containing it
+///
+/// Serializes as fields of `message` plus `conversationId`
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct MessageRef<'a> {
+	/// Conversation that message belongs to
+	#[serde(
+		rename = "conversationId",
+		serialize_with = "serialize_conversation_id"
+	)]
+	pub conversation: &'a Conversation,
+
+	/// Message that met every condition
+	#[serde(flatten)]
+	pub message: &'a Message,
+}
+
+impl MessageFilter {
+	/// Filter that matches every message until conditions are added
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Keep messages sent strictly after `date`
+	pub fn after(mut self, date: DateTime<Utc>) -> Self {
+		self.after = Some(date);
+		self
+	}
+
+	/// Keep messages sent strictly before `date`
+	pub fn before(mut self, date: DateTime<Utc>) -> Self {
+		self.before = Some(date);
+		self
+	}
+
+	/// Keep messages of conversations that user with `user_id` took part in
+	pub fn participant(mut self, user_id: &str) -> Self {
+		self.participants.push(user_id.to_string());
+		self
+	}
+
+	/// Keep messages sent by user with `user_id`, where calling more than once keeps messages of
+	/// any given sender
+	pub fn sender(mut self, user_id: &str) -> Self {
+		self.senders.push(user_id.to_string());
+		self
+	}
+
+	/// Keep messages whose decoded `text` contains `text`, compared ignoring case
+	pub fn contains(mut self, text: &str) -> Self {
+		self.contains.push(text.to_lowercase());
+		self
+	}
+
+	/// Does `message`, within `conversation`, meet every condition
+	pub fn matches(&self, conversation: &Conversation, message: &Message) -> bool {
+		if self.after.is_some_and(|after| message.created_at <= after)
+			|| self
+				.before
+				.is_some_and(|before| message.created_at >= before)
+		{
+			return false;
+		}
+
+		let participants = conversation.participants();
+		if !self.participants.iter().all(|id| participants.contains(id)) {
+			return false;
+		}
+
+		if !self.senders.is_empty() && !self.senders.contains(&message.sender_id) {
+			return false;
+		}
+
+		if !self.contains.is_empty() {
+			let text = message.text_decoded().to_lowercase();
+			if !self.contains.iter().all(|wanted| text.contains(wanted)) {
+				return false;
+			}
+		}
+
+		true
+	}
+
+	/// Lazily find messages of `conversations` that match, in order of conversations then of
+	/// messages within each
+	pub fn search<'a>(
+		&'a self,
+		conversations: &'a [Conversation],
+	) -> impl Iterator<Item = MessageRef<'a>> + 'a {
+		conversations
+			.iter()
+			.flat_map(|conversation| {
+				conversation
+					.messages()
+					.iter()
+					.map(move |message| MessageRef {
+						conversation,
+						message,
+					})
+			})
+			.filter(move |found| self.matches(found.conversation, found.message))
+	}
+}
+
+/// Stable sort of `tweets` by `created_at`, then by numeric value of `id_str` for Tweets created
+/// within same second, since archives do not guarantee any order
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::query;
+/// use twitter_archive::structs::tweets::Tweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [ "1111111111111111111" ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [ "0", "5" ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "Hello",
+///   "lang": "en"
+/// }"#;
+///
+/// let first: Tweet = serde_json::from_str(&json).unwrap();
+///
+/// // Same second, larger ID
+/// let mut second = first.clone();
+/// second.id_str = "1111111111111111112".to_string();
+///
+/// // Shorter ID is an older Tweet
+/// let mut older = first.clone();
+/// older.id_str = "999".to_string();
+/// older.created_at -= chrono::Duration::days(1);
+///
+/// let mut tweets = vec![second.clone(), first.clone(), older.clone()];
+/// query::sort_by_created_at(&mut tweets);
+///
+/// let ids: Vec<&str> = tweets.iter().map(|tweet| tweet.id_str.as_str()).collect();
+/// assert_eq!(ids, ["999", "1111111111111111111", "1111111111111111112"]);
+/// ```
+pub fn sort_by_created_at<T: AsRef<Tweet>>(tweets: &mut [T]) {
+	tweets.sort_by(|a, b| {
+		let (a, b) = (a.as_ref(), b.as_ref());
+		a.created_at
+			.cmp(&b.created_at)
+			.then_with(|| compare_ids(&a.id_str, &b.id_str))
+	});
+}
+
+/// Order decimal ID strings numerically, as `u64` when both fit, otherwise by digits so IDs of
+/// any length compare correctly
+pub(crate) fn compare_ids(a: &str, b: &str) -> Ordering {
+	if let (Ok(a), Ok(b)) = (id_u64::parse(a), id_u64::parse(b)) {
+		return a.cmp(&b);
+	}
+
+	let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+	a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Serialize only ID of conversation that `MessageRef` points within
+fn serialize_conversation_id<S: Serializer>(
+	conversation: &&Conversation,
+	serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+	serializer.serialize_str(conversation.conversation_id())
+}
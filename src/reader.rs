@@ -0,0 +1,1328 @@
+#!/usr/bin/env rust
+
+//! Read data from `twitter-<DATE>-<UID>.zip` archives without each consumer having to re-implement
+//! zip file access and stripping of JavaScript prefixes
+//!
+//! ## Example archive reader
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.tweets().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Tweet */
+//!         println!("Index: {index}");
+//!         println!("Created at: {}", object.tweet.created_at);
+//!         println!("vvv Content\n{}\n^^^ Content", object.tweet.full_text);
+//!     }
+//! }
+//! ```
+
+use std::fs;
+use std::io::{Read, Seek};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::dm;
+use crate::parse::{self, FromJsStr};
+use crate::progress::{ProgressReader, ProgressSink, ProgressState};
+use crate::query;
+use crate::source::{DirSource, Source, ZipSource};
+use crate::version::ArchiveVersion;
+use crate::{Error, Result};
+
+use crate::structs::{
+	account, account_creation_ip, account_label, account_suspension, account_timezone,
+	ad_engagements, ad_free_article_visits, ad_impressions, ageinfo, app, block, branch_links,
+	catalog_item, commerce_catalog, community_note, community_note_rating,
+	community_note_tombstone, community_tweet, connected_application, contact, deleted_note_tweet,
+	deleted_tweet_headers, deleted_tweets, device_token, direct_message_group_headers,
+	direct_message_headers, direct_message_mute, direct_messages, direct_messages_group,
+	email_address_change, follower, following, grok_chat_item, ip_audit, key_registry, like,
+	lists_created, lists_member, lists_subscribed, manifest, moment, mute, ni_devices, note_tweet,
+	payment_transaction, periscope, personalization, phone_number, product_drop, product_set,
+	professional_data, profile, protected_history, reply_prompt, saved_search, screen_name_change,
+	shop_module, shopify_account, smartblock, spaces_metadata, sso, tweet_headers, tweetdeck,
+	tweets, twitter_article, twitter_article_metadata, twitter_circle, twitter_circle_member,
+	twitter_circle_tweet, user_link_clicks, verified,
+};
+
+/// Wraps an archive `Source`, zip file or extracted directory, and provides typed accessors for
+/// data files found within
+///
+/// ## Example
+///
+/// ```
+/// use std::io::{Cursor, Write};
+/// use zip::write::{FileOptions, ZipWriter};
+///
+/// use twitter_archive::reader::ArchiveReader;
+///
+/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+///   {
+///     "verified" : {
+///       "accountId" : "435455769",
+///       "verified" : false
+///     }
+///   }
+/// ]"#).unwrap();
+///
+/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+///
+/// let data = archive.verified().unwrap();
+///
+/// assert_eq!(data.len(), 1);
+/// assert_eq!(data[0].verified.account_id, "435455769");
+/// assert_eq!(data[0].verified.verified, false);
+/// ```
+pub struct ArchiveReader<S> {
+	source: S,
+
+	/// Cached untyped `data/manifest.js`, `Value::Null` when archive has no manifest
+	manifest: Option<Value>,
+
+	/// Cached `data/key-registry.js` for `media_for_dm`, empty when archive has no key registry
+	registered_devices: Option<Vec<key_registry::RegisteredDevices>>,
+
+	/// Optional progress reporting, see `with_progress_sink`
+	progress: Option<ProgressState>,
+}
+
+/// Reads extracted archive directories with the same typed accessors as zip files
+///
+/// ## Example
+///
+/// ```
+/// use std::fs;
+///
+/// use twitter_archive::reader::DirArchive;
+///
+/// let root = std::env::temp_dir().join(format!("twitter-archive-doc-{}", std::process::id()));
+/// fs::create_dir_all(root.join("data")).unwrap();
+/// fs::write(root.join("data/verified.js"), r#"window.YTD.verified.part0 = [
+///   {
+///     "verified" : {
+///       "accountId" : "435455769",
+///       "verified" : false
+///     }
+///   }
+/// ]"#).unwrap();
+///
+/// let mut archive = DirArchive::open_dir(&root).unwrap();
+///
+/// let data = archive.verified().unwrap();
+/// assert_eq!(data.len(), 1);
+/// assert_eq!(data[0].verified.account_id, "435455769");
+///
+/// fs::remove_dir_all(&root).unwrap();
+/// ```
+pub type DirArchive = ArchiveReader<DirSource>;
+
+impl ArchiveReader<ZipSource<fs::File>> {
+	/// Open `twitter-<DATE>-<UID>.zip` file found at `path`
+	pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+		Self::new(fs::File::open(path)?)
+	}
+
+	/// Open `twitter-<DATE>-<UID>.zip` file, found at `path`, that was encrypted at rest
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::error::Error;
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// // `data/verified.js` is AES-256 encrypted and `data/README.txt` is ZipCrypto encrypted
+	/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/encrypted.zip");
+	///
+	/// let mut archive = ArchiveReader::open_with_password(path, b"hunter2").unwrap();
+	///
+	/// let data = archive.verified().unwrap();
+	/// assert_eq!(data[0].verified.account_id, "435455769");
+	/// assert_eq!(archive.read_to_string("data/README.txt").unwrap(), "Hello!");
+	///
+	/// let mut archive = ArchiveReader::open_with_password(path, b"wrong").unwrap();
+	///
+	/// for file_name in ["data/verified.js", "data/README.txt"] {
+	///     match archive.read_to_string(file_name) {
+	///         Err(Error::InvalidPassword { file_name: name }) => assert_eq!(name, file_name),
+	///         other => panic!("expected Error::InvalidPassword, got {other:?}"),
+	///     }
+	/// }
+	/// ```
+	pub fn open_with_password<P: AsRef<Path>>(path: P, password: &[u8]) -> Result<Self> {
+		Self::new_with_password(fs::File::open(path)?, password)
+	}
+}
+
+impl<R: Read + Seek> ArchiveReader<ZipSource<R>> {
+	/// Wrap anything that reads and seeks through zip formatted data, such as `fs::File` or
+	/// `io::Cursor<Vec<u8>>`
+	pub fn new(reader: R) -> Result<Self> {
+		Ok(Self::from_source(ZipSource::new(reader)?))
+	}
+
+	/// Same as `new` for zip formatted data with AES or ZipCrypto encrypted entries
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/README.txt", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(b"Hello!").unwrap();
+	///
+	/// // Entries that are not encrypted are read as usual
+	/// let mut archive = ArchiveReader::new_with_password(zip_writer.finish().unwrap(), b"hunter2").unwrap();
+	///
+	/// assert_eq!(archive.read_to_string("data/README.txt").unwrap(), "Hello!");
+	///
+	/// // Encrypted entries need the right password
+	/// let bytes = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/encrypted.zip"));
+	///
+	/// let mut archive = ArchiveReader::new_with_password(Cursor::new(bytes), b"hunter2").unwrap();
+	/// assert_eq!(archive.read_to_string("data/README.txt").unwrap(), "Hello!");
+	///
+	/// let mut archive = ArchiveReader::new_with_password(Cursor::new(bytes), b"wrong").unwrap();
+	/// assert!(matches!(
+	///     archive.read_to_string("data/verified.js"),
+	///     Err(twitter_archive::error::Error::InvalidPassword { .. })
+	/// ));
+	/// ```
+	pub fn new_with_password(reader: R, password: &[u8]) -> Result<Self> {
+		Ok(Self::from_source(ZipSource::with_password(
+			reader, password,
+		)?))
+	}
+}
+
+impl ArchiveReader<DirSource> {
+	/// Open directory, containing `data/`, that a `twitter-<DATE>-<UID>.zip` file was extracted
+	/// into
+	pub fn open_dir<P: AsRef<Path>>(path: P) -> Result<Self> {
+		Ok(Self::from_source(DirSource::new(path)?))
+	}
+}
+
+impl<S: Source> ArchiveReader<S> {
+	/// Wrap any archive `Source`
+	pub fn from_source(source: S) -> Self {
+		Self {
+			source,
+			manifest: None,
+			registered_devices: None,
+			progress: None,
+		}
+	}
+
+	/// Report bytes read, entries parsed, and current file to `sink` while loading data, see
+	/// `progress` module for an example
+	pub fn with_progress_sink<P: ProgressSink + Send + 'static>(mut self, sink: P) -> Self {
+		self.set_progress_sink(sink);
+		self
+	}
+
+	/// Same as `with_progress_sink` for readers that are already in use
+	pub fn set_progress_sink<P: ProgressSink + Send + 'static>(&mut self, sink: P) {
+		self.progress = Some(ProgressState::new(sink));
+	}
+
+	/// Read full content of file within archive into a `String`
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/README.txt", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(b"Hello!").unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// assert_eq!(archive.read_to_string("data/README.txt").unwrap(), "Hello!");
+	/// assert!(archive.read_to_string("data/missing.js").is_err());
+	/// ```
+	pub fn read_to_string(&mut self, file_name: &str) -> Result<String> {
+		let mut buff = String::new();
+		self.source
+			.open_file(file_name)?
+			.read_to_string(&mut buff)?;
+		Ok(buff)
+	}
+
+	/// Open file within archive for reading raw bytes, such as media
+	pub(crate) fn open_file(&mut self, file_name: &str) -> Result<Box<dyn Read + '_>> {
+		self.source.open_file(file_name)
+	}
+
+	/// Read file within archive, clear JavaScript prefix, and parse remaining bytes as JSON
+	/// without buffering whole file into memory
+	pub(crate) fn read_data<T: DeserializeOwned>(
+		&mut self,
+		file_name: &str,
+		global_name: &str,
+	) -> Result<T> {
+		let result = match &mut self.progress {
+			Some(progress) => {
+				progress.start_file(file_name, self.source.file_size(file_name));
+				let reader = ProgressReader::new(self.source.open_file(file_name)?, progress);
+				parse::from_js_reader_with_global_name(reader, global_name)
+			}
+			None => parse::from_js_reader_with_global_name(
+				self.source.open_file(file_name)?,
+				global_name,
+			),
+		};
+		result.map_err(|error| error.with_file_name(file_name))
+	}
+
+	/// Check if archive contains a file, such as `data/tweets-part1.js`, without reading it
+	pub fn contains(&self, file_name: &str) -> bool {
+		self.source.contains(file_name)
+	}
+
+	/// Read `data/manifest.js` once as untyped JSON, which unlike `manifest()` tolerates data
+	/// types this crate does not know about, `Value::Null` is returned when archive has no
+	/// manifest
+	pub fn manifest_raw(&mut self) -> Result<&Value> {
+		if self.manifest.is_none() {
+			let manifest = if self.contains("data/manifest.js") {
+				self.read_data("data/manifest.js", manifest::Manifest::GLOBAL_NAME)?
+			} else {
+				Value::Null
+			};
+			self.manifest = Some(manifest);
+		}
+		Ok(self.manifest.get_or_insert(Value::Null))
+	}
+
+	/// List every file within archive, such as `data/tweets.js` and `data/tweets_media/<ID>.jpg`
+	pub fn file_names(&self) -> Vec<String> {
+		self.source.file_names()
+	}
+
+	/// List files holding data assigned to `global_name`, such as `"YTD.tweets"`
+	///
+	/// File list is read from `data/manifest.js` `dataTypes.<name>.files`, and when the manifest
+	/// is missing or does not describe `global_name` then single `data/<name>.js` file is assumed.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/manifest.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.__THAR_CONFIG = {
+	///   "dataTypes": {
+	///     "accountTimezone": {
+	///       "files": [
+	///         {
+	///           "fileName": "data/account-timezone.js",
+	///           "globalName": "YTD.account_timezone.part0",
+	///           "count": "1"
+	///         }
+	///       ]
+	///     }
+	///   }
+	/// }"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let files = archive.data_files("YTD.account_timezone").unwrap();
+	/// assert_eq!(files.len(), 1);
+	/// assert_eq!(files[0].count, 1);
+	///
+	/// // Not described by manifest
+	/// let files = archive.data_files("YTD.ni_devices").unwrap();
+	/// assert_eq!(files[0].file_name, "data/ni-devices.js");
+	/// assert_eq!(files[0].global_name, "YTD.ni_devices.part0");
+	/// ```
+	pub fn data_files(&mut self, global_name: &str) -> Result<Vec<manifest::File>> {
+		let name = global_name.strip_prefix("window.").unwrap_or(global_name);
+		let name = name.strip_prefix("YTD.").unwrap_or(name);
+
+		let files = self
+			.manifest_raw()?
+			.get("dataTypes")
+			.and_then(|data_types| data_types.get(snake_to_camel_case(name)))
+			.and_then(|data_type| data_type.get("files"));
+
+		match files {
+			Some(files) => Vec::<manifest::File>::deserialize(files)
+				.map_err(|error| Error::from(error).with_file_name("data/manifest.js")),
+			None => Ok(vec![manifest::File {
+				file_name: format!("data/{}.js", name.replace('_', "-")),
+				global_name: format!("YTD.{name}.part0"),
+				count: 0,
+			}]),
+		}
+	}
+
+	/// Does archive contain any file of data type parsed as `Vec<T>`
+	pub(crate) fn has_data_files<T>(&mut self) -> Result<bool>
+	where
+		Vec<T>: FromJsStr,
+	{
+		let files = self.data_files(<Vec<T>>::GLOBAL_NAME)?;
+		Ok(files.iter().any(|file| self.contains(&file.file_name)))
+	}
+
+	/// List media files present within archive for Tweet with `tweet_id`, such as
+	/// `data/tweets_media/<TWEET_ID>-<FILE_NAME>.jpg`
+	///
+	/// Directory is read from `data/manifest.js` `dataTypes.tweetsMedia.mediaDirectory`, and
+	/// when the manifest is missing or does not describe it then `data/tweets_media` is assumed.
+	/// See `Tweet::media_file_names` for paths expected from media entities of parsed Tweet.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// for file_name in [
+	///     "data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg",
+	///     "data/tweets_media/1111111111111111111-abc.mp4",
+	///     "data/tweets_media/2222222222222222222-F3UNnlRWoAAQXa_.jpg",
+	/// ] {
+	///     zip_writer.start_file(file_name, FileOptions::default()).unwrap();
+	///     zip_writer.write_all(b"").unwrap();
+	/// }
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// assert_eq!(
+	///     archive.media_for_tweet("1111111111111111111").unwrap(),
+	///     vec![
+	///         "data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg",
+	///         "data/tweets_media/1111111111111111111-abc.mp4",
+	///     ]
+	/// );
+	///
+	/// assert!(archive.media_for_tweet("3333333333333333333").unwrap().is_empty());
+	/// ```
+	pub fn media_for_tweet(&mut self, tweet_id: &str) -> Result<Vec<String>> {
+		let prefix = format!("{}/{tweet_id}-", self.tweets_media_directory()?);
+
+		Ok(self
+			.file_names()
+			.into_iter()
+			.filter(|file_name| file_name.starts_with(&prefix))
+			.collect())
+	}
+
+	/// Directory of Tweet media per `data/manifest.js`, or `data/tweets_media`, without trailing
+	/// slash
+	pub(crate) fn tweets_media_directory(&mut self) -> Result<String> {
+		Ok(self
+			.manifest_raw()?
+			.pointer("/dataTypes/tweetsMedia/mediaDirectory")
+			.and_then(Value::as_str)
+			.unwrap_or("data/tweets_media")
+			.trim_end_matches('/')
+			.to_string())
+	}
+
+	/// Read and concatenate every part, `data/tweets.js`, `data/tweets-part1.js`, etc., of data
+	/// assigned to `global_name`
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	/// use twitter_archive::structs::verified::VerifiedObject;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/manifest.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.__THAR_CONFIG = {
+	///   "dataTypes": {
+	///     "verified": {
+	///       "files": [
+	///         {
+	///           "fileName": "data/verified.js",
+	///           "globalName": "YTD.verified.part0",
+	///           "count": "1"
+	///         },
+	///         {
+	///           "fileName": "data/verified-part1.js",
+	///           "globalName": "YTD.verified.part1",
+	///           "count": "1"
+	///         }
+	///       ]
+	///     }
+	///   }
+	/// }"#).unwrap();
+	/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+	///   { "verified" : { "accountId" : "111", "verified" : false } }
+	/// ]"#).unwrap();
+	/// zip_writer.start_file("data/verified-part1.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.verified.part1 = [
+	///   { "verified" : { "accountId" : "222", "verified" : true } }
+	/// ]"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let data: Vec<VerifiedObject> = archive.read_parts("YTD.verified").unwrap();
+	///
+	/// assert_eq!(data.len(), 2);
+	/// assert_eq!(data[0].verified.account_id, "111");
+	/// assert_eq!(data[1].verified.account_id, "222");
+	///
+	/// // Accessors concatenate parts too
+	/// assert_eq!(archive.verified().unwrap().len(), 2);
+	/// ```
+	pub fn read_parts<T: DeserializeOwned>(&mut self, global_name: &str) -> Result<Vec<T>> {
+		let files = self.data_files(global_name)?;
+		if let Some(progress) = &mut self.progress {
+			progress.start_data_type();
+		}
+
+		let mut data = Vec::new();
+		for file in files {
+			let mut part: Vec<T> = self.read_data(&file.file_name, &file.global_name)?;
+			if let Some(progress) = &mut self.progress {
+				progress.finish_file(part.len());
+			}
+			data.append(&mut part);
+		}
+		Ok(data)
+	}
+
+	/// Stream Tweets from `data/tweets.js` one at a time instead of parsing the whole file into a
+	/// `Vec`, remaining parts listed by `data_files("YTD.tweets")` may be streamed via
+	/// `stream_file`
+	pub fn stream_tweets(
+		&mut self,
+	) -> Result<parse::JsArrayStream<Box<dyn Read + '_>, tweets::TweetObject>> {
+		self.stream_file("data/tweets.js", "YTD.tweets")
+	}
+
+	/// Stream elements of any data file, such as `data/like-part1.js`, one at a time
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	/// use twitter_archive::structs::verified::VerifiedObject;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+	///   { "verified" : { "accountId" : "111", "verified" : false } },
+	///   { "verified" : { "accountId" : "222", "verified" : true } }
+	/// ]"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let verified = archive
+	///     .stream_file::<VerifiedObject>("data/verified.js", "YTD.verified")
+	///     .unwrap()
+	///     .filter_map(Result::ok)
+	///     .filter(|object| object.verified.verified)
+	///     .count();
+	///
+	/// assert_eq!(verified, 1);
+	/// ```
+	pub fn stream_file<T: DeserializeOwned>(
+		&mut self,
+		file_name: &str,
+		global_name: &str,
+	) -> Result<parse::JsArrayStream<Box<dyn Read + '_>, T>> {
+		Ok(parse::stream_js(
+			self.source.open_file(file_name)?,
+			global_name,
+		))
+	}
+
+	/// Load every part of data for structures that know their own global name
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	/// use twitter_archive::structs::verified::VerifiedObject;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/verified.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.verified.part0 = [
+	///   { "verified" : { "accountId" : "111", "verified" : false } }
+	/// ]"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let data = archive.load::<VerifiedObject>().unwrap();
+	/// assert_eq!(data[0].verified.account_id, "111");
+	/// ```
+	pub fn load<T>(&mut self) -> Result<Vec<T>>
+	where
+		Vec<T>: FromJsStr,
+		T: DeserializeOwned,
+	{
+		self.read_parts(<Vec<T>>::GLOBAL_NAME)
+	}
+
+	/// Load every part of data keyed by manifest `dataTypes` name, such as `"communityNote"`, into
+	/// any data structure; useful for data types this crate does not yet model
+	pub fn load_as<T: DeserializeOwned>(&mut self, data_type: &str) -> Result<Vec<T>> {
+		self.read_parts(&format!("YTD.{}", camel_to_snake_case(data_type)))
+	}
+
+	/// Load every part of data keyed by manifest `dataTypes` name as untyped JSON array
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/manifest.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.__THAR_CONFIG = {
+	///   "dataTypes": {
+	///     "communityNote": {
+	///       "files": [
+	///         {
+	///           "fileName": "data/community-note.js",
+	///           "globalName": "YTD.community_note.part0",
+	///           "count": "1"
+	///         }
+	///       ]
+	///     }
+	///   }
+	/// }"#).unwrap();
+	/// zip_writer.start_file("data/community-note.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.community_note.part0 = [
+	///   { "communityNote" : { "noteId" : "1111111111111111111" } }
+	/// ]"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let data = archive.load_raw("communityNote").unwrap();
+	///
+	/// assert_eq!(data[0]["communityNote"]["noteId"], "1111111111111111111");
+	/// assert!(archive.load_raw("grokChatItem").is_err());
+	/// ```
+	pub fn load_raw(&mut self, data_type: &str) -> Result<Value> {
+		self.load_as(data_type).map(Value::Array)
+	}
+
+	/// Read and parse `data/account-creation-ip.js` file
+	pub fn account_creation_ip(
+		&mut self,
+	) -> Result<Vec<account_creation_ip::AccountCreationIpObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/account-label.js` file
+	pub fn account_label(&mut self) -> Result<Vec<account_label::AccountLabelObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/account-suspension.js` file
+	pub fn account_suspension(
+		&mut self,
+	) -> Result<Vec<account_suspension::AccountSuspensionObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ad-free-article-visits.js` file
+	pub fn ad_free_article_visits(
+		&mut self,
+	) -> Result<Vec<ad_free_article_visits::AdFreeArticleVisitObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ageinfo.js` file
+	pub fn ageinfo(&mut self) -> Result<Vec<ageinfo::AgeinfoObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/app.js` file
+	pub fn app(&mut self) -> Result<Vec<app::AppObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/branch-links.js` file
+	pub fn branch_links(&mut self) -> Result<Vec<branch_links::BranchLinkObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/catalog-item.js` file
+	pub fn catalog_item(&mut self) -> Result<Vec<catalog_item::CatalogItemObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/commerce-catalog.js` file
+	pub fn commerce_catalog(&mut self) -> Result<Vec<commerce_catalog::CommerceCatalogObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/community-note.js` file
+	pub fn community_note(&mut self) -> Result<Vec<community_note::CommunityNoteObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/community-note-tombstone.js` file
+	pub fn community_note_tombstone(
+		&mut self,
+	) -> Result<Vec<community_note_tombstone::CommunityNoteTombstoneObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/community-tweet.js` file
+	pub fn community_tweet(&mut self) -> Result<Vec<community_tweet::CommunityTweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/contact.js` file
+	pub fn contact(&mut self) -> Result<Vec<contact::ContactObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/deleted-note-tweet.js` file
+	pub fn deleted_note_tweet(
+		&mut self,
+	) -> Result<Vec<deleted_note_tweet::DeletedNoteTweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-message-mute.js` file
+	pub fn direct_message_mute(
+		&mut self,
+	) -> Result<Vec<direct_message_mute::DirectMessageMuteObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/grok-chat-item.js` file
+	pub fn grok_chat_item(&mut self) -> Result<Vec<grok_chat_item::GrokChatItemObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/lists-created.js` file
+	pub fn lists_created(&mut self) -> Result<Vec<lists_created::ListsCreatedObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/lists-subscribed.js` file
+	pub fn lists_subscribed(&mut self) -> Result<Vec<lists_subscribed::ListsSubscribedObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/manifest.js` file
+	pub fn manifest(&mut self) -> Result<manifest::Manifest> {
+		self.read_data("data/manifest.js", manifest::Manifest::GLOBAL_NAME)
+	}
+
+	/// Read and parse `data/account.js` file
+	pub fn account(&mut self) -> Result<Vec<account::AccountObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/account-timezone.js` file
+	pub fn account_timezone(&mut self) -> Result<Vec<account_timezone::AccountTimezoneObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ad-engagements.js` file
+	pub fn ad_engagements(&mut self) -> Result<Vec<ad_engagements::AdObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ad-impressions.js` file
+	pub fn ad_impressions(&mut self) -> Result<Vec<ad_impressions::AdObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/block.js` file
+	pub fn block(&mut self) -> Result<Vec<block::BlockingObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/community-note-rating.js` file
+	pub fn community_note_rating(
+		&mut self,
+	) -> Result<Vec<community_note_rating::CommunityNoteRatingObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/connected-application.js` file
+	pub fn connected_application(
+		&mut self,
+	) -> Result<Vec<connected_application::ConnectedApplicationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/deleted-tweet-headers.js` file
+	pub fn deleted_tweet_headers(&mut self) -> Result<Vec<deleted_tweet_headers::TweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/deleted-tweets.js` file
+	pub fn deleted_tweets(&mut self) -> Result<Vec<tweets::TweetObject>> {
+		self.read_parts("YTD.deleted_tweets")
+	}
+
+	/// Read and parse `data/deleted-tweets.js` file, with `deleted_at` filled in from
+	/// `data/deleted-tweet-headers.js` when archive has that file and Tweets lack it
+	///
+	/// See `deleted_tweets::join_headers` for how headers are matched to Tweets.
+	pub fn deleted_tweets_with_headers(
+		&mut self,
+	) -> Result<Vec<deleted_tweets::DeletedTweetObject>> {
+		let mut tweets: Vec<deleted_tweets::DeletedTweetObject> = self.load()?;
+
+		let header_files =
+			self.data_files(<Vec<deleted_tweet_headers::TweetObject>>::GLOBAL_NAME)?;
+		if header_files
+			.iter()
+			.any(|file| self.contains(&file.file_name))
+		{
+			deleted_tweets::join_headers(&mut tweets, &self.deleted_tweet_headers()?);
+		}
+
+		Ok(tweets)
+	}
+
+	/// Read and parse `data/device-token.js` file
+	pub fn device_token(&mut self) -> Result<Vec<device_token::DeviceTokenObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-message-group-headers.js` file
+	pub fn direct_message_group_headers(
+		&mut self,
+	) -> Result<Vec<direct_message_group_headers::DmConversationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-message-headers.js` file
+	pub fn direct_message_headers(
+		&mut self,
+	) -> Result<Vec<direct_message_headers::DmConversationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-messages.js` file
+	pub fn direct_messages(&mut self) -> Result<Vec<direct_messages::DmConversationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-messages-group.js` file
+	pub fn direct_messages_group(
+		&mut self,
+	) -> Result<Vec<direct_messages_group::DmConversationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/direct-messages.js` and `data/direct-messages-group.js` files, when
+	/// archive has them, into conversations with ordered messages and resolved participants
+	pub fn dm_conversations(&mut self) -> Result<Vec<dm::Conversation>> {
+		let mut conversations = Vec::new();
+
+		let files = self.data_files(<Vec<direct_messages::DmConversationObject>>::GLOBAL_NAME)?;
+		if files.iter().any(|file| self.contains(&file.file_name)) {
+			let data = self.direct_messages()?;
+			conversations.extend(
+				data.into_iter()
+					.map(|object| dm::Conversation::from(object.dm_conversation)),
+			);
+		}
+
+		let files =
+			self.data_files(<Vec<direct_messages_group::DmConversationObject>>::GLOBAL_NAME)?;
+		if files.iter().any(|file| self.contains(&file.file_name)) {
+			let data = self.direct_messages_group()?;
+			conversations.extend(
+				data.into_iter()
+					.map(|object| dm::Conversation::from(object.dm_conversation)),
+			);
+		}
+
+		Ok(conversations)
+	}
+
+	/// Resolve media of `message`, within `conversation`, to files within
+	/// `data/direct_messages_media` or `data/direct_messages_group_media`, along with devices of
+	/// `data/key-registry.js` registered when message was sent
+	///
+	/// Each URL is matched to `<message_id>-<FILE_NAME>` named by its last path segment, and
+	/// `file_name` is `None` when archive lacks that file.  Key registry is parsed once per reader.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::dm::Conversation;
+	/// use twitter_archive::reader::ArchiveReader;
+	///
+	/// let messages = r#"window.YTD.direct_messages.part0 = [
+	///   {
+	///     "dmConversation": {
+	///       "conversationId": "111111111-222222222",
+	///       "messages": [
+	///         {
+	///           "messageCreate": {
+	///             "recipientId": "222222222",
+	///             "reactions": [],
+	///             "urls": [],
+	///             "text": "Look! https://t.co/AbCdEfGhIj",
+	///             "mediaUrls": [
+	///               "https://ton.twitter.com/dm/3333333333333333333/",
+	///               "https://ton.twitter.com/dm/3333333333333333333/5555555555555555555/AbCdEfGh.jpg",
+	///               "https://ton.twitter.com/dm/3333333333333333333/6666666666666666666/IjKlMnOp.png"
+	///             ],
+	///             "senderId": "111111111",
+	///             "id": "3333333333333333333",
+	///             "createdAt": "2023-08-12T17:10:37.000Z"
+	///           }
+	///         }
+	///       ]
+	///     }
+	///   }
+	/// ]"#;
+	///
+	/// let key_registry = r#"window.YTD.key_registry.part0 = [
+	///   {
+	///     "registeredDevices": {
+	///       "deviceMetadataList": [
+	///         {
+	///           "userAgent": "Mozilla/5.0 (Windows NT 10.0; rv:102.0) Gecko/20100101 Firefox/102.0",
+	///           "registrationToken": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+	///           "identityKey": "DEADBEEF",
+	///           "createdAt": "2023-05-30T13:31:42.908Z",
+	///           "deviceId": "xxxxxxxx-111a-0000-abcd-333333333333"
+	///         }
+	///       ]
+	///     }
+	///   }
+	/// ]"#;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// for (file_name, content) in [
+	///     ("data/direct-messages.js", messages),
+	///     ("data/key-registry.js", key_registry),
+	///     ("data/direct_messages_media/3333333333333333333-AbCdEfGh.jpg", ""),
+	///     ("data/direct_messages_media/3333333333333333333-QrStUvWx.png", ""),
+	/// ] {
+	///     zip_writer.start_file(file_name, FileOptions::default()).unwrap();
+	///     zip_writer.write_all(content.as_bytes()).unwrap();
+	/// }
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let conversations = archive.dm_conversations().unwrap();
+	/// let conversation = &conversations[0];
+	/// let message = &conversation.messages()[0];
+	///
+	/// let media = archive.media_for_dm(conversation, message).unwrap();
+	///
+	/// // URL naming no file
+	/// assert_eq!(media[0].file_name, None);
+	///
+	/// assert_eq!(
+	///     media[1].file_name.as_deref(),
+	///     Some("data/direct_messages_media/3333333333333333333-AbCdEfGh.jpg")
+	/// );
+	/// assert_eq!(media[1].devices[0].identity_key, "DEADBEEF");
+	///
+	/// // Missing file is not guessed from other files of message
+	/// assert_eq!(media[2].file_name, None);
+	/// ```
+	pub fn media_for_dm(
+		&mut self,
+		conversation: &dm::Conversation,
+		message: &dm::Message,
+	) -> Result<Vec<dm::MediaFile>> {
+		let (pointer, default) = if conversation.is_group() {
+			(
+				"/dataTypes/directMessagesGroupMedia/mediaDirectory",
+				"data/direct_messages_group_media",
+			)
+		} else {
+			(
+				"/dataTypes/directMessagesMedia/mediaDirectory",
+				"data/direct_messages_media",
+			)
+		};
+		let media_directory = manifest::MediaDirectory {
+			media_directory: self
+				.manifest_raw()?
+				.pointer(pointer)
+				.and_then(Value::as_str)
+				.unwrap_or(default)
+				.to_string(),
+		};
+
+		if self.registered_devices.is_none() {
+			let key_files =
+				self.data_files(<Vec<key_registry::RegisteredDevicesObject>>::GLOBAL_NAME)?;
+			let registered_devices = if key_files.iter().any(|file| self.contains(&file.file_name))
+			{
+				self.key_registry()?
+					.into_iter()
+					.map(|object| object.registered_devices)
+					.collect()
+			} else {
+				Vec::new()
+			};
+			self.registered_devices = Some(registered_devices);
+		}
+		let devices: Vec<key_registry::DeviceMetadata> = self
+			.registered_devices
+			.iter()
+			.flatten()
+			.flat_map(|registered_devices| registered_devices.devices_at(message.created_at))
+			.cloned()
+			.collect();
+
+		let mut media = Vec::with_capacity(message.media_urls.len());
+		for url in &message.media_urls {
+			let file_name = message
+				.media_file_name(url, &media_directory)
+				.filter(|file_name| self.contains(file_name));
+
+			media.push(dm::MediaFile {
+				url: url.clone(),
+				file_name,
+				devices: devices.clone(),
+			});
+		}
+		Ok(media)
+	}
+
+	/// Read and parse `data/email-address-change.js` file
+	pub fn email_address_change(
+		&mut self,
+	) -> Result<Vec<email_address_change::EmailAddressChangeObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/follower.js` file
+	pub fn follower(&mut self) -> Result<Vec<follower::FollowerObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/following.js` file
+	pub fn following(&mut self) -> Result<Vec<following::FollowingObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ip-audit.js` file
+	pub fn ip_audit(&mut self) -> Result<Vec<ip_audit::IpAuditObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/key-registry.js` file
+	pub fn key_registry(&mut self) -> Result<Vec<key_registry::RegisteredDevicesObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/like.js` file
+	pub fn like(&mut self) -> Result<Vec<like::LikeObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/lists-member.js` file
+	pub fn lists_member(&mut self) -> Result<Vec<lists_member::UserListInfoObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/moment.js` file
+	pub fn moment(&mut self) -> Result<Vec<moment::MomentObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/mute.js` file
+	pub fn mute(&mut self) -> Result<Vec<mute::MutingObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/ni-devices.js` file
+	pub fn ni_devices(&mut self) -> Result<Vec<ni_devices::NiDeviceResponseObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/note-tweet.js` file
+	pub fn note_tweet(&mut self) -> Result<Vec<note_tweet::NoteTweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/payment-transaction.js` file
+	pub fn payment_transaction(
+		&mut self,
+	) -> Result<Vec<payment_transaction::PaymentTransactionObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-account-information.js` file
+	pub fn periscope_account_information(
+		&mut self,
+	) -> Result<Vec<periscope::AccountInformationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-ban-information.js` file
+	pub fn periscope_ban_information(&mut self) -> Result<Vec<periscope::BanInformationObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-broadcast-metadata.js` file
+	pub fn periscope_broadcast_metadata(
+		&mut self,
+	) -> Result<Vec<periscope::BroadcastMetadataObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-comments-made-by-user.js` file
+	pub fn periscope_comments_made_by_user(
+		&mut self,
+	) -> Result<Vec<periscope::CommentsMadeByUserObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-expired-broadcasts.js` file
+	pub fn periscope_expired_broadcasts(
+		&mut self,
+	) -> Result<Vec<periscope::ExpiredBroadcastsObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-followers.js` file
+	pub fn periscope_followers(&mut self) -> Result<Vec<periscope::FollowersObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/periscope-profile-description.js` file
+	pub fn periscope_profile_description(
+		&mut self,
+	) -> Result<Vec<periscope::ProfileDescriptionObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/personalization.js` file
+	pub fn personalization(&mut self) -> Result<Vec<personalization::P13nDataObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/phone-number.js` file
+	pub fn phone_number(&mut self) -> Result<Vec<phone_number::DeviceObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/product-drop.js` file
+	pub fn product_drop(&mut self) -> Result<Vec<product_drop::ProductDropObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/product-set.js` file
+	pub fn product_set(&mut self) -> Result<Vec<product_set::ProductSetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/professional-data.js` file
+	pub fn professional_data(&mut self) -> Result<Vec<professional_data::ProfessionalDataObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/profile.js` file
+	pub fn profile(&mut self) -> Result<Vec<profile::ProfileObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/protected-history.js` file
+	pub fn protected_history(&mut self) -> Result<Vec<protected_history::ProtectedHistoryObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/reply-prompt.js` file
+	pub fn reply_prompt(&mut self) -> Result<Vec<reply_prompt::ReplyPromptObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/saved-search.js` file
+	pub fn saved_search(&mut self) -> Result<Vec<saved_search::SavedSearchObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/screen-name-change.js` file
+	pub fn screen_name_change(
+		&mut self,
+	) -> Result<Vec<screen_name_change::ScreenNameChangeObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/shop-module.js` file
+	pub fn shop_module(&mut self) -> Result<Vec<shop_module::ShopModuleObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/shopify-account.js` file
+	pub fn shopify_account(&mut self) -> Result<Vec<shopify_account::ShopifyAccountObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/smartblock.js` file
+	pub fn smartblock(&mut self) -> Result<Vec<smartblock::SmartblockObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/spaces-metadata.js` file
+	pub fn spaces_metadata(&mut self) -> Result<Vec<spaces_metadata::SpacesMetadataObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/sso.js` file
+	pub fn sso(&mut self) -> Result<Vec<sso::SsoObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/tweet-headers.js` file
+	pub fn tweet_headers(&mut self) -> Result<Vec<tweet_headers::TweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/tweetdeck.js` file
+	pub fn tweetdeck(&mut self) -> Result<Vec<tweetdeck::DeckObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/tweets.js` file
+	///
+	/// Older exports, see `version::ArchiveVersion`, are read from `data/tweet.js` instead
+	pub fn tweets(&mut self) -> Result<Vec<tweets::TweetObject>> {
+		match self.version()? {
+			ArchiveVersion::V2019 | ArchiveVersion::V2021 => self.legacy_tweets(),
+			ArchiveVersion::V2023 | ArchiveVersion::Unknown => self.load(),
+		}
+	}
+
+	/// Read and parse every part of `data/tweets.js`, ordered oldest first by `created_at` then
+	/// ID, see `query::sort_by_created_at`
+	pub fn tweets_sorted(&mut self) -> Result<Vec<tweets::TweetObject>> {
+		let mut tweets = self.tweets()?;
+		query::sort_by_created_at(&mut tweets);
+		Ok(tweets)
+	}
+
+	/// Read and parse `data/tweets.js` file, with truncated Tweets replaced by full long-form
+	/// text of their Notes from `data/note-tweet.js` when archive has that file
+	///
+	/// See `note_tweet::merge_into_tweets` for how Notes are matched to Tweets.
+	pub fn tweets_with_notes(&mut self) -> Result<Vec<tweets::TweetObject>> {
+		let mut tweets = self.tweets()?;
+
+		let note_files = self.data_files(<Vec<note_tweet::NoteTweetObject>>::GLOBAL_NAME)?;
+		if note_files.iter().any(|file| self.contains(&file.file_name)) {
+			note_tweet::merge_into_tweets(&mut tweets, &self.note_tweet()?);
+		}
+
+		Ok(tweets)
+	}
+
+	/// Read and parse `data/twitter-article.js` file
+	pub fn twitter_article(&mut self) -> Result<Vec<twitter_article::TwitterArticleObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/twitter-article-metadata.js` file
+	pub fn twitter_article_metadata(
+		&mut self,
+	) -> Result<Vec<twitter_article_metadata::TwitterArticleMetadataObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/twitter-circle.js` file
+	pub fn twitter_circle(&mut self) -> Result<Vec<twitter_circle::TwitterCircleObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/twitter-circle-member.js` file
+	pub fn twitter_circle_member(
+		&mut self,
+	) -> Result<Vec<twitter_circle_member::TwitterCircleMemberObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/twitter-circle-tweet.js` file
+	pub fn twitter_circle_tweet(
+		&mut self,
+	) -> Result<Vec<twitter_circle_tweet::TwitterCircleTweetObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/user-link-clicks.js` file
+	pub fn user_link_clicks(&mut self) -> Result<Vec<user_link_clicks::UserLinkClickObject>> {
+		self.load()
+	}
+
+	/// Read and parse `data/verified.js` file
+	pub fn verified(&mut self) -> Result<Vec<verified::VerifiedObject>> {
+		self.load()
+	}
+}
+
+/// Convert `camelCase` keys of manifest `dataTypes` into `snake_case` global names
+fn camel_to_snake_case(name: &str) -> String {
+	let mut snake = String::with_capacity(name.len());
+	for c in name.chars() {
+		if c.is_uppercase() {
+			snake.push('_');
+			snake.extend(c.to_lowercase());
+		} else {
+			snake.push(c);
+		}
+	}
+	snake
+}
+
+/// Convert `snake_case` global names into `camelCase` keys of manifest `dataTypes`
+fn snake_to_camel_case(name: &str) -> String {
+	let mut words = name.split('_');
+	let mut camel = words.next().unwrap_or_default().to_string();
+	for word in words {
+		let mut chars = word.chars();
+		if let Some(first) = chars.next() {
+			camel.extend(first.to_uppercase());
+			camel.push_str(chars.as_str());
+		}
+	}
+	camel
+}
@@ -0,0 +1,350 @@
+#!/usr/bin/env rust
+
+//! Render Tweets, and threads of Tweets, as sanitized HTML for archive viewers, similar to;
+//!
+//! ```html
+//! <article class="tweet" id="tweet-1111111111111111111">
+//! <p>Fish &amp; chips with <a href="https://twitter.com/EXAMPLE">@example</a> <a href="https://twitter.com/hashtag/rustlang">#rustlang</a><br>
+//! Recipe at <a href="https://example.com/fish">example.com/fish</a></p>
+//! <figure><img src="data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg" alt="Photo"></figure>
+//! <blockquote><a href="https://twitter.com/EXAMPLE/status/2222222222222222222">Quoted Tweet</a></blockquote>
+//! </article>
+//! ```
+//!
+//! Entities are linked at positions given by their `indices`, all text and attribute values are
+//! escaped, and only `http://` or `https://` URLs become links.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::render::html;
+//! use twitter_archive::structs::tweets::Tweet;
+//!
+//! let json = r#"{
+//!   "edit_info": {
+//!     "initial": {
+//!       "editTweetIds": [ "1111111111111111111" ],
+//!       "editableUntil": "2023-08-12T17:10:37.000Z",
+//!       "editsRemaining": "5",
+//!       "isEditEligible": true
+//!     }
+//!   },
+//!   "retweeted": false,
+//!   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!   "entities": {
+//!     "hashtags": [
+//!       {
+//!         "text": "rustlang",
+//!         "indices": [ "31", "40" ]
+//!       }
+//!     ],
+//!     "symbols": [],
+//!     "user_mentions": [
+//!       {
+//!         "name": "Example",
+//!         "screen_name": "EXAMPLE",
+//!         "indices": [ "22", "30" ],
+//!         "id_str": "222222222",
+//!         "id": "222222222"
+//!       }
+//!     ],
+//!     "urls": [
+//!       {
+//!         "url": "https://t.co/BBBBBBBBBB",
+//!         "expanded_url": "https://example.com/fish",
+//!         "display_url": "example.com/fish",
+//!         "indices": [ "51", "74" ]
+//!       },
+//!       {
+//!         "url": "https://t.co/DDDDDDDDDD",
+//!         "expanded_url": "https://twitter.com/EXAMPLE/status/2222222222222222222",
+//!         "display_url": "twitter.com/EXAMPLE/statu…",
+//!         "indices": [ "75", "98" ]
+//!       }
+//!     ]
+//!   },
+//!   "extended_entities": {
+//!     "media": [
+//!       {
+//!         "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+//!         "indices": [ "99", "122" ],
+//!         "url": "https://t.co/CCCCCCCCCC",
+//!         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "id_str": "3333333333333333333",
+//!         "id": "3333333333333333333",
+//!         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "sizes": {
+//!           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+//!           "small": { "w": "680", "h": "383", "resize": "fit" },
+//!           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+//!           "large": { "w": "1280", "h": "720", "resize": "fit" }
+//!         },
+//!         "type": "photo",
+//!         "display_url": "pic.twitter.com/CCCCCCCCCC"
+//!       }
+//!     ]
+//!   },
+//!   "display_text_range": [ "0", "98" ],
+//!   "favorite_count": "0",
+//!   "id_str": "1111111111111111111",
+//!   "truncated": false,
+//!   "retweet_count": "0",
+//!   "id": "1111111111111111111",
+//!   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!   "favorited": false,
+//!   "full_text": "Fish &amp; chips with @example #rustlang\nRecipe at https://t.co/BBBBBBBBBB https://t.co/DDDDDDDDDD https://t.co/CCCCCCCCCC",
+//!   "lang": "en"
+//! }"#;
+//!
+//! let data: Tweet = serde_json::from_str(&json).unwrap();
+//!
+//! let options = html::Options {
+//!     media_directory: Some("data/tweets_media".to_string()),
+//! };
+//!
+//! assert_eq!(
+//!     html::tweet(&data, &options),
+//!     [
+//!         r#"<article class="tweet" id="tweet-1111111111111111111">"#,
+//!         r#"<p>Fish &amp; chips with <a href="https://twitter.com/EXAMPLE">@example</a> <a href="https://twitter.com/hashtag/rustlang">#rustlang</a><br>"#,
+//!         r#"Recipe at <a href="https://example.com/fish">example.com/fish</a></p>"#,
+//!         r#"<figure><img src="data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg" alt="Photo"></figure>"#,
+//!         r#"<blockquote><a href="https://twitter.com/EXAMPLE/status/2222222222222222222">Quoted Tweet</a></blockquote>"#,
+//!         "</article>",
+//!     ].join("\n")
+//! );
+//! ```
+
+use crate::structs::tweets::{self, MediaType, TextSegment, Tweet};
+
+/// Choices for `html::tweet` and `html::thread`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Options {
+	/// Directory media sources point within, e.g. `data/tweets_media`, where `None` points to
+	/// remote `pbs.twimg.com` and `video.twimg.com` URLs instead
+	pub media_directory: Option<String>,
+}
+
+/// Render one Tweet as `<article>` element, see module documentation for example
+pub fn tweet(tweet: &Tweet, options: &Options) -> String {
+	let (body, quote_url) = text_body(tweet, true);
+
+	let mut html = format!(
+		"<article class=\"tweet\" id=\"tweet-{}\">",
+		escape(&tweet.id_str)
+	);
+
+	for paragraph in body.trim().split("\n\n") {
+		let paragraph = paragraph.trim();
+		if !paragraph.is_empty() {
+			html.push_str("\n<p>");
+			html.push_str(&paragraph.replace('\n', "<br>\n"));
+			html.push_str("</p>");
+		}
+	}
+
+	for media in tweet.media() {
+		let source = match (&options.media_directory, media.file_name()) {
+			(Some(directory), Some(file_name)) => format!(
+				"{}/{}-{file_name}",
+				directory.trim_end_matches('/'),
+				tweet.id_str
+			),
+			_ if is_http(media.file_url()) => media.file_url().to_string(),
+			_ => continue,
+		};
+		let source = escape(&source);
+
+		html.push_str("\n<figure>");
+		match &media.media_type {
+			MediaType::Photo => {
+				html.push_str(&format!("<img src=\"{source}\" alt=\"Photo\">"));
+			}
+			MediaType::Video => {
+				html.push_str(&format!("<video src=\"{source}\" controls></video>"));
+			}
+			MediaType::AnimatedGif => {
+				html.push_str(&format!(
+					"<video src=\"{source}\" autoplay loop muted playsinline></video>"
+				));
+			}
+			MediaType::Other(value) => {
+				html.push_str(&format!("<a href=\"{source}\">{}</a>", escape(value)));
+			}
+		}
+		html.push_str("</figure>");
+	}
+
+	if let Some(url) = quote_url {
+		html.push_str("\n<blockquote>");
+		push_link(&mut html, "Quoted Tweet", url);
+		html.push_str("</blockquote>");
+	}
+
+	html.push_str("\n</article>");
+	html
+}
+
+/// Render Tweets, in order given, as `<article>` elements within one `<section>` element
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::render::html;
+/// use twitter_archive::structs::tweets::Tweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [ "1111111111111111111" ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [ "0", "20" ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "&lt;script&gt;alert(1)&lt;/script&gt;",
+///   "lang": "en"
+/// }"#;
+///
+/// let first: Tweet = serde_json::from_str(&json).unwrap();
+///
+/// let mut second = first.clone();
+/// second.id_str = "2222222222222222222".to_string();
+/// second.full_text = "Second".to_string();
+///
+/// assert_eq!(
+///     html::thread(&[first, second], &html::Options::default()),
+///     [
+///         r#"<section class="thread">"#,
+///         r#"<article class="tweet" id="tweet-1111111111111111111">"#,
+///         "<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>",
+///         "</article>",
+///         r#"<article class="tweet" id="tweet-2222222222222222222">"#,
+///         "<p>Second</p>",
+///         "</article>",
+///         "</section>",
+///     ].join("\n")
+/// );
+/// ```
+pub fn thread(tweets: &[Tweet], options: &Options) -> String {
+	let mut html = String::from("<section class=\"thread\">");
+	for data in tweets {
+		html.push('\n');
+		html.push_str(&tweet(data, options));
+	}
+	html.push_str("\n</section>");
+	html
+}
+
+/// Replace characters that HTML would otherwise treat as markup with character references
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::render::html;
+///
+/// assert_eq!(
+///     html::escape(r#"<a href="x">Tom & Jerry's</a>"#),
+///     "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+/// );
+/// ```
+pub fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for character in text.chars() {
+		match character {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&#39;"),
+			_ => escaped.push(character),
+		}
+	}
+	escaped
+}
+
+/// Tweet text as escaped HTML, with links for URLs, mentions, and hashtags, and link to attached
+/// media removed
+///
+/// When `extract_quote` is set, link to quoted Tweet is removed too and returned separately so
+/// callers may render it after media.
+pub(crate) fn text_body(tweet: &Tweet, extract_quote: bool) -> (String, Option<&str>) {
+	let quoted_tweet_id = tweet.quoted_tweet_id().filter(|_| extract_quote);
+	let mut quote_url = None;
+
+	let mut body = String::with_capacity(tweet.full_text.len() * 2);
+	for segment in tweet.text_segments() {
+		match segment {
+			TextSegment::Text(text) => body.push_str(&escape(&text)),
+			TextSegment::Url {
+				expanded_url,
+				display_url,
+				..
+			} => {
+				if quote_url.is_none()
+					&& quoted_tweet_id.is_some()
+					&& tweets::status_id_from_url(expanded_url) == quoted_tweet_id
+				{
+					quote_url = Some(expanded_url);
+					body.truncate(body.trim_end().len());
+				} else {
+					push_link(&mut body, display_url, expanded_url);
+				}
+			}
+			TextSegment::Media { .. } => body.truncate(body.trim_end().len()),
+			TextSegment::Mention { text, screen_name } => {
+				push_link(
+					&mut body,
+					text,
+					&format!("https://twitter.com/{screen_name}"),
+				);
+			}
+			TextSegment::Hashtag { text, tag } => {
+				push_link(
+					&mut body,
+					text,
+					&format!("https://twitter.com/hashtag/{tag}"),
+				);
+			}
+		}
+	}
+
+	(body, quote_url)
+}
+
+/// Append `<a href="...">text</a>`, or only escaped text when `href` is not HTTP(S) URL
+pub(crate) fn push_link(html: &mut String, text: &str, href: &str) {
+	if is_http(href) {
+		html.push_str(&format!(
+			"<a href=\"{}\">{}</a>",
+			escape(href),
+			escape(text)
+		));
+	} else {
+		html.push_str(&escape(text));
+	}
+}
+
+/// Does `url` use scheme that is safe to link to
+pub(crate) fn is_http(url: &str) -> bool {
+	let scheme = url.split_once("://").map(|(scheme, _)| scheme);
+	scheme.is_some_and(|scheme| {
+		scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+	})
+}
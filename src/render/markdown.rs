@@ -0,0 +1,335 @@
+#!/usr/bin/env rust
+
+//! Render Tweets, and threads of Tweets, as Markdown for static-site exports, similar to;
+//!
+//! ```markdown
+//! Fish & chips with [@example](https://twitter.com/EXAMPLE) [#rustlang](https://twitter.com/hashtag/rustlang)\
+//! Recipe at [example.com/fish](https://example.com/fish)
+//!
+//! ![Photo](data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg)
+//!
+//! > [Quoted Tweet](https://twitter.com/EXAMPLE/status/2222222222222222222)
+//! ```
+//!
+//! Links are expanded from `entities.urls`, mentions and hashtags link to their pages, link to
+//! attached media is replaced by references after text, and link to quoted Tweet becomes quote
+//! block.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::render::markdown;
+//! use twitter_archive::structs::tweets::Tweet;
+//!
+//! let json = r#"{
+//!   "edit_info": {
+//!     "initial": {
+//!       "editTweetIds": [ "1111111111111111111" ],
+//!       "editableUntil": "2023-08-12T17:10:37.000Z",
+//!       "editsRemaining": "5",
+//!       "isEditEligible": true
+//!     }
+//!   },
+//!   "retweeted": false,
+//!   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!   "entities": {
+//!     "hashtags": [
+//!       {
+//!         "text": "rustlang",
+//!         "indices": [ "31", "40" ]
+//!       }
+//!     ],
+//!     "symbols": [],
+//!     "user_mentions": [
+//!       {
+//!         "name": "Example",
+//!         "screen_name": "EXAMPLE",
+//!         "indices": [ "22", "30" ],
+//!         "id_str": "222222222",
+//!         "id": "222222222"
+//!       }
+//!     ],
+//!     "urls": [
+//!       {
+//!         "url": "https://t.co/BBBBBBBBBB",
+//!         "expanded_url": "https://example.com/fish",
+//!         "display_url": "example.com/fish",
+//!         "indices": [ "51", "74" ]
+//!       },
+//!       {
+//!         "url": "https://t.co/DDDDDDDDDD",
+//!         "expanded_url": "https://twitter.com/EXAMPLE/status/2222222222222222222",
+//!         "display_url": "twitter.com/EXAMPLE/statu…",
+//!         "indices": [ "75", "98" ]
+//!       }
+//!     ]
+//!   },
+//!   "extended_entities": {
+//!     "media": [
+//!       {
+//!         "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+//!         "indices": [ "99", "122" ],
+//!         "url": "https://t.co/CCCCCCCCCC",
+//!         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "id_str": "3333333333333333333",
+//!         "id": "3333333333333333333",
+//!         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+//!         "sizes": {
+//!           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+//!           "small": { "w": "680", "h": "383", "resize": "fit" },
+//!           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+//!           "large": { "w": "1280", "h": "720", "resize": "fit" }
+//!         },
+//!         "type": "photo",
+//!         "display_url": "pic.twitter.com/CCCCCCCCCC"
+//!       }
+//!     ]
+//!   },
+//!   "display_text_range": [ "0", "98" ],
+//!   "favorite_count": "0",
+//!   "id_str": "1111111111111111111",
+//!   "truncated": false,
+//!   "retweet_count": "0",
+//!   "id": "1111111111111111111",
+//!   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!   "favorited": false,
+//!   "full_text": "Fish &amp; chips with @example #rustlang\nRecipe at https://t.co/BBBBBBBBBB https://t.co/DDDDDDDDDD https://t.co/CCCCCCCCCC",
+//!   "lang": "en"
+//! }"#;
+//!
+//! let data: Tweet = serde_json::from_str(&json).unwrap();
+//!
+//! let options = markdown::Options {
+//!     media_directory: Some("data/tweets_media".to_string()),
+//! };
+//!
+//! assert_eq!(
+//!     markdown::tweet(&data, &options),
+//!     [
+//!         "Fish & chips with [@example](https://twitter.com/EXAMPLE) [#rustlang](https://twitter.com/hashtag/rustlang)\\",
+//!         "Recipe at [example.com/fish](https://example.com/fish)",
+//!         "",
+//!         "![Photo](data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg)",
+//!         "",
+//!         "> [Quoted Tweet](https://twitter.com/EXAMPLE/status/2222222222222222222)",
+//!     ].join("\n")
+//! );
+//!
+//! // Without media directory remote URLs are referenced
+//! assert!(markdown::tweet(&data, &markdown::Options::default())
+//!     .contains("![Photo](https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg)"));
+//! ```
+
+use crate::structs::tweets::{self, MediaType, TextSegment, Tweet};
+
+/// Choices for `markdown::tweet` and `markdown::thread`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Options {
+	/// Directory media references point within, e.g. `data/tweets_media`, where `None` points to
+	/// remote `pbs.twimg.com` and `video.twimg.com` URLs instead
+	pub media_directory: Option<String>,
+}
+
+/// Render one Tweet as Markdown, see module documentation for example
+pub fn tweet(tweet: &Tweet, options: &Options) -> String {
+	let quoted_tweet_id = tweet.quoted_tweet_id();
+	let mut quote_url = None;
+
+	let mut body = String::with_capacity(tweet.full_text.len());
+	for segment in tweet.text_segments() {
+		match segment {
+			TextSegment::Text(text) => body.push_str(&escape(&text)),
+			TextSegment::Url {
+				expanded_url,
+				display_url,
+				..
+			} => {
+				if quote_url.is_none()
+					&& quoted_tweet_id.is_some()
+					&& tweets::status_id_from_url(expanded_url) == quoted_tweet_id
+				{
+					quote_url = Some(expanded_url);
+					body.truncate(body.trim_end().len());
+				} else {
+					push_link(&mut body, display_url, expanded_url);
+				}
+			}
+			TextSegment::Media { .. } => body.truncate(body.trim_end().len()),
+			TextSegment::Mention { text, screen_name } => {
+				push_link(
+					&mut body,
+					text,
+					&format!("https://twitter.com/{screen_name}"),
+				);
+			}
+			TextSegment::Hashtag { text, tag } => {
+				push_link(
+					&mut body,
+					text,
+					&format!("https://twitter.com/hashtag/{tag}"),
+				);
+			}
+		}
+	}
+
+	let mut markdown = hard_line_breaks(body.trim());
+
+	for media in tweet.media() {
+		let destination = match (&options.media_directory, media.file_name()) {
+			(Some(directory), Some(file_name)) => format!(
+				"{}/{}-{file_name}",
+				directory.trim_end_matches('/'),
+				tweet.id_str
+			),
+			_ => media.file_url().to_string(),
+		};
+
+		markdown.push_str("\n\n");
+		match &media.media_type {
+			MediaType::Photo => markdown.push('!'),
+			MediaType::Video | MediaType::AnimatedGif | MediaType::Other(_) => {}
+		}
+		push_link(&mut markdown, media_label(&media.media_type), &destination);
+	}
+
+	if let Some(url) = quote_url {
+		markdown.push_str("\n\n> ");
+		push_link(&mut markdown, "Quoted Tweet", url);
+	}
+
+	markdown
+}
+
+/// Render Tweets, in order given, as one Markdown document with Tweets separated by thematic
+/// breaks
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::render::markdown;
+/// use twitter_archive::structs::tweets::Tweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [ "1111111111111111111" ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [ "0", "12" ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "First *post*",
+///   "lang": "en"
+/// }"#;
+///
+/// let first: Tweet = serde_json::from_str(&json).unwrap();
+///
+/// let mut second = first.clone();
+/// second.full_text = "1. Second".to_string();
+///
+/// assert_eq!(
+///     markdown::thread(&[first, second], &markdown::Options::default()),
+///     "First \\*post\\*\n\n---\n\n1\\. Second"
+/// );
+/// ```
+pub fn thread(tweets: &[Tweet], options: &Options) -> String {
+	tweets
+		.iter()
+		.map(|data| tweet(data, options))
+		.collect::<Vec<String>>()
+		.join("\n\n---\n\n")
+}
+
+/// Backslash escape characters that Markdown would otherwise treat as formatting
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::render::markdown;
+///
+/// assert_eq!(markdown::escape("2*3 = [six]"), "2\\*3 = \\[six\\]");
+/// assert_eq!(markdown::escape("snake_case <b>"), "snake\\_case \\<b\\>");
+/// ```
+pub fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for character in text.chars() {
+		if matches!(character, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>') {
+			escaped.push('\\');
+		}
+		escaped.push(character);
+	}
+	escaped
+}
+
+/// Append `[text](destination)` link, or image when preceded by `!`
+fn push_link(markdown: &mut String, text: &str, destination: &str) {
+	markdown.push('[');
+	markdown.push_str(&escape(text));
+	markdown.push_str("](");
+	for character in destination.chars() {
+		match character {
+			' ' => markdown.push_str("%20"),
+			'(' => markdown.push_str("%28"),
+			')' => markdown.push_str("%29"),
+			_ => markdown.push(character),
+		}
+	}
+	markdown.push(')');
+}
+
+/// Link text for media references
+fn media_label(media_type: &MediaType) -> &str {
+	match media_type {
+		MediaType::Photo => "Photo",
+		MediaType::Video => "Video",
+		MediaType::AnimatedGif => "Animated GIF",
+		MediaType::Other(value) => value,
+	}
+}
+
+/// Keep single new-lines of Tweet text as hard line breaks, and escape line starts that would
+/// otherwise become headings, lists, or thematic breaks
+fn hard_line_breaks(body: &str) -> String {
+	let lines: Vec<&str> = body.split('\n').map(str::trim_end).collect();
+
+	let mut markdown = String::with_capacity(body.len() + lines.len());
+	for (index, line) in lines.iter().enumerate() {
+		if index > 0 {
+			markdown.push('\n');
+		}
+
+		let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+		if line.starts_with(['#', '-', '+', '=']) {
+			markdown.push('\\');
+			markdown.push_str(line);
+		} else if digits > 0 && line[digits..].starts_with(['.', ')']) {
+			markdown.push_str(&line[..digits]);
+			markdown.push('\\');
+			markdown.push_str(&line[digits..]);
+		} else {
+			markdown.push_str(line);
+		}
+
+		let next_is_text = lines.get(index + 1).is_some_and(|next| !next.is_empty());
+		if !line.is_empty() && next_is_text {
+			markdown.push('\\');
+		}
+	}
+	markdown
+}
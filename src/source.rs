@@ -0,0 +1,176 @@
+#!/usr/bin/env rust
+
+//! Storage forms archive data may be read from, either the `twitter-<DATE>-<UID>.zip` file as
+//! downloaded or a directory it was extracted into
+//!
+//! `reader::ArchiveReader` is generic over `Source` so downstream code need not care which form
+//! archives are stored in.
+
+use std::fs;
+use std::io::{self, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use zip::read::ZipArchive;
+
+use crate::{Error, Result};
+
+/// Provides readers for files, named relative to archive root such as `data/tweets.js`
+pub trait Source {
+	/// Open file for reading, `Error::MissingFile` is expected when file does not exist
+	fn open_file(&mut self, file_name: &str) -> Result<Box<dyn Read + '_>>;
+
+	/// Check if file exists without reading it
+	fn contains(&self, file_name: &str) -> bool;
+
+	/// List every file, named relative to archive root
+	fn file_names(&self) -> Vec<String>;
+
+	/// Uncompressed size of file in bytes, if known, used for progress reporting
+	fn file_size(&mut self, _file_name: &str) -> Option<u64> {
+		None
+	}
+}
+
+/// Zip formatted data, such as `fs::File` or `io::Cursor<Vec<u8>>`
+pub struct ZipSource<R> {
+	zip_archive: ZipArchive<R>,
+	password: Option<Vec<u8>>,
+}
+
+impl<R: Read + Seek> ZipSource<R> {
+	/// Index zip formatted data read from `reader`
+	pub fn new(reader: R) -> Result<Self> {
+		Ok(Self {
+			zip_archive: ZipArchive::new(reader)?,
+			password: None,
+		})
+	}
+
+	/// Index zip formatted data read from `reader`, decrypting AES or ZipCrypto encrypted entries
+	/// with `password`; entries that are not encrypted are read as usual
+	pub fn with_password(reader: R, password: &[u8]) -> Result<Self> {
+		Ok(Self {
+			zip_archive: ZipArchive::new(reader)?,
+			password: Some(password.to_vec()),
+		})
+	}
+}
+
+impl<R: Read + Seek> Source for ZipSource<R> {
+	fn open_file(&mut self, file_name: &str) -> Result<Box<dyn Read + '_>> {
+		let zip_file = match &self.password {
+			Some(password) => match self.zip_archive.by_name_decrypt(file_name, password) {
+				Ok(Ok(zip_file)) => Ok(zip_file),
+				Ok(Err(zip::result::InvalidPassword)) => {
+					return Err(Error::InvalidPassword {
+						file_name: file_name.to_string(),
+					})
+				}
+				Err(error) => Err(error),
+			},
+			None => self.zip_archive.by_name(file_name),
+		};
+
+		match zip_file {
+			Ok(zip_file) => Ok(Box::new(zip_file)),
+			Err(zip::result::ZipError::FileNotFound) => Err(Error::MissingFile {
+				file_name: file_name.to_string(),
+			}),
+			Err(error) => Err(Error::from(error).with_file_name(file_name)),
+		}
+	}
+
+	fn contains(&self, file_name: &str) -> bool {
+		self.zip_archive.file_names().any(|name| name == file_name)
+	}
+
+	fn file_names(&self) -> Vec<String> {
+		let mut file_names: Vec<String> = self
+			.zip_archive
+			.file_names()
+			.filter(|name| !name.ends_with('/'))
+			.map(String::from)
+			.collect();
+		file_names.sort();
+		file_names
+	}
+
+	fn file_size(&mut self, file_name: &str) -> Option<u64> {
+		let zip_file = match &self.password {
+			Some(password) => self
+				.zip_archive
+				.by_name_decrypt(file_name, password)
+				.ok()?
+				.ok(),
+			None => self.zip_archive.by_name(file_name).ok(),
+		};
+		zip_file.map(|zip_file| zip_file.size())
+	}
+}
+
+/// Directory a `twitter-<DATE>-<UID>.zip` file was extracted into, the one containing `data/`
+pub struct DirSource {
+	root: PathBuf,
+}
+
+impl DirSource {
+	/// Point at extracted archive directory, fails if `root` is not a directory
+	pub fn new<P: AsRef<Path>>(root: P) -> Result<Self> {
+		let root = root.as_ref().to_path_buf();
+		if !root.is_dir() {
+			return Err(io::Error::new(
+				io::ErrorKind::NotFound,
+				format!("{} is not a directory", root.display()),
+			)
+			.into());
+		}
+		Ok(Self { root })
+	}
+
+	/// Full path to file named relative to archive root
+	fn path(&self, file_name: &str) -> PathBuf {
+		self.root.join(file_name)
+	}
+}
+
+impl Source for DirSource {
+	fn open_file(&mut self, file_name: &str) -> Result<Box<dyn Read + '_>> {
+		match fs::File::open(self.path(file_name)) {
+			Ok(file) => Ok(Box::new(file)),
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Err(Error::MissingFile {
+				file_name: file_name.to_string(),
+			}),
+			Err(error) => Err(Error::from(error).with_file_name(file_name)),
+		}
+	}
+
+	fn contains(&self, file_name: &str) -> bool {
+		self.path(file_name).is_file()
+	}
+
+	fn file_names(&self) -> Vec<String> {
+		let mut file_names = Vec::new();
+		let mut directories = vec![(self.root.clone(), String::new())];
+		while let Some((directory, prefix)) = directories.pop() {
+			let Ok(entries) = fs::read_dir(&directory) else {
+				continue;
+			};
+			for entry in entries.flatten() {
+				let name = format!("{prefix}{}", entry.file_name().to_string_lossy());
+				if entry.path().is_dir() {
+					directories.push((entry.path(), format!("{name}/")));
+				} else {
+					file_names.push(name);
+				}
+			}
+		}
+		file_names.sort();
+		file_names
+	}
+
+	fn file_size(&mut self, file_name: &str) -> Option<u64> {
+		fs::metadata(self.path(file_name))
+			.ok()
+			.map(|metadata| metadata.len())
+	}
+}
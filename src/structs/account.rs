@@ -59,6 +59,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -223,3 +224,7 @@ pub struct Account {
 	/// ```
 	pub account_display_name: String,
 }
+
+impl FromJsStr for Vec<AccountObject> {
+	const GLOBAL_NAME: &'static str = "YTD.account";
+}
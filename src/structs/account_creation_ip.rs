@@ -0,0 +1,137 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private account creation IP address data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/account-creation-ip.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/account-creation-ip.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.account_creation_ip().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each account creation IP */
+//!         println!("Account creation IP index: {index}");
+//!         println!("Account ID: {}", object.account_creation_ip.account_id);
+//!         println!("User creation IP: {}", object.account_creation_ip.user_creation_ip);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/account-creation-ip.js` content
+//!
+//! ```javascript
+//! window.YTD.account_creation_ip.part0 = [
+//!   {
+//!     "accountCreationIp" : {
+//!       "accountId" : "111111111",
+//!       "userCreationIp" : "127.0.0.1"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use std::net::IpAddr;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::account_creation_ip::AccountCreationIpObject;
+///
+/// let json = r#"{
+///   "accountCreationIp": {
+///     "accountId": "111111111",
+///     "userCreationIp": "127.0.0.1"
+///   }
+/// }"#;
+///
+/// let data: AccountCreationIpObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.account_creation_ip.account_id, "111111111");
+/// assert!(data.account_creation_ip.user_creation_ip.is_loopback());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCreationIpObject {
+	/// Why they wrapped a list of creation IP addresses within unnecessary object label is anyone's
+	/// guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "accountCreationIp": {
+	///     "accountId": "111111111",
+	///     "userCreationIp": "127.0.0.1"
+	///   }
+	/// }
+	/// ```
+	pub account_creation_ip: AccountCreationIp,
+}
+
+/// ## Example
+///
+/// ```
+/// use std::net::{IpAddr, Ipv6Addr};
+///
+/// use twitter_archive::structs::account_creation_ip::AccountCreationIp;
+///
+/// let json = r#"{
+///   "accountId": "111111111",
+///   "userCreationIp": "2001:db8::1"
+/// }"#;
+///
+/// let data: AccountCreationIp = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.account_id, "111111111");
+/// assert_eq!(data.user_creation_ip, IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCreationIp {
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{account_id}
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "accountId": "111111111" }
+	/// ```
+	pub account_id: String,
+
+	/// IPv4 or IPv6 address account was created from
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "userCreationIp": "127.0.0.1" }
+	/// ```
+	pub user_creation_ip: IpAddr,
+}
+
+impl FromJsStr for Vec<AccountCreationIpObject> {
+	const GLOBAL_NAME: &'static str = "YTD.account_creation_ip";
+}
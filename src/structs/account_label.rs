@@ -0,0 +1,202 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private account label, such as government affiliated or
+//! automated, data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/account-label.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/account-label.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.account_label().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each label */
+//!         println!("Label index: {index}");
+//!         println!("Label type: {}", object.account_label.label_type);
+//!         println!("Applied at: {}", object.account_label.applied_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/account-label.js` content
+//!
+//! ```javascript
+//! window.YTD.account_label.part0 = [
+//!   {
+//!     "accountLabel" : {
+//!       "accountId" : "111111111",
+//!       "labelType" : "Automated",
+//!       "description" : "Automated by @EXAMPLE",
+//!       "appliedAt" : "2023-01-01T00:00:00.000Z",
+//!       "removedAt" : "2023-06-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::account_label::AccountLabelObject;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let applied_at_string = "2023-01-01T00:00:00.000Z";
+/// let applied_at_native_time = NaiveDateTime::parse_from_str(&applied_at_string, FORMAT).unwrap();
+/// let applied_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(applied_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "accountLabel": {{
+///     "accountId": "111111111",
+///     "labelType": "Automated",
+///     "appliedAt": "{applied_at_string}"
+///   }}
+/// }}"#);
+///
+/// let data: AccountLabelObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.account_label.account_id, "111111111");
+/// assert_eq!(data.account_label.label_type, "Automated");
+/// assert_eq!(data.account_label.applied_at, applied_at_date_time);
+/// assert!(data.account_label.removed_at.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountLabelObject {
+	/// Why they wrapped a list of labels within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "accountLabel": {
+	///     "accountId": "111111111",
+	///     "labelType": "Automated",
+	///     "appliedAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub account_label: AccountLabel,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::account_label::AccountLabel;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let applied_at_string = "2023-01-01T00:00:00.000Z";
+/// let applied_at_native_time = NaiveDateTime::parse_from_str(&applied_at_string, FORMAT).unwrap();
+/// let applied_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(applied_at_native_time, Utc);
+///
+/// let removed_at_string = "2023-06-01T00:00:00.000Z";
+/// let removed_at_native_time = NaiveDateTime::parse_from_str(&removed_at_string, FORMAT).unwrap();
+/// let removed_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(removed_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "accountId": "111111111",
+///   "labelType": "Automated",
+///   "description": "Automated by @EXAMPLE",
+///   "appliedAt": "{applied_at_string}",
+///   "removedAt": "{removed_at_string}"
+/// }}"#);
+///
+/// let data: AccountLabel = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.account_id, "111111111");
+/// assert_eq!(data.label_type, "Automated");
+/// assert_eq!(data.description, Some("Automated by @EXAMPLE".to_string()));
+/// assert_eq!(data.applied_at, applied_at_date_time);
+/// assert_eq!(data.removed_at, Some(removed_at_date_time));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountLabel {
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{account_id}
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "accountId": "111111111" }
+	/// ```
+	pub account_id: String,
+
+	/// Kind of label, such as `Automated` or `GovernmentAffiliated`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "labelType": "Automated" }
+	/// ```
+	pub label_type: String,
+
+	/// Text shown alongside label, if any
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "description": "Automated by @EXAMPLE" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+
+	/// Date time-stamp of when label was applied to account
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "appliedAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub applied_at: DateTime<Utc>,
+
+	/// Date time-stamp of when label was removed from account, absent while label is still shown
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "removedAt": "2023-06-01T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub removed_at: Option<DateTime<Utc>>,
+}
+
+impl FromJsStr for Vec<AccountLabelObject> {
+	const GLOBAL_NAME: &'static str = "YTD.account_label";
+}
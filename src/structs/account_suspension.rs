@@ -0,0 +1,169 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private account suspension and restoration data found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/account-suspension.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/account-suspension.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.account_suspension().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each suspension event */
+//!         println!("Suspension index: {index}");
+//!         println!("Time stamp: {}", object.account_suspension.time_stamp);
+//!         println!("Action: {}", object.account_suspension.action);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/account-suspension.js` content
+//!
+//! ```javascript
+//! window.YTD.account_suspension.part0 = [
+//!   {
+//!     "accountSuspension" : {
+//!       "timeStamp" : "2022-01-01T00:00:00.000Z",
+//!       "action" : "Suspend",
+//!       "reason" : "Spam"
+//!     }
+//!   },
+//!   {
+//!     "accountSuspension" : {
+//!       "timeStamp" : "2022-01-08T00:00:00.000Z",
+//!       "action" : "Unsuspend"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::account_suspension::AccountSuspensionObject;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let time_stamp_string = "2022-01-08T00:00:00.000Z";
+/// let time_stamp_native_time = NaiveDateTime::parse_from_str(&time_stamp_string, FORMAT).unwrap();
+/// let time_stamp_date_time = DateTime::<Utc>::from_naive_utc_and_offset(time_stamp_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "accountSuspension": {{
+///     "timeStamp": "{time_stamp_string}",
+///     "action": "Unsuspend"
+///   }}
+/// }}"#);
+///
+/// let data: AccountSuspensionObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.account_suspension.time_stamp, time_stamp_date_time);
+/// assert_eq!(data.account_suspension.action, "Unsuspend");
+/// assert!(data.account_suspension.reason.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSuspensionObject {
+	/// Why they wrapped a list of suspension events within unnecessary object label is anyone's
+	/// guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "accountSuspension": {
+	///     "timeStamp": "2022-01-08T00:00:00.000Z",
+	///     "action": "Unsuspend"
+	///   }
+	/// }
+	/// ```
+	pub account_suspension: AccountSuspension,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::account_suspension::AccountSuspension;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let time_stamp_string = "2022-01-01T00:00:00.000Z";
+/// let time_stamp_native_time = NaiveDateTime::parse_from_str(&time_stamp_string, FORMAT).unwrap();
+/// let time_stamp_date_time = DateTime::<Utc>::from_naive_utc_and_offset(time_stamp_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "timeStamp": "{time_stamp_string}",
+///   "action": "Suspend",
+///   "reason": "Spam"
+/// }}"#);
+///
+/// let data: AccountSuspension = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.time_stamp, time_stamp_date_time);
+/// assert_eq!(data.action, "Suspend");
+/// assert_eq!(data.reason, Some("Spam".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSuspension {
+	/// Date time-stamp of when account was suspended or restored
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "timeStamp": "2022-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub time_stamp: DateTime<Utc>,
+
+	/// What happened to account, such as `Suspend` or `Unsuspend`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "action": "Suspend" }
+	/// ```
+	pub action: String,
+
+	/// Why action was taken, when Twitter bothered to say
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "reason": "Spam" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+}
+
+impl FromJsStr for Vec<AccountSuspensionObject> {
+	const GLOBAL_NAME: &'static str = "YTD.account_suspension";
+}
@@ -50,6 +50,10 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "chrono-tz")]
+use crate::convert;
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -126,9 +130,9 @@ pub struct AccountTimezone {
 	/// ```
 	pub account_id: String,
 
-	/// Best guess at account time-zone
+	/// Best guess at account time-zone, as Ruby on Rails style friendly name
 	///
-	/// TODO: Maybe convert to `enum` in future major version release
+	/// With `chrono-tz` feature enabled `AccountTimezone::tz` parses this into typed time-zone
 	///
 	/// ## Example JSON data
 	///
@@ -137,3 +141,41 @@ pub struct AccountTimezone {
 	/// ```
 	pub time_zone: String,
 }
+
+#[cfg(feature = "chrono-tz")]
+impl AccountTimezone {
+	/// Typed time-zone parsed from `time_zone`, for localizing `created_at` time-stamps
+	///
+	/// Returns `None` for names unknown at time of writing.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use chrono::{DateTime, Utc};
+	///
+	/// use twitter_archive::structs::account_timezone::AccountTimezone;
+	///
+	/// let json = r#"{
+	///   "accountId": "111111111",
+	///   "timeZone": "Arizona"
+	/// }"#;
+	///
+	/// let data: AccountTimezone = serde_json::from_str(&json).unwrap();
+	///
+	/// let tz = data.tz().unwrap();
+	/// assert_eq!(tz, chrono_tz::Tz::America__Phoenix);
+	///
+	/// let created_at: DateTime<Utc> = "2023-08-12T16:10:37Z".parse().unwrap();
+	/// assert_eq!(created_at.with_timezone(&tz).to_rfc3339(), "2023-08-12T09:10:37-07:00");
+	///
+	/// // Raw string is kept
+	/// assert_eq!(data.time_zone, "Arizona");
+	/// ```
+	pub fn tz(&self) -> Option<chrono_tz::Tz> {
+		convert::time_zone::parse(&self.time_zone)
+	}
+}
+
+impl FromJsStr for Vec<AccountTimezoneObject> {
+	const GLOBAL_NAME: &'static str = "YTD.account_timezone";
+}
@@ -33,7 +33,7 @@ use crate::convert;
 ///
 /// use twitter_archive::convert::date_year_month_day_hour_minute_second::FORMAT;
 ///
-/// use twitter_archive::structs::ad::Impression;
+/// use twitter_archive::structs::ad::{DisplayLocation, Impression};
 ///
 /// let impression_time_string = "2023-06-05 17:00:52";
 /// let impression_time_native_time = NaiveDateTime::parse_from_str(&impression_time_string, FORMAT).unwrap();
@@ -70,7 +70,7 @@ use crate::convert;
 /// // De-serialized properties
 /// assert_eq!(data.device_info.os_type, "Desktop");
 ///
-/// assert_eq!(data.display_location, "TweetConversation");
+/// assert_eq!(data.display_location, DisplayLocation::TweetConversation);
 ///
 /// if let Some(promoted_tweet_info) = &data.promoted_tweet_info {
 ///     assert_eq!(promoted_tweet_info.tweet_id, "1111111111111111111");
@@ -120,7 +120,7 @@ pub struct Impression {
 	/// ```json
 	/// { "displayLocation": "TweetConversation" }
 	/// ```
-	pub display_location: String,
+	pub display_location: DisplayLocation,
 
 	/// ## Example JSON data
 	///
@@ -175,10 +175,54 @@ pub struct Impression {
 	pub impression_time: DateTime<Utc>,
 }
 
+string_enum! {
+	/// Where within clients an ad was shown
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::ad::DisplayLocation;
+	///
+	/// let data: Vec<DisplayLocation> = serde_json::from_str(r#"["TimelineHome", "ExploreTab"]"#).unwrap();
+	///
+	/// // De-serialized properties
+	/// assert_eq!(data[0], DisplayLocation::TimelineHome);
+	/// assert_eq!(data[1], DisplayLocation::Other("ExploreTab".to_string()));
+	///
+	/// // Both known and unknown values re-serialize to original strings
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"["TimelineHome","ExploreTab"]"#);
+	/// ```
+	pub enum DisplayLocation {
+		/// Replies below a Tweet
+		TweetConversation => "TweetConversation",
+
+		/// Home timeline
+		TimelineHome => "TimelineHome",
+
+		/// Profile page of an account
+		Profile => "Profile",
+
+		/// Tweets tab of a profile page
+		ProfileTweets => "ProfileTweets",
+
+		/// Accounts sidebar of a profile page
+		ProfileAccountsSidebar => "ProfileAccountsSidebar",
+
+		/// Tweet search results
+		SearchTweets => "SearchTweets",
+
+		/// Who to follow suggestions
+		WtfSidebar => "WtfSidebar",
+
+		/// Trends list
+		Trends => "Trends",
+	}
+}
+
 /// ## Example
 ///
 /// ```
-/// use twitter_archive::structs::ad::DeviceInfo;
+/// use twitter_archive::structs::ad::{DeviceInfo, OsType};
 ///
 /// let json = format!(r#"{{
 ///   "osType": "Desktop"
@@ -187,7 +231,7 @@ pub struct Impression {
 /// let data: DeviceInfo = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.os_type, "Desktop");
+/// assert_eq!(data.os_type, OsType::Desktop);
 ///
 /// // Re-serialize is equivalent to original data without pretty printing
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
@@ -201,7 +245,37 @@ pub struct DeviceInfo {
 	/// ```json
 	/// { "osType": "Desktop" }
 	/// ```
-	pub os_type: String,
+	pub os_type: OsType,
+}
+
+string_enum! {
+	/// Class of device an ad was shown on
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::ad::OsType;
+	///
+	/// let data: Vec<OsType> = serde_json::from_str(r#"["Ios", "Android", "KaiOS"]"#).unwrap();
+	///
+	/// // De-serialized properties
+	/// assert_eq!(data[0], OsType::Ios);
+	/// assert_eq!(data[1], OsType::Android);
+	/// assert_eq!(data[2], OsType::Other("KaiOS".to_string()));
+	///
+	/// // Both known and unknown values re-serialize to original strings
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"["Ios","Android","KaiOS"]"#);
+	/// ```
+	pub enum OsType {
+		/// Web browser, regardless of operating system
+		Desktop => "Desktop",
+
+		/// Android application
+		Android => "Android",
+
+		/// iPhone or iPad application
+		Ios => "Ios",
+	}
 }
 
 /// ## Example
@@ -87,6 +87,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 use crate::structs::ad;
 
 /// ## Example
@@ -882,7 +883,7 @@ pub struct Engagement {
 ///
 /// use twitter_archive::convert::date_year_month_day_hour_minute_second::FORMAT;
 ///
-/// use twitter_archive::structs::ad_engagements::EngagementAttributes;
+/// use twitter_archive::structs::ad_engagements::{EngagementAttributes, EngagementType};
 ///
 /// let engagement_time_string = "2023-06-05 17:00:52";
 /// let engagement_time_native_time = NaiveDateTime::parse_from_str(&engagement_time_string, FORMAT).unwrap();
@@ -897,7 +898,7 @@ pub struct Engagement {
 ///
 /// // De-serialized properties
 /// assert_eq!(data.engagement_time, engagement_time_date_time);
-/// assert_eq!(data.engagement_type, "ChargeableImpression");
+/// assert_eq!(data.engagement_type, EngagementType::ChargeableImpression);
 ///
 /// // Re-serialize is equivalent to original data without pretty printing
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
@@ -919,5 +920,74 @@ pub struct EngagementAttributes {
 	/// ```json
 	/// { "engagementType": "ChargeableImpression" }
 	/// ```
-	pub engagement_type: String,
+	pub engagement_type: EngagementType,
+}
+
+string_enum! {
+	/// Kind of interaction with a promoted Tweet, trend, or account
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::ad_engagements::EngagementType;
+	///
+	/// let data: Vec<EngagementType> = serde_json::from_str(r#"["Mute", "PollCardVote"]"#).unwrap();
+	///
+	/// // De-serialized properties
+	/// assert_eq!(data[0], EngagementType::Mute);
+	/// assert_eq!(data[1], EngagementType::Other("PollCardVote".to_string()));
+	///
+	/// // Both known and unknown values re-serialize to original strings
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"["Mute","PollCardVote"]"#);
+	/// ```
+	pub enum EngagementType {
+		/// Ad was shown long enough for advertiser to be billed
+		ChargeableImpression => "ChargeableImpression",
+
+		/// Ad was expanded to show details
+		Detail => "Detail",
+
+		/// Link or card within ad was clicked
+		Click => "Click",
+
+		/// Media within ad was expanded
+		PhotoExpand => "PhotoExpand",
+
+		/// Profile of advertiser was opened from ad
+		UserProfileClick => "UserProfileClick",
+
+		/// Video within ad started playing
+		VideoView => "VideoView",
+
+		/// Advertiser was followed from ad
+		Follow => "Follow",
+
+		/// Advertiser was unfollowed from ad
+		Unfollow => "Unfollow",
+
+		/// Ad was liked
+		Like => "Like",
+
+		/// Ad was Retweeted
+		Retweet => "Retweet",
+
+		/// Ad was replied to
+		Reply => "Reply",
+
+		/// Ad was shared outside of Twitter
+		Share => "Share",
+
+		/// Advertiser was muted from ad
+		Mute => "Mute",
+
+		/// Advertiser was blocked from ad
+		Block => "Block",
+
+		/// Ad was dismissed as irrelevant
+		Dismiss => "Dismiss",
+	}
+}
+
+impl FromJsStr for Vec<AdObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ad_engagements";
 }
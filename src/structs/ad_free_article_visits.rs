@@ -0,0 +1,153 @@
+#!/usr/bin/env rust
+
+//! Archives exported from X after the Twitter rebrand have private ad-free, Premium, publisher
+//! article visit data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/ad-free-article-visits.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/ad-free-article-visits.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.ad_free_article_visits().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each visit */
+//!         println!("Visit index: {index}");
+//!         println!("Article URL: {}", object.ad_free_article_visit.article_url);
+//!         println!("Visited at: {}", object.ad_free_article_visit.visited_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/ad-free-article-visits.js` content
+//!
+//! ```javascript
+//! window.YTD.ad_free_article_visits.part0 = [
+//!   {
+//!     "adFreeArticleVisit" : {
+//!       "articleUrl" : "https://news.example.com/2024/01/01/story",
+//!       "publisher" : "Example News",
+//!       "visitedAt" : "2024-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::ad_free_article_visits::AdFreeArticleVisitObject;
+///
+/// let json = r#"{
+///   "adFreeArticleVisit": {
+///     "articleUrl": "https://news.example.com/2024/01/01/story",
+///     "publisher": "Example News",
+///     "visitedAt": "2024-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: AdFreeArticleVisitObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.ad_free_article_visit.article_url, "https://news.example.com/2024/01/01/story");
+/// assert_eq!(data.ad_free_article_visit.publisher, Some("Example News".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AdFreeArticleVisitObject {
+	/// Why they wrapped a list of visits within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "adFreeArticleVisit": {
+	///     "articleUrl": "https://news.example.com/2024/01/01/story",
+	///     "publisher": "Example News",
+	///     "visitedAt": "2024-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub ad_free_article_visit: AdFreeArticleVisit,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::ad_free_article_visits::AdFreeArticleVisit;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let visited_at_string = "2024-01-01T00:00:00.000Z";
+/// let visited_at_native_time = NaiveDateTime::parse_from_str(&visited_at_string, FORMAT).unwrap();
+/// let visited_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(visited_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "articleUrl": "https://news.example.com/2024/01/01/story",
+///   "visitedAt": "{visited_at_string}"
+/// }}"#);
+///
+/// let data: AdFreeArticleVisit = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.article_url, "https://news.example.com/2024/01/01/story");
+/// assert!(data.publisher.is_none());
+/// assert_eq!(data.visited_at, visited_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AdFreeArticleVisit {
+	/// Address of article that was read without ads
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "articleUrl": "https://news.example.com/2024/01/01/story" }
+	/// ```
+	pub article_url: String,
+
+	/// Name of participating publisher
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "publisher": "Example News" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub publisher: Option<String>,
+
+	/// Date time-stamp of when article was visited
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "visitedAt": "2024-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub visited_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<AdFreeArticleVisitObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ad_free_article_visits";
+}
@@ -85,6 +85,7 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
 use crate::structs::ad;
 
 /// ## Example
@@ -585,3 +586,7 @@ pub struct AdImpressions {
 	/// ```
 	pub impressions: Vec<ad::Impression>,
 }
+
+impl FromJsStr for Vec<AdObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ad_impressions";
+}
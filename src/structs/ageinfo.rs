@@ -0,0 +1,238 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private declared and inferred age data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/ageinfo.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/ageinfo.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.ageinfo().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each age record */
+//!         println!("Age index: {index}");
+//!         if let Some(age_info) = &object.age_meta.age_info {
+//!             println!("Declared age ranges: {:?}", age_info.age);
+//!         }
+//!         if let Some(inferred_age_info) = &object.age_meta.inferred_age_info {
+//!             println!("Inferred age ranges: {:?}", inferred_age_info.age);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/ageinfo.js` content
+//!
+//! ```javascript
+//! window.YTD.ageinfo.part0 = [
+//!   {
+//!     "ageMeta" : {
+//!       "ageInfo" : {
+//!         "age" : [
+//!           "13-54"
+//!         ],
+//!         "birthDate" : "1990-01-01"
+//!       },
+//!       "inferredAgeInfo" : {
+//!         "age" : [
+//!           "13-54"
+//!         ],
+//!         "birthDate" : ""
+//!       }
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::ageinfo::AgeinfoObject;
+///
+/// let json = r#"{
+///   "ageMeta": {
+///     "ageInfo": {
+///       "age": [
+///         "13-54"
+///       ],
+///       "birthDate": "1990-01-01"
+///     },
+///     "inferredAgeInfo": {
+///       "age": [
+///         "13-54"
+///       ],
+///       "birthDate": ""
+///     }
+///   }
+/// }"#;
+///
+/// let data: AgeinfoObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// let age_info = data.age_meta.age_info.as_ref().unwrap();
+/// assert_eq!(age_info.age, vec!["13-54"]);
+/// assert_eq!(age_info.birth_date, Some("1990-01-01".to_string()));
+///
+/// let inferred_age_info = data.age_meta.inferred_age_info.as_ref().unwrap();
+/// assert_eq!(inferred_age_info.age, vec!["13-54"]);
+/// assert_eq!(inferred_age_info.birth_date, Some("".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AgeinfoObject {
+	/// Why they wrapped age data within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "ageMeta": {
+	///     "ageInfo": {
+	///       "age": [
+	///         "13-54"
+	///       ],
+	///       "birthDate": "1990-01-01"
+	///     },
+	///     "inferredAgeInfo": {
+	///       "age": [
+	///         "13-54"
+	///       ],
+	///       "birthDate": ""
+	///     }
+	///   }
+	/// }
+	/// ```
+	pub age_meta: AgeMeta,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::ageinfo::AgeMeta;
+///
+/// let json = r#"{
+///   "inferredAgeInfo": {
+///     "age": [
+///       "13-54"
+///     ],
+///     "birthDate": ""
+///   }
+/// }"#;
+///
+/// let data: AgeMeta = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert!(data.age_info.is_none());
+/// assert_eq!(data.inferred_age_info.as_ref().unwrap().age, vec!["13-54"]);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AgeMeta {
+	/// Age declared by account owner, absent if birth date was never provided
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "ageInfo": {
+	///     "age": [
+	///       "13-54"
+	///     ],
+	///     "birthDate": "1990-01-01"
+	///   }
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub age_info: Option<AgeInfo>,
+
+	/// Age guessed by Twitter, absent if no guess has been made
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "inferredAgeInfo": {
+	///     "age": [
+	///       "13-54"
+	///     ],
+	///     "birthDate": ""
+	///   }
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub inferred_age_info: Option<AgeInfo>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::ageinfo::AgeInfo;
+///
+/// let json = r#"{
+///   "age": [
+///     "13-54"
+///   ],
+///   "birthDate": "1990-01-01"
+/// }"#;
+///
+/// let data: AgeInfo = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.age, vec!["13-54"]);
+/// assert_eq!(data.birth_date, Some("1990-01-01".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AgeInfo {
+	/// Age ranges, in years, that account owner is thought to fall within
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "age": [
+	///     "13-54"
+	///   ]
+	/// }
+	/// ```
+	pub age: Vec<String>,
+
+	/// Birth date formatted as `YYYY-MM-DD`, or empty string when only a range is known
+	///
+	/// TODO: Maybe convert to `chrono::NaiveDate` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "birthDate": "1990-01-01" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub birth_date: Option<String>,
+}
+
+impl FromJsStr for Vec<AgeinfoObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ageinfo";
+}
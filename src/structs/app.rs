@@ -0,0 +1,169 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private authorized application data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/app.js
+//!
+//! Application IDs may be matched against `connected_application[].connectedApplication.id` for
+//! organization details and approval dates.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/app.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.app().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each application */
+//!         println!("Application index: {index}");
+//!         println!("Application ID: {}", object.app.app_id);
+//!         println!("Application names: {:?}", object.app.app_names);
+//!         println!("Permissions: {:?}", object.app.permissions);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/app.js` content
+//!
+//! ```javascript
+//! window.YTD.app.part0 = [
+//!   {
+//!     "app" : {
+//!       "appId" : "11111111",
+//!       "appNames" : [
+//!         "Medium"
+//!       ],
+//!       "permissions" : [
+//!         "read",
+//!         "write"
+//!       ]
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::app::AppObject;
+///
+/// let json = r#"{
+///   "app": {
+///     "appId": "11111111",
+///     "appNames": [
+///       "Medium"
+///     ]
+///   }
+/// }"#;
+///
+/// let data: AppObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.app.app_id, "11111111");
+/// assert_eq!(data.app.app_names, vec!["Medium"]);
+/// assert!(data.app.permissions.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AppObject {
+	/// Why they wrapped a list of applications within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "app": {
+	///     "appId": "11111111",
+	///     "appNames": [
+	///       "Medium"
+	///     ]
+	///   }
+	/// }
+	/// ```
+	pub app: App,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::app::App;
+///
+/// let json = r#"{
+///   "appId": "11111111",
+///   "appNames": [
+///     "Medium"
+///   ],
+///   "permissions": [
+///     "read",
+///     "write"
+///   ]
+/// }"#;
+///
+/// let data: App = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.app_id, "11111111");
+/// assert_eq!(data.app_names, vec!["Medium"]);
+/// assert_eq!(data.permissions, Some(vec!["read".to_string(), "write".to_string()]));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct App {
+	/// Unique ID of application, same as `connected_application[].connectedApplication.id`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "appId": "11111111" }
+	/// ```
+	pub app_id: String,
+
+	/// Names application has been known by
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "appNames": [
+	///     "Medium"
+	///   ]
+	/// }
+	/// ```
+	pub app_names: Vec<String>,
+
+	/// Permissions granted to application, absent within older archives
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "permissions": [
+	///     "read",
+	///     "write"
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub permissions: Option<Vec<String>>,
+}
+
+impl FromJsStr for Vec<AppObject> {
+	const GLOBAL_NAME: &'static str = "YTD.app";
+}
@@ -48,6 +48,8 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -148,3 +150,7 @@ pub struct Blocking {
 	/// ```
 	pub user_link: String,
 }
+
+impl FromJsStr for Vec<BlockingObject> {
+	const GLOBAL_NAME: &'static str = "YTD.block";
+}
@@ -0,0 +1,185 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private deep-link, via Branch, data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/branch-links.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/branch-links.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.branch_links().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each deep-link */
+//!         println!("Branch link index: {index}");
+//!         println!("URL: {}", object.branch_link.url);
+//!         println!("Time stamp: {}", object.branch_link.timestamp);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/branch-links.js` content
+//!
+//! ```javascript
+//! window.YTD.branch_links.part0 = [
+//!   {
+//!     "branchLink" : {
+//!       "url" : "https://twitter.com/EXAMPLE/status/1111111111111111111",
+//!       "timestamp" : "2023-01-01T00:00:00.000Z",
+//!       "channel" : "email",
+//!       "feature" : "notification",
+//!       "campaign" : "recommendations"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::branch_links::BranchLinkObject;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let timestamp_string = "2023-01-01T00:00:00.000Z";
+/// let timestamp_native_time = NaiveDateTime::parse_from_str(&timestamp_string, FORMAT).unwrap();
+/// let timestamp_date_time = DateTime::<Utc>::from_naive_utc_and_offset(timestamp_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "branchLink": {{
+///     "url": "https://twitter.com/EXAMPLE/status/1111111111111111111",
+///     "timestamp": "{timestamp_string}"
+///   }}
+/// }}"#);
+///
+/// let data: BranchLinkObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.branch_link.url, "https://twitter.com/EXAMPLE/status/1111111111111111111");
+/// assert_eq!(data.branch_link.timestamp, timestamp_date_time);
+/// assert!(data.branch_link.channel.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLinkObject {
+	/// Why they wrapped a list of deep-links within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "branchLink": {
+	///     "url": "https://twitter.com/EXAMPLE/status/1111111111111111111",
+	///     "timestamp": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub branch_link: BranchLink,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::branch_links::BranchLink;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let timestamp_string = "2023-01-01T00:00:00.000Z";
+/// let timestamp_native_time = NaiveDateTime::parse_from_str(&timestamp_string, FORMAT).unwrap();
+/// let timestamp_date_time = DateTime::<Utc>::from_naive_utc_and_offset(timestamp_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "url": "https://twitter.com/EXAMPLE/status/1111111111111111111",
+///   "timestamp": "{timestamp_string}",
+///   "channel": "email",
+///   "feature": "notification",
+///   "campaign": "recommendations"
+/// }}"#);
+///
+/// let data: BranchLink = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.url, "https://twitter.com/EXAMPLE/status/1111111111111111111");
+/// assert_eq!(data.timestamp, timestamp_date_time);
+/// assert_eq!(data.channel, Some("email".to_string()));
+/// assert_eq!(data.feature, Some("notification".to_string()));
+/// assert_eq!(data.campaign, Some("recommendations".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLink {
+	/// Destination that deep-link opened
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://twitter.com/EXAMPLE/status/1111111111111111111" }
+	/// ```
+	pub url: String,
+
+	/// Date time-stamp of when deep-link was followed
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "timestamp": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub timestamp: DateTime<Utc>,
+
+	/// Medium deep-link was shared through, such as `email` or `sms`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "channel": "email" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub channel: Option<String>,
+
+	/// Product feature that produced deep-link
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "feature": "notification" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub feature: Option<String>,
+
+	/// Marketing campaign deep-link belongs to
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "campaign": "recommendations" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub campaign: Option<String>,
+}
+
+impl FromJsStr for Vec<BranchLinkObject> {
+	const GLOBAL_NAME: &'static str = "YTD.branch_links";
+}
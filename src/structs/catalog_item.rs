@@ -0,0 +1,318 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private shop catalog item data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/catalog-item.js
+//!
+//! Items belong to catalogs described by `data/commerce-catalog.js`, check
+//! `structs::commerce_catalog` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/catalog-item.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.catalog_item().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each catalog item */
+//!         println!("Catalog item index: {index}");
+//!         println!("Title: {}", object.catalog_item.title);
+//!         println!("Price: {}", object.catalog_item.price);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/catalog-item.js` content
+//!
+//! ```javascript
+//! window.YTD.catalog_item.part0 = [
+//!   {
+//!     "catalogItem" : {
+//!       "catalogItemId" : "1111111111111111111",
+//!       "catalogId" : "2222222222222222222",
+//!       "title" : "Example T-shirt",
+//!       "description" : "Soft cotton T-shirt",
+//!       "price" : {
+//!         "amount" : "19.99",
+//!         "currency" : "USD"
+//!       },
+//!       "link" : "https://shop.example.com/t-shirt",
+//!       "imageUrl" : "https://shop.example.com/t-shirt.jpg",
+//!       "availability" : "InStock",
+//!       "createdAt" : "2023-01-01T00:00:00.000Z",
+//!       "updatedAt" : "2023-02-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::catalog_item::CatalogItemObject;
+///
+/// let json = r#"{
+///   "catalogItem": {
+///     "catalogItemId": "1111111111111111111",
+///     "catalogId": "2222222222222222222",
+///     "title": "Example T-shirt",
+///     "description": "Soft cotton T-shirt",
+///     "price": {
+///       "amount": "19.99",
+///       "currency": "USD"
+///     },
+///     "link": "https://shop.example.com/t-shirt",
+///     "imageUrl": "https://shop.example.com/t-shirt.jpg",
+///     "availability": "InStock",
+///     "createdAt": "2023-01-01T00:00:00.000Z",
+///     "updatedAt": "2023-02-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: CatalogItemObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.catalog_item.catalog_item_id, "1111111111111111111");
+/// assert_eq!(data.catalog_item.title, "Example T-shirt");
+/// assert_eq!(data.catalog_item.price.amount, "19.99");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogItemObject {
+	/// Why they wrapped a list of catalog items within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "catalogItem": {
+	///     "catalogItemId": "1111111111111111111",
+	///     "catalogId": "2222222222222222222",
+	///     "title": "Example T-shirt",
+	///     "description": "Soft cotton T-shirt",
+	///     "price": {
+	///       "amount": "19.99",
+	///       "currency": "USD"
+	///     },
+	///     "link": "https://shop.example.com/t-shirt",
+	///     "imageUrl": "https://shop.example.com/t-shirt.jpg",
+	///     "availability": "InStock",
+	///     "createdAt": "2023-01-01T00:00:00.000Z",
+	///     "updatedAt": "2023-02-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub catalog_item: CatalogItem,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::catalog_item::CatalogItem;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let updated_at_string = "2023-02-01T00:00:00.000Z";
+/// let updated_at_native_time = NaiveDateTime::parse_from_str(&updated_at_string, FORMAT).unwrap();
+/// let updated_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(updated_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "catalogItemId": "1111111111111111111",
+///   "catalogId": "2222222222222222222",
+///   "title": "Example T-shirt",
+///   "price": {{
+///     "amount": "19.99",
+///     "currency": "USD"
+///   }},
+///   "link": "https://shop.example.com/t-shirt",
+///   "createdAt": "{created_at_string}",
+///   "updatedAt": "{updated_at_string}"
+/// }}"#);
+///
+/// let data: CatalogItem = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.catalog_item_id, "1111111111111111111");
+/// assert_eq!(data.catalog_id, "2222222222222222222");
+/// assert_eq!(data.title, "Example T-shirt");
+/// assert!(data.description.is_none());
+/// assert_eq!(data.price.currency, "USD");
+/// assert_eq!(data.link, "https://shop.example.com/t-shirt");
+/// assert!(data.image_url.is_none());
+/// assert!(data.availability.is_none());
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert_eq!(data.updated_at, updated_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogItem {
+	/// Unique ID of item
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogItemId": "1111111111111111111" }
+	/// ```
+	pub catalog_item_id: String,
+
+	/// ID of catalog item belongs to, same as `commerce_catalog[].commerceCatalog.catalogId`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogId": "2222222222222222222" }
+	/// ```
+	pub catalog_id: String,
+
+	/// Name of item as shown to shoppers
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "title": "Example T-shirt" }
+	/// ```
+	pub title: String,
+
+	/// Longer description of item, if any
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "description": "Soft cotton T-shirt" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "price": {
+	///     "amount": "19.99",
+	///     "currency": "USD"
+	///   }
+	/// }
+	/// ```
+	pub price: Price,
+
+	/// Shop page where item may be bought
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "link": "https://shop.example.com/t-shirt" }
+	/// ```
+	pub link: String,
+
+	/// Picture of item, if any
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "imageUrl": "https://shop.example.com/t-shirt.jpg" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub image_url: Option<String>,
+
+	/// Stock status, such as `InStock` or `OutOfStock`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "availability": "InStock" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub availability: Option<String>,
+
+	/// Date time-stamp of when item was added to catalog
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when item was last changed
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "updatedAt": "2023-02-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub updated_at: DateTime<Utc>,
+}
+
+/// Amount of money, shared by commerce data types
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::catalog_item::Price;
+///
+/// let json = r#"{
+///   "amount": "19.99",
+///   "currency": "USD"
+/// }"#;
+///
+/// let data: Price = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.amount, "19.99");
+/// assert_eq!(data.currency, "USD");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+	/// Decimal amount kept as string to avoid floating point rounding
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "amount": "19.99" }
+	/// ```
+	pub amount: String,
+
+	/// ISO 4217 currency code
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "currency": "USD" }
+	/// ```
+	pub currency: String,
+}
+
+impl FromJsStr for Vec<CatalogItemObject> {
+	const GLOBAL_NAME: &'static str = "YTD.catalog_item";
+}
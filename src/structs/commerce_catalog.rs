@@ -0,0 +1,188 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private shop catalog data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/commerce-catalog.js
+//!
+//! Items within each catalog are described by `data/catalog-item.js`, check
+//! `structs::catalog_item` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/commerce-catalog.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.commerce_catalog().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each catalog */
+//!         println!("Catalog index: {index}");
+//!         println!("Catalog ID: {}", object.commerce_catalog.catalog_id);
+//!         println!("Catalog name: {}", object.commerce_catalog.catalog_name);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/commerce-catalog.js` content
+//!
+//! ```javascript
+//! window.YTD.commerce_catalog.part0 = [
+//!   {
+//!     "commerceCatalog" : {
+//!       "catalogId" : "2222222222222222222",
+//!       "catalogName" : "Example Shop",
+//!       "catalogType" : "Manual",
+//!       "createdAt" : "2023-01-01T00:00:00.000Z",
+//!       "updatedAt" : "2023-02-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::commerce_catalog::CommerceCatalogObject;
+///
+/// let json = r#"{
+///   "commerceCatalog": {
+///     "catalogId": "2222222222222222222",
+///     "catalogName": "Example Shop",
+///     "catalogType": "Manual",
+///     "createdAt": "2023-01-01T00:00:00.000Z",
+///     "updatedAt": "2023-02-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: CommerceCatalogObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.commerce_catalog.catalog_id, "2222222222222222222");
+/// assert_eq!(data.commerce_catalog.catalog_name, "Example Shop");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommerceCatalogObject {
+	/// Why they wrapped a list of catalogs within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "commerceCatalog": {
+	///     "catalogId": "2222222222222222222",
+	///     "catalogName": "Example Shop",
+	///     "catalogType": "Manual",
+	///     "createdAt": "2023-01-01T00:00:00.000Z",
+	///     "updatedAt": "2023-02-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub commerce_catalog: CommerceCatalog,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::commerce_catalog::CommerceCatalog;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let updated_at_string = "2023-02-01T00:00:00.000Z";
+/// let updated_at_native_time = NaiveDateTime::parse_from_str(&updated_at_string, FORMAT).unwrap();
+/// let updated_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(updated_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "catalogId": "2222222222222222222",
+///   "catalogName": "Example Shop",
+///   "createdAt": "{created_at_string}",
+///   "updatedAt": "{updated_at_string}"
+/// }}"#);
+///
+/// let data: CommerceCatalog = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.catalog_id, "2222222222222222222");
+/// assert_eq!(data.catalog_name, "Example Shop");
+/// assert!(data.catalog_type.is_none());
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert_eq!(data.updated_at, updated_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommerceCatalog {
+	/// Unique ID of catalog, same as `catalog_item[].catalogItem.catalogId`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogId": "2222222222222222222" }
+	/// ```
+	pub catalog_id: String,
+
+	/// Name of catalog as shown to shoppers
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogName": "Example Shop" }
+	/// ```
+	pub catalog_name: String,
+
+	/// How catalog is populated, such as `Manual` or by feed from `Shopify`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogType": "Manual" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub catalog_type: Option<String>,
+
+	/// Date time-stamp of when catalog was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when catalog was last changed
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "updatedAt": "2023-02-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub updated_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<CommerceCatalogObject> {
+	const GLOBAL_NAME: &'static str = "YTD.commerce_catalog";
+}
@@ -0,0 +1,280 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have public community notes, authored by account, found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/community-note.js
+//!
+//! Ratings given to notes of others are found within `data/community-note-rating.js`, check
+//! `structs::community_note_rating` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/community-note.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.community_note().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each community note */
+//!         println!("Community note index: {index}");
+//!         println!("Note ID: {}", object.community_note.note_id);
+//!         println!("Tweet ID: {}", object.community_note.tweet_id);
+//!         println!("Classification: {}", object.community_note.classification);
+//!         println!("Summary: {}", object.community_note.summary);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/community-note.js` content
+//!
+//! ```javascript
+//! window.YTD.community_note.part0 = [
+//!   {
+//!     "communityNote" : {
+//!       "noteId" : "9999999999999999999",
+//!       "tweetId" : "1111111111111111111",
+//!       "summary" : "Image is from 2019, not this week https://example.com/",
+//!       "classification" : "MisinformedOrPotentiallyMisleading",
+//!       "misleadingTags" : [
+//!         "OutdatedInformation",
+//!         "MissingImportantContext"
+//!       ],
+//!       "trustworthySources" : true,
+//!       "createdAt" : "2023-01-20T21:42:09.068Z",
+//!       "userId" : "111111111"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// use twitter_archive::structs::community_note::CommunityNoteObject;
+///
+/// let created_at_string = "2023-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "communityNote": {{
+///     "noteId": "9999999999999999999",
+///     "tweetId": "1111111111111111111",
+///     "summary": "Image is from 2019, not this week https://example.com/",
+///     "classification": "MisinformedOrPotentiallyMisleading",
+///     "misleadingTags": [
+///       "OutdatedInformation",
+///       "MissingImportantContext"
+///     ],
+///     "trustworthySources": true,
+///     "createdAt": "{created_at_string}",
+///     "userId": "111111111"
+///   }}
+/// }}"#);
+///
+/// let data: CommunityNoteObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.community_note.note_id, "9999999999999999999");
+/// assert_eq!(data.community_note.tweet_id, "1111111111111111111");
+/// assert_eq!(data.community_note.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityNoteObject {
+	/// Why they wrapped a list of notes within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "communityNote": {
+	///     "noteId": "9999999999999999999",
+	///     "tweetId": "1111111111111111111",
+	///     "summary": "Image is from 2019, not this week https://example.com/",
+	///     "classification": "MisinformedOrPotentiallyMisleading",
+	///     "misleadingTags": [
+	///       "OutdatedInformation",
+	///       "MissingImportantContext"
+	///     ],
+	///     "trustworthySources": true,
+	///     "createdAt": "2023-01-20T21:42:09.068Z",
+	///     "userId": "111111111"
+	///   }
+	/// }
+	/// ```
+	pub community_note: CommunityNote,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// use twitter_archive::structs::community_note::CommunityNote;
+///
+/// let created_at_string = "2023-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "noteId": "9999999999999999999",
+///   "tweetId": "1111111111111111111",
+///   "summary": "Photo is satire, as stated by https://example.com/",
+///   "classification": "NotMisleading",
+///   "notMisleadingTags": [
+///     "ClearlySatire"
+///   ],
+///   "trustworthySources": false,
+///   "createdAt": "{created_at_string}",
+///   "userId": "111111111"
+/// }}"#);
+///
+/// let data: CommunityNote = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_id, "9999999999999999999");
+/// assert_eq!(data.tweet_id, "1111111111111111111");
+/// assert_eq!(data.summary, "Photo is satire, as stated by https://example.com/");
+/// assert_eq!(data.classification, "NotMisleading");
+/// assert!(data.misleading_tags.is_none());
+/// assert_eq!(data.not_misleading_tags, Some(vec!["ClearlySatire".to_string()]));
+/// assert_eq!(data.trustworthy_sources, false);
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert_eq!(data.user_id, "111111111");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityNote {
+	/// Unique ID of note, same as `community_note_rating[].communityNoteRating.noteId`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "noteId": "9999999999999999999" }
+	/// ```
+	pub note_id: String,
+
+	/// ID of Tweet note was written about
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "tweetId": "1111111111111111111" }
+	/// ```
+	pub tweet_id: String,
+
+	/// Text of note
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "summary": "Image is from 2019, not this week https://example.com/" }
+	/// ```
+	pub summary: String,
+
+	/// Answer to "is this Tweet misleading" classification question
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "classification": "MisinformedOrPotentiallyMisleading" }
+	/// ```
+	pub classification: String,
+
+	/// Reasons Tweet was classified misleading
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "misleadingTags": [
+	///     "OutdatedInformation",
+	///     "MissingImportantContext"
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub misleading_tags: Option<Vec<String>>,
+
+	/// Reasons Tweet was classified not misleading
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "notMisleadingTags": [
+	///     "ClearlySatire"
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub not_misleading_tags: Option<Vec<String>>,
+
+	/// Answer to "did you link to sources you believe most people would consider trustworthy"
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "trustworthySources": true }
+	/// ```
+	pub trustworthy_sources: bool,
+
+	/// Date time-stamp of when note was written
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// ID of note author
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{user_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "userId": "111111111" }
+	/// ```
+	pub user_id: String,
+}
+
+impl FromJsStr for Vec<CommunityNoteObject> {
+	const GLOBAL_NAME: &'static str = "YTD.community_note";
+}
@@ -61,6 +61,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -214,3 +215,7 @@ pub struct CommunityNoteRating {
 	/// ```
 	pub user_id: String,
 }
+
+impl FromJsStr for Vec<CommunityNoteRatingObject> {
+	const GLOBAL_NAME: &'static str = "YTD.community_note_rating";
+}
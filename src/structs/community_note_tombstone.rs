@@ -0,0 +1,198 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have public community note tombstones, left behind by notes
+//! account deleted, found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/community-note-tombstone.js
+//!
+//! Notes that still exist are found within `data/community-note.js`, check
+//! `structs::community_note` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/community-note-tombstone.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.community_note_tombstone().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each tombstone */
+//!         println!("Community note tombstone index: {index}");
+//!         println!("Note ID: {}", object.community_note_tombstone.note_id);
+//!         println!("Deleted at: {}", object.community_note_tombstone.deleted_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/community-note-tombstone.js` content
+//!
+//! ```javascript
+//! window.YTD.community_note_tombstone.part0 = [
+//!   {
+//!     "communityNoteTombstone" : {
+//!       "noteId" : "9999999999999999999",
+//!       "tweetId" : "1111111111111111111",
+//!       "createdAt" : "2023-01-20T21:42:09.068Z",
+//!       "deletedAt" : "2023-01-21T08:00:00.000Z",
+//!       "userId" : "111111111"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::community_note_tombstone::CommunityNoteTombstoneObject;
+///
+/// let json = r#"{
+///   "communityNoteTombstone": {
+///     "noteId": "9999999999999999999",
+///     "tweetId": "1111111111111111111",
+///     "createdAt": "2023-01-20T21:42:09.068Z",
+///     "deletedAt": "2023-01-21T08:00:00.000Z",
+///     "userId": "111111111"
+///   }
+/// }"#;
+///
+/// let data: CommunityNoteTombstoneObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.community_note_tombstone.note_id, "9999999999999999999");
+/// assert!(data.community_note_tombstone.deleted_at > data.community_note_tombstone.created_at);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityNoteTombstoneObject {
+	/// Why they wrapped a list of tombstones within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "communityNoteTombstone": {
+	///     "noteId": "9999999999999999999",
+	///     "tweetId": "1111111111111111111",
+	///     "createdAt": "2023-01-20T21:42:09.068Z",
+	///     "deletedAt": "2023-01-21T08:00:00.000Z",
+	///     "userId": "111111111"
+	///   }
+	/// }
+	/// ```
+	pub community_note_tombstone: CommunityNoteTombstone,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// use twitter_archive::structs::community_note_tombstone::CommunityNoteTombstone;
+///
+/// let created_at_string = "2023-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let deleted_at_string = "2023-01-21T08:00:00.000Z";
+/// let deleted_at_native_time = NaiveDateTime::parse_from_str(&deleted_at_string, FORMAT).unwrap();
+/// let deleted_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(deleted_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "noteId": "9999999999999999999",
+///   "createdAt": "{created_at_string}",
+///   "deletedAt": "{deleted_at_string}",
+///   "userId": "111111111"
+/// }}"#);
+///
+/// let data: CommunityNoteTombstone = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_id, "9999999999999999999");
+/// assert!(data.tweet_id.is_none());
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert_eq!(data.deleted_at, deleted_at_date_time);
+/// assert_eq!(data.user_id, "111111111");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityNoteTombstone {
+	/// Unique ID of deleted note
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "noteId": "9999999999999999999" }
+	/// ```
+	pub note_id: String,
+
+	/// ID of Tweet note was written about, absent within some archives
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "tweetId": "1111111111111111111" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tweet_id: Option<String>,
+
+	/// Date time-stamp of when note was written
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when note was deleted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "deletedAt": "2023-01-21T08:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub deleted_at: DateTime<Utc>,
+
+	/// ID of note author
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{user_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "userId": "111111111" }
+	/// ```
+	pub user_id: String,
+}
+
+impl FromJsStr for Vec<CommunityNoteTombstoneObject> {
+	const GLOBAL_NAME: &'static str = "YTD.community_note_tombstone";
+}
@@ -0,0 +1,316 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have Tweets posted within Communities found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/community-tweet.js
+//!
+//! Each entry is a regular `tweets::Tweet` plus community specific properties.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/community-tweet.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.community_tweet().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each community Tweet */
+//!         println!("Index: {index}");
+//!         println!("Community ID: {}", object.tweet.community_id);
+//!         println!("Created at: {}", object.tweet.tweet.created_at);
+//!         println!("vvv Content\n{}\n^^^ Content", object.tweet.tweet.full_text);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/community-tweet.js` content
+//!
+//! ```javascript
+//! window.YTD.community_tweet.part0 = [
+//!   {
+//!     "tweet" : {
+//!       "edit_info" : {
+//!         "initial" : {
+//!           "editTweetIds" : [
+//!             "1111111111111111111"
+//!           ],
+//!           "editableUntil" : "2023-08-12T17:10:37.000Z",
+//!           "editsRemaining" : "5",
+//!           "isEditEligible" : true
+//!         }
+//!       },
+//!       "retweeted" : false,
+//!       "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities" : {
+//!         "hashtags" : [ ],
+//!         "symbols" : [ ],
+//!         "user_mentions" : [ ],
+//!         "urls" : [ ]
+//!       },
+//!       "display_text_range" : [
+//!         "0",
+//!         "22"
+//!       ],
+//!       "favorite_count" : "0",
+//!       "id_str" : "1111111111111111111",
+//!       "truncated" : false,
+//!       "retweet_count" : "0",
+//!       "id" : "1111111111111111111",
+//!       "created_at" : "Sat Aug 12 16:10:37 +0000 2023",
+//!       "favorited" : false,
+//!       "full_text" : "Hello fellow Rustaceans",
+//!       "lang" : "en",
+//!       "community_id" : "2222222222222222222",
+//!       "community_id_str" : "2222222222222222222",
+//!       "scopes" : {
+//!         "followers" : false
+//!       }
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+use crate::structs::tweets;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::community_tweet::CommunityTweetObject;
+///
+/// let json = r#"{
+///   "tweet": {
+///     "edit_info": {
+///       "initial": {
+///         "editTweetIds": [
+///           "1111111111111111111"
+///         ],
+///         "editableUntil": "2023-08-12T17:10:37.000Z",
+///         "editsRemaining": "5",
+///         "isEditEligible": true
+///       }
+///     },
+///     "retweeted": false,
+///     "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///     "entities": {
+///       "hashtags": [],
+///       "symbols": [],
+///       "user_mentions": [],
+///       "urls": []
+///     },
+///     "display_text_range": [
+///       "0",
+///       "22"
+///     ],
+///     "favorite_count": "0",
+///     "id_str": "1111111111111111111",
+///     "truncated": false,
+///     "retweet_count": "0",
+///     "id": "1111111111111111111",
+///     "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///     "favorited": false,
+///     "full_text": "Hello fellow Rustaceans",
+///     "lang": "en",
+///     "community_id": "2222222222222222222",
+///     "community_id_str": "2222222222222222222",
+///     "scopes": {
+///       "followers": false
+///     }
+///   }
+/// }"#;
+///
+/// let data: CommunityTweetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.tweet.tweet.id, "1111111111111111111");
+/// assert_eq!(data.tweet.tweet.full_text, "Hello fellow Rustaceans");
+/// assert_eq!(data.tweet.community_id, "2222222222222222222");
+/// assert_eq!(data.tweet.scopes.as_ref().unwrap().followers, false);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct CommunityTweetObject {
+	/// Why they wrapped a list of Tweets within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "tweet": {
+	///     "edit_info": {
+	///       "initial": {
+	///         "editTweetIds": ["1111111111111111111"],
+	///         "editableUntil": "2023-08-12T17:10:37.000Z",
+	///         "editsRemaining": "5",
+	///         "isEditEligible": true
+	///       }
+	///     },
+	///     "retweeted": false,
+	///     "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///     "entities": {
+	///       "hashtags": [],
+	///       "symbols": [],
+	///       "user_mentions": [],
+	///       "urls": []
+	///     },
+	///     "display_text_range": ["0", "22"],
+	///     "favorite_count": "0",
+	///     "id_str": "1111111111111111111",
+	///     "truncated": false,
+	///     "retweet_count": "0",
+	///     "id": "1111111111111111111",
+	///     "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///     "favorited": false,
+	///     "full_text": "Hello fellow Rustaceans",
+	///     "lang": "en",
+	///     "community_id": "2222222222222222222",
+	///     "community_id_str": "2222222222222222222",
+	///     "scopes": {
+	///       "followers": false
+	///     }
+	///   }
+	/// }
+	/// ```
+	pub tweet: CommunityTweet,
+}
+
+/// Regular Tweet properties, flattened from `tweets::Tweet`, followed by community specific ones
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::community_tweet::CommunityTweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [
+///         "1111111111111111111"
+///       ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [
+///     "0",
+///     "22"
+///   ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "Hello fellow Rustaceans",
+///   "lang": "en",
+///   "community_id": "2222222222222222222",
+///   "community_id_str": "2222222222222222222"
+/// }"#;
+///
+/// let data: CommunityTweet = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.tweet.display_text_range, [0, 22]);
+/// assert_eq!(data.community_id, "2222222222222222222");
+/// assert_eq!(data.community_id_str, "2222222222222222222");
+/// assert!(data.scopes.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct CommunityTweet {
+	/// Properties shared with every other Tweet, check `tweets::Tweet` for details
+	#[serde(flatten)]
+	pub tweet: tweets::Tweet,
+
+	/// ID of Community Tweet was posted within
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/communities/{community_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "community_id": "2222222222222222222" }
+	/// ```
+	pub community_id: String,
+
+	/// Seems to always equal `community_id`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "community_id_str": "2222222222222222222" }
+	/// ```
+	pub community_id_str: String,
+
+	/// Who beyond Community members may see Tweet
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "scopes": {
+	///     "followers": false
+	///   }
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub scopes: Option<CommunityTweetScopes>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::community_tweet::CommunityTweetScopes;
+///
+/// let json = r#"{
+///   "followers": true
+/// }"#;
+///
+/// let data: CommunityTweetScopes = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.followers, true);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct CommunityTweetScopes {
+	/// Is or is not also shown to followers of author who are not Community members
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "followers": false }
+	/// ```
+	pub followers: bool,
+}
+
+impl FromJsStr for Vec<CommunityTweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.community_tweet";
+}
@@ -71,6 +71,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -342,3 +343,7 @@ pub struct Organization {
 	/// ```
 	pub terms_and_conditions_url: String,
 }
+
+impl FromJsStr for Vec<ConnectedApplicationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.connected_application";
+}
@@ -0,0 +1,171 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private uploaded address book data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/contact.js
+//!
+//! > Note; entries describe people other than account owner, so take care when sharing
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/contact.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.contact().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each contact */
+//!         println!("Contact index: {index}");
+//!         println!("ID: {}", object.contact.id);
+//!         println!("Emails: {:?}", object.contact.emails);
+//!         println!("Phone numbers: {:?}", object.contact.phone_numbers);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/contact.js` content
+//!
+//! ```javascript
+//! window.YTD.contact.part0 = [
+//!   {
+//!     "contact" : {
+//!       "id" : "1111111111111111111",
+//!       "emails" : [
+//!         "friend@example.com"
+//!       ],
+//!       "phoneNumbers" : [
+//!         "+15555555555"
+//!       ]
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::contact::ContactObject;
+///
+/// let json = r#"{
+///   "contact": {
+///     "id": "1111111111111111111",
+///     "emails": [
+///       "friend@example.com"
+///     ],
+///     "phoneNumbers": [
+///       "+15555555555"
+///     ]
+///   }
+/// }"#;
+///
+/// let data: ContactObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.contact.id, "1111111111111111111");
+/// assert_eq!(data.contact.emails, vec!["friend@example.com"]);
+/// assert_eq!(data.contact.phone_numbers, vec!["+15555555555"]);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ContactObject {
+	/// Why they wrapped a list of contacts within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "contact": {
+	///     "id": "1111111111111111111",
+	///     "emails": [
+	///       "friend@example.com"
+	///     ],
+	///     "phoneNumbers": [
+	///       "+15555555555"
+	///     ]
+	///   }
+	/// }
+	/// ```
+	pub contact: Contact,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::contact::Contact;
+///
+/// let json = r#"{
+///   "id": "1111111111111111111",
+///   "emails": [],
+///   "phoneNumbers": [
+///     "+15555555555",
+///     "+15555555556"
+///   ]
+/// }"#;
+///
+/// let data: Contact = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "1111111111111111111");
+/// assert!(data.emails.is_empty());
+/// assert_eq!(data.phone_numbers.len(), 2);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+	/// Unique ID Twitter assigned to uploaded contact, **not** an account ID
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1111111111111111111" }
+	/// ```
+	pub id: String,
+
+	/// E-mail addresses of contact
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "emails": [
+	///     "friend@example.com"
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub emails: Vec<String>,
+
+	/// Phone numbers of contact
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "phoneNumbers": [
+	///     "+15555555555"
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub phone_numbers: Vec<String>,
+}
+
+impl FromJsStr for Vec<ContactObject> {
+	const GLOBAL_NAME: &'static str = "YTD.contact";
+}
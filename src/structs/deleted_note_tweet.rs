@@ -0,0 +1,210 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2024-01-01 have private deleted long-form post (Notes) data found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/deleted-note-tweet.js
+//!
+//! Each entry is a `note_tweet::NoteTweet`, check `structs::note_tweet` for details, plus when
+//! Note was deleted.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/deleted-note-tweet.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.deleted_note_tweet().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each deleted Note */
+//!         println!("Deleted Note index: {index}");
+//!         println!("Deleted at: {:?}", object.note_tweet.deleted_at);
+//!         println!("vvv Content\n{}\n^^^ Content", object.note_tweet.note_tweet.core.text);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/deleted-note-tweet.js` content
+//!
+//! ```javascript
+//! window.YTD.deleted_note_tweet.part0 = [
+//!   {
+//!     "noteTweet" : {
+//!       "noteTweetId" : "1111111111111111111",
+//!       "updatedAt" : "2023-06-01T12:34:56.000Z",
+//!       "lifecycle" : {
+//!         "value" : "3",
+//!         "name" : "Delete",
+//!         "originalName" : "Delete",
+//!         "annotations" : { }
+//!       },
+//!       "createdAt" : "2023-06-01T12:34:56.000Z",
+//!       "core" : {
+//!         "styletags" : [ ],
+//!         "urls" : [ ],
+//!         "text" : "Regrettable words that did not fit within a Tweet",
+//!         "mentions" : [ ],
+//!         "cashtags" : [ ],
+//!         "hashtags" : [ ]
+//!       },
+//!       "deletedAt" : "2023-06-02T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::note_tweet;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::deleted_note_tweet::DeletedNoteTweetObject;
+///
+/// let json = r#"{
+///   "noteTweet": {
+///     "noteTweetId": "1111111111111111111",
+///     "updatedAt": "2023-06-01T12:34:56.000Z",
+///     "lifecycle": {
+///       "value": "3",
+///       "name": "Delete",
+///       "originalName": "Delete",
+///       "annotations": {}
+///     },
+///     "createdAt": "2023-06-01T12:34:56.000Z",
+///     "core": {
+///       "styletags": [],
+///       "urls": [],
+///       "text": "Regrettable words that did not fit within a Tweet",
+///       "mentions": [],
+///       "cashtags": [],
+///       "hashtags": []
+///     },
+///     "deletedAt": "2023-06-02T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: DeletedNoteTweetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_tweet.note_tweet.note_tweet_id, "1111111111111111111");
+/// assert_eq!(data.note_tweet.note_tweet.lifecycle.name, "Delete");
+/// assert!(data.note_tweet.deleted_at.is_some());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedNoteTweetObject {
+	/// Why they wrapped a list of deleted Notes within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "noteTweet": {
+	///     "noteTweetId": "1111111111111111111",
+	///     "updatedAt": "2023-06-01T12:34:56.000Z",
+	///     "lifecycle": {
+	///       "value": "3",
+	///       "name": "Delete",
+	///       "originalName": "Delete",
+	///       "annotations": {}
+	///     },
+	///     "createdAt": "2023-06-01T12:34:56.000Z",
+	///     "core": {
+	///       "styletags": [],
+	///       "urls": [],
+	///       "text": "Regrettable words that did not fit within a Tweet",
+	///       "mentions": [],
+	///       "cashtags": [],
+	///       "hashtags": []
+	///     },
+	///     "deletedAt": "2023-06-02T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub note_tweet: DeletedNoteTweet,
+}
+
+/// Regular Note properties, flattened from `note_tweet::NoteTweet`, followed by deletion
+/// time-stamp
+///
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::deleted_note_tweet::DeletedNoteTweet;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let deleted_at_string = "2023-06-02T00:00:00.000Z";
+/// let deleted_at_native_time = NaiveDateTime::parse_from_str(&deleted_at_string, FORMAT).unwrap();
+/// let deleted_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(deleted_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "noteTweetId": "1111111111111111111",
+///   "updatedAt": "2023-06-01T12:34:56.000Z",
+///   "lifecycle": {{
+///     "value": "3",
+///     "name": "Delete",
+///     "originalName": "Delete",
+///     "annotations": {{}}
+///   }},
+///   "createdAt": "2023-06-01T12:34:56.000Z",
+///   "core": {{
+///     "styletags": [],
+///     "urls": [],
+///     "text": "Regrettable words that did not fit within a Tweet",
+///     "mentions": [],
+///     "cashtags": [],
+///     "hashtags": []
+///   }},
+///   "deletedAt": "{deleted_at_string}"
+/// }}"#);
+///
+/// let data: DeletedNoteTweet = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_tweet.core.text, "Regrettable words that did not fit within a Tweet");
+/// assert_eq!(data.deleted_at, Some(deleted_at_date_time));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedNoteTweet {
+	/// Properties shared with every other Note, check `note_tweet::NoteTweet` for details
+	#[serde(flatten)]
+	pub note_tweet: note_tweet::NoteTweet,
+
+	/// Date time-stamp of when Note was deleted, absent within some archives
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "deletedAt": "2023-06-02T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl FromJsStr for Vec<DeletedNoteTweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.deleted_note_tweet";
+}
@@ -65,6 +65,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -210,3 +211,15 @@ pub struct Tweet {
 	#[serde(with = "convert::created_at")]
 	pub deleted_at: DateTime<Utc>,
 }
+
+impl Tweet {
+	/// Creation date time-stamp embedded within `tweet_id`, check `convert::snowflake` for
+	/// details
+	pub fn tweet_id_timestamp(&self) -> Option<DateTime<Utc>> {
+		convert::snowflake::timestamp_from_str(&self.tweet_id)
+	}
+}
+
+impl FromJsStr for Vec<TweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.deleted_tweet_headers";
+}
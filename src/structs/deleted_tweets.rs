@@ -0,0 +1,339 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have public deleted_tweets found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/deleted-tweets.js
+//!
+//! Entries are shaped like those of `data/tweets.js`, plus a `deleted_at` time-stamp that some
+//! archives leave out.  When missing, it may be filled in from `data/deleted-tweet-headers.js`
+//! via `deleted_tweets::join_headers`.
+//!
+//! ## Example file reader
+//!
+//! ```no_build
+//! use std::io::Read;
+//! use std::{fs, path};
+//! use zip::read::ZipArchive;
+//!
+//! use twitter_archive::structs::deleted_tweets;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let file_descriptor = fs::File::open(input_file).expect("Unable to read --input-file");
+//!     let mut zip_archive = ZipArchive::new(file_descriptor).unwrap();
+//!     let mut zip_file = zip_archive.by_name("data/deleted-tweets.js").unwrap();
+//!     let mut buff = String::new();
+//!     zip_file.read_to_string(&mut buff).unwrap();
+//!
+//!     let json = buff.replacen("window.YTD.deleted_tweets.part0 = ", "", 1);
+//!     let data: Vec<deleted_tweets::DeletedTweetObject> = serde_json::from_str(&json).expect("Unable to parse");
+//!
+//!     for (index, object) in data.iter().enumerate() {
+//!         /* Do stuff with each deleted Tweet */
+//!         println!("Deleted Tweet index: {index}");
+//!         println!("Tweet ID: {}", object.tweet.tweet.id_str);
+//!         println!("Created at: {}", object.tweet.tweet.created_at);
+//!         println!("Deleted at: {:?}", object.tweet.deleted_at);
+//!     }
+//!     assert_eq!(true, false);
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/deleted-tweets.js` content
+//!
+//! ```javascript
+//! window.YTD.deleted_tweets.part0 = [
+//!   {
+//!     "tweet" : {
+//!       "edit_info" : {
+//!         "initial" : {
+//!           "editTweetIds" : [
+//!             "1697011324369178968"
+//!           ],
+//!           "editableUntil" : "2023-08-30T23:20:03.000Z",
+//!           "editsRemaining" : "5",
+//!           "isEditEligible" : true
+//!         }
+//!       },
+//!       "retweeted" : false,
+//!       "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities" : {
+//!         "hashtags" : [ ],
+//!         "symbols" : [ ],
+//!         "user_mentions" : [ ],
+//!         "urls" : [ ]
+//!       },
+//!       "display_text_range" : [
+//!         "0",
+//!         "6"
+//!       ],
+//!       "favorite_count" : "0",
+//!       "id_str" : "1697011324369178968",
+//!       "truncated" : false,
+//!       "retweet_count" : "0",
+//!       "id" : "1697011324369178968",
+//!       "created_at" : "Wed Aug 30 22:20:03 +0000 2023",
+//!       "favorited" : false,
+//!       "full_text" : "Oops!",
+//!       "lang" : "en",
+//!       "deleted_at" : "Wed Aug 30 23:20:03 +0000 2023"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::{deleted_tweet_headers, tweets};
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::deleted_tweets::DeletedTweetObject;
+///
+/// let json = r#"{
+///   "tweet": {
+///     "edit_info": {
+///       "initial": {
+///         "editTweetIds": [
+///           "1697011324369178968"
+///         ],
+///         "editableUntil": "2023-08-30T23:20:03.000Z",
+///         "editsRemaining": "5",
+///         "isEditEligible": true
+///       }
+///     },
+///     "retweeted": false,
+///     "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///     "entities": {
+///       "hashtags": [],
+///       "symbols": [],
+///       "user_mentions": [],
+///       "urls": []
+///     },
+///     "display_text_range": [
+///       "0",
+///       "5"
+///     ],
+///     "favorite_count": "0",
+///     "id_str": "1697011324369178968",
+///     "truncated": false,
+///     "retweet_count": "0",
+///     "id": "1697011324369178968",
+///     "created_at": "Wed Aug 30 22:20:03 +0000 2023",
+///     "favorited": false,
+///     "full_text": "Oops!",
+///     "lang": "en",
+///     "deleted_at": "Wed Aug 30 23:20:03 +0000 2023"
+///   }
+/// }"#;
+///
+/// let data: DeletedTweetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.tweet.tweet.id_str, "1697011324369178968");
+/// assert_eq!(data.tweet.tweet.full_text, "Oops!");
+/// assert_eq!(
+///     data.tweet.deleted_at.unwrap().format("%F %T").to_string(),
+///     "2023-08-30 23:20:03"
+/// );
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct DeletedTweetObject {
+	/// Why they wrapped a list of Tweets within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "tweet": {
+	///     "id_str": "1697011324369178968",
+	///     "created_at": "Wed Aug 30 22:20:03 +0000 2023",
+	///     "full_text": "Oops!",
+	///     "deleted_at": "Wed Aug 30 23:20:03 +0000 2023"
+	///   }
+	/// }
+	/// ```
+	pub tweet: DeletedTweet,
+}
+
+/// Same properties as `tweets::Tweet`, flattened, plus time-stamp of deletion
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::deleted_tweets::DeletedTweet;
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [
+///         "1697011324369178968"
+///       ],
+///       "editableUntil": "2023-08-30T23:20:03.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [],
+///     "symbols": [],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [
+///     "0",
+///     "5"
+///   ],
+///   "favorite_count": "0",
+///   "id_str": "1697011324369178968",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1697011324369178968",
+///   "created_at": "Wed Aug 30 22:20:03 +0000 2023",
+///   "favorited": false,
+///   "full_text": "Oops!",
+///   "lang": "en"
+/// }"#;
+///
+/// let data: DeletedTweet = serde_json::from_str(&json).unwrap();
+///
+/// // Archives without `deleted_at` still parse
+/// assert!(data.deleted_at.is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct DeletedTweet {
+	/// Properties shared with `data/tweets.js` entries, check `tweets::Tweet` for details
+	#[serde(flatten)]
+	pub tweet: tweets::Tweet,
+
+	/// Date time-stamp of when Tweet was deleted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "deleted_at": "Wed Aug 30 23:20:03 +0000 2023" }
+	/// ```
+	#[serde(
+		default,
+		with = "convert::optional_created_at",
+		skip_serializing_if = "Option::is_none"
+	)]
+	pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl AsRef<tweets::Tweet> for DeletedTweet {
+	fn as_ref(&self) -> &tweets::Tweet {
+		&self.tweet
+	}
+}
+
+impl AsRef<tweets::Tweet> for DeletedTweetObject {
+	fn as_ref(&self) -> &tweets::Tweet {
+		&self.tweet.tweet
+	}
+}
+
+/// Fill in missing `deleted_at` of `tweets` from `headers` with matching Tweet ID, returning how
+/// many Tweets have `deleted_at` afterwards
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::deleted_tweet_headers;
+/// use twitter_archive::structs::deleted_tweets::{self, DeletedTweetObject};
+///
+/// let tweets_json = r#"[
+///   {
+///     "tweet": {
+///       "edit_info": {
+///         "initial": {
+///           "editTweetIds": [ "1697011324369178968" ],
+///           "editableUntil": "2023-08-30T23:20:03.000Z",
+///           "editsRemaining": "5",
+///           "isEditEligible": true
+///         }
+///       },
+///       "retweeted": false,
+///       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///       "entities": {
+///         "hashtags": [],
+///         "symbols": [],
+///         "user_mentions": [],
+///         "urls": []
+///       },
+///       "display_text_range": [ "0", "5" ],
+///       "favorite_count": "0",
+///       "id_str": "1697011324369178968",
+///       "truncated": false,
+///       "retweet_count": "0",
+///       "id": "1697011324369178968",
+///       "created_at": "Wed Aug 30 22:20:03 +0000 2023",
+///       "favorited": false,
+///       "full_text": "Oops!",
+///       "lang": "en"
+///     }
+///   }
+/// ]"#;
+///
+/// let headers_json = r#"[
+///   {
+///     "tweet": {
+///       "tweet_id": "1697011324369178968",
+///       "user_id": "111111111",
+///       "created_at": "Wed Aug 30 22:20:03 +0000 2023",
+///       "deleted_at": "Wed Aug 30 23:20:03 +0000 2023"
+///     }
+///   }
+/// ]"#;
+///
+/// let mut tweets: Vec<DeletedTweetObject> = serde_json::from_str(&tweets_json).unwrap();
+/// let headers: Vec<deleted_tweet_headers::TweetObject> = serde_json::from_str(&headers_json).unwrap();
+///
+/// assert!(tweets[0].tweet.deleted_at.is_none());
+///
+/// assert_eq!(deleted_tweets::join_headers(&mut tweets, &headers), 1);
+///
+/// assert_eq!(tweets[0].tweet.deleted_at, Some(headers[0].tweet.deleted_at));
+/// ```
+pub fn join_headers(
+	tweets: &mut [DeletedTweetObject],
+	headers: &[deleted_tweet_headers::TweetObject],
+) -> usize {
+	let deleted_at_by_id: HashMap<&str, DateTime<Utc>> = headers
+		.iter()
+		.map(|header| (header.tweet.tweet_id.as_str(), header.tweet.deleted_at))
+		.collect();
+
+	tweets
+		.iter_mut()
+		.filter_map(|object| {
+			let tweet = &mut object.tweet;
+			if tweet.deleted_at.is_none() {
+				tweet.deleted_at = deleted_at_by_id.get(tweet.tweet.id_str.as_str()).copied();
+			}
+			tweet.deleted_at
+		})
+		.count()
+}
+
+impl FromJsStr for Vec<DeletedTweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.deleted_tweets";
+}
@@ -49,6 +49,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -191,3 +192,7 @@ pub struct DeviceToken {
 	/// ```
 	pub client_application_name: String,
 }
+
+impl FromJsStr for Vec<DeviceTokenObject> {
+	const GLOBAL_NAME: &'static str = "YTD.device_token";
+}
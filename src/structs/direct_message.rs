@@ -178,6 +178,198 @@ pub struct JoinConversation {
 	pub created_at: DateTime<Utc>,
 }
 
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::direct_message::ParticipantsJoin;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2020-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "initiatingUserId": "111111111",
+///   "userIds": [
+///     "1234",
+///     "9876"
+///   ],
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ParticipantsJoin = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.initiating_user_id, "111111111");
+/// assert_eq!(data.user_ids, ["1234", "9876"]);
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantsJoin {
+	/// ID of user that added participants to group
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{initiating_user_id}
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "initiatingUserId": "111111111" }
+	/// ```
+	pub initiating_user_id: String,
+
+	/// List of user IDs that were added to group
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "userIds": [
+	///     "1234",
+	///     "9876"
+	///   ]
+	/// }
+	/// ```
+	pub user_ids: Vec<String>,
+
+	/// Date time stamp when listed participants joined
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2020-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::direct_message::ConversationNameUpdate;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2020-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "initiatingUserId": "111111111",
+///   "name": "Fish &amp; chips club",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ConversationNameUpdate = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.initiating_user_id, "111111111");
+/// assert_eq!(data.name, "Fish &amp; chips club");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationNameUpdate {
+	/// ID of user that renamed group
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{initiating_user_id}
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "initiatingUserId": "111111111" }
+	/// ```
+	pub initiating_user_id: String,
+
+	/// New name of group, with HTML entities such as `&amp;` left as is
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "name": "Fish &amp; chips club" }
+	/// ```
+	pub name: String,
+
+	/// Date time stamp when group was renamed
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2020-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::direct_message::MessageDestroy;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2020-01-20T21:42:09.068Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "id": "3333333333333333333",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: MessageDestroy = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "3333333333333333333");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct MessageDestroy {
+	/// ID of message that was deleted, which archive no longer contains
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "3333333333333333333" }
+	/// ```
+	pub id: String,
+
+	/// Date time stamp when message was deleted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2020-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
 /// ## Example
 ///
 /// ```
@@ -251,6 +443,42 @@ pub struct MessageCreateReaction {
 	pub created_at: DateTime<Utc>,
 }
 
+impl MessageCreateReaction {
+	/// Emoji clients display for `reaction_key`, or `None` for keys not known to this crate
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::direct_message::MessageCreateReaction;
+	///
+	/// let json = r#"{
+	///   "senderId": "222222222",
+	///   "reactionKey": "excited",
+	///   "eventId": "1020304050607080901",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }"#;
+	///
+	/// let mut data: MessageCreateReaction = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.emoji(), Some("🔥"));
+	///
+	/// data.reaction_key = "unheard-of".to_string();
+	/// assert_eq!(data.emoji(), None);
+	/// ```
+	pub fn emoji(&self) -> Option<&'static str> {
+		match self.reaction_key.as_str() {
+			"like" => Some("❤️"),
+			"funny" => Some("😂"),
+			"surprised" => Some("😲"),
+			"sad" => Some("😢"),
+			"excited" => Some("🔥"),
+			"agree" => Some("👍"),
+			"disagree" => Some("👎"),
+			_ => None,
+		}
+	}
+}
+
 /// ## Example
 ///
 /// ```
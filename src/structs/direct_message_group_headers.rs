@@ -48,6 +48,8 @@
 //!                     println!("Created at: {}", join.created_at);
 //!                     println!("Initiating user ID: {}", join.initiating_user_id);
 //!                 }
+//!
+//!                 _ => {}
 //!             }
 //!         }
 //!     }
@@ -61,11 +63,15 @@
 //! ]
 //! ```
 
+use std::collections::BTreeSet;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::convert;
+use crate::parse::FromJsStr;
 use crate::structs::direct_message;
 
 /// ## Example
@@ -298,6 +304,116 @@ pub struct DmConversation {
 	pub messages: Vec<Message>,
 }
 
+impl DmConversation {
+	/// User IDs taking part in group as of `date`, found by replaying events up to, and including,
+	/// `date` oldest first
+	///
+	/// Each `joinConversation` event resets participants to its `participantsSnapshot`, plus the
+	/// initiating user, after which `participantsJoin` events add users, `participantsLeave`
+	/// events remove them, and senders of messages are assumed to be participants
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use chrono::{TimeZone, Utc};
+	///
+	/// use twitter_archive::structs::direct_message_group_headers::DmConversation;
+	///
+	/// let json = r#"{
+	///   "conversationId": "6666666666666666666",
+	///   "messages": [
+	///     {
+	///       "participantsLeave": {
+	///         "userIds": [ "333333333" ],
+	///         "createdAt": "2023-08-12T18:00:00.000Z"
+	///       }
+	///     },
+	///     {
+	///       "messageCreate": {
+	///         "id": "4444444444444444444",
+	///         "senderId": "555555555",
+	///         "createdAt": "2023-08-12T17:30:00.000Z"
+	///       }
+	///     },
+	///     {
+	///       "participantsJoin": {
+	///         "initiatingUserId": "111111111",
+	///         "userIds": [ "555555555" ],
+	///         "createdAt": "2023-08-12T17:20:00.000Z"
+	///       }
+	///     },
+	///     {
+	///       "joinConversation": {
+	///         "initiatingUserId": "111111111",
+	///         "participantsSnapshot": [ "222222222", "333333333" ],
+	///         "createdAt": "2023-08-12T17:00:00.000Z"
+	///       }
+	///     }
+	///   ]
+	/// }"#;
+	///
+	/// let data: DmConversation = serde_json::from_str(&json).unwrap();
+	///
+	/// let at = |hour, minute| Utc.with_ymd_and_hms(2023, 8, 12, hour, minute, 0).unwrap();
+	///
+	/// assert!(data.participants_at(at(16, 0)).is_empty());
+	/// assert_eq!(
+	///     data.participants_at(at(17, 10)).into_iter().collect::<Vec<_>>(),
+	///     ["111111111", "222222222", "333333333"]
+	/// );
+	/// assert_eq!(
+	///     data.participants_at(at(18, 0)).into_iter().collect::<Vec<_>>(),
+	///     ["111111111", "222222222", "555555555"]
+	/// );
+	/// ```
+	pub fn participants_at(&self, date: DateTime<Utc>) -> BTreeSet<String> {
+		// Archives list events newest first, so reversing keeps ties in order they happened
+		let mut events: Vec<(DateTime<Utc>, &Message)> = self
+			.messages
+			.iter()
+			.rev()
+			.filter_map(|message| {
+				let created_at = match message {
+					Message::MessageCreate(message) => message.created_at,
+					Message::ParticipantsLeave(leave) => leave.created_at,
+					Message::JoinConversation(join) => join.created_at,
+					Message::ParticipantsJoin(join) => join.created_at,
+					Message::ConversationNameUpdate(update) => update.created_at,
+					Message::Other(_) => return None,
+				};
+				Some((created_at, message)).filter(|(created_at, _)| *created_at <= date)
+			})
+			.collect();
+		events.sort_by_key(|(created_at, _)| *created_at);
+
+		let mut participants = BTreeSet::new();
+		for (_, message) in events {
+			match message {
+				Message::MessageCreate(message) => {
+					participants.insert(message.sender_id.clone());
+				}
+				Message::ParticipantsLeave(leave) => {
+					for user_id in &leave.user_ids {
+						participants.remove(user_id);
+					}
+				}
+				Message::JoinConversation(join) => {
+					participants = join.participants_snapshot.iter().cloned().collect();
+					participants.insert(join.initiating_user_id.clone());
+				}
+				Message::ParticipantsJoin(join) => {
+					participants.extend(join.user_ids.iter().cloned());
+				}
+				Message::ConversationNameUpdate(update) => {
+					participants.insert(update.initiating_user_id.clone());
+				}
+				Message::Other(_) => {}
+			}
+		}
+		participants
+	}
+}
+
 /// Because, for reasons, the Twitter devs decided to create a list of messages that contains one
 /// data structure, at the very end, that be not like the others we must leverage a Rust `enum`
 ///
@@ -425,6 +541,16 @@ pub enum Message {
 	/// }
 	/// ```
 	JoinConversation(direct_message::JoinConversation),
+
+	/// Users added to group, check `direct_message::ParticipantsJoin` for example
+	ParticipantsJoin(direct_message::ParticipantsJoin),
+
+	/// Group renamed, check `direct_message::ConversationNameUpdate` for example
+	ConversationNameUpdate(direct_message::ConversationNameUpdate),
+
+	/// Events of kinds not known to this crate, kept as is so re-serializing loses no data
+	#[serde(untagged)]
+	Other(Value),
 }
 
 /// ## Example
@@ -492,3 +618,7 @@ pub struct MessageCreate {
 	#[serde(with = "convert::date_time_iso_8601")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl FromJsStr for Vec<DmConversationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.direct_message_group_headers";
+}
@@ -53,6 +53,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -328,3 +329,7 @@ pub struct MessageCreate {
 	#[serde(with = "convert::date_time_iso_8601")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl FromJsStr for Vec<DmConversationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.direct_message_headers";
+}
@@ -0,0 +1,140 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private muted direct message conversation data found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/direct-message-mute.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/direct-message-mute.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.direct_message_mute().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each muted conversation */
+//!         println!("Muted conversation index: {index}");
+//!         println!("Conversation ID: {}", object.direct_message_mute.conversation_id);
+//!         println!("Muted at: {}", object.direct_message_mute.created_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/direct-message-mute.js` content
+//!
+//! ```javascript
+//! window.YTD.direct_message_mute.part0 = [
+//!   {
+//!     "directMessageMute" : {
+//!       "conversationId" : "111111111-222222222",
+//!       "createdAt" : "2023-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::direct_message_mute::DirectMessageMuteObject;
+///
+/// let json = r#"{
+///   "directMessageMute": {
+///     "conversationId": "111111111-222222222",
+///     "createdAt": "2023-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: DirectMessageMuteObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.direct_message_mute.conversation_id, "111111111-222222222");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct DirectMessageMuteObject {
+	/// Why they wrapped a list of muted conversations within unnecessary object label is anyone's
+	/// guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "directMessageMute": {
+	///     "conversationId": "111111111-222222222",
+	///     "createdAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub direct_message_mute: DirectMessageMute,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::direct_message_mute::DirectMessageMute;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "conversationId": "111111111-222222222",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: DirectMessageMute = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.conversation_id, "111111111-222222222");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct DirectMessageMute {
+	/// ID of muted conversation, same as `direct_messages[].dmConversation.conversationId` or
+	/// `direct_messages_group[].dmConversation.conversationId`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "conversationId": "111111111-222222222" }
+	/// ```
+	pub conversation_id: String,
+
+	/// Date time-stamp of when conversation was muted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<DirectMessageMuteObject> {
+	const GLOBAL_NAME: &'static str = "YTD.direct_message_mute";
+}
@@ -29,7 +29,9 @@
 //!         let messages = &object_conversation.dm_conversation.messages;
 //!         /* Do stuff with each conversation and message */
 //!         for (index_message, object_message) in messages.iter().enumerate() {
-//!             let message = &object_message.message_create;
+//!             let direct_messages::Message::MessageCreate(message) = object_message else {
+//!                 continue;
+//!             };
 //!             println!("{index_conversation} -- {index_message}");
 //!             println!("{} -> {}", message.sender_id, message.recipient_id);
 //!             println!("Created at: {}", message.created_at);
@@ -46,11 +48,15 @@
 //! ]
 //! ```
 
+use std::borrow::Cow;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::convert;
+use crate::parse::FromJsStr;
 use crate::structs::direct_message;
 
 /// ## Example
@@ -203,71 +209,203 @@ pub struct DMConversation {
 	///   ]
 	/// }
 	/// ```
-	pub messages: Vec<MessageCreateObject>,
+	pub messages: Vec<Message>,
 }
 
+impl DMConversation {
+	/// Sent messages, leaving out welcome messages, deleted message markers, and events of
+	/// unknown kinds, which eases migrating from `messages` listing only `MessageCreateObject`
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::direct_messages::DMConversation;
+	///
+	/// let json = r#"{
+	///   "conversationId": "111111111-222222222",
+	///   "messages": [
+	///     {
+	///       "messageCreate": {
+	///         "recipientId": "222222222",
+	///         "reactions": [],
+	///         "urls": [],
+	///         "text": "Salutations!",
+	///         "mediaUrls": [],
+	///         "senderId": "111111111",
+	///         "id": "3333333333333333333",
+	///         "createdAt": "2020-01-20T21:42:09.068Z"
+	///       }
+	///     },
+	///     {
+	///       "messageDestroy": {
+	///         "id": "5555555555555555555",
+	///         "createdAt": "2020-01-20T21:43:09.068Z"
+	///       }
+	///     }
+	///   ]
+	/// }"#;
+	///
+	/// let data: DMConversation = serde_json::from_str(&json).unwrap();
+	///
+	/// let ids: Vec<&str> = data.message_creates().map(|message| message.id.as_str()).collect();
+	/// assert_eq!(ids, vec!["3333333333333333333"]);
+	/// ```
+	pub fn message_creates(&self) -> impl Iterator<Item = &MessageCreate> {
+		self.messages.iter().filter_map(|message| match message {
+			Message::MessageCreate(message_create) => Some(message_create),
+			_ => None,
+		})
+	}
+}
+
+/// Former wrapper of each entry within `DMConversation::messages`, which now lists `Message`
+/// events instead
+///
+/// Match `Message::MessageCreate`, or iterate `DMConversation::message_creates`, to migrate.
+#[deprecated(note = "use `Message::MessageCreate` or `DMConversation::message_creates` instead")]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageCreateObject {
+	/// Sent message
+	pub message_create: MessageCreate,
+}
+
+#[allow(deprecated)]
+impl std::fmt::Display for MessageCreateObject {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", serde_json::to_value(self).unwrap())
+	}
+}
+
+#[allow(deprecated)]
+impl From<MessageCreateObject> for Message {
+	fn from(object: MessageCreateObject) -> Self {
+		Self::MessageCreate(object.message_create)
+	}
+}
+
+/// Like group conversations, one-to-one conversations list events of differing kinds, such as
+/// welcome messages of business accounts and markers of deleted messages, thus a Rust `enum`
+///
 /// ## Example
 ///
 /// ```
 /// use chrono::{DateTime, NaiveDateTime, Utc};
 ///
-/// use twitter_archive::structs::direct_messages::MessageCreateObject;
+/// use twitter_archive::structs::direct_messages::Message;
 /// use twitter_archive::convert::date_time_iso_8601::FORMAT;
 ///
 /// let created_at_string = "2020-01-20T21:42:09.068Z";
 /// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
 /// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
 ///
-/// let json = format!(r#"{{
-///   "messageCreate": {{
-///     "recipientId": "222222222",
-///     "reactions": [],
-///     "urls": [],
-///     "text": "Salutations!",
-///     "mediaUrls": [],
-///     "senderId": "111111111",
-///     "id": "3333333333333333333",
-///     "createdAt": "{created_at_string}"
+/// let json = format!(r#"[
+///   {{
+///     "messageCreate": {{
+///       "recipientId": "222222222",
+///       "reactions": [],
+///       "urls": [],
+///       "text": "Salutations!",
+///       "mediaUrls": [],
+///       "senderId": "111111111",
+///       "id": "3333333333333333333",
+///       "createdAt": "{created_at_string}"
+///     }}
+///   }},
+///   {{
+///     "welcomeMessageCreate": {{
+///       "urls": [],
+///       "text": "Thanks for reaching out!",
+///       "mediaUrls": [],
+///       "senderId": "222222222",
+///       "id": "4444444444444444444",
+///       "createdAt": "{created_at_string}"
+///     }}
+///   }},
+///   {{
+///     "messageDestroy": {{
+///       "id": "5555555555555555555",
+///       "createdAt": "{created_at_string}"
+///     }}
 ///   }}
-/// }}"#);
+/// ]"#);
 ///
-/// let data: MessageCreateObject = serde_json::from_str(&json).unwrap();
+/// let data: Vec<Message> = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.message_create.recipient_id, "222222222");
-/// assert_eq!(data.message_create.reactions.len(), 0);
-/// assert_eq!(data.message_create.urls.len(), 0);
-/// assert_eq!(data.message_create.media_urls.len(), 0);
-/// assert_eq!(data.message_create.sender_id, "111111111");
-/// assert_eq!(data.message_create.id, "3333333333333333333");
-/// assert_eq!(data.message_create.created_at, created_at_date_time);
+/// assert_eq!(data.len(), 3);
+///
+/// if let Message::MessageCreate(message_create) = &data[0] {
+///     assert_eq!(message_create.recipient_id, "222222222");
+///     assert_eq!(message_create.reactions.as_ref().map(Vec::len), Some(0));
+///     assert_eq!(message_create.urls.len(), 0);
+///     assert_eq!(message_create.media_urls.len(), 0);
+///     assert_eq!(message_create.sender_id, "111111111");
+///     assert_eq!(message_create.id, "3333333333333333333");
+///     assert_eq!(message_create.created_at, created_at_date_time);
+/// } else {
+///     panic!("Expected `Message::MessageCreate`");
+/// }
+///
+/// assert!(matches!(&data[1], Message::WelcomeMessageCreate(welcome) if welcome.recipient_id.is_none()));
+/// assert!(matches!(&data[2], Message::MessageDestroy(destroy) if destroy.id == "5555555555555555555"));
 ///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
 #[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
 #[serde(rename_all = "camelCase")]
-pub struct MessageCreateObject {
+pub enum Message {
 	/// Similar to Tweets list the list of messages are wrapped by an additional layer indirection
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// {
-	///   "messageCreate": {
-	///     "recipientId": "222222222",
-	///     "reactions": [],
-	///     "urls": [],
-	///     "text": "Salutations!",
-	///     "mediaUrls": [],
-	///     "senderId": "111111111",
-	///     "id": "3333333333333333333",
-	///     "createdAt": "2020-01-20T21:42:09.068Z"
-	///   }
+	///   "recipientId": "222222222",
+	///   "reactions": [],
+	///   "urls": [],
+	///   "text": "Salutations!",
+	///   "mediaUrls": [],
+	///   "senderId": "111111111",
+	///   "id": "3333333333333333333",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
 	/// }
 	/// ```
-	pub message_create: MessageCreate,
+	MessageCreate(MessageCreate),
+
+	/// Automatic greeting business accounts send to users opening a conversation with them,
+	/// shaped as other messages but lacking `recipientId`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "urls": [],
+	///   "text": "Thanks for reaching out!",
+	///   "mediaUrls": [],
+	///   "senderId": "222222222",
+	///   "id": "4444444444444444444",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }
+	/// ```
+	WelcomeMessageCreate(WelcomeMessageCreate),
+
+	/// Marker left where a message was deleted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "id": "5555555555555555555",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }
+	/// ```
+	MessageDestroy(direct_message::MessageDestroy),
+
+	/// Events of kinds not known to this crate, kept as is so re-serializing loses no data
+	#[serde(untagged)]
+	Other(Value),
 }
 
 /// Note, other than the addition of a `recipient_id` (`recipientId`) key, this is identical to
@@ -300,7 +438,7 @@ pub struct MessageCreateObject {
 ///
 /// // De-serialized properties
 /// assert_eq!(data.recipient_id, "222222222");
-/// assert_eq!(data.reactions.len(), 0);
+/// assert_eq!(data.reactions.as_ref().map(Vec::len), Some(0));
 /// assert_eq!(data.urls.len(), 0);
 /// assert_eq!(data.media_urls.len(), 0);
 /// assert_eq!(data.sender_id, "111111111");
@@ -310,6 +448,31 @@ pub struct MessageCreateObject {
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
+///
+/// ## Example without reactions
+///
+/// Archives predating reactions lack the key, which re-serializing leaves out too
+///
+/// ```
+/// use twitter_archive::structs::direct_messages::MessageCreate;
+///
+/// let json = r#"{
+///   "recipientId": "222222222",
+///   "urls": [],
+///   "text": "Salutations!",
+///   "mediaUrls": [],
+///   "senderId": "111111111",
+///   "id": "3333333333333333333",
+///   "createdAt": "2020-01-20T21:42:09.068Z"
+/// }"#;
+///
+/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+///
+/// assert!(data.reactions.is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
 #[derive(Deserialize, Serialize, Debug, Clone, Display)]
 #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
 #[serde(rename_all = "camelCase")]
@@ -327,14 +490,16 @@ pub struct MessageCreate {
 	/// ```
 	pub recipient_id: String,
 
-	/// List data about who, when, and what reactions were had about a given message
+	/// List data about who, when, and what reactions were had about a given message, or `None`
+	/// for archives predating reactions, which lack the key
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// { "reactions": [] }
 	/// ```
-	pub reactions: Vec<direct_message::MessageCreateReaction>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reactions: Option<Vec<direct_message::MessageCreateReaction>>,
 
 	/// List of mangled/tracking URL data that includes originally written link too
 	///
@@ -397,3 +562,142 @@ pub struct MessageCreate {
 	#[serde(with = "convert::date_time_iso_8601")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl MessageCreate {
+	/// Content of `text` with HTML entities, e.g. `&amp;`, decoded
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::direct_messages::MessageCreate;
+	///
+	/// let json = r#"{
+	///   "recipientId": "222222222",
+	///   "reactions": [],
+	///   "urls": [],
+	///   "text": "Salt &amp; pepper?",
+	///   "mediaUrls": [],
+	///   "senderId": "111111111",
+	///   "id": "3333333333333333333",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }"#;
+	///
+	/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.text_decoded(), "Salt & pepper?");
+	/// ```
+	pub fn text_decoded(&self) -> Cow<'_, str> {
+		convert::html_entities::decode(&self.text)
+	}
+}
+
+/// Automatic greeting sent by business accounts, which unlike `MessageCreate` may lack
+/// `recipientId`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::direct_messages::WelcomeMessageCreate;
+///
+/// let json = r#"{
+///   "urls": [],
+///   "text": "Thanks for reaching out!",
+///   "mediaUrls": [],
+///   "senderId": "222222222",
+///   "id": "4444444444444444444",
+///   "createdAt": "2020-01-20T21:42:09.068Z"
+/// }"#;
+///
+/// let data: WelcomeMessageCreate = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.recipient_id, None);
+/// assert_eq!(data.sender_id, "222222222");
+/// assert_eq!(data.text, "Thanks for reaching out!");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct WelcomeMessageCreate {
+	/// Same as `MessageCreate::recipient_id`, when archive records it
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "recipientId": "111111111" }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub recipient_id: Option<String>,
+
+	/// Same as `MessageCreate::reactions`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "reactions": [] }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reactions: Option<Vec<direct_message::MessageCreateReaction>>,
+
+	/// Same as `MessageCreate::urls`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "urls": [] }
+	/// ```
+	pub urls: Vec<direct_message::MessageCreateUrl>,
+
+	/// Content of greeting
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "text": "Thanks for reaching out!" }
+	/// ```
+	pub text: String,
+
+	/// Same as `MessageCreate::media_urls`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "mediaUrls": [] }
+	/// ```
+	pub media_urls: Vec<String>,
+
+	/// ID of business account sending greeting
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "senderId": "222222222" }
+	/// ```
+	pub sender_id: String,
+
+	/// Same as `MessageCreate::id`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "4444444444444444444" }
+	/// ```
+	pub id: String,
+
+	/// Date time-stamp of when greeting was sent
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2020-01-20T21:42:09.068Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<DmConversationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.direct_messages";
+}
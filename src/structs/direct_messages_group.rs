@@ -48,6 +48,25 @@
 //!                     println!("Created at: {}", join.created_at);
 //!                     println!("Initiating user ID: {}", join.initiating_user_id);
 //!                 }
+//!
+//!                 direct_messages_group::Message::ParticipantsJoin(participants) => {
+//!                     println!("Created at: {}", participants.created_at);
+//!                     println!("Joining user IDs: {:?}", participants.user_ids);
+//!                 }
+//!
+//!                 direct_messages_group::Message::ConversationNameUpdate(update) => {
+//!                     println!("Created at: {}", update.created_at);
+//!                     println!("New name: {}", update.name);
+//!                 }
+//!
+//!                 direct_messages_group::Message::MessageDestroy(destroy) => {
+//!                     println!("Created at: {}", destroy.created_at);
+//!                     println!("Deleted message ID: {}", destroy.id);
+//!                 }
+//!
+//!                 direct_messages_group::Message::Other(event) => {
+//!                     println!("Unknown event: {event}");
+//!                 }
 //!             }
 //!         }
 //!     }
@@ -61,11 +80,15 @@
 //! ]
 //! ```
 
+use std::borrow::Cow;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::convert;
+use crate::parse::FromJsStr;
 use crate::structs::direct_message;
 
 /// ## Example
@@ -396,7 +419,7 @@ pub struct DmConversation {
 /// assert_eq!(data.len(), 4);
 ///
 /// if let Message::MessageCreate(message_create) = &data[0] {
-///     assert_eq!(message_create.reactions.len(), 0);
+///     assert_eq!(message_create.reactions.as_ref().map(Vec::len), Some(0));
 ///     assert_eq!(message_create.urls.len(), 0);
 ///     assert_eq!(message_create.text, "Sup!?");
 ///     assert_eq!(message_create.media_urls.len(), 0);
@@ -406,7 +429,7 @@ pub struct DmConversation {
 /// }
 ///
 /// if let Message::MessageCreate(message_create) = &data.get(1).unwrap() {
-///     assert_eq!(message_create.reactions.len(), 0);
+///     assert_eq!(message_create.reactions.as_ref().map(Vec::len), Some(0));
 ///     assert_eq!(message_create.urls.len(), 0);
 ///     assert_eq!(message_create.text, "Salutations!");
 ///     assert_eq!(message_create.media_urls.len(), 0);
@@ -477,6 +500,94 @@ pub enum Message {
 	/// }
 	/// ```
 	JoinConversation(direct_message::JoinConversation),
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "initiatingUserId": "111111111",
+	///   "userIds": [
+	///     "1234",
+	///     "9876"
+	///   ],
+	///   "createdAt": "2023-08-12T17:10:37.000Z"
+	/// }
+	/// ```
+	ParticipantsJoin(direct_message::ParticipantsJoin),
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "initiatingUserId": "111111111",
+	///   "name": "Fish &amp; chips club",
+	///   "createdAt": "2023-08-12T17:10:37.000Z"
+	/// }
+	/// ```
+	ConversationNameUpdate(direct_message::ConversationNameUpdate),
+
+	/// Marker left where a message was deleted
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "id": "5555555555555555555",
+	///   "createdAt": "2023-08-12T17:10:37.000Z"
+	/// }
+	/// ```
+	MessageDestroy(direct_message::MessageDestroy),
+
+	/// Events of kinds not known to this crate, kept as is so re-serializing loses no data
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::direct_messages_group::Message;
+	///
+	/// let json = r#"[
+	///   {
+	///     "participantsJoin": {
+	///       "initiatingUserId": "111111111",
+	///       "userIds": [
+	///         "1234"
+	///       ],
+	///       "createdAt": "2023-08-12T17:10:37.000Z"
+	///     }
+	///   },
+	///   {
+	///     "conversationNameUpdate": {
+	///       "initiatingUserId": "111111111",
+	///       "name": "Fish &amp; chips club",
+	///       "createdAt": "2023-08-12T17:10:37.000Z"
+	///     }
+	///   },
+	///   {
+	///     "messageDestroy": {
+	///       "id": "5555555555555555555",
+	///       "createdAt": "2023-08-12T17:10:37.000Z"
+	///     }
+	///   },
+	///   {
+	///     "conversationAvatarUpdate": {
+	///       "createdAt": "2023-08-12T17:10:37.000Z",
+	///       "initiatingUserId": "111111111"
+	///     }
+	///   }
+	/// ]"#;
+	///
+	/// let data: Vec<Message> = serde_json::from_str(&json).unwrap();
+	///
+	/// assert!(matches!(&data[0], Message::ParticipantsJoin(join) if join.user_ids == ["1234"]));
+	/// assert!(matches!(&data[1], Message::ConversationNameUpdate(update) if update.name == "Fish &amp; chips club"));
+	/// assert!(matches!(&data[2], Message::MessageDestroy(destroy) if destroy.id == "5555555555555555555"));
+	/// assert!(matches!(&data[3], Message::Other(_)));
+	///
+	/// // Re-serialize is equivalent to original data, with keys of unknown events sorted
+	/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+	/// ```
+	#[serde(untagged)]
+	Other(Value),
 }
 
 /// Note, other than the lack of a `recipient_id` (`recipientId`) key, this is identical to
@@ -507,7 +618,7 @@ pub enum Message {
 /// let data: MessageCreate = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.reactions.len(), 0);
+/// assert_eq!(data.reactions.as_ref().map(Vec::len), Some(0));
 /// assert_eq!(data.urls.len(), 0);
 /// assert_eq!(data.media_urls.len(), 0);
 /// assert_eq!(data.sender_id, "111111111");
@@ -517,18 +628,44 @@ pub enum Message {
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
+///
+/// ## Example without reactions
+///
+/// Archives predating reactions lack the key, which re-serializing leaves out too
+///
+/// ```
+/// use twitter_archive::structs::direct_messages_group::MessageCreate;
+///
+/// let json = r#"{
+///   "urls": [],
+///   "text": "Salutations!",
+///   "mediaUrls": [],
+///   "senderId": "111111111",
+///   "id": "3333333333333333333",
+///   "createdAt": "2020-01-20T21:42:09.068Z"
+/// }"#;
+///
+/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+///
+/// assert!(data.reactions.is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
 #[derive(Deserialize, Serialize, Debug, Clone, Display)]
 #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
 #[serde(rename_all = "camelCase")]
 pub struct MessageCreate {
-	/// List data about who, when, and what reactions were had about a given message
+	/// List data about who, when, and what reactions were had about a given message, or `None`
+	/// for archives predating reactions, which lack the key
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// { "reactions": [] }
 	/// ```
-	pub reactions: Vec<direct_message::MessageCreateReaction>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reactions: Option<Vec<direct_message::MessageCreateReaction>>,
 
 	/// List of mangled/tracking URL data that includes originally written link too
 	///
@@ -591,3 +728,34 @@ pub struct MessageCreate {
 	#[serde(with = "convert::date_time_iso_8601")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl MessageCreate {
+	/// Content of `text` with HTML entities, e.g. `&amp;`, decoded
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::direct_messages_group::MessageCreate;
+	///
+	/// let json = r#"{
+	///   "reactions": [],
+	///   "urls": [],
+	///   "text": "Salt &amp; pepper?",
+	///   "mediaUrls": [],
+	///   "senderId": "111111111",
+	///   "id": "3333333333333333333",
+	///   "createdAt": "2020-01-20T21:42:09.068Z"
+	/// }"#;
+	///
+	/// let data: MessageCreate = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.text_decoded(), "Salt & pepper?");
+	/// ```
+	pub fn text_decoded(&self) -> Cow<'_, str> {
+		convert::html_entities::decode(&self.text)
+	}
+}
+
+impl FromJsStr for Vec<DmConversationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.direct_messages_group";
+}
@@ -56,6 +56,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -211,3 +212,7 @@ pub struct EmailChange {
 	/// ```
 	pub changed_to: String,
 }
+
+impl FromJsStr for Vec<EmailAddressChangeObject> {
+	const GLOBAL_NAME: &'static str = "YTD.email_address_change";
+}
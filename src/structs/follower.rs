@@ -50,6 +50,7 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
 use crate::structs::follow::Follow;
 
 /// ## Example
@@ -88,3 +89,7 @@ pub struct FollowerObject {
 	/// ```
 	pub follower: Follow,
 }
+
+impl FromJsStr for Vec<FollowerObject> {
+	const GLOBAL_NAME: &'static str = "YTD.follower";
+}
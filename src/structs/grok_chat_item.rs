@@ -0,0 +1,184 @@
+#!/usr/bin/env rust
+
+//! Archives exported from X after the Twitter rebrand have private Grok chat history found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/grok-chat-item.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/grok-chat-item.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.grok_chat_item().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each chat message */
+//!         println!("Grok chat item index: {index}");
+//!         println!("Conversation ID: {}", object.grok_chat_item.conversation_id);
+//!         println!("{}: {}", object.grok_chat_item.sender, object.grok_chat_item.message);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/grok-chat-item.js` content
+//!
+//! ```javascript
+//! window.YTD.grok_chat_item.part0 = [
+//!   {
+//!     "grokChatItem" : {
+//!       "conversationId" : "1111111111111111111",
+//!       "message" : "What is a borrow checker?",
+//!       "sender" : "User",
+//!       "grokMode" : "Regular",
+//!       "createdAt" : "2024-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::grok_chat_item::GrokChatItemObject;
+///
+/// let json = r#"{
+///   "grokChatItem": {
+///     "conversationId": "1111111111111111111",
+///     "message": "What is a borrow checker?",
+///     "sender": "User",
+///     "grokMode": "Regular",
+///     "createdAt": "2024-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: GrokChatItemObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.grok_chat_item.conversation_id, "1111111111111111111");
+/// assert_eq!(data.grok_chat_item.sender, "User");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct GrokChatItemObject {
+	/// Why they wrapped a list of chat messages within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "grokChatItem": {
+	///     "conversationId": "1111111111111111111",
+	///     "message": "What is a borrow checker?",
+	///     "sender": "User",
+	///     "grokMode": "Regular",
+	///     "createdAt": "2024-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub grok_chat_item: GrokChatItem,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::grok_chat_item::GrokChatItem;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2024-01-01T00:00:05.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "conversationId": "1111111111111111111",
+///   "message": "A compile time check of reference lifetimes",
+///   "sender": "Agent",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: GrokChatItem = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.conversation_id, "1111111111111111111");
+/// assert_eq!(data.message, "A compile time check of reference lifetimes");
+/// assert_eq!(data.sender, "Agent");
+/// assert!(data.grok_mode.is_none());
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct GrokChatItem {
+	/// ID shared by every message within same chat
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "conversationId": "1111111111111111111" }
+	/// ```
+	pub conversation_id: String,
+
+	/// Content of message
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "message": "What is a borrow checker?" }
+	/// ```
+	pub message: String,
+
+	/// Who wrote message, `User` for account owner or `Agent` for Grok
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "sender": "User" }
+	/// ```
+	pub sender: String,
+
+	/// Persona Grok answered with, such as `Regular` or `Fun`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "grokMode": "Regular" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub grok_mode: Option<String>,
+
+	/// Date time-stamp of when message was sent
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2024-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<GrokChatItemObject> {
+	const GLOBAL_NAME: &'static str = "YTD.grok_chat_item";
+}
@@ -56,11 +56,14 @@
 //! ]
 //! ```
 
+use std::net::IpAddr;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -136,6 +139,7 @@ pub struct IpAuditObject {
 /// assert_eq!(data.account_id, "111111111");
 /// assert_eq!(data.created_at, created_at_date_time);
 /// assert_eq!(data.login_ip, "127.0.0.1");
+/// assert!(data.login_ip.ip().unwrap().is_loopback());
 ///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
@@ -164,5 +168,101 @@ pub struct IpAudit {
 	/// ```json
 	/// { "loginIp": "127.0.0.1" }
 	/// ```
-	pub login_ip: String,
+	pub login_ip: LoginIp,
+}
+
+/// IP address used to log-in, parsed when possible
+///
+/// Values that fail to parse, or that would not re-serialize byte-for-byte, e.g. upper-case IPv6,
+/// are kept as `LoginIp::Unparsed` so re-serializing is equivalent to original data.
+///
+/// ## Example
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use twitter_archive::structs::ip_audit::LoginIp;
+///
+/// let data: LoginIp = serde_json::from_str(r#""192.0.2.1""#).unwrap();
+/// assert_eq!(data, LoginIp::Ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))));
+/// assert!(data.is_ipv4());
+///
+/// let data: LoginIp = serde_json::from_str(r#""2001:db8::1""#).unwrap();
+/// assert!(data.is_ipv6());
+///
+/// let data: LoginIp = serde_json::from_str(r#""not-an-ip""#).unwrap();
+/// assert_eq!(data, LoginIp::Unparsed("not-an-ip".to_string()));
+/// assert!(data.ip().is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#""not-an-ip""#);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum LoginIp {
+	/// Well formed IPv4 or IPv6 address
+	Ip(IpAddr),
+
+	/// Malformed, or non-canonical, value kept verbatim
+	Unparsed(String),
+}
+
+impl LoginIp {
+	/// Parsed address, if any
+	pub fn ip(&self) -> Option<IpAddr> {
+		match self {
+			Self::Ip(ip) => Some(*ip),
+			Self::Unparsed(_) => None,
+		}
+	}
+
+	/// `true` only for parsed IPv4 addresses
+	pub fn is_ipv4(&self) -> bool {
+		matches!(self, Self::Ip(IpAddr::V4(_)))
+	}
+
+	/// `true` only for parsed IPv6 addresses
+	pub fn is_ipv6(&self) -> bool {
+		matches!(self, Self::Ip(IpAddr::V6(_)))
+	}
+}
+
+impl From<String> for LoginIp {
+	fn from(value: String) -> Self {
+		match value.parse::<IpAddr>() {
+			Ok(ip) if ip.to_string() == value => Self::Ip(ip),
+			_ => Self::Unparsed(value),
+		}
+	}
+}
+
+impl From<LoginIp> for String {
+	fn from(value: LoginIp) -> Self {
+		match value {
+			LoginIp::Ip(ip) => ip.to_string(),
+			LoginIp::Unparsed(value) => value,
+		}
+	}
+}
+
+impl std::fmt::Display for LoginIp {
+	fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Ip(ip) => ip.fmt(formatter),
+			Self::Unparsed(value) => formatter.write_str(value),
+		}
+	}
+}
+
+impl PartialEq<&str> for LoginIp {
+	fn eq(&self, other: &&str) -> bool {
+		match self {
+			Self::Ip(ip) => ip.to_string() == *other,
+			Self::Unparsed(value) => value == other,
+		}
+	}
+}
+
+impl FromJsStr for Vec<IpAuditObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ip_audit";
 }
@@ -66,6 +66,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -262,3 +263,44 @@ pub struct DeviceMetadata {
 	/// ```
 	pub device_id: String,
 }
+
+impl RegisteredDevices {
+	/// Devices registered at, or before, `time`; such as when a Direct Message was sent
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use chrono::{TimeZone, Utc};
+	///
+	/// use twitter_archive::structs::key_registry::RegisteredDevices;
+	///
+	/// let json = r#"{
+	///   "deviceMetadataList": [
+	///     {
+	///       "userAgent": "Mozilla/5.0 (Windows NT 10.0; rv:102.0) Gecko/20100101 Firefox/102.0",
+	///       "registrationToken": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+	///       "identityKey": "DEADBEEF",
+	///       "createdAt": "2023-05-30T13:31:42.908Z",
+	///       "deviceId": "xxxxxxxx-111a-0000-abcd-333333333333"
+	///     }
+	///   ]
+	/// }"#;
+	///
+	/// let data: RegisteredDevices = serde_json::from_str(&json).unwrap();
+	///
+	/// let before = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+	/// let after = Utc.with_ymd_and_hms(2023, 8, 12, 0, 0, 0).unwrap();
+	///
+	/// assert_eq!(data.devices_at(before).count(), 0);
+	/// assert_eq!(data.devices_at(after).next().unwrap().identity_key, "DEADBEEF");
+	/// ```
+	pub fn devices_at(&self, time: DateTime<Utc>) -> impl Iterator<Item = &DeviceMetadata> {
+		self.device_metadata_list
+			.iter()
+			.filter(move |device| device.created_at <= time)
+	}
+}
+
+impl FromJsStr for Vec<RegisteredDevicesObject> {
+	const GLOBAL_NAME: &'static str = "YTD.key_registry";
+}
@@ -59,6 +59,8 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -154,3 +156,7 @@ pub struct Like {
 	/// ```
 	pub expanded_url: String,
 }
+
+impl FromJsStr for Vec<LikeObject> {
+	const GLOBAL_NAME: &'static str = "YTD.like";
+}
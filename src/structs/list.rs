@@ -0,0 +1,60 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/lists-created.js
+//!   twitter-<DATE>-<UID>.zip:data/lists-member.js
+//!   twitter-<DATE>-<UID>.zip:data/lists-subscribed.js
+//!
+//! Check following source code files for example usage;
+//!
+//! - ./lists_created.rs
+//! - ./lists_member.rs
+//! - ./lists_subscribed.rs
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/lists-created.js` content
+//!
+//! ```javascript
+//! window.YTD.lists_created.part0 = [
+//!   {
+//!     "userListInfo" : {
+//!       "url" : "https://twitter.com/EXAMPLE/lists/1111111111111111111"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::list::UserListInfo;
+///
+/// let json = r#"{
+///   "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198"
+/// }"#;
+///
+/// let data: UserListInfo = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.url, "https://twitter.com/R0oTk1t/lists/1572592337959944198");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct UserListInfo {
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/{screen_name}/lists/{list_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198" }
+	/// ```
+	pub url: String,
+}
@@ -0,0 +1,80 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private data, Lists account owns, found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/lists-created.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/lists-created.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.lists_created().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each List */
+//!         println!("Lists created index: {index}");
+//!         println!("URL: {}", object.user_list_info.url);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/lists-created.js` content
+//!
+//! ```javascript
+//! window.YTD.lists_created.part0 = [
+//!   {
+//!     "userListInfo" : {
+//!       "url" : "https://twitter.com/EXAMPLE/lists/1111111111111111111"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+use crate::structs::list::UserListInfo;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::lists_created::ListsCreatedObject;
+///
+/// let json = r#"{
+///   "userListInfo": {
+///     "url": "https://twitter.com/EXAMPLE/lists/1111111111111111111"
+///   }
+/// }"#;
+///
+/// let data: ListsCreatedObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.user_list_info.url, "https://twitter.com/EXAMPLE/lists/1111111111111111111");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ListsCreatedObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "userListInfo": {
+	///     "url": "https://twitter.com/EXAMPLE/lists/1111111111111111111"
+	///   }
+	/// }
+	/// ```
+	pub user_list_info: UserListInfo,
+}
+
+impl FromJsStr for Vec<ListsCreatedObject> {
+	const GLOBAL_NAME: &'static str = "YTD.lists_created";
+}
@@ -57,6 +57,9 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+pub use crate::structs::list::UserListInfo;
+
 /// ## Example
 ///
 /// ```
@@ -92,30 +95,6 @@ pub struct UserListInfoObject {
 	pub user_list_info: UserListInfo,
 }
 
-/// ## Example
-///
-/// ```
-/// use twitter_archive::structs::lists_member::UserListInfo;
-///
-/// let json = r#"{
-///   "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198"
-/// }"#;
-///
-/// let data: UserListInfo = serde_json::from_str(&json).unwrap();
-///
-/// // De-serialized properties
-/// assert_eq!(data.url, "https://twitter.com/R0oTk1t/lists/1572592337959944198");
-///
-/// // Re-serialize is equivalent to original data
-/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
-/// ```
-#[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct UserListInfo {
-	/// ## Example JSON data
-	///
-	/// ```json
-	/// { "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198" }
-	/// ```
-	pub url: String,
+impl FromJsStr for Vec<UserListInfoObject> {
+	const GLOBAL_NAME: &'static str = "YTD.lists_member";
 }
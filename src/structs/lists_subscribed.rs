@@ -0,0 +1,80 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private data, Lists account follows, found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/lists-subscribed.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/lists-subscribed.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.lists_subscribed().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each List */
+//!         println!("Lists subscribed index: {index}");
+//!         println!("URL: {}", object.user_list_info.url);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/lists-subscribed.js` content
+//!
+//! ```javascript
+//! window.YTD.lists_subscribed.part0 = [
+//!   {
+//!     "userListInfo" : {
+//!       "url" : "https://twitter.com/R0oTk1t/lists/1572592337959944198"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+use crate::structs::list::UserListInfo;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::lists_subscribed::ListsSubscribedObject;
+///
+/// let json = r#"{
+///   "userListInfo": {
+///     "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198"
+///   }
+/// }"#;
+///
+/// let data: ListsSubscribedObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.user_list_info.url, "https://twitter.com/R0oTk1t/lists/1572592337959944198");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ListsSubscribedObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "userListInfo": {
+	///     "url": "https://twitter.com/R0oTk1t/lists/1572592337959944198"
+	///   }
+	/// }
+	/// ```
+	pub user_list_info: UserListInfo,
+}
+
+impl FromJsStr for Vec<ListsSubscribedObject> {
+	const GLOBAL_NAME: &'static str = "YTD.lists_subscribed";
+}
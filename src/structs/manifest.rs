@@ -819,6 +819,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -2488,6 +2489,29 @@ pub struct UserInfo {
 	pub display_name: String,
 }
 
+impl UserInfo {
+	/// Link to account profile, e.g. `https://twitter.com/<USER_NAME>`
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::manifest::UserInfo;
+	///
+	/// let json = r#"{
+	///   "accountId": "111111111",
+	///   "userName": "S0_And_S0",
+	///   "displayName": "S0AndS0.eth"
+	/// }"#;
+	///
+	/// let data: UserInfo = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.profile_url(), "https://twitter.com/S0_And_S0");
+	/// ```
+	pub fn profile_url(&self) -> String {
+		format!("https://twitter.com/{}", self.user_name)
+	}
+}
+
 /// ## Example
 ///
 /// ```
@@ -3382,6 +3406,11 @@ pub struct ReadmeInfo {
 /// assert_eq!(data.verified.files[0].global_name, "YTD.verified.part0");
 /// assert_eq!(data.verified.files[0].count, 1);
 ///
+/// // Data types only found within newer X exports are optional
+/// assert!(data.ad_free_article_visits.is_none());
+/// assert!(data.grok_chat_item.is_none());
+/// assert!(data.payment_transaction.is_none());
+///
 /// // Re-serialize is equivalent to original data without pretty printing
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
@@ -3491,6 +3520,26 @@ pub struct DataTypes {
 	/// ```
 	pub ad_engagements: FileObject,
 
+	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/ad-free-article-visits.js`
+	///
+	/// Only present within archives exported from X after the Twitter rebrand
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "files": [
+	///     {
+	///       "fileName": "data/ad-free-article-visits.js",
+	///       "globalName": "YTD.ad_free_article_visits.part0",
+	///       "count": "0"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ad_free_article_visits: Option<FileObject>,
+
 	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/ad-impressions.js`
 	///
 	/// ## Example JSON data
@@ -4024,6 +4073,26 @@ pub struct DataTypes {
 	/// ```
 	pub following: FileObject,
 
+	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/grok-chat-item.js`
+	///
+	/// Only present within archives exported from X after the Twitter rebrand
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "files": [
+	///     {
+	///       "fileName": "data/grok-chat-item.js",
+	///       "globalName": "YTD.grok_chat_item.part0",
+	///       "count": "0"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub grok_chat_item: Option<FileObject>,
+
 	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/ip-audit.js`
 	///
 	/// ## Example JSON data
@@ -4213,6 +4282,26 @@ pub struct DataTypes {
 	/// ```
 	pub note_tweet: FileObject,
 
+	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/payment-transaction.js`
+	///
+	/// Only present within archives exported from X after the Twitter rebrand
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "files": [
+	///     {
+	///       "fileName": "data/payment-transaction.js",
+	///       "globalName": "YTD.payment_transaction.part0",
+	///       "count": "0"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub payment_transaction: Option<FileObject>,
+
 	/// Metadata that usually points to `twitter-archive-<UID>.zip:data/periscope-account-information.js`
 	///
 	/// ## Example JSON data
@@ -5017,3 +5106,7 @@ pub struct File {
 	#[serde(with = "convert::number_like_string")]
 	pub count: usize,
 }
+
+impl FromJsStr for Manifest {
+	const GLOBAL_NAME: &'static str = "__THAR_CONFIG";
+}
@@ -0,0 +1,263 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have public Moment, curated collection of Tweets, data found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/moment.js
+//!
+//! Cover media files, when archive includes them, are found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/moments_media/<MOMENT_ID>-<FILE_NAME>
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/moment.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.moment().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Moment */
+//!         println!("Moment index: {index}");
+//!         println!("Title: {}", object.moment.title);
+//!         println!("Tweet IDs: {:?}", object.moment.tweet_ids);
+//!         if let Some(path) = object.moment.cover_media_path() {
+//!             println!("Cover media: {path}");
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/moment.js` content
+//!
+//! ```javascript
+//! window.YTD.moment.part0 = [
+//!   {
+//!     "moment" : {
+//!       "momentId" : "1111111111111111111",
+//!       "createdAt" : "2018-01-01T00:00:00.000Z",
+//!       "createdBy" : "111111111",
+//!       "title" : "Example Moment",
+//!       "description" : "Tweets worth keeping together",
+//!       "coverMediaUrl" : "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+//!       "tweetIds" : [
+//!         "2222222222222222222",
+//!         "3333333333333333333"
+//!       ]
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// Directory within archive that Moment cover media files are saved to
+pub const MEDIA_DIRECTORY: &str = "data/moments_media";
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::moment::MomentObject;
+///
+/// let json = r#"{
+///   "moment": {
+///     "momentId": "1111111111111111111",
+///     "createdAt": "2018-01-01T00:00:00.000Z",
+///     "createdBy": "111111111",
+///     "title": "Example Moment",
+///     "description": "Tweets worth keeping together",
+///     "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+///     "tweetIds": [
+///       "2222222222222222222",
+///       "3333333333333333333"
+///     ]
+///   }
+/// }"#;
+///
+/// let data: MomentObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.moment.moment_id, "1111111111111111111");
+/// assert_eq!(data.moment.title, "Example Moment");
+/// assert_eq!(data.moment.tweet_ids.len(), 2);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct MomentObject {
+	/// Why they wrapped a list of Moments within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "moment": {
+	///     "momentId": "1111111111111111111",
+	///     "createdAt": "2018-01-01T00:00:00.000Z",
+	///     "createdBy": "111111111",
+	///     "title": "Example Moment",
+	///     "description": "Tweets worth keeping together",
+	///     "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+	///     "tweetIds": [
+	///       "2222222222222222222",
+	///       "3333333333333333333"
+	///     ]
+	///   }
+	/// }
+	/// ```
+	pub moment: Moment,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::moment::Moment;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2018-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "momentId": "1111111111111111111",
+///   "createdAt": "{created_at_string}",
+///   "createdBy": "111111111",
+///   "title": "Example Moment",
+///   "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+///   "tweetIds": [
+///     "2222222222222222222"
+///   ]
+/// }}"#);
+///
+/// let data: Moment = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.moment_id, "1111111111111111111");
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert_eq!(data.created_by, "111111111");
+/// assert_eq!(data.title, "Example Moment");
+/// assert!(data.description.is_none());
+/// assert_eq!(data.cover_media_url, Some("https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg".to_string()));
+/// assert_eq!(data.tweet_ids, vec!["2222222222222222222"]);
+///
+/// // Media path within archive
+/// assert_eq!(
+///     data.cover_media_path(),
+///     Some("data/moments_media/1111111111111111111-AAAAAAAAAAAAAAA.jpg".to_string())
+/// );
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Moment {
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/moments/{moment_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "momentId": "1111111111111111111" }
+	/// ```
+	pub moment_id: String,
+
+	/// Date time-stamp of when Moment was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2018-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// ID of account that created Moment
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{created_by}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdBy": "111111111" }
+	/// ```
+	pub created_by: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "title": "Example Moment" }
+	/// ```
+	pub title: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "description": "Tweets worth keeping together" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+
+	/// Where cover image was hosted, check `Moment::cover_media_path` for archived copy
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cover_media_url: Option<String>,
+
+	/// IDs of Tweets curated within Moment, in display order
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "tweetIds": [
+	///     "2222222222222222222",
+	///     "3333333333333333333"
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub tweet_ids: Vec<String>,
+}
+
+impl Moment {
+	/// Relative path within archive to cover media file, following
+	/// `data/moments_media/<MOMENT_ID>-<FILE_NAME>` naming convention
+	///
+	/// Returns `None` when Moment has no cover media.  File may still be absent from archives
+	/// exported without media.
+	pub fn cover_media_path(&self) -> Option<String> {
+		let url = self.cover_media_url.as_ref()?;
+		let file_name = url.rsplit('/').next().filter(|name| !name.is_empty())?;
+		Some(format!("{MEDIA_DIRECTORY}/{}-{file_name}", self.moment_id))
+	}
+}
+
+impl FromJsStr for Vec<MomentObject> {
+	const GLOBAL_NAME: &'static str = "YTD.moment";
+}
@@ -60,6 +60,8 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -135,3 +137,7 @@ pub struct Muting {
 	/// ```
 	pub user_link: String,
 }
+
+impl FromJsStr for Vec<MutingObject> {
+	const GLOBAL_NAME: &'static str = "YTD.mute";
+}
@@ -63,6 +63,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -264,3 +265,37 @@ pub struct MessagingDevice {
 	#[serde(with = "convert::date_year_month_day")]
 	pub created_date: DateTime<Utc>,
 }
+
+#[cfg(feature = "e164")]
+impl MessagingDevice {
+	/// Typed phone number parsed from `phone_number`
+	///
+	/// Returns `None` for values that are not E.164 formatted.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::ni_devices::MessagingDevice;
+	///
+	/// let json = r#"{
+	///   "phoneNumber": "+15551234567",
+	///   "carrier": "us.carriername",
+	///   "deviceType": "Auth",
+	///   "updatedDate": "2021.10.20",
+	///   "createdDate": "2021.10.20"
+	/// }"#;
+	///
+	/// let data: MessagingDevice = serde_json::from_str(&json).unwrap();
+	///
+	/// let phone_number = data.e164().unwrap();
+	/// assert_eq!(phone_number.country_code, 1);
+	/// assert_eq!(phone_number.national_number, "5551234567");
+	/// ```
+	pub fn e164(&self) -> Option<convert::e164::PhoneNumber> {
+		convert::e164::parse(&self.phone_number)
+	}
+}
+
+impl FromJsStr for Vec<NiDeviceResponseObject> {
+	const GLOBAL_NAME: &'static str = "YTD.ni_devices";
+}
@@ -0,0 +1,1050 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2024-01-01 have private long-form post (Notes) data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/note-tweet.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/note-tweet.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.note_tweet().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Note */
+//!         println!("Note index: {index}");
+//!         println!("Created at: {}", object.note_tweet.created_at);
+//!         println!("vvv Content\n{}\n^^^ Content", object.note_tweet.core.text);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/note-tweet.js` content
+//!
+//! ```javascript
+//! window.YTD.note_tweet.part0 = [
+//!   {
+//!     "noteTweet" : {
+//!       "noteTweetId" : "1111111111111111111",
+//!       "updatedAt" : "2023-06-01T12:34:56.000Z",
+//!       "lifecycle" : {
+//!         "value" : "1",
+//!         "name" : "Create",
+//!         "originalName" : "Create",
+//!         "annotations" : { }
+//!       },
+//!       "createdAt" : "2023-06-01T12:34:56.000Z",
+//!       "core" : {
+//!         "styletags" : [
+//!           {
+//!             "styleTypes" : [
+//!               {
+//!                 "value" : "0",
+//!                 "name" : "Bold",
+//!                 "originalName" : "Bold",
+//!                 "annotations" : { }
+//!               }
+//!             ],
+//!             "fromIndex" : "0",
+//!             "toIndex" : "5"
+//!           }
+//!         ],
+//!         "urls" : [
+//!           {
+//!             "shortUrl" : "https://t.co/AAAAAAAAAA",
+//!             "expandedUrl" : "https://example.com/",
+//!             "displayUrl" : "example.com",
+//!             "fromIndex" : "6",
+//!             "toIndex" : "29"
+//!           }
+//!         ],
+//!         "text" : "Hello https://t.co/AAAAAAAAAA @EXAMPLE #RustLang $XMPL and more words than fit within a Tweet",
+//!         "mentions" : [
+//!           {
+//!             "screenName" : "EXAMPLE",
+//!             "fromIndex" : "30",
+//!             "toIndex" : "38"
+//!           }
+//!         ],
+//!         "cashtags" : [
+//!           {
+//!             "cashtag" : "XMPL",
+//!             "fromIndex" : "49",
+//!             "toIndex" : "54"
+//!           }
+//!         ],
+//!         "hashtags" : [
+//!           {
+//!             "hashtag" : "RustLang",
+//!             "fromIndex" : "39",
+//!             "toIndex" : "48"
+//!           }
+//!         ]
+//!       }
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::tweets;
+
+/// Greatest difference, in seconds, between `created_at` of Note and of Tweet linking to it
+const MATCH_TOLERANCE_SECONDS: i64 = 60;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::note_tweet::NoteTweetObject;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let date_string = "2023-06-01T12:34:56.000Z";
+/// let date_native_time = NaiveDateTime::parse_from_str(&date_string, FORMAT).unwrap();
+/// let date_time = DateTime::<Utc>::from_naive_utc_and_offset(date_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "noteTweet": {{
+///     "noteTweetId": "1111111111111111111",
+///     "updatedAt": "{date_string}",
+///     "lifecycle": {{
+///       "value": "1",
+///       "name": "Create",
+///       "originalName": "Create",
+///       "annotations": {{}}
+///     }},
+///     "createdAt": "{date_string}",
+///     "core": {{
+///       "styletags": [],
+///       "urls": [],
+///       "text": "Hello and more words than fit within a Tweet",
+///       "mentions": [],
+///       "cashtags": [],
+///       "hashtags": []
+///     }}
+///   }}
+/// }}"#);
+///
+/// let data: NoteTweetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_tweet.note_tweet_id, "1111111111111111111");
+/// assert_eq!(data.note_tweet.created_at, date_time);
+/// assert_eq!(data.note_tweet.core.text, "Hello and more words than fit within a Tweet");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct NoteTweetObject {
+	/// Why they wrapped a list of Notes within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "noteTweet": {
+	///     "noteTweetId": "1111111111111111111",
+	///     "updatedAt": "2023-06-01T12:34:56.000Z",
+	///     "lifecycle": {
+	///       "value": "1",
+	///       "name": "Create",
+	///       "originalName": "Create",
+	///       "annotations": {}
+	///     },
+	///     "createdAt": "2023-06-01T12:34:56.000Z",
+	///     "core": {
+	///       "styletags": [],
+	///       "urls": [],
+	///       "text": "Hello and more words than fit within a Tweet",
+	///       "mentions": [],
+	///       "cashtags": [],
+	///       "hashtags": []
+	///     }
+	///   }
+	/// }
+	/// ```
+	pub note_tweet: NoteTweet,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::note_tweet::NoteTweet;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-06-01T12:34:56.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let updated_at_string = "2023-06-02T01:02:03.000Z";
+/// let updated_at_native_time = NaiveDateTime::parse_from_str(&updated_at_string, FORMAT).unwrap();
+/// let updated_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(updated_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "noteTweetId": "1111111111111111111",
+///   "updatedAt": "{updated_at_string}",
+///   "lifecycle": {{
+///     "value": "2",
+///     "name": "Edit",
+///     "originalName": "Edit",
+///     "annotations": {{}}
+///   }},
+///   "createdAt": "{created_at_string}",
+///   "core": {{
+///     "styletags": [],
+///     "urls": [],
+///     "text": "Hello and more words than fit within a Tweet",
+///     "mentions": [],
+///     "cashtags": [],
+///     "hashtags": []
+///   }}
+/// }}"#);
+///
+/// let data: NoteTweet = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.note_tweet_id, "1111111111111111111");
+/// assert_eq!(data.updated_at, updated_at_date_time);
+/// assert_eq!(data.lifecycle.name, "Edit");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct NoteTweet {
+	/// Unique ID of Note, which is **not** the same as ID of Tweet that links to it
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "noteTweetId": "1111111111111111111" }
+	/// ```
+	pub note_tweet_id: String,
+
+	/// Date time-stamp of when Note was last changed
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "updatedAt": "2023-06-02T01:02:03.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub updated_at: DateTime<Utc>,
+
+	/// Most recent life-cycle event, such as `Create` or `Edit`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "lifecycle": {
+	///     "value": "1",
+	///     "name": "Create",
+	///     "originalName": "Create",
+	///     "annotations": {}
+	///   }
+	/// }
+	/// ```
+	pub lifecycle: EnumValue,
+
+	/// Date time-stamp of when Note was originally created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-06-01T12:34:56.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Long-form text and rich-text entities
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "core": {
+	///     "styletags": [],
+	///     "urls": [],
+	///     "text": "Hello and more words than fit within a Tweet",
+	///     "mentions": [],
+	///     "cashtags": [],
+	///     "hashtags": []
+	///   }
+	/// }
+	/// ```
+	pub core: NoteTweetCore,
+}
+
+impl NoteTweet {
+	/// Does `tweet` look like truncated Tweet linking to this Note; created within a minute of
+	/// Note, with `full_text`, less trailing `…` and `t.co` link, beginning Note text
+	///
+	/// Tweets do not record ID of their Note, nor Notes ID of their Tweet, so time and text are
+	/// all there is to go on.  Whitespace is ignored when comparing text.
+	pub fn matches(&self, tweet: &tweets::Tweet) -> bool {
+		self.is_near(tweet.created_at) && self.begins_with(&truncated_prefix(tweet))
+	}
+
+	/// Was Note created within `MATCH_TOLERANCE_SECONDS` of `created_at`
+	fn is_near(&self, created_at: DateTime<Utc>) -> bool {
+		(self.created_at - created_at).num_seconds().abs() <= MATCH_TOLERANCE_SECONDS
+	}
+
+	/// Does Note text, ignoring whitespace, begin with non-empty `prefix` of `truncated_prefix`
+	fn begins_with(&self, prefix: &str) -> bool {
+		if prefix.is_empty() {
+			return false;
+		}
+
+		let mut text = self.core.text.chars().filter(|c| !c.is_whitespace());
+		prefix.chars().all(|c| text.next() == Some(c))
+	}
+}
+
+/// Decoded `full_text` of Tweet less trailing `…` and `t.co` link, and without whitespace
+fn truncated_prefix(tweet: &tweets::Tweet) -> String {
+	let decoded = tweet.full_text_decoded();
+	let mut prefix = decoded.trim_end();
+	if let Some((rest, last)) = prefix.rsplit_once(char::is_whitespace) {
+		if last.starts_with("https://t.co/") {
+			prefix = rest.trim_end();
+		}
+	}
+	let prefix = prefix.strip_suffix('…').unwrap_or(prefix);
+
+	prefix.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Enumerated value as exported by Twitter's internal services, found within Notes for life-cycle
+/// events and text styles
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::EnumValue;
+///
+/// let json = r#"{
+///   "value": "0",
+///   "name": "Bold",
+///   "originalName": "Bold",
+///   "annotations": {}
+/// }"#;
+///
+/// let data: EnumValue = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.value, 0);
+/// assert_eq!(data.name, "Bold");
+/// assert_eq!(data.original_name, "Bold");
+/// assert!(data.annotations.is_empty());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct EnumValue {
+	/// Numeric value of enumeration
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "value": "0" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub value: usize,
+
+	/// Name of enumeration value
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "name": "Bold" }
+	/// ```
+	pub name: String,
+
+	/// Seems to always equal `name`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "originalName": "Bold" }
+	/// ```
+	pub original_name: String,
+
+	/// Seems to always be empty, kept as untyped JSON until an example with content is found
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "annotations": {} }
+	/// ```
+	pub annotations: Map<String, Value>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::NoteTweetCore;
+///
+/// let json = r#"{
+///   "styletags": [
+///     {
+///       "styleTypes": [
+///         {
+///           "value": "0",
+///           "name": "Bold",
+///           "originalName": "Bold",
+///           "annotations": {}
+///         }
+///       ],
+///       "fromIndex": "0",
+///       "toIndex": "5"
+///     }
+///   ],
+///   "urls": [
+///     {
+///       "shortUrl": "https://t.co/AAAAAAAAAA",
+///       "expandedUrl": "https://example.com/",
+///       "displayUrl": "example.com",
+///       "fromIndex": "6",
+///       "toIndex": "29"
+///     }
+///   ],
+///   "text": "Hello https://t.co/AAAAAAAAAA @EXAMPLE #RustLang $XMPL and more words than fit within a Tweet",
+///   "mentions": [
+///     {
+///       "screenName": "EXAMPLE",
+///       "fromIndex": "30",
+///       "toIndex": "38"
+///     }
+///   ],
+///   "cashtags": [
+///     {
+///       "cashtag": "XMPL",
+///       "fromIndex": "49",
+///       "toIndex": "54"
+///     }
+///   ],
+///   "hashtags": [
+///     {
+///       "hashtag": "RustLang",
+///       "fromIndex": "39",
+///       "toIndex": "48"
+///     }
+///   ]
+/// }"#;
+///
+/// let data: NoteTweetCore = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.styletags[0].style_types[0].name, "Bold");
+/// assert_eq!(data.urls[0].expanded_url, "https://example.com/");
+/// assert_eq!(&data.text[data.urls[0].from_index..data.urls[0].to_index], data.urls[0].short_url);
+/// assert_eq!(&data.text[data.mentions[0].from_index..data.mentions[0].to_index], "@EXAMPLE");
+/// assert_eq!(&data.text[data.cashtags[0].from_index..data.cashtags[0].to_index], "$XMPL");
+/// assert_eq!(&data.text[data.hashtags[0].from_index..data.hashtags[0].to_index], "#RustLang");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct NoteTweetCore {
+	/// Ranges of text with styles, such as bold or italic, applied
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "styletags": [
+	///     {
+	///       "styleTypes": [
+	///         {
+	///           "value": "0",
+	///           "name": "Bold",
+	///           "originalName": "Bold",
+	///           "annotations": {}
+	///         }
+	///       ],
+	///       "fromIndex": "0",
+	///       "toIndex": "5"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub styletags: Vec<StyleTag>,
+
+	/// Shortened links found within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "urls": [
+	///     {
+	///       "shortUrl": "https://t.co/AAAAAAAAAA",
+	///       "expandedUrl": "https://example.com/",
+	///       "displayUrl": "example.com",
+	///       "fromIndex": "6",
+	///       "toIndex": "29"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub urls: Vec<Url>,
+
+	/// Full text of Note, which may be much longer than a Tweet
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "text": "Hello https://t.co/AAAAAAAAAA @EXAMPLE #RustLang $XMPL and more words than fit within a Tweet" }
+	/// ```
+	pub text: String,
+
+	/// Accounts mentioned within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "mentions": [
+	///     {
+	///       "screenName": "EXAMPLE",
+	///       "fromIndex": "30",
+	///       "toIndex": "38"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub mentions: Vec<Mention>,
+
+	/// Stock ticker symbols found within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "cashtags": [
+	///     {
+	///       "cashtag": "XMPL",
+	///       "fromIndex": "49",
+	///       "toIndex": "54"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub cashtags: Vec<Cashtag>,
+
+	/// Hashtags found within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "hashtags": [
+	///     {
+	///       "hashtag": "RustLang",
+	///       "fromIndex": "39",
+	///       "toIndex": "48"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub hashtags: Vec<Hashtag>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::StyleTag;
+///
+/// let json = r#"{
+///   "styleTypes": [
+///     {
+///       "value": "1",
+///       "name": "Italic",
+///       "originalName": "Italic",
+///       "annotations": {}
+///     }
+///   ],
+///   "fromIndex": "0",
+///   "toIndex": "5"
+/// }"#;
+///
+/// let data: StyleTag = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.style_types.len(), 1);
+/// assert_eq!(data.style_types[0].name, "Italic");
+/// assert_eq!(data.from_index, 0);
+/// assert_eq!(data.to_index, 5);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct StyleTag {
+	/// Styles applied to range of text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "styleTypes": [
+	///     {
+	///       "value": "1",
+	///       "name": "Italic",
+	///       "originalName": "Italic",
+	///       "annotations": {}
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub style_types: Vec<EnumValue>,
+
+	/// Start index of styled text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "fromIndex": "0" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub from_index: usize,
+
+	/// End index of styled text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "toIndex": "5" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub to_index: usize,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::Url;
+///
+/// let json = r#"{
+///   "shortUrl": "https://t.co/AAAAAAAAAA",
+///   "expandedUrl": "https://example.com/",
+///   "displayUrl": "example.com",
+///   "fromIndex": "6",
+///   "toIndex": "29"
+/// }"#;
+///
+/// let data: Url = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.short_url, "https://t.co/AAAAAAAAAA");
+/// assert_eq!(data.expanded_url, "https://example.com/");
+/// assert_eq!(data.display_url, "example.com");
+/// assert_eq!(data.from_index, 6);
+/// assert_eq!(data.to_index, 29);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Url {
+	/// Shortened link as it appears within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "shortUrl": "https://t.co/AAAAAAAAAA" }
+	/// ```
+	pub short_url: String,
+
+	/// Link that shortened URL redirects to
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expandedUrl": "https://example.com/" }
+	/// ```
+	pub expanded_url: String,
+
+	/// Link as shown to readers
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "displayUrl": "example.com" }
+	/// ```
+	pub display_url: String,
+
+	/// Start index of shortened link within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "fromIndex": "6" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub from_index: usize,
+
+	/// End index of shortened link within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "toIndex": "29" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub to_index: usize,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::Mention;
+///
+/// let json = r#"{
+///   "screenName": "EXAMPLE",
+///   "fromIndex": "30",
+///   "toIndex": "38"
+/// }"#;
+///
+/// let data: Mention = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.screen_name, "EXAMPLE");
+/// assert_eq!(data.from_index, 30);
+/// assert_eq!(data.to_index, 38);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Mention {
+	/// Handle of mentioned account, without leading `@`
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/{screen_name}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "screenName": "EXAMPLE" }
+	/// ```
+	pub screen_name: String,
+
+	/// Start index of mention, including leading `@`, within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "fromIndex": "30" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub from_index: usize,
+
+	/// End index of mention within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "toIndex": "38" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub to_index: usize,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::Cashtag;
+///
+/// let json = r#"{
+///   "cashtag": "XMPL",
+///   "fromIndex": "49",
+///   "toIndex": "54"
+/// }"#;
+///
+/// let data: Cashtag = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.cashtag, "XMPL");
+/// assert_eq!(data.from_index, 49);
+/// assert_eq!(data.to_index, 54);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Cashtag {
+	/// Stock ticker symbol, without leading `$`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "cashtag": "XMPL" }
+	/// ```
+	pub cashtag: String,
+
+	/// Start index of cashtag, including leading `$`, within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "fromIndex": "49" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub from_index: usize,
+
+	/// End index of cashtag within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "toIndex": "54" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub to_index: usize,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::Hashtag;
+///
+/// let json = r#"{
+///   "hashtag": "RustLang",
+///   "fromIndex": "39",
+///   "toIndex": "48"
+/// }"#;
+///
+/// let data: Hashtag = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.hashtag, "RustLang");
+/// assert_eq!(data.from_index, 39);
+/// assert_eq!(data.to_index, 48);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Hashtag {
+	/// Hashtag text, without leading `#`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "hashtag": "RustLang" }
+	/// ```
+	pub hashtag: String,
+
+	/// Start index of hashtag, including leading `#`, within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "fromIndex": "39" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub from_index: usize,
+
+	/// End index of hashtag within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "toIndex": "48" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub to_index: usize,
+}
+
+/// Replace `full_text`, and URL, hashtag, symbol, and mention entities, of each Tweet that a Note
+/// `matches` with long-form content of that Note, returning how many Tweets were merged
+///
+/// Each Note is merged into at most one Tweet, see `Tweet::merge_note_tweet` for details.
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::note_tweet::{self, NoteTweetObject};
+/// use twitter_archive::structs::tweets::TweetObject;
+///
+/// let tweets_json = r#"[
+///   {
+///     "tweet": {
+///       "edit_info": {
+///         "initial": {
+///           "editTweetIds": [ "1111111111111111111" ],
+///           "editableUntil": "2023-08-12T17:10:37.000Z",
+///           "editsRemaining": "5",
+///           "isEditEligible": true
+///         }
+///       },
+///       "retweeted": false,
+///       "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///       "entities": {
+///         "hashtags": [],
+///         "symbols": [],
+///         "user_mentions": [],
+///         "urls": [
+///           {
+///             "url": "https://t.co/NNNNNNNNNN",
+///             "expanded_url": "https://twitter.com/i/web/status/1111111111111111111",
+///             "display_url": "twitter.com/i/web/status/1…",
+///             "indices": [ "25", "48" ]
+///           }
+///         ]
+///       },
+///       "display_text_range": [ "0", "48" ],
+///       "favorite_count": "0",
+///       "id_str": "1111111111111111111",
+///       "truncated": false,
+///       "retweet_count": "0",
+///       "id": "1111111111111111111",
+///       "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///       "favorited": false,
+///       "full_text": "Fish &amp; chips, and more… https://t.co/NNNNNNNNNN",
+///       "lang": "en"
+///     }
+///   }
+/// ]"#;
+///
+/// let notes_json = r#"[
+///   {
+///     "noteTweet": {
+///       "noteTweetId": "2222222222222222222",
+///       "updatedAt": "2023-08-12T16:10:37.000Z",
+///       "lifecycle": {
+///         "value": "1",
+///         "name": "Create",
+///         "originalName": "Create",
+///         "annotations": {}
+///       },
+///       "createdAt": "2023-08-12T16:10:37.000Z",
+///       "core": {
+///         "styletags": [],
+///         "urls": [
+///           {
+///             "shortUrl": "https://t.co/BBBBBBBBBB",
+///             "expandedUrl": "https://example.com/",
+///             "displayUrl": "example.com",
+///             "fromIndex": "47",
+///             "toIndex": "70"
+///           }
+///         ],
+///         "text": "Fish & chips, and more words than fit within a https://t.co/BBBBBBBBBB",
+///         "mentions": [],
+///         "cashtags": [],
+///         "hashtags": []
+///       }
+///     }
+///   }
+/// ]"#;
+///
+/// let mut tweets: Vec<TweetObject> = serde_json::from_str(&tweets_json).unwrap();
+/// let notes: Vec<NoteTweetObject> = serde_json::from_str(&notes_json).unwrap();
+///
+/// assert_eq!(note_tweet::merge_into_tweets(&mut tweets, &notes), 1);
+///
+/// let tweet = &tweets[0].tweet;
+/// assert_eq!(
+///     tweet.full_text,
+///     "Fish &amp; chips, and more words than fit within a https://t.co/BBBBBBBBBB"
+/// );
+///
+/// // Entity indices account for encoded `&amp;`
+/// assert_eq!(tweet.entities.urls[0].expanded_url, "https://example.com/");
+/// assert_eq!(tweet.entities.urls[0].indices, [51, 74]);
+///
+/// // Merging again leaves text as is
+/// let full_text = tweet.full_text.clone();
+/// assert_eq!(note_tweet::merge_into_tweets(&mut tweets, &notes), 1);
+/// assert_eq!(tweets[0].tweet.full_text, full_text);
+/// ```
+pub fn merge_into_tweets(tweets: &mut [tweets::TweetObject], notes: &[NoteTweetObject]) -> usize {
+	let mut merged = vec![false; tweets.len()];
+
+	// Indexes of Tweets ordered by creation, so each Note only compares Tweets within tolerance
+	let mut by_created_at: Vec<usize> = (0..tweets.len()).collect();
+	by_created_at.sort_by_key(|&index| tweets[index].tweet.created_at);
+
+	// Decoded once per Tweet, and only for Tweets some Note is near
+	let mut prefixes: Vec<Option<String>> = vec![None; tweets.len()];
+
+	for note in notes {
+		let note = &note.note_tweet;
+		// Widened by a second as `is_near` compares whole seconds
+		let window = chrono::Duration::seconds(MATCH_TOLERANCE_SECONDS + 1);
+		let (earliest, latest) = (note.created_at - window, note.created_at + window);
+		let start =
+			by_created_at.partition_point(|&index| tweets[index].tweet.created_at < earliest);
+
+		// Prefer earliest listed Tweet among matches, as scanning `tweets` in order would
+		let mut found = None;
+		for &index in &by_created_at[start..] {
+			let tweet = &tweets[index].tweet;
+			if tweet.created_at > latest {
+				break;
+			}
+			if merged[index]
+				|| !note.is_near(tweet.created_at)
+				|| found.is_some_and(|found| found < index)
+			{
+				continue;
+			}
+
+			let prefix = prefixes[index].get_or_insert_with(|| truncated_prefix(tweet));
+			if note.begins_with(prefix) {
+				found = Some(index);
+			}
+		}
+
+		if let Some(index) = found {
+			tweets[index].tweet.merge_note_tweet(note);
+			merged[index] = true;
+		}
+	}
+
+	merged.into_iter().filter(|merged| *merged).count()
+}
+
+impl FromJsStr for Vec<NoteTweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.note_tweet";
+}
@@ -0,0 +1,203 @@
+#!/usr/bin/env rust
+
+//! Archives exported from X after the Twitter rebrand have private payment and monetization data,
+//! such as subscriptions, tips, and creator payouts, found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/payment-transaction.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/payment-transaction.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.payment_transaction().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each transaction */
+//!         println!("Transaction index: {index}");
+//!         println!("Type: {}", object.payment_transaction.transaction_type);
+//!         println!("Amount: {}", object.payment_transaction.amount);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/payment-transaction.js` content
+//!
+//! ```javascript
+//! window.YTD.payment_transaction.part0 = [
+//!   {
+//!     "paymentTransaction" : {
+//!       "transactionId" : "7777777777777777777",
+//!       "transactionType" : "Subscription",
+//!       "amount" : {
+//!         "amount" : "8.00",
+//!         "currency" : "USD"
+//!       },
+//!       "status" : "Completed",
+//!       "createdAt" : "2024-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::catalog_item::Price;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::payment_transaction::PaymentTransactionObject;
+///
+/// let json = r#"{
+///   "paymentTransaction": {
+///     "transactionId": "7777777777777777777",
+///     "transactionType": "Subscription",
+///     "amount": {
+///       "amount": "8.00",
+///       "currency": "USD"
+///     },
+///     "status": "Completed",
+///     "createdAt": "2024-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: PaymentTransactionObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.payment_transaction.transaction_id, "7777777777777777777");
+/// assert_eq!(data.payment_transaction.amount.currency, "USD");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentTransactionObject {
+	/// Why they wrapped a list of transactions within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "paymentTransaction": {
+	///     "transactionId": "7777777777777777777",
+	///     "transactionType": "Subscription",
+	///     "amount": {
+	///       "amount": "8.00",
+	///       "currency": "USD"
+	///     },
+	///     "status": "Completed",
+	///     "createdAt": "2024-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub payment_transaction: PaymentTransaction,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::payment_transaction::PaymentTransaction;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2024-02-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "transactionId": "8888888888888888888",
+///   "transactionType": "CreatorPayout",
+///   "amount": {{
+///     "amount": "42.10",
+///     "currency": "USD"
+///   }},
+///   "status": "Pending",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: PaymentTransaction = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.transaction_id, "8888888888888888888");
+/// assert_eq!(data.transaction_type, "CreatorPayout");
+/// assert_eq!(data.amount.amount, "42.10");
+/// assert_eq!(data.status, "Pending");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentTransaction {
+	/// Unique ID of transaction
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "transactionId": "7777777777777777777" }
+	/// ```
+	pub transaction_id: String,
+
+	/// Kind of transaction, such as `Subscription`, `Tip`, or `CreatorPayout`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "transactionType": "Subscription" }
+	/// ```
+	pub transaction_type: String,
+
+	/// Money moved by transaction
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "amount": {
+	///     "amount": "8.00",
+	///     "currency": "USD"
+	///   }
+	/// }
+	/// ```
+	pub amount: Price,
+
+	/// Processing state, such as `Completed`, `Pending`, or `Refunded`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "status": "Completed" }
+	/// ```
+	pub status: String,
+
+	/// Date time-stamp of when transaction was made
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2024-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<PaymentTransactionObject> {
+	const GLOBAL_NAME: &'static str = "YTD.payment_transaction";
+}
@@ -0,0 +1,826 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private Periscope, live video broadcasting, data found
+//! under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/periscope-account-information.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-ban-information.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-broadcast-metadata.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-comments-made-by-user.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-expired-broadcasts.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-followers.js
+//!   twitter-<DATE>-<UID>.zip:data/periscope-profile-description.js
+//!
+//! Each file is parsed by a `reader::ArchiveReader` accessor of matching name, for example
+//! `periscope_broadcast_metadata` for `data/periscope-broadcast-metadata.js`
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/periscope-broadcast-metadata.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.periscope_broadcast_metadata().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each broadcast */
+//!         println!("Broadcast index: {index}");
+//!         println!("Broadcast ID: {}", object.periscope_broadcast_metadata.id);
+//!         println!("Title: {}", object.periscope_broadcast_metadata.title);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/periscope-account-information.js` content
+//!
+//! ```javascript
+//! window.YTD.periscope_account_information.part0 = [
+//!   {
+//!     "periscopeAccountInformation" : {
+//!       "id" : "1AAAAAAAAAAAA",
+//!       "username" : "EXAMPLE",
+//!       "displayName" : "Example Name",
+//!       "createdAt" : "2016-01-01T00:00:00.000Z",
+//!       "twitterId" : "111111111",
+//!       "twitterScreenName" : "EXAMPLE"
+//!     }
+//!   }
+//! ]
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/periscope-broadcast-metadata.js` content
+//!
+//! ```javascript
+//! window.YTD.periscope_broadcast_metadata.part0 = [
+//!   {
+//!     "periscopeBroadcastMetadata" : {
+//!       "id" : "1BBBBBBBBBBBB",
+//!       "title" : "Live from the workshop",
+//!       "state" : "ENDED",
+//!       "createdAt" : "2016-02-01T00:00:00.000Z",
+//!       "startedAt" : "2016-02-01T00:00:05.000Z",
+//!       "endedAt" : "2016-02-01T01:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// Periscope account, shared by account information and follower files
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::User;
+///
+/// let json = r#"{
+///   "id": "1AAAAAAAAAAAA",
+///   "username": "EXAMPLE",
+///   "displayName": "Example Name"
+/// }"#;
+///
+/// let data: User = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "1AAAAAAAAAAAA");
+/// assert_eq!(data.username, "EXAMPLE");
+/// assert_eq!(data.display_name, "Example Name");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+	/// Periscope ID of account, which is **not** a Twitter account ID
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1AAAAAAAAAAAA" }
+	/// ```
+	pub id: String,
+
+	/// Periscope handle of account
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "username": "EXAMPLE" }
+	/// ```
+	pub username: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "displayName": "Example Name" }
+	/// ```
+	pub display_name: String,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::AccountInformationObject;
+///
+/// let json = r#"{
+///   "periscopeAccountInformation": {
+///     "id": "1AAAAAAAAAAAA",
+///     "username": "EXAMPLE",
+///     "displayName": "Example Name",
+///     "createdAt": "2016-01-01T00:00:00.000Z",
+///     "twitterId": "111111111",
+///     "twitterScreenName": "EXAMPLE"
+///   }
+/// }"#;
+///
+/// let data: AccountInformationObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_account_information.user.username, "EXAMPLE");
+/// assert_eq!(data.periscope_account_information.created_at.format("%F").to_string(), "2016-01-01");
+/// assert_eq!(data.periscope_account_information.twitter_id, Some("111111111".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeAccountInformation": {
+	///     "id": "1AAAAAAAAAAAA",
+	///     "username": "EXAMPLE",
+	///     "displayName": "Example Name",
+	///     "createdAt": "2016-01-01T00:00:00.000Z",
+	///     "twitterId": "111111111",
+	///     "twitterScreenName": "EXAMPLE"
+	///   }
+	/// }
+	/// ```
+	pub periscope_account_information: AccountInformation,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::AccountInformation;
+///
+/// let json = r#"{
+///   "id": "1AAAAAAAAAAAA",
+///   "username": "EXAMPLE",
+///   "displayName": "Example Name",
+///   "createdAt": "2016-01-01T00:00:00.000Z"
+/// }"#;
+///
+/// let data: AccountInformation = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.user.id, "1AAAAAAAAAAAA");
+/// assert_eq!(data.created_at.format("%F").to_string(), "2016-01-01");
+/// assert!(data.twitter_id.is_none());
+/// assert!(data.twitter_screen_name.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+	/// Properties shared with followers, check `periscope::User` for details
+	#[serde(flatten)]
+	pub user: User,
+
+	/// Date time-stamp of when Periscope account was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2016-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Twitter account ID linked to Periscope account, if any
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{twitter_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "twitterId": "111111111" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub twitter_id: Option<String>,
+
+	/// Twitter handle linked to Periscope account, if any
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "twitterScreenName": "EXAMPLE" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub twitter_screen_name: Option<String>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::BanInformationObject;
+///
+/// let json = r#"{
+///   "periscopeBanInformation": {
+///     "banType": "Temporary",
+///     "reason": "Spam",
+///     "createdAt": "2016-03-01T00:00:00.000Z",
+///     "expiresAt": "2016-03-02T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: BanInformationObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_ban_information.ban_type, "Temporary");
+/// assert_eq!(data.periscope_ban_information.reason, Some("Spam".to_string()));
+/// assert_eq!(data.periscope_ban_information.created_at.format("%F").to_string(), "2016-03-01");
+/// assert_eq!(data.periscope_ban_information.expires_at.unwrap().format("%F").to_string(), "2016-03-02");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BanInformationObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeBanInformation": {
+	///     "banType": "Temporary",
+	///     "reason": "Spam",
+	///     "createdAt": "2016-03-01T00:00:00.000Z",
+	///     "expiresAt": "2016-03-02T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub periscope_ban_information: BanInformation,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::BanInformation;
+///
+/// let json = r#"{
+///   "banType": "Permanent",
+///   "createdAt": "2016-03-01T00:00:00.000Z"
+/// }"#;
+///
+/// let data: BanInformation = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.ban_type, "Permanent");
+/// assert!(data.reason.is_none());
+/// assert!(data.expires_at.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BanInformation {
+	/// Kind of ban, such as `Temporary` or `Permanent`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "banType": "Temporary" }
+	/// ```
+	pub ban_type: String,
+
+	/// Why ban was applied, when Periscope bothered to say
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "reason": "Spam" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+
+	/// Date time-stamp of when ban was applied
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2016-03-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when ban is lifted, absent for permanent bans
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expiresAt": "2016-03-02T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::BroadcastMetadataObject;
+///
+/// let json = r#"{
+///   "periscopeBroadcastMetadata": {
+///     "id": "1BBBBBBBBBBBB",
+///     "title": "Live from the workshop",
+///     "state": "ENDED",
+///     "createdAt": "2016-02-01T00:00:00.000Z",
+///     "startedAt": "2016-02-01T00:00:05.000Z",
+///     "endedAt": "2016-02-01T01:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: BroadcastMetadataObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_broadcast_metadata.id, "1BBBBBBBBBBBB");
+/// assert_eq!(data.periscope_broadcast_metadata.title, "Live from the workshop");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastMetadataObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeBroadcastMetadata": {
+	///     "id": "1BBBBBBBBBBBB",
+	///     "title": "Live from the workshop",
+	///     "state": "ENDED",
+	///     "createdAt": "2016-02-01T00:00:00.000Z",
+	///     "startedAt": "2016-02-01T00:00:05.000Z",
+	///     "endedAt": "2016-02-01T01:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub periscope_broadcast_metadata: BroadcastMetadata,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::periscope::BroadcastMetadata;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2016-02-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "id": "1BBBBBBBBBBBB",
+///   "title": "Scheduled, never started",
+///   "state": "CANCELLED",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: BroadcastMetadata = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "1BBBBBBBBBBBB");
+/// assert_eq!(data.title, "Scheduled, never started");
+/// assert_eq!(data.state, "CANCELLED");
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert!(data.started_at.is_none());
+/// assert!(data.ended_at.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastMetadata {
+	/// Unique ID of broadcast
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://www.pscp.tv/w/{id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1BBBBBBBBBBBB" }
+	/// ```
+	pub id: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "title": "Live from the workshop" }
+	/// ```
+	pub title: String,
+
+	/// Broadcast status, such as `RUNNING` or `ENDED`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "state": "ENDED" }
+	/// ```
+	pub state: String,
+
+	/// Date time-stamp of when broadcast was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2016-02-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when broadcast went live, absent if it never did
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "startedAt": "2016-02-01T00:00:05.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub started_at: Option<DateTime<Utc>>,
+
+	/// Date time-stamp of when broadcast stopped, absent if it never started
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "endedAt": "2016-02-01T01:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::CommentsMadeByUserObject;
+///
+/// let json = r#"{
+///   "periscopeCommentsMadeByUser": {
+///     "broadcastId": "1BBBBBBBBBBBB",
+///     "text": "Great stream!",
+///     "createdAt": "2016-02-01T00:30:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: CommentsMadeByUserObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_comments_made_by_user.broadcast_id, "1BBBBBBBBBBBB");
+/// assert_eq!(data.periscope_comments_made_by_user.text, "Great stream!");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsMadeByUserObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeCommentsMadeByUser": {
+	///     "broadcastId": "1BBBBBBBBBBBB",
+	///     "text": "Great stream!",
+	///     "createdAt": "2016-02-01T00:30:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub periscope_comments_made_by_user: Comment,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::Comment;
+///
+/// let json = r#"{
+///   "broadcastId": "1BBBBBBBBBBBB",
+///   "text": "Great stream!",
+///   "createdAt": "2016-02-01T00:30:00.000Z"
+/// }"#;
+///
+/// let data: Comment = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.broadcast_id, "1BBBBBBBBBBBB");
+/// assert_eq!(data.text, "Great stream!");
+/// assert_eq!(data.created_at.format("%T").to_string(), "00:30:00");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+	/// ID of broadcast commented on, same as `periscope_broadcast_metadata[].periscopeBroadcastMetadata.id`
+	/// when account commented on own broadcast
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "broadcastId": "1BBBBBBBBBBBB" }
+	/// ```
+	pub broadcast_id: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "text": "Great stream!" }
+	/// ```
+	pub text: String,
+
+	/// Date time-stamp of when comment was made
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2016-02-01T00:30:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::ExpiredBroadcastsObject;
+///
+/// let json = r#"{
+///   "periscopeExpiredBroadcasts": {
+///     "broadcastIds": [
+///       "1BBBBBBBBBBBB",
+///       "1CCCCCCCCCCCC"
+///     ],
+///     "reason": "Replay expired"
+///   }
+/// }"#;
+///
+/// let data: ExpiredBroadcastsObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_expired_broadcasts.broadcast_ids.len(), 2);
+/// assert_eq!(data.periscope_expired_broadcasts.reason, Some("Replay expired".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiredBroadcastsObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeExpiredBroadcasts": {
+	///     "broadcastIds": [
+	///       "1BBBBBBBBBBBB",
+	///       "1CCCCCCCCCCCC"
+	///     ],
+	///     "reason": "Replay expired"
+	///   }
+	/// }
+	/// ```
+	pub periscope_expired_broadcasts: ExpiredBroadcasts,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::ExpiredBroadcasts;
+///
+/// let json = r#"{
+///   "broadcastIds": [
+///     "1BBBBBBBBBBBB"
+///   ]
+/// }"#;
+///
+/// let data: ExpiredBroadcasts = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.broadcast_ids, vec!["1BBBBBBBBBBBB"]);
+/// assert!(data.reason.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiredBroadcasts {
+	/// IDs of broadcasts whose replays are no longer available
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "broadcastIds": [
+	///     "1BBBBBBBBBBBB"
+	///   ]
+	/// }
+	/// ```
+	pub broadcast_ids: Vec<String>,
+
+	/// Why broadcasts expired, if stated
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "reason": "Replay expired" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::FollowersObject;
+///
+/// let json = r#"{
+///   "periscopeFollowers": {
+///     "id": "1DDDDDDDDDDDD",
+///     "username": "FOLLOWER",
+///     "displayName": "Follower Name"
+///   }
+/// }"#;
+///
+/// let data: FollowersObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_followers.id, "1DDDDDDDDDDDD");
+/// assert_eq!(data.periscope_followers.username, "FOLLOWER");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct FollowersObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeFollowers": {
+	///     "id": "1DDDDDDDDDDDD",
+	///     "username": "FOLLOWER",
+	///     "displayName": "Follower Name"
+	///   }
+	/// }
+	/// ```
+	pub periscope_followers: User,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::ProfileDescriptionObject;
+///
+/// let json = r#"{
+///   "periscopeProfileDescription": {
+///     "description": "Streaming woodworking projects"
+///   }
+/// }"#;
+///
+/// let data: ProfileDescriptionObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.periscope_profile_description.description, "Streaming woodworking projects");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileDescriptionObject {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "periscopeProfileDescription": {
+	///     "description": "Streaming woodworking projects"
+	///   }
+	/// }
+	/// ```
+	pub periscope_profile_description: ProfileDescription,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::periscope::ProfileDescription;
+///
+/// let json = r#"{
+///   "description": "Streaming woodworking projects"
+/// }"#;
+///
+/// let data: ProfileDescription = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.description, "Streaming woodworking projects");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileDescription {
+	/// Biography shown on Periscope profile
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "description": "Streaming woodworking projects" }
+	/// ```
+	pub description: String,
+}
+
+impl FromJsStr for Vec<AccountInformationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_account_information";
+}
+
+impl FromJsStr for Vec<BanInformationObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_ban_information";
+}
+
+impl FromJsStr for Vec<BroadcastMetadataObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_broadcast_metadata";
+}
+
+impl FromJsStr for Vec<CommentsMadeByUserObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_comments_made_by_user";
+}
+
+impl FromJsStr for Vec<ExpiredBroadcastsObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_expired_broadcasts";
+}
+
+impl FromJsStr for Vec<FollowersObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_followers";
+}
+
+impl FromJsStr for Vec<ProfileDescriptionObject> {
+	const GLOBAL_NAME: &'static str = "YTD.periscope_profile_description";
+}
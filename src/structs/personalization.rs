@@ -54,6 +54,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -448,7 +449,7 @@ pub struct LanguageEntry {
 /// ## Example
 ///
 /// ```
-/// use twitter_archive::structs::personalization::GenderInfo;
+/// use twitter_archive::structs::personalization::{Gender, GenderInfo};
 ///
 /// let json = r#"{
 ///   "gender": "unknown",
@@ -458,8 +459,8 @@ pub struct LanguageEntry {
 /// let data: GenderInfo = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.gender, "unknown");
-/// assert_eq!(data.gender_override, "Borg");
+/// assert_eq!(data.gender, Gender::Unknown);
+/// assert_eq!(data.gender_override, Gender::Custom("Borg".to_string()));
 ///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
@@ -473,14 +474,52 @@ pub struct GenderInfo {
 	/// ```json
 	/// { "gender": "unknown" }
 	/// ```
-	pub gender: String,
+	pub gender: Gender,
 
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// { "genderOverride": "Borg" }
 	/// ```
-	pub gender_override: String,
+	pub gender_override: Gender,
+}
+
+string_enum! {
+	/// Gender inferred by, or declared to, Twitter for demographic targeting
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::personalization::Gender;
+	///
+	/// let data: Gender = serde_json::from_str(r#""female""#).unwrap();
+	/// assert_eq!(data, Gender::Female);
+	///
+	/// let data: Gender = serde_json::from_str(r#""Borg""#).unwrap();
+	/// assert_eq!(data, Gender::Custom("Borg".to_string()));
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#""Borg""#);
+	/// ```
+	pub enum Gender(
+		/// Self-described, or otherwise unrecognized, gender kept verbatim
+		Custom
+	) {
+		/// Not yet inferred nor declared
+		Unknown => "unknown",
+
+		/// ## Example JSON data
+		///
+		/// ```json
+		/// { "gender": "male" }
+		/// ```
+		Male => "male",
+
+		/// ## Example JSON data
+		///
+		/// ```json
+		/// { "gender": "female" }
+		/// ```
+		Female => "female",
+	}
 }
 
 /// ## Example
@@ -764,3 +803,7 @@ pub struct InferredAgeInfo {
 	/// ```
 	pub birth_date: String,
 }
+
+impl FromJsStr for Vec<P13nDataObject> {
+	const GLOBAL_NAME: &'static str = "YTD.personalization";
+}
@@ -49,6 +49,10 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "e164")]
+use crate::convert;
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -114,3 +118,33 @@ pub struct Device {
 	/// ```
 	pub phone_number: String,
 }
+
+#[cfg(feature = "e164")]
+impl Device {
+	/// Typed phone number parsed from `phone_number`
+	///
+	/// Returns `None` for values that are not E.164 formatted.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::phone_number::Device;
+	///
+	/// let json = r#"{
+	///   "phoneNumber": "+15551234567"
+	/// }"#;
+	///
+	/// let data: Device = serde_json::from_str(&json).unwrap();
+	///
+	/// let phone_number = data.e164().unwrap();
+	/// assert_eq!(phone_number.country_code, 1);
+	/// assert_eq!(phone_number.national_number, "5551234567");
+	/// ```
+	pub fn e164(&self) -> Option<convert::e164::PhoneNumber> {
+		convert::e164::parse(&self.phone_number)
+	}
+}
+
+impl FromJsStr for Vec<DeviceObject> {
+	const GLOBAL_NAME: &'static str = "YTD.phone_number";
+}
@@ -0,0 +1,198 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private scheduled product launch data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/product-drop.js
+//!
+//! Dropped items are described by `data/catalog-item.js`, check `structs::catalog_item` for
+//! details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/product-drop.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.product_drop().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each product drop */
+//!         println!("Product drop index: {index}");
+//!         println!("Catalog item ID: {}", object.product_drop.catalog_item_id);
+//!         println!("Drop at: {}", object.product_drop.drop_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/product-drop.js` content
+//!
+//! ```javascript
+//! window.YTD.product_drop.part0 = [
+//!   {
+//!     "productDrop" : {
+//!       "productDropId" : "5555555555555555555",
+//!       "catalogItemId" : "3333333333333333333",
+//!       "price" : {
+//!         "amount" : "19.99",
+//!         "currency" : "USD"
+//!       },
+//!       "dropAt" : "2023-03-01T17:00:00.000Z",
+//!       "createdAt" : "2023-02-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::catalog_item::Price;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::product_drop::ProductDropObject;
+///
+/// let json = r#"{
+///   "productDrop": {
+///     "productDropId": "5555555555555555555",
+///     "catalogItemId": "3333333333333333333",
+///     "price": {
+///       "amount": "19.99",
+///       "currency": "USD"
+///     },
+///     "dropAt": "2023-03-01T17:00:00.000Z",
+///     "createdAt": "2023-02-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ProductDropObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.product_drop.product_drop_id, "5555555555555555555");
+/// assert_eq!(data.product_drop.price.as_ref().unwrap().amount, "19.99");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDropObject {
+	/// Why they wrapped a list of product drops within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "productDrop": {
+	///     "productDropId": "5555555555555555555",
+	///     "catalogItemId": "3333333333333333333",
+	///     "price": {
+	///       "amount": "19.99",
+	///       "currency": "USD"
+	///     },
+	///     "dropAt": "2023-03-01T17:00:00.000Z",
+	///     "createdAt": "2023-02-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub product_drop: ProductDrop,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::product_drop::ProductDrop;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let drop_at_string = "2023-03-01T17:00:00.000Z";
+/// let drop_at_native_time = NaiveDateTime::parse_from_str(&drop_at_string, FORMAT).unwrap();
+/// let drop_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(drop_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "productDropId": "5555555555555555555",
+///   "catalogItemId": "3333333333333333333",
+///   "dropAt": "{drop_at_string}",
+///   "createdAt": "2023-02-01T00:00:00.000Z"
+/// }}"#);
+///
+/// let data: ProductDrop = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.product_drop_id, "5555555555555555555");
+/// assert_eq!(data.catalog_item_id, "3333333333333333333");
+/// assert!(data.price.is_none());
+/// assert_eq!(data.drop_at, drop_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDrop {
+	/// Unique ID of product drop
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "productDropId": "5555555555555555555" }
+	/// ```
+	pub product_drop_id: String,
+
+	/// ID of item within `data/catalog-item.js` being launched
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogItemId": "3333333333333333333" }
+	/// ```
+	pub catalog_item_id: String,
+
+	/// Launch price, absent when same as catalog item price
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "price": {
+	///     "amount": "19.99",
+	///     "currency": "USD"
+	///   }
+	/// }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub price: Option<Price>,
+
+	/// Date time-stamp of when item becomes available to buy
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "dropAt": "2023-03-01T17:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub drop_at: DateTime<Utc>,
+
+	/// Date time-stamp of when product drop was scheduled
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-02-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<ProductDropObject> {
+	const GLOBAL_NAME: &'static str = "YTD.product_drop";
+}
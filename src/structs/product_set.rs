@@ -0,0 +1,198 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private shop product grouping data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/product-set.js
+//!
+//! Grouped items are described by `data/catalog-item.js`, check `structs::catalog_item` for
+//! details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/product-set.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.product_set().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each product set */
+//!         println!("Product set index: {index}");
+//!         println!("Name: {}", object.product_set.name);
+//!         println!("Item count: {}", object.product_set.catalog_item_ids.len());
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/product-set.js` content
+//!
+//! ```javascript
+//! window.YTD.product_set.part0 = [
+//!   {
+//!     "productSet" : {
+//!       "productSetId" : "6666666666666666666",
+//!       "catalogId" : "2222222222222222222",
+//!       "name" : "Summer collection",
+//!       "catalogItemIds" : [
+//!         "3333333333333333333",
+//!         "4444444444444444444"
+//!       ],
+//!       "createdAt" : "2023-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::product_set::ProductSetObject;
+///
+/// let json = r#"{
+///   "productSet": {
+///     "productSetId": "6666666666666666666",
+///     "catalogId": "2222222222222222222",
+///     "name": "Summer collection",
+///     "catalogItemIds": [
+///       "3333333333333333333",
+///       "4444444444444444444"
+///     ],
+///     "createdAt": "2023-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ProductSetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.product_set.product_set_id, "6666666666666666666");
+/// assert_eq!(data.product_set.catalog_item_ids.len(), 2);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProductSetObject {
+	/// Why they wrapped a list of product sets within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "productSet": {
+	///     "productSetId": "6666666666666666666",
+	///     "catalogId": "2222222222222222222",
+	///     "name": "Summer collection",
+	///     "catalogItemIds": [
+	///       "3333333333333333333",
+	///       "4444444444444444444"
+	///     ],
+	///     "createdAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub product_set: ProductSet,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::product_set::ProductSet;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "productSetId": "6666666666666666666",
+///   "catalogId": "2222222222222222222",
+///   "name": "Empty set",
+///   "catalogItemIds": [],
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ProductSet = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.product_set_id, "6666666666666666666");
+/// assert_eq!(data.catalog_id, "2222222222222222222");
+/// assert_eq!(data.name, "Empty set");
+/// assert!(data.catalog_item_ids.is_empty());
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProductSet {
+	/// Unique ID of product set
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "productSetId": "6666666666666666666" }
+	/// ```
+	pub product_set_id: String,
+
+	/// ID of catalog within `data/commerce-catalog.js` that set belongs to
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogId": "2222222222222222222" }
+	/// ```
+	pub catalog_id: String,
+
+	/// Name of set as shown to shoppers
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "name": "Summer collection" }
+	/// ```
+	pub name: String,
+
+	/// IDs of items within `data/catalog-item.js`, in display order
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "catalogItemIds": [
+	///     "3333333333333333333",
+	///     "4444444444444444444"
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub catalog_item_ids: Vec<String>,
+
+	/// Date time-stamp of when set was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<ProductSetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.product_set";
+}
@@ -0,0 +1,232 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private professional, creator or business, account data
+//! found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/professional-data.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/professional-data.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.professional_data().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each professional profile */
+//!         println!("Professional data index: {index}");
+//!         println!("Professional type: {}", object.professional_data.professional_type);
+//!         for category in object.professional_data.categories.iter() {
+//!             println!("Category: {}", category.category_name);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/professional-data.js` content
+//!
+//! ```javascript
+//! window.YTD.professional_data.part0 = [
+//!   {
+//!     "professionalData" : {
+//!       "professionalId" : "1111111111111111111",
+//!       "professionalType" : "Creator",
+//!       "categories" : [
+//!         {
+//!           "categoryId" : "1009",
+//!           "categoryName" : "Software Engineer"
+//!         }
+//!       ],
+//!       "createdAt" : "2022-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::professional_data::ProfessionalDataObject;
+///
+/// let json = r#"{
+///   "professionalData": {
+///     "professionalId": "1111111111111111111",
+///     "professionalType": "Creator",
+///     "categories": [
+///       {
+///         "categoryId": "1009",
+///         "categoryName": "Software Engineer"
+///       }
+///     ],
+///     "createdAt": "2022-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ProfessionalDataObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.professional_data.professional_id, "1111111111111111111");
+/// assert_eq!(data.professional_data.categories[0].category_name, "Software Engineer");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProfessionalDataObject {
+	/// Why they wrapped professional data within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "professionalData": {
+	///     "professionalId": "1111111111111111111",
+	///     "professionalType": "Creator",
+	///     "categories": [
+	///       {
+	///         "categoryId": "1009",
+	///         "categoryName": "Software Engineer"
+	///       }
+	///     ],
+	///     "createdAt": "2022-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub professional_data: ProfessionalData,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::professional_data::ProfessionalData;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2022-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "professionalId": "1111111111111111111",
+///   "professionalType": "Business",
+///   "categories": [],
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ProfessionalData = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.professional_id, "1111111111111111111");
+/// assert_eq!(data.professional_type, "Business");
+/// assert!(data.categories.is_empty());
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProfessionalData {
+	/// Unique ID of professional profile
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "professionalId": "1111111111111111111" }
+	/// ```
+	pub professional_id: String,
+
+	/// Kind of professional account, such as `Creator` or `Business`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "professionalType": "Creator" }
+	/// ```
+	pub professional_type: String,
+
+	/// Categories shown on profile
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "categories": [
+	///     {
+	///       "categoryId": "1009",
+	///       "categoryName": "Software Engineer"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub categories: Vec<Category>,
+
+	/// Date time-stamp of when account became professional
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2022-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::professional_data::Category;
+///
+/// let json = r#"{
+///   "categoryId": "1009",
+///   "categoryName": "Software Engineer"
+/// }"#;
+///
+/// let data: Category = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.category_id, "1009");
+/// assert_eq!(data.category_name, "Software Engineer");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "categoryId": "1009" }
+	/// ```
+	pub category_id: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "categoryName": "Software Engineer" }
+	/// ```
+	pub category_name: String,
+}
+
+impl FromJsStr for Vec<ProfessionalDataObject> {
+	const GLOBAL_NAME: &'static str = "YTD.professional_data";
+}
@@ -53,9 +53,14 @@
 //! ]
 //! ```
 
+use std::borrow::Cow;
+
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::convert;
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -201,3 +206,30 @@ pub struct ProfileDescription {
 	/// ```
 	pub location: String,
 }
+
+impl ProfileDescription {
+	/// Content of `bio` with HTML entities, e.g. `&amp;`, decoded
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::profile::ProfileDescription;
+	///
+	/// let json = r#"{
+	///   "bio": "Rust &amp; &lt;coffee&gt;",
+	///   "website": "",
+	///   "location": ""
+	/// }"#;
+	///
+	/// let data: ProfileDescription = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.bio_decoded(), "Rust & <coffee>");
+	/// ```
+	pub fn bio_decoded(&self) -> Cow<'_, str> {
+		convert::html_entities::decode(&self.bio)
+	}
+}
+
+impl FromJsStr for Vec<ProfileObject> {
+	const GLOBAL_NAME: &'static str = "YTD.profile";
+}
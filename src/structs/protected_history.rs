@@ -0,0 +1,147 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private history of protecting, and unprotecting, Tweets
+//! found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/protected-history.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/protected-history.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.protected_history().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each toggle */
+//!         println!("Protected history index: {index}");
+//!         println!("Time stamp: {}", object.protected_history.protected_at);
+//!         println!("Action: {}", object.protected_history.action);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/protected-history.js` content
+//!
+//! ```javascript
+//! window.YTD.protected_history.part0 = [
+//!   {
+//!     "protectedHistory" : {
+//!       "protectedAt" : "2020-01-01T00:00:00.000Z",
+//!       "action" : "Protect"
+//!     }
+//!   },
+//!   {
+//!     "protectedHistory" : {
+//!       "protectedAt" : "2021-01-01T00:00:00.000Z",
+//!       "action" : "Unprotect"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::protected_history::ProtectedHistoryObject;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let protected_at_string = "2020-01-01T00:00:00.000Z";
+/// let protected_at_native_time = NaiveDateTime::parse_from_str(&protected_at_string, FORMAT).unwrap();
+/// let protected_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(protected_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "protectedHistory": {{
+///     "protectedAt": "{protected_at_string}",
+///     "action": "Protect"
+///   }}
+/// }}"#);
+///
+/// let data: ProtectedHistoryObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.protected_history.protected_at, protected_at_date_time);
+/// assert_eq!(data.protected_history.action, "Protect");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectedHistoryObject {
+	/// Why they wrapped a list of toggles within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "protectedHistory": {
+	///     "protectedAt": "2020-01-01T00:00:00.000Z",
+	///     "action": "Protect"
+	///   }
+	/// }
+	/// ```
+	pub protected_history: ProtectedHistory,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::protected_history::ProtectedHistory;
+///
+/// let json = r#"{
+///   "protectedAt": "2021-01-01T00:00:00.000Z",
+///   "action": "Unprotect"
+/// }"#;
+///
+/// let data: ProtectedHistory = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.protected_at.format("%F").to_string(), "2021-01-01");
+/// assert_eq!(data.action, "Unprotect");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectedHistory {
+	/// Date time-stamp of when Tweets were protected or unprotected
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "protectedAt": "2020-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub protected_at: DateTime<Utc>,
+
+	/// What happened, such as `Protect` or `Unprotect`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "action": "Protect" }
+	/// ```
+	pub action: String,
+}
+
+impl FromJsStr for Vec<ProtectedHistoryObject> {
+	const GLOBAL_NAME: &'static str = "YTD.protected_history";
+}
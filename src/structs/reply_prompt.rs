@@ -0,0 +1,175 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private reply prompt, "want to review this before
+//! Tweeting?", data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/reply-prompt.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/reply-prompt.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.reply_prompt().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each prompt */
+//!         println!("Reply prompt index: {index}");
+//!         println!("Prompt type: {}", object.reply_prompt.prompt_type);
+//!         println!("Action: {}", object.reply_prompt.action);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/reply-prompt.js` content
+//!
+//! ```javascript
+//! window.YTD.reply_prompt.part0 = [
+//!   {
+//!     "replyPrompt" : {
+//!       "inReplyToTweetId" : "1111111111111111111",
+//!       "promptType" : "OffensiveReply",
+//!       "action" : "Edited",
+//!       "createdAt" : "2022-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::reply_prompt::ReplyPromptObject;
+///
+/// let json = r#"{
+///   "replyPrompt": {
+///     "inReplyToTweetId": "1111111111111111111",
+///     "promptType": "OffensiveReply",
+///     "action": "Edited",
+///     "createdAt": "2022-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ReplyPromptObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.reply_prompt.prompt_type, "OffensiveReply");
+/// assert_eq!(data.reply_prompt.action, "Edited");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyPromptObject {
+	/// Why they wrapped a list of prompts within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "replyPrompt": {
+	///     "inReplyToTweetId": "1111111111111111111",
+	///     "promptType": "OffensiveReply",
+	///     "action": "Edited",
+	///     "createdAt": "2022-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub reply_prompt: ReplyPrompt,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::reply_prompt::ReplyPrompt;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2022-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "promptType": "OffensiveReply",
+///   "action": "Sent",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ReplyPrompt = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert!(data.in_reply_to_tweet_id.is_none());
+/// assert_eq!(data.prompt_type, "OffensiveReply");
+/// assert_eq!(data.action, "Sent");
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyPrompt {
+	/// ID of Tweet that was being replied to, absent within some archives
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{in_reply_to_tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "inReplyToTweetId": "1111111111111111111" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub in_reply_to_tweet_id: Option<String>,
+
+	/// Why prompt was shown, such as `OffensiveReply`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "promptType": "OffensiveReply" }
+	/// ```
+	pub prompt_type: String,
+
+	/// What account did after being prompted, such as `Edited`, `Deleted`, or `Sent`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "action": "Edited" }
+	/// ```
+	pub action: String,
+
+	/// Date time-stamp of when prompt was shown
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2022-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<ReplyPromptObject> {
+	const GLOBAL_NAME: &'static str = "YTD.reply_prompt";
+}
@@ -0,0 +1,133 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private saved search data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/saved-search.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/saved-search.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.saved_search().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each saved search */
+//!         println!("Saved search index: {index}");
+//!         println!("Query: {}", object.saved_search.query);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/saved-search.js` content
+//!
+//! ```javascript
+//! window.YTD.saved_search.part0 = [
+//!   {
+//!     "savedSearch" : {
+//!       "savedSearchId" : "1111111111111111111",
+//!       "query" : "RustLang -filter:replies"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::saved_search::SavedSearchObject;
+///
+/// let json = r#"{
+///   "savedSearch": {
+///     "savedSearchId": "1111111111111111111",
+///     "query": "RustLang -filter:replies"
+///   }
+/// }"#;
+///
+/// let data: SavedSearchObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.saved_search.saved_search_id, "1111111111111111111");
+/// assert_eq!(data.saved_search.query, "RustLang -filter:replies");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchObject {
+	/// Why they wrapped a list of saved searches within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "savedSearch": {
+	///     "savedSearchId": "1111111111111111111",
+	///     "query": "RustLang -filter:replies"
+	///   }
+	/// }
+	/// ```
+	pub saved_search: SavedSearch,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::saved_search::SavedSearch;
+///
+/// let json = r#"{
+///   "savedSearchId": "2222222222222222222",
+///   "query": "from:EXAMPLE since:2023-01-01"
+/// }"#;
+///
+/// let data: SavedSearch = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.saved_search_id, "2222222222222222222");
+/// assert_eq!(data.query, "from:EXAMPLE since:2023-01-01");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+	/// Unique ID of saved search
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "savedSearchId": "1111111111111111111" }
+	/// ```
+	pub saved_search_id: String,
+
+	/// Search query, including any advanced search operators
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/search?q={query}
+	///
+	/// > Note; `query` must be URL encoded before being placed within above URL
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "query": "RustLang -filter:replies" }
+	/// ```
+	pub query: String,
+}
+
+impl FromJsStr for Vec<SavedSearchObject> {
+	const GLOBAL_NAME: &'static str = "YTD.saved_search";
+}
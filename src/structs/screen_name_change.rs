@@ -58,6 +58,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -230,3 +231,7 @@ pub struct ScreenNameChange {
 	/// ```
 	pub changed_to: String,
 }
+
+impl FromJsStr for Vec<ScreenNameChangeObject> {
+	const GLOBAL_NAME: &'static str = "YTD.screen_name_change";
+}
@@ -0,0 +1,187 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private profile shop module data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/shop-module.js
+//!
+//! Catalogs shown by each module are described by `data/commerce-catalog.js`, check
+//! `structs::commerce_catalog` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/shop-module.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.shop_module().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each shop module */
+//!         println!("Shop module index: {index}");
+//!         println!("Module ID: {}", object.shop_module.module_id);
+//!         println!("Enabled: {}", object.shop_module.is_enabled);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/shop-module.js` content
+//!
+//! ```javascript
+//! window.YTD.shop_module.part0 = [
+//!   {
+//!     "shopModule" : {
+//!       "moduleId" : "3333333333333333333",
+//!       "profileUserId" : "1111111111111111111",
+//!       "catalogId" : "2222222222222222222",
+//!       "isEnabled" : true,
+//!       "createdAt" : "2023-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::shop_module::ShopModuleObject;
+///
+/// let json = r#"{
+///   "shopModule": {
+///     "moduleId": "3333333333333333333",
+///     "profileUserId": "1111111111111111111",
+///     "catalogId": "2222222222222222222",
+///     "isEnabled": true,
+///     "createdAt": "2023-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ShopModuleObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.shop_module.module_id, "3333333333333333333");
+/// assert_eq!(data.shop_module.is_enabled, true);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ShopModuleObject {
+	/// Why they wrapped a list of shop modules within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "shopModule": {
+	///     "moduleId": "3333333333333333333",
+	///     "profileUserId": "1111111111111111111",
+	///     "catalogId": "2222222222222222222",
+	///     "isEnabled": true,
+	///     "createdAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub shop_module: ShopModule,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::shop_module::ShopModule;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "moduleId": "3333333333333333333",
+///   "profileUserId": "1111111111111111111",
+///   "isEnabled": false,
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: ShopModule = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.module_id, "3333333333333333333");
+/// assert_eq!(data.profile_user_id, "1111111111111111111");
+/// assert!(data.catalog_id.is_none());
+/// assert_eq!(data.is_enabled, false);
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ShopModule {
+	/// Unique ID of shop module
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "moduleId": "3333333333333333333" }
+	/// ```
+	pub module_id: String,
+
+	/// Account ID of profile the shop module is shown on
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{profile_user_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "profileUserId": "1111111111111111111" }
+	/// ```
+	pub profile_user_id: String,
+
+	/// ID of catalog, from `data/commerce-catalog.js`, shown within module
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogId": "2222222222222222222" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub catalog_id: Option<String>,
+
+	/// Is module currently visible on profile
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "isEnabled": true }
+	/// ```
+	pub is_enabled: bool,
+
+	/// Date time-stamp of when shop module was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<ShopModuleObject> {
+	const GLOBAL_NAME: &'static str = "YTD.shop_module";
+}
@@ -0,0 +1,169 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private connected Shopify account data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/shopify-account.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/shopify-account.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.shopify_account().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Shopify account */
+//!         println!("Shopify account index: {index}");
+//!         println!("Store domain: {}", object.shopify_account.store_domain);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/shopify-account.js` content
+//!
+//! ```javascript
+//! window.YTD.shopify_account.part0 = [
+//!   {
+//!     "shopifyAccount" : {
+//!       "shopifyAccountId" : "44444444",
+//!       "storeDomain" : "example.myshopify.com",
+//!       "catalogId" : "2222222222222222222",
+//!       "connectedAt" : "2023-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::shopify_account::ShopifyAccountObject;
+///
+/// let json = r#"{
+///   "shopifyAccount": {
+///     "shopifyAccountId": "44444444",
+///     "storeDomain": "example.myshopify.com",
+///     "catalogId": "2222222222222222222",
+///     "connectedAt": "2023-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: ShopifyAccountObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.shopify_account.shopify_account_id, "44444444");
+/// assert_eq!(data.shopify_account.store_domain, "example.myshopify.com");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ShopifyAccountObject {
+	/// Why they wrapped a list of Shopify accounts within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "shopifyAccount": {
+	///     "shopifyAccountId": "44444444",
+	///     "storeDomain": "example.myshopify.com",
+	///     "catalogId": "2222222222222222222",
+	///     "connectedAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub shopify_account: ShopifyAccount,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::shopify_account::ShopifyAccount;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let connected_at_string = "2023-01-01T00:00:00.000Z";
+/// let connected_at_native_time = NaiveDateTime::parse_from_str(&connected_at_string, FORMAT).unwrap();
+/// let connected_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(connected_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "shopifyAccountId": "44444444",
+///   "storeDomain": "example.myshopify.com",
+///   "connectedAt": "{connected_at_string}"
+/// }}"#);
+///
+/// let data: ShopifyAccount = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.shopify_account_id, "44444444");
+/// assert_eq!(data.store_domain, "example.myshopify.com");
+/// assert!(data.catalog_id.is_none());
+/// assert_eq!(data.connected_at, connected_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct ShopifyAccount {
+	/// ID of account on Shopify side of integration
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "shopifyAccountId": "44444444" }
+	/// ```
+	pub shopify_account_id: String,
+
+	/// Domain of connected Shopify store
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://{store_domain}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "storeDomain": "example.myshopify.com" }
+	/// ```
+	pub store_domain: String,
+
+	/// ID of catalog, from `data/commerce-catalog.js`, that store products are synced into
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "catalogId": "2222222222222222222" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub catalog_id: Option<String>,
+
+	/// Date time-stamp of when Shopify store was connected
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "connectedAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub connected_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<ShopifyAccountObject> {
+	const GLOBAL_NAME: &'static str = "YTD.shopify_account";
+}
@@ -0,0 +1,155 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private Safety Mode, automatic and temporary, blocking
+//! data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/smartblock.js
+//!
+//! Entries share account fields with `data/block.js`, check `structs::block` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/smartblock.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.smartblock().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each automatic block */
+//!         println!("Smartblock index: {index}");
+//!         println!("Account ID: {}", object.smartblock.blocking.account_id);
+//!         println!("Expires at: {:?}", object.smartblock.expires_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/smartblock.js` content
+//!
+//! ```javascript
+//! window.YTD.smartblock.part0 = [
+//!   {
+//!     "smartblock" : {
+//!       "accountId" : "3333333333333333333",
+//!       "userLink" : "https://twitter.com/intent/user?user_id=3333333333333333333",
+//!       "expiresAt" : "2022-01-08T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+use crate::structs::block::Blocking;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::smartblock::SmartblockObject;
+///
+/// let json = r#"{
+///   "smartblock": {
+///     "accountId": "3333333333333333333",
+///     "userLink": "https://twitter.com/intent/user?user_id=3333333333333333333",
+///     "expiresAt": "2022-01-08T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: SmartblockObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.smartblock.blocking.account_id, "3333333333333333333");
+/// assert!(data.smartblock.expires_at.is_some());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct SmartblockObject {
+	/// Why they wrapped a list of automatic blocks within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "smartblock": {
+	///     "accountId": "3333333333333333333",
+	///     "userLink": "https://twitter.com/intent/user?user_id=3333333333333333333",
+	///     "expiresAt": "2022-01-08T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub smartblock: Smartblock,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::smartblock::Smartblock;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let expires_at_string = "2022-01-08T00:00:00.000Z";
+/// let expires_at_native_time = NaiveDateTime::parse_from_str(&expires_at_string, FORMAT).unwrap();
+/// let expires_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(expires_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "accountId": "3333333333333333333",
+///   "userLink": "https://twitter.com/intent/user?user_id=3333333333333333333",
+///   "expiresAt": "{expires_at_string}"
+/// }}"#);
+///
+/// let data: Smartblock = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.blocking.account_id, "3333333333333333333");
+/// assert_eq!(data.blocking.user_link, "https://twitter.com/intent/user?user_id=3333333333333333333");
+/// assert_eq!(data.expires_at, Some(expires_at_date_time));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Smartblock {
+	/// Same `accountId` and `userLink` properties found within `data/block.js`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "accountId": "3333333333333333333",
+	///   "userLink": "https://twitter.com/intent/user?user_id=3333333333333333333"
+	/// }
+	/// ```
+	#[serde(flatten)]
+	pub blocking: Blocking,
+
+	/// Date time-stamp of when automatic block is lifted, absent or empty if unknown
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expiresAt": "2022-01-08T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl FromJsStr for Vec<SmartblockObject> {
+	const GLOBAL_NAME: &'static str = "YTD.smartblock";
+}
@@ -0,0 +1,263 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private data about hosted Spaces, live audio
+//! conversations, found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/spaces-metadata.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/spaces-metadata.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.spaces_metadata().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Space */
+//!         println!("Space index: {index}");
+//!         println!("Space ID: {}", object.space.id);
+//!         println!("Title: {:?}", object.space.title);
+//!         println!("Started at: {:?}", object.space.started_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/spaces-metadata.js` content
+//!
+//! ```javascript
+//! window.YTD.spaces_metadata.part0 = [
+//!   {
+//!     "space" : {
+//!       "id" : "1AbCdEfGhIjKl",
+//!       "creatorUserId" : "1111111111111111111",
+//!       "title" : "Rust office hours",
+//!       "state" : "Ended",
+//!       "hostUserIds" : [
+//!         "1111111111111111111"
+//!       ],
+//!       "createdAt" : "2023-01-01T00:00:00.000Z",
+//!       "startedAt" : "2023-01-01T00:05:00.000Z",
+//!       "endedAt" : "2023-01-01T01:05:00.000Z",
+//!       "isSpaceAvailableForReplay" : true
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::spaces_metadata::SpacesMetadataObject;
+///
+/// let json = r#"{
+///   "space": {
+///     "id": "1AbCdEfGhIjKl",
+///     "creatorUserId": "1111111111111111111",
+///     "title": "Rust office hours",
+///     "state": "Ended",
+///     "hostUserIds": [
+///       "1111111111111111111"
+///     ],
+///     "createdAt": "2023-01-01T00:00:00.000Z",
+///     "startedAt": "2023-01-01T00:05:00.000Z",
+///     "endedAt": "2023-01-01T01:05:00.000Z",
+///     "isSpaceAvailableForReplay": true
+///   }
+/// }"#;
+///
+/// let data: SpacesMetadataObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.space.id, "1AbCdEfGhIjKl");
+/// assert_eq!(data.space.title, Some("Rust office hours".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct SpacesMetadataObject {
+	/// Why they wrapped a list of Spaces within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "space": {
+	///     "id": "1AbCdEfGhIjKl",
+	///     "creatorUserId": "1111111111111111111",
+	///     "title": "Rust office hours",
+	///     "state": "Ended",
+	///     "hostUserIds": [
+	///       "1111111111111111111"
+	///     ],
+	///     "createdAt": "2023-01-01T00:00:00.000Z",
+	///     "startedAt": "2023-01-01T00:05:00.000Z",
+	///     "endedAt": "2023-01-01T01:05:00.000Z",
+	///     "isSpaceAvailableForReplay": true
+	///   }
+	/// }
+	/// ```
+	pub space: Space,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::spaces_metadata::Space;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "id": "1AbCdEfGhIjKl",
+///   "creatorUserId": "1111111111111111111",
+///   "state": "Canceled",
+///   "hostUserIds": [],
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: Space = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "1AbCdEfGhIjKl");
+/// assert_eq!(data.creator_user_id, "1111111111111111111");
+/// assert!(data.title.is_none());
+/// assert_eq!(data.state, "Canceled");
+/// assert!(data.host_user_ids.is_empty());
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert!(data.started_at.is_none());
+/// assert!(data.ended_at.is_none());
+/// assert!(data.is_space_available_for_replay.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Space {
+	/// Unique ID of Space
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/spaces/{id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1AbCdEfGhIjKl" }
+	/// ```
+	pub id: String,
+
+	/// Account ID of who created the Space
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{creator_user_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "creatorUserId": "1111111111111111111" }
+	/// ```
+	pub creator_user_id: String,
+
+	/// Title shown to listeners, absent if none was set
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "title": "Rust office hours" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+
+	/// Last known state of Space, such as `Running`, `Ended`, or `Canceled`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "state": "Ended" }
+	/// ```
+	pub state: String,
+
+	/// Account IDs of hosts and co-hosts
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "hostUserIds": [ "1111111111111111111" ] }
+	/// ```
+	#[serde(default)]
+	pub host_user_ids: Vec<String>,
+
+	/// Date time-stamp of when Space was created, or scheduled
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of when Space went live, absent if it never did
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "startedAt": "2023-01-01T00:05:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub started_at: Option<DateTime<Utc>>,
+
+	/// Date time-stamp of when Space ended
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "endedAt": "2023-01-01T01:05:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub ended_at: Option<DateTime<Utc>>,
+
+	/// Was recording kept for listeners to replay
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "isSpaceAvailableForReplay": true }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_space_available_for_replay: Option<bool>,
+}
+
+impl FromJsStr for Vec<SpacesMetadataObject> {
+	const GLOBAL_NAME: &'static str = "YTD.spaces_metadata";
+}
@@ -0,0 +1,185 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private single-sign-on, Google or Apple, association
+//! data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/sso.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/sso.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.sso().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each association */
+//!         println!("SSO index: {index}");
+//!         println!("Provider: {}", object.sso.sso_provider);
+//!         println!("Associated at: {}", object.sso.associated_at);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/sso.js` content
+//!
+//! ```javascript
+//! window.YTD.sso.part0 = [
+//!   {
+//!     "sso" : {
+//!       "ssoId" : "123456789012345678901",
+//!       "ssoProvider" : "Google",
+//!       "ssoEmail" : "name@example.com",
+//!       "associationMethodType" : "Signup",
+//!       "associatedAt" : "2021-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::sso::SsoObject;
+///
+/// let json = r#"{
+///   "sso": {
+///     "ssoId": "123456789012345678901",
+///     "ssoProvider": "Google",
+///     "ssoEmail": "name@example.com",
+///     "associationMethodType": "Signup",
+///     "associatedAt": "2021-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: SsoObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.sso.sso_id, "123456789012345678901");
+/// assert_eq!(data.sso.sso_provider, "Google");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct SsoObject {
+	/// Why they wrapped a list of associations within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "sso": {
+	///     "ssoId": "123456789012345678901",
+	///     "ssoProvider": "Google",
+	///     "ssoEmail": "name@example.com",
+	///     "associationMethodType": "Signup",
+	///     "associatedAt": "2021-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub sso: Sso,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::sso::Sso;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let associated_at_string = "2021-01-01T00:00:00.000Z";
+/// let associated_at_native_time = NaiveDateTime::parse_from_str(&associated_at_string, FORMAT).unwrap();
+/// let associated_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(associated_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "ssoId": "000000.abcdef0123456789.0000",
+///   "ssoProvider": "Apple",
+///   "associatedAt": "{associated_at_string}"
+/// }}"#);
+///
+/// let data: Sso = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.sso_id, "000000.abcdef0123456789.0000");
+/// assert_eq!(data.sso_provider, "Apple");
+/// assert!(data.sso_email.is_none());
+/// assert!(data.association_method_type.is_none());
+/// assert_eq!(data.associated_at, associated_at_date_time);
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct Sso {
+	/// ID of account on identity provider side of association
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "ssoId": "123456789012345678901" }
+	/// ```
+	pub sso_id: String,
+
+	/// Identity provider, such as `Google` or `Apple`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "ssoProvider": "Google" }
+	/// ```
+	pub sso_provider: String,
+
+	/// E-mail address shared by identity provider, Apple may hide this behind a relay address
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "ssoEmail": "name@example.com" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sso_email: Option<String>,
+
+	/// How association was made, such as `Signup` or `Login`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "associationMethodType": "Signup" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub association_method_type: Option<String>,
+
+	/// Date time-stamp of when association was made
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "associatedAt": "2021-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub associated_at: DateTime<Utc>,
+}
+
+impl FromJsStr for Vec<SsoObject> {
+	const GLOBAL_NAME: &'static str = "YTD.sso";
+}
@@ -78,6 +78,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -157,6 +158,9 @@ pub struct TweetObject {
 /// assert_eq!(data.user_id, "435455769");
 /// assert_eq!(data.created_at, created_at_date_time);
 ///
+/// // Snowflake time-stamp agrees with `created_at`, to the second
+/// assert_eq!(data.tweet_id_timestamp().unwrap().timestamp(), created_at_date_time.timestamp());
+///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
@@ -198,3 +202,15 @@ pub struct Tweet {
 	#[serde(with = "convert::created_at")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl Tweet {
+	/// Creation date time-stamp embedded within `tweet_id`, check `convert::snowflake` for
+	/// details
+	pub fn tweet_id_timestamp(&self) -> Option<DateTime<Utc>> {
+		convert::snowflake::timestamp_from_str(&self.tweet_id)
+	}
+}
+
+impl FromJsStr for Vec<TweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.tweet_headers";
+}
@@ -90,6 +90,8 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -268,3 +270,7 @@ pub struct DeckColumn {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub query: Option<String>,
 }
+
+impl FromJsStr for Vec<DeckObject> {
+	const GLOBAL_NAME: &'static str = "YTD.tweetdeck";
+}
@@ -128,11 +128,20 @@
 //! +window.YTD.deleted_tweets.part0
 //! ```
 
+use std::borrow::Cow;
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use crate::convert;
+use crate::convert::geo::GeoPoint;
+use crate::parse::FromJsStr;
+use crate::structs::url_entity::UrlEntity;
+use crate::structs::{manifest, note_tweet};
+use crate::thread::ThreadIndex;
 
 /// ## Example
 ///
@@ -427,6 +436,8 @@ pub struct Tweet {
 
 	/// URL that almost, if not, always points to `"<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>"`
 	///
+	/// Check `Tweet::source_client` for parsed client application name and link
+	///
 	/// ## Example JSON data
 	///
 	/// ```json
@@ -465,6 +476,38 @@ pub struct Tweet {
 	/// ```
 	pub entities: TweetEntities,
 
+	/// All media attached to Tweet, only present for Tweets with photos, videos, or animated GIFs
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "extended_entities": {
+	///     "media": [
+	///       {
+	///         "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/photo/1",
+	///         "indices": ["3", "26"],
+	///         "url": "https://t.co/AbCdEfGhIj",
+	///         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "id_str": "1690395366854377472",
+	///         "id": "1690395366854377472",
+	///         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "sizes": {
+	///           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///           "small": { "w": "680", "h": "383", "resize": "fit" },
+	///           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///           "large": { "w": "1920", "h": "1080", "resize": "fit" }
+	///         },
+	///         "type": "photo",
+	///         "display_url": "pic.twitter.com/AbCdEfGhIj"
+	///       }
+	///     ]
+	///   }
+	/// }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub extended_entities: Option<TweetExtendedEntities>,
+
 	/// Indexes of beginning and end of Tweeted text
 	///
 	/// ## Example JSON data
@@ -609,7 +652,8 @@ pub struct Tweet {
 	/// ```
 	pub full_text: String,
 
-	/// Two letter string representing language Tweet was authored in (e.g. "en")
+	/// Two letter string representing language Tweet was authored in (e.g. "en"), check
+	/// `Tweet::language` for typed value
 	///
 	/// ## Example JSON data
 	///
@@ -648,426 +692,2929 @@ pub struct Tweet {
 	/// ```
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub in_reply_to_user_id_str: Option<String>,
-}
 
-/// ## Example
-///
-/// ```
-/// use chrono::{DateTime, NaiveDateTime, Utc};
-///
-/// use twitter_archive::structs::tweets::TweetEditInfo;
-///
-/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
-///
-/// let editable_until_string = "2023-08-12T17:10:37.000Z";
-/// let editable_until_native_time = NaiveDateTime::parse_from_str(&editable_until_string, FORMAT).unwrap();
-/// let editable_until_date_time = DateTime::<Utc>::from_naive_utc_and_offset(editable_until_native_time, Utc);
-///
-/// let json = format!(r#"{{
-///   "initial": {{
-///     "editTweetIds": [
-///       "1690395372546301952"
-///     ],
-///     "editableUntil": "{editable_until_string}",
-///     "editsRemaining": "5",
-///     "isEditEligible": true
-///   }}
-/// }}"#);
-///
-/// let data: TweetEditInfo = serde_json::from_str(&json).unwrap();
-///
-/// // De-serialized properties
-/// assert_eq!(data.initial.edit_tweet_ids, ["1690395372546301952"]);
-/// assert_eq!(data.initial.editable_until, editable_until_date_time);
-/// assert_eq!(data.initial.edits_remaining, 5);
-/// assert_eq!(data.initial.is_edit_eligible, true);
-///
-/// // Re-serialize is equivalent to original data
-/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
-/// ```
-#[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct TweetEditInfo {
-	/// Object/data-structure containing information about edited tweets
+	/// Latitude and longitude of geo-tagged Tweets, see `Tweet::location` for preferred point
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// {
-	///  "initial": {
-	///    "editTweetIds": ["1690395372546301952"],
-	///    "editableUntil": "2023-08-12T17:10:37.000Z",
-	///    "editsRemaining": "5",
-	///    "isEditEligible": true
-	///  }
+	///   "geo": {
+	///     "type": "Point",
+	///     "coordinates": ["37.7749", "-122.4194"]
+	///   }
 	/// }
 	/// ```
-	pub initial: TweetEditInfoInitial,
-}
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub geo: Option<TweetGeo>,
 
-/// Whom-ever originally added the edit feature seems to have said, "F existing conventions, we're
-/// doing this camel style" X-D
-///
-/// ```
-/// use chrono::{DateTime, NaiveDateTime, Utc};
-///
-/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
-/// use twitter_archive::structs::tweets::TweetEditInfoInitial;
-///
-/// let time = "2023-08-12T17:10:37.000Z";
-/// let date_time = NaiveDateTime::parse_from_str(&time, FORMAT).unwrap();
-/// let editable_until = DateTime::<Utc>::from_naive_utc_and_offset(date_time, Utc);
-///
-/// let json = format!(r#"{{
-///   "editTweetIds": [
-///     "1690395372546301952"
-///   ],
-///   "editableUntil": "{time}",
-///   "editsRemaining": "5",
-///   "isEditEligible": true
-/// }}"#);
-///
-/// let data: TweetEditInfoInitial = serde_json::from_str(&json).unwrap();
-///
-/// // De-serialized properties
-/// assert_eq!(data.edit_tweet_ids, ["1690395372546301952"]);
-/// assert_eq!(data.editable_until, editable_until);
-/// assert_eq!(data.edits_remaining, 5);
-/// assert_eq!(data.is_edit_eligible, true);
-///
-/// // Re-serialize is equivalent to original data
-/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
-/// ```
-#[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-#[serde(rename_all = "camelCase")]
-pub struct TweetEditInfoInitial {
-	/// URL formats;
+	/// GeoJSON longitude and latitude of geo-tagged Tweets
 	///
-	/// - Desktop: `https://twitter.com/i/web/status/{edit_tweet_ids}`
-	/// - Mobile: `https://mobile.twitter.com/i/web/status/{edit_tweet_ids}`
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "coordinates": {
+	///     "type": "Point",
+	///     "coordinates": ["-122.4194", "37.7749"]
+	///   }
+	/// }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub coordinates: Option<TweetCoordinates>,
+
+	/// Named location Tweet was tagged with, which may be present without exact coordinates
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// {
-	///    "editTweetIds": ["1690395372546301952"]
+	///   "place": {
+	///     "id": "5a110d312052166f",
+	///     "url": "https://api.twitter.com/1.1/geo/id/5a110d312052166f.json",
+	///     "place_type": "city",
+	///     "name": "San Francisco",
+	///     "full_name": "San Francisco, CA",
+	///     "country_code": "US",
+	///     "country": "United States"
+	///   }
 	/// }
 	/// ```
-	pub edit_tweet_ids: Vec<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub place: Option<TweetPlace>,
 
-	/// Date time stamp until editing is no longer allowed, even if paying for Mr. Musk perks
+	/// ISO 3166-1 alpha-2 codes of countries Tweet is withheld within, where `XX` means all
+	/// countries and `XY` means withheld due to a DMCA request
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "editableUntil": "2023-08-12T17:10:37.000Z" }
+	/// { "withheld_in_countries": ["DE", "FR"] }
 	/// ```
-	#[serde(with = "convert::date_time_iso_8601")]
-	pub editable_until: DateTime<Utc>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub withheld_in_countries: Option<Vec<String>>,
 
-	/// Remaining edits available, if account is currently paying Mr. Musk for check-mark parks
+	/// Whether withholding applies to just this Tweet or whole account
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "editsRemaining": "5" }
+	/// { "withheld_scope": "status" }
 	/// ```
-	#[serde(with = "convert::number_like_string")]
-	pub edits_remaining: usize,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub withheld_scope: Option<WithheldScope>,
 
-	/// State is a lie unless user of this data structure is paying member.  Thanks be to Mr. Musk
+	/// Whether Tweet is withheld due to copyright infringement notice
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "isEditEligible": true }
+	/// { "withheld_copyright": false }
 	/// ```
-	pub is_edit_eligible: bool,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub withheld_copyright: Option<bool>,
 }
 
-/// ## Example
-///
-/// ```
-/// use twitter_archive::structs::tweets::TweetEntities;
-///
-/// let json = r#"{
-///   "hashtags": [],
-///   "symbols": [],
-///   "user_mentions": [
-///     {
-///       "name": "ThePrimeagen",
-///       "screen_name": "ThePrimeagen",
-///       "indices": [
-///         "0",
-///         "13"
-///       ],
-///       "id_str": "291797158",
-///       "id": "291797158"
-///     }
-///   ],
-///   "urls": [
-///     {
-///       "url": "https://t.co/4LBPKIGBzf",
-///       "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
-///       "display_url": "youtube.com/watch?v=J7bX5d…",
-///       "indices": [
-///         "132",
-///         "155"
-///       ]
-///     }
-///   ]
-/// }"#;
-///
-/// let data: TweetEntities = serde_json::from_str(&json).unwrap();
-///
-/// // De-serialized properties
-/// assert_eq!(data.hashtags.len(), 0);
-/// assert_eq!(data.symbols.len(), 0);
-/// assert_eq!(data.user_mentions.len(), 1);
-/// assert_eq!(data.urls.len(), 1);
-///
-/// // Re-serialize is equivalent to original data
-/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
-/// ```
-#[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct TweetEntities {
-	/// List of hashtags (string prefixed by `#`) data within Tweet
+impl Tweet {
+	/// Typed form of `lang`, so language statistics need not normalize strings themselves
 	///
-	/// TODO: Add example JSON data
-	pub hashtags: Vec<TweetEntitiesEntry>,
-
-	/// List of symbols (string prefixed by `$`) data within Tweet
+	/// ## Example
 	///
-	/// TODO: Add example JSON data
-	pub symbols: Vec<TweetEntitiesEntry>,
-
-	/// List of user data mentioned by Tweet
-	/// ## Example JSON data
+	/// ```
+	/// use twitter_archive::structs::tweets::{Lang, Tweet};
 	///
-	/// ```json
-	/// {
-	///   "user_mentions": [
-	///     {
-	///       "name": "ThePrimeagen",
-	///       "screen_name": "ThePrimeagen",
-	///       "indices": ["0", "13"],
-	///       "id_str": "291797158",
-	///       "id": "291797158"
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
 	///     }
-	///   ]
-	/// }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": { "hashtags": [], "symbols": [], "user_mentions": [], "urls": [] },
+	///   "display_text_range": [ "0", "2" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "RT",
+	///   "lang": "qst"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.language(), Lang::ShortText);
+	/// assert!(!data.language().is_linguistic());
 	/// ```
-	pub user_mentions: Vec<TweetEntitiesUserMention>,
+	pub fn language(&self) -> Lang {
+		Lang::from(self.lang.as_str())
+	}
 
-	/// List of URL data within Tweet
+	/// Content of `full_text` with HTML entities, e.g. `&amp;`, decoded
 	///
-	/// ## Example JSON data
+	/// ## Example
 	///
-	/// ```json
-	/// {
-	///   "urls" [
-	///     {
-	///       "url": "https://t.co/4LBPKIGBzf",
-	///       "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
-	///       "display_url": "youtube.com/watch?v=J7bX5d…",
-	///       "indices": ["132", "155"]
+	/// ```
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
 	///     }
-	///   ]
-	/// }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": { "hashtags": [], "symbols": [], "user_mentions": [], "urls": [] },
+	///   "display_text_range": [ "0", "27" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Fish &amp; chips &lt;3",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.full_text_decoded(), "Fish & chips <3");
+	///
+	/// // Stored field is untouched
+	/// assert_eq!(data.full_text, "Fish &amp; chips &lt;3");
 	/// ```
-	pub urls: Vec<TweetEntitiesUserUrl>,
-}
+	pub fn full_text_decoded(&self) -> Cow<'_, str> {
+		convert::html_entities::decode(&self.full_text)
+	}
 
-/// Common structure for;
-///
-/// - `tweets[].tweet.entities.hashtags[]`
-/// - `tweets[].tweet.entities.symbols[]`
-///
-/// TODO: Add doc-tests
-#[derive(Deserialize, Serialize, Debug, Clone, Display)]
-#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct TweetEntitiesEntry {
-	/// String representation of hashtag or symbol entry
+	/// Parsed form of `source`, which itself is kept verbatim for re-serializing
+	pub fn source_client(&self) -> ClientSource {
+		ClientSource::parse(&self.source)
+	}
+
+	/// Name of client application Tweet was posted from, such as `Twitter Web App`
+	pub fn source_client_name(&self) -> String {
+		self.source_client().name
+	}
+
+	/// Link to client application Tweet was posted from, if `source` had one
+	pub fn source_client_href(&self) -> Option<String> {
+		self.source_client().href
+	}
+
+	/// Creation date time-stamp embedded within `id_str`, with millisecond precision that
+	/// `created_at` lacks, check `convert::snowflake` for details
+	pub fn id_timestamp(&self) -> Option<DateTime<Utc>> {
+		convert::snowflake::timestamp_from_str(&self.id_str)
+	}
+
+	/// Classify Tweet as original, reply, Retweet, or quote
 	///
-	/// TODO: Add example JSON data
-	pub text: String,
+	/// Checks are made in that order of precedence; `RT @` prefix of `full_text`, then presence of
+	/// `in_reply_to_status_id_str`, then any `entities.urls[].expanded_url` pointing to a Tweet.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::{Tweet, TweetKind};
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": [
+	///       {
+	///         "url": "https://t.co/AAAAAAAAAA",
+	///         "expanded_url": "https://twitter.com/EXAMPLE/status/2222222222222222222",
+	///         "display_url": "twitter.com/EXAMPLE/statu…",
+	///         "indices": [ "10", "33" ]
+	///       }
+	///     ]
+	///   },
+	///   "display_text_range": [ "0", "33" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Well said https://t.co/AAAAAAAAAA",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let mut data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.kind(), TweetKind::Quote);
+	///
+	/// data.in_reply_to_status_id_str = Some("3333333333333333333".to_string());
+	/// assert_eq!(data.kind(), TweetKind::Reply);
+	///
+	/// data.full_text = "RT @EXAMPLE: Well said".to_string();
+	/// assert_eq!(data.kind(), TweetKind::Retweet);
+	///
+	/// data.full_text = "Well said".to_string();
+	/// data.in_reply_to_status_id_str = None;
+	/// data.entities.urls.clear();
+	/// assert_eq!(data.kind(), TweetKind::Original);
+	/// ```
+	pub fn kind(&self) -> TweetKind {
+		if self.full_text.starts_with("RT @") {
+			TweetKind::Retweet
+		} else if self.in_reply_to_status_id_str.is_some() {
+			TweetKind::Reply
+		} else if self.quoted_tweet_id().is_some() {
+			TweetKind::Quote
+		} else {
+			TweetKind::Original
+		}
+	}
 
-	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	/// ID of quoted Tweet, parsed from first `entities.urls[].expanded_url` linking to a Tweet on
+	/// `twitter.com` or `x.com`
 	///
-	/// TODO: Add example JSON data
-	#[serde(with = "convert::indices")]
-	pub indices: [usize; 2],
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": [
+	///       {
+	///         "url": "https://t.co/BBBBBBBBBB",
+	///         "expanded_url": "https://example.com/",
+	///         "display_url": "example.com",
+	///         "indices": [ "10", "33" ]
+	///       },
+	///       {
+	///         "url": "https://t.co/AAAAAAAAAA",
+	///         "expanded_url": "https://x.com/EXAMPLE/status/2222222222222222222?s=20",
+	///         "display_url": "x.com/EXAMPLE/status…",
+	///         "indices": [ "34", "57" ]
+	///       }
+	///     ]
+	///   },
+	///   "display_text_range": [ "0", "57" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Well said https://t.co/BBBBBBBBBB https://t.co/AAAAAAAAAA",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let mut data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.quoted_tweet_id(), Some("2222222222222222222"));
+	///
+	/// data.entities.urls.pop();
+	/// assert!(data.quoted_tweet_id().is_none());
+	/// ```
+	pub fn quoted_tweet_id(&self) -> Option<&str> {
+		self.entities
+			.urls
+			.iter()
+			.find_map(|url| status_id_from_url(&url.expanded_url))
+	}
+
+	/// Retweeted account and text parsed from `RT @<SCREEN_NAME>: <TEXT>` form of `full_text`
+	///
+	/// Returns `None` for Tweets that are not Retweets.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [
+	///       {
+	///         "name": "Example",
+	///         "screen_name": "EXAMPLE",
+	///         "indices": [ "3", "11" ],
+	///         "id_str": "222222222",
+	///         "id": "222222222"
+	///       }
+	///     ],
+	///     "urls": []
+	///   },
+	///   "display_text_range": [ "0", "140" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "RT @EXAMPLE: Archives truncate Retweets at one hundred and forty characters, so longer ones end…",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// let retweet = data.retweet_of().unwrap();
+	///
+	/// assert_eq!(retweet.screen_name, "EXAMPLE");
+	/// assert_eq!(retweet.user_id, Some("222222222".to_string()));
+	/// assert!(retweet.text.starts_with("Archives truncate"));
+	/// assert!(retweet.is_truncated());
+	/// ```
+	pub fn retweet_of(&self) -> Option<RetweetOf> {
+		let rest = self.full_text.strip_prefix("RT @")?;
+
+		let (screen_name, text) = rest.split_once(':')?;
+		if screen_name.is_empty()
+			|| !screen_name
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '_')
+		{
+			return None;
+		}
+
+		let user_id = self
+			.entities
+			.user_mentions
+			.iter()
+			.find(|mention| mention.screen_name.eq_ignore_ascii_case(screen_name))
+			.map(|mention| mention.id_str.clone());
+
+		Some(RetweetOf {
+			screen_name: screen_name.to_string(),
+			user_id,
+			text: text.trim_start().to_string(),
+		})
+	}
+
+	/// Paths, within archive, of media files attached to Tweet, e.g.
+	/// `data/tweets_media/<TWEET_ID>-<FILE_NAME>.jpg`
+	///
+	/// File names are derived from `extended_entities.media`, or `entities.media` when extended
+	/// entities are missing, with videos and animated GIFs pointing at highest bitrate MP4
+	/// variant.  Archives may omit some files, so check presence with `ArchiveReader::contains`
+	/// or use `ArchiveReader::media_for_tweet` instead.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::manifest::MediaDirectory;
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let media_directory: MediaDirectory = serde_json::from_str(r#"{
+	///   "mediaDirectory": "data/tweets_media"
+	/// }"#).unwrap();
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": []
+	///   },
+	///   "extended_entities": {
+	///     "media": [
+	///       {
+	///         "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+	///         "indices": [ "6", "29" ],
+	///         "url": "https://t.co/AbCdEfGhIj",
+	///         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "id_str": "2222222222222222222",
+	///         "id": "2222222222222222222",
+	///         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "sizes": {
+	///           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///           "small": { "w": "680", "h": "383", "resize": "fit" },
+	///           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///           "large": { "w": "1280", "h": "720", "resize": "fit" }
+	///         },
+	///         "type": "photo",
+	///         "display_url": "pic.twitter.com/AbCdEfGhIj"
+	///       }
+	///     ]
+	///   },
+	///   "display_text_range": [ "0", "5" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Look! https://t.co/AbCdEfGhIj",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(
+	///     data.media_file_names(&media_directory),
+	///     vec!["data/tweets_media/1111111111111111111-F3UNnlRWoAAQXa_.jpg"]
+	/// );
+	/// ```
+	pub fn media_file_names(&self, media_directory: &manifest::MediaDirectory) -> Vec<String> {
+		let directory = media_directory.media_directory.trim_end_matches('/');
+
+		let mut file_names: Vec<String> = Vec::new();
+		for entity in self.media() {
+			if let Some(name) = entity.file_name() {
+				let file_name = format!("{directory}/{}-{name}", self.id_str);
+				if !file_names.contains(&file_name) {
+					file_names.push(file_name);
+				}
+			}
+		}
+		file_names
+	}
+
+	/// Link to Tweet that works without knowing account screen name, e.g.
+	/// `https://twitter.com/i/web/status/<ID>`
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": []
+	///   },
+	///   "display_text_range": [ "0", "5" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Hello",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.permalink(), "https://twitter.com/i/web/status/1111111111111111111");
+	///
+	/// assert_eq!(
+	///     data.nitter_url("nitter.net"),
+	///     "https://nitter.net/i/web/status/1111111111111111111"
+	/// );
+	///
+	/// // Scheme and trailing slash of host are kept and trimmed respectively
+	/// assert_eq!(
+	///     data.nitter_url("http://localhost:8080/"),
+	///     "http://localhost:8080/i/web/status/1111111111111111111"
+	/// );
+	/// ```
+	pub fn permalink(&self) -> String {
+		format!("https://twitter.com/i/web/status/{}", self.id_str)
+	}
+
+	/// Link to Tweet on Nitter, or compatible mirror, instance at `host`, where `https://` is
+	/// assumed when `host` lacks scheme
+	pub fn nitter_url(&self, host: &str) -> String {
+		let host = host.trim_end_matches('/');
+		if host.contains("://") {
+			format!("{host}/i/web/status/{}", self.id_str)
+		} else {
+			format!("https://{host}/i/web/status/{}", self.id_str)
+		}
+	}
+
+	/// Is Tweet withheld within country with ISO 3166-1 alpha-2 `country_code`, either listed
+	/// directly or via `XX` for all countries
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::{Tweet, WithheldScope};
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": []
+	///   },
+	///   "display_text_range": [ "0", "5" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Hello",
+	///   "lang": "en",
+	///   "withheld_in_countries": [
+	///     "DE",
+	///     "FR"
+	///   ],
+	///   "withheld_scope": "status"
+	/// }"#;
+	///
+	/// let mut data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.withheld_scope, Some(WithheldScope::Status));
+	/// assert!(data.is_withheld_in("de"));
+	/// assert!(!data.is_withheld_in("US"));
+	///
+	/// data.withheld_in_countries = Some(vec!["XX".to_string()]);
+	/// assert!(data.is_withheld_in("US"));
+	///
+	/// data.withheld_in_countries = None;
+	/// assert!(!data.is_withheld_in("DE"));
+	/// ```
+	pub fn is_withheld_in(&self, country_code: &str) -> bool {
+		self.withheld_in_countries
+			.iter()
+			.flatten()
+			.any(|code| code.eq_ignore_ascii_case(country_code) || code.eq_ignore_ascii_case("XX"))
+	}
+
+	/// Replace `full_text`, with encoded HTML entities, and URL, hashtag, symbol, and mention
+	/// entities with long-form content of `note`, see `note_tweet::merge_into_tweets`
+	///
+	/// Notes do not record IDs nor names of mentioned accounts, so only mentions already found
+	/// within truncated text are kept.  Media entities are left as is.
+	pub fn merge_note_tweet(&mut self, note: &note_tweet::NoteTweet) {
+		let text = &note.core.text;
+
+		// Note indices count characters before `&`, `<`, and `>` are encoded
+		let offset = |index: usize| -> usize {
+			text.chars()
+				.take(index)
+				.map(|c| match c {
+					'&' => 5,
+					'<' | '>' => 4,
+					_ => 1,
+				})
+				.sum()
+		};
+
+		self.entities.urls = note
+			.core
+			.urls
+			.iter()
+			.map(|url| TweetEntitiesUserUrl {
+				url: url.short_url.clone(),
+				expanded_url: url.expanded_url.clone(),
+				display_url: url.display_url.clone(),
+				indices: vec![offset(url.from_index), offset(url.to_index)],
+			})
+			.collect();
+
+		self.entities.hashtags = note
+			.core
+			.hashtags
+			.iter()
+			.map(|hashtag| TweetEntitiesEntry {
+				text: hashtag.hashtag.clone(),
+				indices: vec![offset(hashtag.from_index), offset(hashtag.to_index)],
+			})
+			.collect();
+
+		self.entities.symbols = note
+			.core
+			.cashtags
+			.iter()
+			.map(|cashtag| TweetEntitiesEntry {
+				text: cashtag.cashtag.clone(),
+				indices: vec![offset(cashtag.from_index), offset(cashtag.to_index)],
+			})
+			.collect();
+
+		self.entities.user_mentions =
+			note.core
+				.mentions
+				.iter()
+				.filter_map(|mention| {
+					let known = self.entities.user_mentions.iter().find(|known| {
+						known.screen_name.eq_ignore_ascii_case(&mention.screen_name)
+					})?;
+					Some(TweetEntitiesUserMention {
+						indices: vec![offset(mention.from_index), offset(mention.to_index)],
+						..known.clone()
+					})
+				})
+				.collect();
+
+		let full_text = convert::html_entities::encode(text).into_owned();
+		self.display_text_range = [0, full_text.chars().count()];
+		self.full_text = full_text;
+	}
+
+	/// ID of Tweet that started thread this Tweet belongs to, found by walking reply chains
+	/// recorded in `threads`, see `thread` module for example
+	pub fn thread_root<'a>(&'a self, threads: &'a ThreadIndex) -> &'a str {
+		threads.root(&self.id_str)
+	}
+
+	/// IDs of every version of this Tweet, from first to latest, as listed by
+	/// `edit_info.initial.editTweetIds`; see `edit` module for linking versions together
+	pub fn edit_tweet_ids(&self) -> &[String] {
+		&self.edit_info.initial.edit_tweet_ids
+	}
+
+	/// Has this Tweet been edited, or is it itself edited version of an earlier Tweet
+	pub fn is_edited(&self) -> bool {
+		self.edit_tweet_ids().len() > 1
+	}
+
+	/// Position of geo-tagged Tweet from `coordinates`, or `geo` when former is missing
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::convert::geo::GeoPoint;
+	/// use twitter_archive::structs::tweets::Tweet;
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [],
+	///     "urls": []
+	///   },
+	///   "display_text_range": [ "0", "5" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Hello",
+	///   "lang": "en",
+	///   "geo": {
+	///     "type": "Point",
+	///     "coordinates": [ "37.7749", "-122.4194" ]
+	///   }
+	/// }"#;
+	///
+	/// let mut data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(data.location(), Some(GeoPoint::new(37.7749, -122.4194)));
+	///
+	/// data.geo = None;
+	/// assert!(data.location().is_none());
+	/// ```
+	pub fn location(&self) -> Option<GeoPoint> {
+		self.coordinates
+			.as_ref()
+			.map(|coordinates| coordinates.coordinates)
+			.or_else(|| self.geo.as_ref().map(|geo| geo.coordinates))
+	}
+
+	/// Links of `entities.urls` as `UrlEntity`, shared with Notes and Direct Messages
+	pub fn url_entities(&self) -> Vec<UrlEntity> {
+		self.entities.urls.iter().map(UrlEntity::from).collect()
+	}
+
+	/// Attached photos, videos, and animated GIFs from `extended_entities.media`, or
+	/// `entities.media` when extended entities are missing
+	pub fn media(&self) -> &[TweetMedia] {
+		match (&self.extended_entities, &self.entities.media) {
+			(Some(extended_entities), _) => &extended_entities.media,
+			(None, Some(media)) => media,
+			(None, None) => &[],
+		}
+	}
+
+	/// Plain text of Tweet with HTML entities decoded and `entities` rendered per `options`
+	///
+	/// By default `t.co` links are replaced by their `expanded_url` and link to attached media
+	/// is removed, see `RenderTextOptions` for linking mentions and hashtags too.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::{RenderTextOptions, Tweet};
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [
+	///       {
+	///         "text": "rustlang",
+	///         "indices": [ "26", "35" ]
+	///       }
+	///     ],
+	///     "symbols": [],
+	///     "user_mentions": [
+	///       {
+	///         "name": "Example",
+	///         "screen_name": "EXAMPLE",
+	///         "indices": [ "0", "8" ],
+	///         "id_str": "222222222",
+	///         "id": "222222222"
+	///       }
+	///     ],
+	///     "urls": [
+	///       {
+	///         "url": "https://t.co/BBBBBBBBBB",
+	///         "expanded_url": "https://example.com/fish?and=chips",
+	///         "display_url": "example.com/fish?and=chips",
+	///         "indices": [ "36", "59" ]
+	///       }
+	///     ]
+	///   },
+	///   "extended_entities": {
+	///     "media": [
+	///       {
+	///         "expanded_url": "https://twitter.com/EXAMPLE/status/1111111111111111111/photo/1",
+	///         "indices": [ "60", "83" ],
+	///         "url": "https://t.co/CCCCCCCCCC",
+	///         "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "id_str": "3333333333333333333",
+	///         "id": "3333333333333333333",
+	///         "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///         "sizes": {
+	///           "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///           "small": { "w": "680", "h": "383", "resize": "fit" },
+	///           "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///           "large": { "w": "1280", "h": "720", "resize": "fit" }
+	///         },
+	///         "type": "photo",
+	///         "display_url": "pic.twitter.com/CCCCCCCCCC"
+	///       }
+	///     ]
+	///   },
+	///   "display_text_range": [ "0", "59" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "@example Fish &amp; chips #rustlang https://t.co/BBBBBBBBBB https://t.co/CCCCCCCCCC",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(
+	///     data.render_text(RenderTextOptions::default()),
+	///     "@example Fish & chips #rustlang https://example.com/fish?and=chips"
+	/// );
+	///
+	/// let options = RenderTextOptions {
+	///     linkify_mentions: true,
+	///     linkify_hashtags: true,
+	///     ..RenderTextOptions::default()
+	/// };
+	///
+	/// assert_eq!(
+	///     data.render_text(options),
+	///     "[@example](https://twitter.com/EXAMPLE) Fish & chips [#rustlang](https://twitter.com/hashtag/rustlang) https://example.com/fish?and=chips"
+	/// );
+	///
+	/// let options = RenderTextOptions {
+	///     expand_urls: false,
+	///     strip_media_url: false,
+	///     ..RenderTextOptions::default()
+	/// };
+	///
+	/// assert_eq!(
+	///     data.render_text(options),
+	///     "@example Fish & chips #rustlang https://t.co/BBBBBBBBBB https://t.co/CCCCCCCCCC"
+	/// );
+	/// ```
+	pub fn render_text(&self, options: RenderTextOptions) -> String {
+		let mut text = String::with_capacity(self.full_text.len());
+		for segment in self.text_segments() {
+			match segment {
+				TextSegment::Text(value) => text.push_str(&value),
+				TextSegment::Url {
+					url, expanded_url, ..
+				} => text.push_str(if options.expand_urls {
+					expanded_url
+				} else {
+					url
+				}),
+				TextSegment::Media { url } => {
+					if options.strip_media_url {
+						text.truncate(text.trim_end().len());
+					} else {
+						text.push_str(url);
+					}
+				}
+				TextSegment::Mention {
+					text: mention,
+					screen_name,
+				} => {
+					if options.linkify_mentions {
+						text.push_str(&format!("[{mention}](https://twitter.com/{screen_name})"));
+					} else {
+						text.push_str(mention);
+					}
+				}
+				TextSegment::Hashtag { text: hashtag, tag } => {
+					if options.linkify_hashtags {
+						text.push_str(&format!("[{hashtag}](https://twitter.com/hashtag/{tag})"));
+					} else {
+						text.push_str(hashtag);
+					}
+				}
+			}
+		}
+		text
+	}
+
+	/// Split `full_text` at URL, media, mention, and hashtag entities, for building renderers
+	///
+	/// Entities are located via their `indices`, falling back to searching remaining text when
+	/// indices disagree with text, and entities that cannot be found are left as plain text.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::{TextSegment, Tweet};
+	///
+	/// let json = r#"{
+	///   "edit_info": {
+	///     "initial": {
+	///       "editTweetIds": [ "1111111111111111111" ],
+	///       "editableUntil": "2023-08-12T17:10:37.000Z",
+	///       "editsRemaining": "5",
+	///       "isEditEligible": true
+	///     }
+	///   },
+	///   "retweeted": false,
+	///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///   "entities": {
+	///     "hashtags": [],
+	///     "symbols": [],
+	///     "user_mentions": [
+	///       {
+	///         "name": "Example",
+	///         "screen_name": "EXAMPLE",
+	///         "indices": [ "3", "11" ],
+	///         "id_str": "222222222",
+	///         "id": "222222222"
+	///       }
+	///     ],
+	///     "urls": [
+	///       {
+	///         "url": "https://t.co/BBBBBBBBBB",
+	///         "expanded_url": "https://example.com/",
+	///         "display_url": "example.com",
+	///         "indices": [ "12", "35" ]
+	///       }
+	///     ]
+	///   },
+	///   "display_text_range": [ "0", "35" ],
+	///   "favorite_count": "0",
+	///   "id_str": "1111111111111111111",
+	///   "truncated": false,
+	///   "retweet_count": "0",
+	///   "id": "1111111111111111111",
+	///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///   "favorited": false,
+	///   "full_text": "Hi @example https://t.co/BBBBBBBBBB &lt;3",
+	///   "lang": "en"
+	/// }"#;
+	///
+	/// let data: Tweet = serde_json::from_str(&json).unwrap();
+	///
+	/// assert_eq!(
+	///     data.text_segments(),
+	///     vec![
+	///         TextSegment::Text("Hi ".into()),
+	///         TextSegment::Mention {
+	///             text: "@example",
+	///             screen_name: "EXAMPLE",
+	///         },
+	///         TextSegment::Text(" ".into()),
+	///         TextSegment::Url {
+	///             url: "https://t.co/BBBBBBBBBB",
+	///             expanded_url: "https://example.com/",
+	///             display_url: "example.com",
+	///         },
+	///         TextSegment::Text(" <3".into()),
+	///     ]
+	/// );
+	/// ```
+	pub fn text_segments(&self) -> Vec<TextSegment<'_>> {
+		let mut entities: Vec<(usize, String, TextSegment)> = Vec::new();
+
+		for url in &self.entities.urls {
+			entities.push((
+				index_hint(&url.indices),
+				url.url.clone(),
+				TextSegment::Url {
+					url: &url.url,
+					expanded_url: &url.expanded_url,
+					display_url: &url.display_url,
+				},
+			));
+		}
+
+		let media = self
+			.extended_entities
+			.iter()
+			.flat_map(|extended_entities| &extended_entities.media)
+			.chain(self.entities.media.iter().flatten());
+		for entity in media {
+			if !entities.iter().any(|(_, needle, _)| needle == &entity.url) {
+				entities.push((
+					index_hint(&entity.indices),
+					entity.url.clone(),
+					TextSegment::Media { url: &entity.url },
+				));
+			}
+		}
+
+		for mention in &self.entities.user_mentions {
+			entities.push((
+				index_hint(&mention.indices),
+				format!("@{}", mention.screen_name),
+				TextSegment::Mention {
+					text: "",
+					screen_name: &mention.screen_name,
+				},
+			));
+		}
+
+		for hashtag in &self.entities.hashtags {
+			entities.push((
+				index_hint(&hashtag.indices),
+				format!("#{}", hashtag.text),
+				TextSegment::Hashtag {
+					text: "",
+					tag: &hashtag.text,
+				},
+			));
+		}
+
+		entities.sort_by_key(|(start, _, _)| *start);
+
+		let text = self.full_text.as_str();
+		let mut segments = Vec::with_capacity(entities.len() * 2 + 1);
+		let mut cursor = 0;
+		for (index, needle, mut segment) in entities {
+			let ignore_case = matches!(segment, TextSegment::Mention { .. });
+			let matches_at = |start: usize| {
+				text.as_bytes()
+					.get(start..start + needle.len())
+					.is_some_and(|window| {
+						if ignore_case {
+							window.eq_ignore_ascii_case(needle.as_bytes())
+						} else {
+							window == needle.as_bytes()
+						}
+					})
+			};
+
+			let hinted = text
+				.char_indices()
+				.nth(index)
+				.map(|(start, _)| start)
+				.filter(|&start| start >= cursor && matches_at(start));
+			let Some(start) = hinted.or_else(|| {
+				text[cursor..]
+					.char_indices()
+					.map(|(offset, _)| cursor + offset)
+					.find(|&start| matches_at(start))
+			}) else {
+				continue;
+			};
+
+			let end = start + needle.len();
+			match &mut segment {
+				TextSegment::Mention { text: matched, .. }
+				| TextSegment::Hashtag { text: matched, .. } => *matched = &text[start..end],
+				_ => {}
+			}
+
+			if start > cursor {
+				segments.push(TextSegment::Text(convert::html_entities::decode(
+					&text[cursor..start],
+				)));
+			}
+			segments.push(segment);
+			cursor = end;
+		}
+		if cursor < text.len() {
+			segments.push(TextSegment::Text(convert::html_entities::decode(
+				&text[cursor..],
+			)));
+		}
+
+		segments
+	}
+}
+
+/// Retweeted account and text returned by `Tweet::retweet_of`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RetweetOf {
+	/// Screen name of retweeted account, without `@` prefix
+	pub screen_name: String,
+
+	/// ID of retweeted account, when found within `entities.user_mentions`
+	pub user_id: Option<String>,
+
+	/// Beginning of retweeted text, which archives truncate with `…`
+	pub text: String,
+}
+
+impl RetweetOf {
+	/// Was retweeted text cut short within archive
+	pub fn is_truncated(&self) -> bool {
+		self.text.ends_with('…')
+	}
+
+	/// Desktop URL of retweeted account
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::RetweetOf;
+	///
+	/// let retweet = RetweetOf {
+	///     screen_name: "EXAMPLE".to_string(),
+	///     user_id: None,
+	///     text: "Hi".to_string(),
+	/// };
+	///
+	/// assert_eq!(retweet.profile_url(), "https://twitter.com/EXAMPLE");
+	/// ```
+	pub fn profile_url(&self) -> String {
+		format!("https://twitter.com/{}", self.screen_name)
+	}
+}
+
+/// Choices for `Tweet::render_text`, where `RenderTextOptions::default()` expands URLs and strips
+/// media links without linking mentions or hashtags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RenderTextOptions {
+	/// Replace `t.co` links with `entities.urls[].expanded_url`
+	pub expand_urls: bool,
+
+	/// Remove `t.co` link to attached photos, videos, or animated GIFs
+	pub strip_media_url: bool,
+
+	/// Render `@<SCREEN_NAME>` as Markdown link to profile
+	pub linkify_mentions: bool,
+
+	/// Render `#<HASHTAG>` as Markdown link to hashtag search
+	pub linkify_hashtags: bool,
+}
+
+impl Default for RenderTextOptions {
+	fn default() -> Self {
+		Self {
+			expand_urls: true,
+			strip_media_url: true,
+			linkify_mentions: false,
+			linkify_hashtags: false,
+		}
+	}
+}
+
+/// Piece of `Tweet::full_text` returned by `Tweet::text_segments`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TextSegment<'a> {
+	/// Text between entities, with HTML entities decoded
+	Text(Cow<'a, str>),
+
+	/// Link listed by `entities.urls`
+	Url {
+		/// Shortened `t.co` link as written within text
+		url: &'a str,
+
+		/// Original link
+		expanded_url: &'a str,
+
+		/// Shortened original link for display
+		display_url: &'a str,
+	},
+
+	/// Shortened `t.co` link to attached photos, videos, or animated GIFs
+	Media {
+		/// Shortened link as written within text
+		url: &'a str,
+	},
+
+	/// Account listed by `entities.user_mentions`
+	Mention {
+		/// `@<SCREEN_NAME>` as written within text
+		text: &'a str,
+
+		/// Screen name of account, without `@` prefix
+		screen_name: &'a str,
+	},
+
+	/// Hashtag listed by `entities.hashtags`
+	Hashtag {
+		/// `#<HASHTAG>` as written within text
+		text: &'a str,
+
+		/// Hashtag without `#` prefix
+		tag: &'a str,
+	},
+}
+
+/// Classification of Tweets returned by `Tweet::kind`
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum TweetKind {
+	/// Neither a Retweet, reply, nor quote
+	Original,
+
+	/// Reply to another Tweet
+	Reply,
+
+	/// Retweet of another Tweet, without added commentary
+	Retweet,
+
+	/// Links to another Tweet, with added commentary
+	Quote,
+}
+
+/// Start index of entity, or `usize::MAX` for malformed entities lacking one, so they are searched
+/// for after entities with known positions
+fn index_hint(indices: &[usize]) -> usize {
+	indices.first().copied().unwrap_or(usize::MAX)
+}
+
+/// ID of Tweet that URL points to, e.g. `https://twitter.com/<SCREEN_NAME>/status/<ID>`
+pub(crate) fn status_id_from_url(url: &str) -> Option<&str> {
+	let (_, rest) = url.split_once("://")?;
+
+	let (host, path) = rest.split_once('/')?;
+
+	if !matches!(
+		host,
+		"twitter.com" | "www.twitter.com" | "mobile.twitter.com" | "x.com" | "www.x.com"
+	) {
+		return None;
+	}
+
+	let path = path.split(['?', '#']).next()?;
+
+	let mut segments = path.split('/');
+	segments.find(|segment| matches!(*segment, "status" | "statuses"))?;
+
+	segments
+		.next()
+		.filter(|id| !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+/// Client application name and link parsed from HTML anchor found within `Tweet::source`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::ClientSource;
+///
+/// let data = ClientSource::parse(r#"<a href="https://mobile.twitter.com" rel="nofollow">Twitter Web App</a>"#);
+///
+/// assert_eq!(data.name, "Twitter Web App");
+/// assert_eq!(data.href, Some("https://mobile.twitter.com".to_string()));
+///
+/// // HTML entities within names are decoded
+/// let data = ClientSource::parse(r#"<a href="https://example.com" rel="nofollow">Tea &amp; Toast</a>"#);
+///
+/// assert_eq!(data.name, "Tea & Toast");
+///
+/// // Values that are not anchors are kept as name
+/// let data = ClientSource::parse("web");
+///
+/// assert_eq!(data.name, "web");
+/// assert!(data.href.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientSource {
+	/// Text of anchor, with HTML entities decoded
+	pub name: String,
+
+	/// Value of anchor `href` attribute
+	pub href: Option<String>,
+}
+
+impl ClientSource {
+	/// Parse `<a href="...">NAME</a>` anchor, falling back to whole string as name
+	pub fn parse(source: &str) -> Self {
+		let source = source.trim();
+
+		let Some(tag_end) = source.strip_prefix("<a").and_then(|_| source.find('>')) else {
+			return Self {
+				name: decode_html_entities(source),
+				href: None,
+			};
+		};
+
+		let href = source[..tag_end]
+			.split_once("href=\"")
+			.and_then(|(_, rest)| rest.split_once('"'))
+			.map(|(href, _)| decode_html_entities(href));
+
+		let text = &source[tag_end + 1..];
+		let text = text.strip_suffix("</a>").unwrap_or(text);
+
+		Self {
+			name: decode_html_entities(text),
+			href,
+		}
+	}
+}
+
+/// Decode the HTML entities Twitter escapes within `source` anchors
+fn decode_html_entities(value: &str) -> String {
+	convert::html_entities::decode(value).into_owned()
+}
+
+string_enum! {
+	/// Language code found within `Tweet::lang`
+	///
+	/// Mostly two letter ISO 639-1 codes, plus ISO 639-2 `und` and `zxx` and `q*` codes Twitter
+	/// uses when language could not, or need not, be detected
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::tweets::Lang;
+	///
+	/// let data: Vec<Lang> = serde_json::from_str(r#"["en", "und", "cy"]"#).unwrap();
+	///
+	/// // De-serialized properties
+	/// assert_eq!(data[0], Lang::English);
+	/// assert_eq!(data[1], Lang::Undetermined);
+	/// assert_eq!(data[2], Lang::Other("cy".to_string()));
+	///
+	/// assert!(data[0].is_linguistic());
+	/// assert!(!data[1].is_linguistic());
+	/// assert!(data[2].is_linguistic());
+	///
+	/// // Both known and unknown values re-serialize to original strings
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"["en","und","cy"]"#);
+	/// ```
+	pub enum Lang {
+		/// Language could not be determined
+		Undetermined => "und",
+
+		/// No linguistic content
+		NoLinguisticContent => "zxx",
+
+		/// Only media, Twitter specific
+		MediaOnly => "qme",
+
+		/// Only hashtags, Twitter specific
+		HashtagsOnly => "qht",
+
+		/// Only mentions, Twitter specific
+		MentionsOnly => "qam",
+
+		/// Only cashtags, Twitter specific
+		CashtagsOnly => "qct",
+
+		/// Too short to determine, Twitter specific
+		ShortText => "qst",
+
+		/// Arabic
+		Arabic => "ar",
+
+		/// Chinese
+		Chinese => "zh",
+
+		/// Dutch
+		Dutch => "nl",
+
+		/// English
+		English => "en",
+
+		/// French
+		French => "fr",
+
+		/// German
+		German => "de",
+
+		/// Hindi
+		Hindi => "hi",
+
+		/// Indonesian
+		Indonesian => "in",
+
+		/// Italian
+		Italian => "it",
+
+		/// Japanese
+		Japanese => "ja",
+
+		/// Korean
+		Korean => "ko",
+
+		/// Persian
+		Persian => "fa",
+
+		/// Polish
+		Polish => "pl",
+
+		/// Portuguese
+		Portuguese => "pt",
+
+		/// Russian
+		Russian => "ru",
+
+		/// Spanish
+		Spanish => "es",
+
+		/// Swedish
+		Swedish => "sv",
+
+		/// Tagalog
+		Tagalog => "tl",
+
+		/// Thai
+		Thai => "th",
+
+		/// Turkish
+		Turkish => "tr",
+
+		/// Ukrainian
+		Ukrainian => "uk",
+	}
+}
+
+impl Lang {
+	/// Is `false` for `und`, `zxx`, and Twitter specific `q*` codes that describe absence of a
+	/// detectable language rather than a language
+	pub fn is_linguistic(&self) -> bool {
+		!matches!(
+			self,
+			Self::Undetermined
+				| Self::NoLinguisticContent
+				| Self::MediaOnly
+				| Self::HashtagsOnly
+				| Self::MentionsOnly
+				| Self::CashtagsOnly
+				| Self::ShortText
+		)
+	}
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::tweets::TweetEditInfo;
+///
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let editable_until_string = "2023-08-12T17:10:37.000Z";
+/// let editable_until_native_time = NaiveDateTime::parse_from_str(&editable_until_string, FORMAT).unwrap();
+/// let editable_until_date_time = DateTime::<Utc>::from_naive_utc_and_offset(editable_until_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "initial": {{
+///     "editTweetIds": [
+///       "1690395372546301952"
+///     ],
+///     "editableUntil": "{editable_until_string}",
+///     "editsRemaining": "5",
+///     "isEditEligible": true
+///   }}
+/// }}"#);
+///
+/// let data: TweetEditInfo = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.initial.edit_tweet_ids, ["1690395372546301952"]);
+/// assert_eq!(data.initial.editable_until, editable_until_date_time);
+/// assert_eq!(data.initial.edits_remaining, 5);
+/// assert_eq!(data.initial.is_edit_eligible, true);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetEditInfo {
+	/// Object/data-structure containing information about edited tweets
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///  "initial": {
+	///    "editTweetIds": ["1690395372546301952"],
+	///    "editableUntil": "2023-08-12T17:10:37.000Z",
+	///    "editsRemaining": "5",
+	///    "isEditEligible": true
+	///  }
+	/// }
+	/// ```
+	pub initial: TweetEditInfoInitial,
+}
+
+/// Whom-ever originally added the edit feature seems to have said, "F existing conventions, we're
+/// doing this camel style" X-D
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+/// use twitter_archive::structs::tweets::TweetEditInfoInitial;
+///
+/// let time = "2023-08-12T17:10:37.000Z";
+/// let date_time = NaiveDateTime::parse_from_str(&time, FORMAT).unwrap();
+/// let editable_until = DateTime::<Utc>::from_naive_utc_and_offset(date_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "editTweetIds": [
+///     "1690395372546301952"
+///   ],
+///   "editableUntil": "{time}",
+///   "editsRemaining": "5",
+///   "isEditEligible": true
+/// }}"#);
+///
+/// let data: TweetEditInfoInitial = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.edit_tweet_ids, ["1690395372546301952"]);
+/// assert_eq!(data.editable_until, editable_until);
+/// assert_eq!(data.edits_remaining, 5);
+/// assert_eq!(data.is_edit_eligible, true);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TweetEditInfoInitial {
+	/// URL formats;
+	///
+	/// - Desktop: `https://twitter.com/i/web/status/{edit_tweet_ids}`
+	/// - Mobile: `https://mobile.twitter.com/i/web/status/{edit_tweet_ids}`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///    "editTweetIds": ["1690395372546301952"]
+	/// }
+	/// ```
+	pub edit_tweet_ids: Vec<String>,
+
+	/// Date time stamp until editing is no longer allowed, even if paying for Mr. Musk perks
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "editableUntil": "2023-08-12T17:10:37.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub editable_until: DateTime<Utc>,
+
+	/// Remaining edits available, if account is currently paying Mr. Musk for check-mark parks
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "editsRemaining": "5" }
+	/// ```
+	#[serde(with = "convert::number_like_string")]
+	pub edits_remaining: usize,
+
+	/// State is a lie unless user of this data structure is paying member.  Thanks be to Mr. Musk
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "isEditEligible": true }
+	/// ```
+	pub is_edit_eligible: bool,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetEntities;
+///
+/// let json = r#"{
+///   "hashtags": [],
+///   "symbols": [],
+///   "user_mentions": [
+///     {
+///       "name": "ThePrimeagen",
+///       "screen_name": "ThePrimeagen",
+///       "indices": [
+///         "0",
+///         "13"
+///       ],
+///       "id_str": "291797158",
+///       "id": "291797158"
+///     }
+///   ],
+///   "urls": [
+///     {
+///       "url": "https://t.co/4LBPKIGBzf",
+///       "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
+///       "display_url": "youtube.com/watch?v=J7bX5d…",
+///       "indices": [
+///         "132",
+///         "155"
+///       ]
+///     }
+///   ]
+/// }"#;
+///
+/// let data: TweetEntities = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.hashtags.len(), 0);
+/// assert_eq!(data.symbols.len(), 0);
+/// assert_eq!(data.user_mentions.len(), 1);
+/// assert_eq!(data.urls.len(), 1);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetEntities {
+	/// List of hashtags (string prefixed by `#`) data within Tweet
+	///
+	/// TODO: Add example JSON data
+	pub hashtags: Vec<TweetEntitiesEntry>,
+
+	/// List of symbols (string prefixed by `$`) data within Tweet
+	///
+	/// TODO: Add example JSON data
+	pub symbols: Vec<TweetEntitiesEntry>,
+
+	/// List of user data mentioned by Tweet
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "user_mentions": [
+	///     {
+	///       "name": "ThePrimeagen",
+	///       "screen_name": "ThePrimeagen",
+	///       "indices": ["0", "13"],
+	///       "id_str": "291797158",
+	///       "id": "291797158"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub user_mentions: Vec<TweetEntitiesUserMention>,
+
+	/// List of URL data within Tweet
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "urls" [
+	///     {
+	///       "url": "https://t.co/4LBPKIGBzf",
+	///       "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
+	///       "display_url": "youtube.com/watch?v=J7bX5d…",
+	///       "indices": ["132", "155"]
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub urls: Vec<TweetEntitiesUserUrl>,
+
+	/// First media attached to Tweet, check `Tweet::extended_entities` for all media
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "media": [
+	///     {
+	///       "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/photo/1",
+	///       "indices": ["3", "26"],
+	///       "url": "https://t.co/AbCdEfGhIj",
+	///       "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///       "id_str": "1690395366854377472",
+	///       "id": "1690395366854377472",
+	///       "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///       "sizes": {
+	///         "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///         "small": { "w": "680", "h": "383", "resize": "fit" },
+	///         "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///         "large": { "w": "1920", "h": "1080", "resize": "fit" }
+	///       },
+	///       "type": "photo",
+	///       "display_url": "pic.twitter.com/AbCdEfGhIj"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub media: Option<Vec<TweetMedia>>,
+}
+
+/// Common structure for;
+///
+/// - `tweets[].tweet.entities.hashtags[]`
+/// - `tweets[].tweet.entities.symbols[]`
+///
+/// ## Example
+///
+/// Entities with other than two `indices` do not abort parsing of their Tweet, and are still
+/// found within text by `Tweet::text_segments`
+///
+/// ```
+/// use twitter_archive::structs::tweets::{TextSegment, Tweet};
+///
+/// let json = r#"{
+///   "edit_info": {
+///     "initial": {
+///       "editTweetIds": [ "1111111111111111111" ],
+///       "editableUntil": "2023-08-12T17:10:37.000Z",
+///       "editsRemaining": "5",
+///       "isEditEligible": true
+///     }
+///   },
+///   "retweeted": false,
+///   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///   "entities": {
+///     "hashtags": [ { "text": "fish", "indices": [ "5", "10", "10" ] } ],
+///     "symbols": [ { "text": "CHIP", "indices": [] } ],
+///     "user_mentions": [],
+///     "urls": []
+///   },
+///   "display_text_range": [ "0", "16" ],
+///   "favorite_count": "0",
+///   "id_str": "1111111111111111111",
+///   "truncated": false,
+///   "retweet_count": "0",
+///   "id": "1111111111111111111",
+///   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///   "favorited": false,
+///   "full_text": "Love #fish $CHIP",
+///   "lang": "en"
+/// }"#;
+///
+/// let tweet: Tweet = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(tweet.entities.hashtags[0].indices, [5, 10, 10]);
+/// assert!(tweet.entities.symbols[0].indices.is_empty());
+///
+/// assert_eq!(
+///     tweet.text_segments()[1],
+///     TextSegment::Hashtag { text: "#fish", tag: "fish" }
+/// );
+///
+/// // Re-serialize keeps indices as found
+/// let data: serde_json::Value = serde_json::from_str(&json).unwrap();
+/// assert_eq!(serde_json::to_value(&tweet).unwrap(), data);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetEntitiesEntry {
+	/// String representation of hashtag or symbol entry
+	///
+	/// TODO: Add example JSON data
+	pub text: String,
+
+	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	///
+	/// Usually a pair, malformed entities may list more or fewer, see `convert::indices_permissive`
+	///
+	/// TODO: Add example JSON data
+	#[serde(with = "convert::indices_permissive")]
+	pub indices: Vec<usize>,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetEntitiesUserMention;
+///
+/// let json = r#"{
+///   "name": "ThePrimeagen",
+///   "screen_name": "ThePrimeagen",
+///   "indices": [
+///     "0",
+///     "13"
+///   ],
+///   "id_str": "291797158",
+///   "id": "291797158"
+/// }"#;
+///
+/// let data: TweetEntitiesUserMention = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.name, "ThePrimeagen");
+/// assert_eq!(data.screen_name, "ThePrimeagen");
+/// assert_eq!(data.indices, [0, 13]);
+/// assert_eq!(data.id_str, "291797158");
+/// assert_eq!(data.id, "291797158");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetEntitiesUserMention {
+	/// Who to _@_ when mentioning a user
+	///
+	/// URL formats;
+	///
+	/// - Desktop: `https://twitter.com/{name}`
+	///
+	/// > Note; redirects to log-in if not logged in, and redirections may be broken.  Thanks be to
+	/// > Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "name": "ThePrimeagen" }
+	/// ```
+	pub name: String,
+
+	/// Contains one value identical to `.tweets[].tweet.in_reply_to_screen_name`
+	///
+	/// URL formats;
+	///
+	/// - Desktop: `https://twitter.com/{screen_name}`
+	///
+	/// > Note; redirects to log-in if not logged in, and redirections may be broken.  Thanks be to
+	/// > Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "screen_name": "ThePrimeagen" }
+	/// ```
+	pub screen_name: String,
+
+	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	///
+	/// Usually a pair, malformed entities may list more or fewer, see `convert::indices_permissive`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "indices": ["0", "13"]
+	/// }
+	/// ```
+	#[serde(with = "convert::indices_permissive")]
+	pub indices: Vec<usize>,
+
+	/// URL formats;
+	///
+	/// - Desktop: `https://twitter.com/i/user/{id_str}`
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id_str": "291797158" }
+	/// ```
+	pub id_str: String,
+
+	/// URL formats;
+	///
+	/// - Desktop: `https://twitter.com/i/user/{id}`
+	///
+	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "291797158" }
+	/// ```
+	pub id: String,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetEntitiesUserUrl;
+///
+/// let json = r#"{
+///   "url": "https://t.co/4LBPKIGBzf",
+///   "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
+///   "display_url": "youtube.com/watch?v=J7bX5d…",
+///   "indices": [
+///     "132",
+///     "155"
+///   ]
+/// }"#;
+///
+/// let data: TweetEntitiesUserUrl = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.url, "https://t.co/4LBPKIGBzf");
+/// assert_eq!(data.expanded_url, "https://www.youtube.com/watch?v=J7bX5dPUw0g");
+/// assert_eq!(data.display_url, "youtube.com/watch?v=J7bX5d…");
+/// assert_eq!(data.indices, [132, 155]);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetEntitiesUserUrl {
+	/// Twitter shortened, and tracking, URL
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://t.co/4LBPKIGBzf" }
+	/// ```
+	pub url: String,
+
+	/// The _real_ URL
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g" }
+	/// ```
+	pub expanded_url: String,
+
+	/// What clients are able to view of URL within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "display_url": "youtube.com/watch?v=J7bX5d…" }
+	/// ```
+	pub display_url: String,
+
+	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	///
+	/// Usually a pair, malformed entities may list more or fewer, see `convert::indices_permissive`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "indices": ["132", "155"]
+	/// }
+	/// ```
+	#[serde(with = "convert::indices_permissive")]
+	pub indices: Vec<usize>,
 }
 
+/// Data found within `tweets[].tweet.extended_entities` of Tweets with photo, video, or animated
+/// GIF media attached
+///
 /// ## Example
 ///
 /// ```
-/// use twitter_archive::structs::tweets::TweetEntitiesUserMention;
+/// use twitter_archive::structs::tweets::{MediaType, TweetExtendedEntities};
+///
+/// let json = r#"{
+///   "media": [
+///     {
+///       "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/photo/1",
+///       "indices": [
+///         "3",
+///         "26"
+///       ],
+///       "url": "https://t.co/AbCdEfGhIj",
+///       "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+///       "id_str": "1690395366854377472",
+///       "id": "1690395366854377472",
+///       "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+///       "sizes": {
+///         "thumb": {
+///           "w": "150",
+///           "h": "150",
+///           "resize": "crop"
+///         },
+///         "small": {
+///           "w": "680",
+///           "h": "383",
+///           "resize": "fit"
+///         },
+///         "medium": {
+///           "w": "1200",
+///           "h": "675",
+///           "resize": "fit"
+///         },
+///         "large": {
+///           "w": "1920",
+///           "h": "1080",
+///           "resize": "fit"
+///         }
+///       },
+///       "type": "photo",
+///       "display_url": "pic.twitter.com/AbCdEfGhIj"
+///     }
+///   ]
+/// }"#;
+///
+/// let data: TweetExtendedEntities = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.media.len(), 1);
+/// assert_eq!(data.media[0].media_type, MediaType::Photo);
+/// assert_eq!(data.media[0].sizes.large.width, 1920);
+/// assert!(data.media[0].video_info.is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetExtendedEntities {
+	/// All media attached to Tweet, where `entities.media` only lists first
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "media": [
+	///     {
+	///       "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/photo/1",
+	///       "indices": ["3", "26"],
+	///       "url": "https://t.co/AbCdEfGhIj",
+	///       "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///       "id_str": "1690395366854377472",
+	///       "id": "1690395366854377472",
+	///       "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg",
+	///       "sizes": {
+	///         "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///         "small": { "w": "680", "h": "383", "resize": "fit" },
+	///         "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///         "large": { "w": "1920", "h": "1080", "resize": "fit" }
+	///       },
+	///       "type": "photo",
+	///       "display_url": "pic.twitter.com/AbCdEfGhIj"
+	///     }
+	///   ]
+	/// }
+	/// ```
+	pub media: Vec<TweetMedia>,
+}
+
+/// Common structure for;
+///
+/// - `tweets[].tweet.entities.media[]`
+/// - `tweets[].tweet.extended_entities.media[]`
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use twitter_archive::structs::tweets::{MediaType, TweetMedia};
+///
+/// let json = r#"{
+///   "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/video/1",
+///   "source_status_id": "1690000000000000000",
+///   "indices": [
+///     "3",
+///     "26"
+///   ],
+///   "url": "https://t.co/AbCdEfGhIj",
+///   "media_url": "http://pbs.twimg.com/ext_tw_video_thumb/1690395366854377472/pu/img/abc.jpg",
+///   "id_str": "1690395366854377472",
+///   "video_info": {
+///     "aspect_ratio": [
+///       "16",
+///       "9"
+///     ],
+///     "duration_millis": "30030",
+///     "variants": [
+///       {
+///         "bitrate": "832000",
+///         "content_type": "video/mp4",
+///         "url": "https://video.twimg.com/ext_tw_video/1690395366854377472/pu/vid/640x360/abc.mp4"
+///       },
+///       {
+///         "content_type": "application/x-mpegURL",
+///         "url": "https://video.twimg.com/ext_tw_video/1690395366854377472/pu/pl/abc.m3u8"
+///       }
+///     ]
+///   },
+///   "source_user_id": "111111111",
+///   "additional_media_info": {
+///     "monetizable": false
+///   },
+///   "id": "1690395366854377472",
+///   "media_url_https": "https://pbs.twimg.com/ext_tw_video_thumb/1690395366854377472/pu/img/abc.jpg",
+///   "source_user_id_str": "111111111",
+///   "sizes": {
+///     "thumb": {
+///       "w": "150",
+///       "h": "150",
+///       "resize": "crop"
+///     },
+///     "small": {
+///       "w": "680",
+///       "h": "383",
+///       "resize": "fit"
+///     },
+///     "medium": {
+///       "w": "1200",
+///       "h": "675",
+///       "resize": "fit"
+///     },
+///     "large": {
+///       "w": "1280",
+///       "h": "720",
+///       "resize": "fit"
+///     }
+///   },
+///   "type": "video",
+///   "source_status_id_str": "1690000000000000000",
+///   "display_url": "pic.twitter.com/AbCdEfGhIj"
+/// }"#;
+///
+/// let data: TweetMedia = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.media_type, MediaType::Video);
+/// assert_eq!(data.indices, [3, 26]);
+/// assert_eq!(data.source_status_id_str, Some("1690000000000000000".to_string()));
+///
+/// let video_info = data.video_info.as_ref().unwrap();
+/// assert_eq!(video_info.aspect_ratio, [16, 9]);
+/// assert_eq!(video_info.duration_millis, Some(Duration::from_millis(30030)));
+/// assert_eq!(video_info.variants[0].bitrate, Some(832000));
+/// assert!(video_info.variants[1].bitrate.is_none());
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetMedia {
+	/// Link to media viewer on Twitter
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expanded_url": "https://twitter.com/S0AndS0/status/1690395372546301952/photo/1" }
+	/// ```
+	pub expanded_url: String,
+
+	/// ID of Tweet media was first attached to, when media was re-used from another Tweet
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "source_status_id": "1690000000000000000" }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub source_status_id: Option<String>,
+
+	/// Start and stop indexes of `url` within `.tweets[].tweet.full_text`
+	///
+	/// Usually a pair, malformed entities may list more or fewer, see `convert::indices_permissive`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "indices": ["3", "26"]
+	/// }
+	/// ```
+	#[serde(with = "convert::indices_permissive")]
+	pub indices: Vec<usize>,
+
+	/// Twitter shortened, and tracking, URL found within Tweet text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://t.co/AbCdEfGhIj" }
+	/// ```
+	pub url: String,
+
+	/// Insecure link to photo, or video thumbnail
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "media_url": "http://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg" }
+	/// ```
+	pub media_url: String,
+
+	/// Media ID, also used for file names within `data/tweets_media/` directory
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id_str": "1690395366854377472" }
+	/// ```
+	pub id_str: String,
+
+	/// Video, or animated GIF, encoding data
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "video_info": {
+	///     "aspect_ratio": ["16", "9"],
+	///     "duration_millis": "30030",
+	///     "variants": [
+	///       {
+	///         "bitrate": "832000",
+	///         "content_type": "video/mp4",
+	///         "url": "https://video.twimg.com/ext_tw_video/1690395366854377472/pu/vid/640x360/abc.mp4"
+	///       }
+	///     ]
+	///   }
+	/// }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub video_info: Option<TweetMediaVideoInfo>,
+
+	/// ID of account that first attached media, when media was re-used from another Tweet
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "source_user_id": "111111111" }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub source_user_id: Option<String>,
+
+	/// Extra data about videos
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "additional_media_info": {
+	///     "monetizable": false
+	///   }
+	/// }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub additional_media_info: Option<TweetMediaAdditionalInfo>,
+
+	/// Media ID, same as `id_str`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1690395366854377472" }
+	/// ```
+	pub id: String,
+
+	/// Secure link to photo, or video thumbnail
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "media_url_https": "https://pbs.twimg.com/media/F3UNnlRWoAAQXa_.jpg" }
+	/// ```
+	pub media_url_https: String,
+
+	/// Same as `source_user_id`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "source_user_id_str": "111111111" }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub source_user_id_str: Option<String>,
+
+	/// Dimensions of each size variant served by Twitter
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "sizes": {
+	///     "thumb": { "w": "150", "h": "150", "resize": "crop" },
+	///     "small": { "w": "680", "h": "383", "resize": "fit" },
+	///     "medium": { "w": "1200", "h": "675", "resize": "fit" },
+	///     "large": { "w": "1920", "h": "1080", "resize": "fit" }
+	///   }
+	/// }
+	/// ```
+	pub sizes: TweetMediaSizes,
+
+	/// Kind of media
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "type": "photo" }
+	/// ```
+	#[serde(rename = "type")]
+	pub media_type: MediaType,
+
+	/// Same as `source_status_id`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "source_status_id_str": "1690000000000000000" }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub source_status_id_str: Option<String>,
+
+	/// What clients are able to view of `url` within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "display_url": "pic.twitter.com/AbCdEfGhIj" }
+	/// ```
+	pub display_url: String,
+}
+
+impl TweetMedia {
+	/// URL of file that archives store for media, highest bitrate MP4 variant for videos and
+	/// animated GIFs, otherwise `media_url_https`
+	pub fn file_url(&self) -> &str {
+		match (&self.media_type, &self.video_info) {
+			(MediaType::Video | MediaType::AnimatedGif, Some(video_info)) => video_info
+				.variants
+				.iter()
+				.filter(|variant| variant.content_type == "video/mp4")
+				.max_by_key(|variant| variant.bitrate.unwrap_or_default())
+				.map_or(&self.media_url_https, |variant| &variant.url),
+			_ => &self.media_url_https,
+		}
+	}
+
+	/// Last path segment of `file_url`, without query string, which archives prefix with
+	/// `<TWEET_ID>-` within `data/tweets_media/`
+	pub fn file_name(&self) -> Option<&str> {
+		self.file_url()
+			.split(['?', '#'])
+			.next()?
+			.rsplit('/')
+			.next()
+			.filter(|name| !name.is_empty())
+	}
+}
+
+string_enum! {
+	/// Kind of media found within `TweetMedia::media_type`
+	pub enum MediaType {
+		/// Still image, possibly one of up to four
+		Photo => "photo",
+
+		/// Video with sound
+		Video => "video",
+
+		/// Looping video without sound
+		AnimatedGif => "animated_gif",
+	}
+}
+
+/// Dimensions of each size variant found within `tweets[].tweet.extended_entities.media[].sizes`
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetMediaSizes {
+	/// Small cropped square
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "thumb": { "w": "150", "h": "150", "resize": "crop" } }
+	/// ```
+	pub thumb: TweetMediaSize,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "small": { "w": "680", "h": "383", "resize": "fit" } }
+	/// ```
+	pub small: TweetMediaSize,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "medium": { "w": "1200", "h": "675", "resize": "fit" } }
+	/// ```
+	pub medium: TweetMediaSize,
+
+	/// Largest size served, usually original upload dimensions
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "large": { "w": "1920", "h": "1080", "resize": "fit" } }
+	/// ```
+	pub large: TweetMediaSize,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetMediaSize;
 ///
 /// let json = r#"{
-///   "name": "ThePrimeagen",
-///   "screen_name": "ThePrimeagen",
-///   "indices": [
-///     "0",
-///     "13"
-///   ],
-///   "id_str": "291797158",
-///   "id": "291797158"
+///   "w": "1200",
+///   "h": "675",
+///   "resize": "fit"
 /// }"#;
 ///
-/// let data: TweetEntitiesUserMention = serde_json::from_str(&json).unwrap();
+/// let data: TweetMediaSize = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.name, "ThePrimeagen");
-/// assert_eq!(data.screen_name, "ThePrimeagen");
-/// assert_eq!(data.indices, [0, 13]);
-/// assert_eq!(data.id_str, "291797158");
-/// assert_eq!(data.id, "291797158");
+/// assert_eq!(data.width, 1200);
+/// assert_eq!(data.height, 675);
+/// assert_eq!(data.resize, "fit");
 ///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
 #[derive(Deserialize, Serialize, Debug, Clone, Display)]
 #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct TweetEntitiesUserMention {
-	/// Who to _@_ when mentioning a user
+pub struct TweetMediaSize {
+	/// Pixels wide
 	///
-	/// URL formats;
-	///
-	/// - Desktop: `https://twitter.com/{name}`
+	/// ## Example JSON data
 	///
-	/// > Note; redirects to log-in if not logged in, and redirections may be broken.  Thanks be to
-	/// > Mr. Musk !-D
+	/// ```json
+	/// { "w": "1200" }
+	/// ```
+	#[serde(rename = "w", with = "convert::number_like_string")]
+	pub width: usize,
+
+	/// Pixels high
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "name": "ThePrimeagen" }
+	/// { "h": "675" }
 	/// ```
-	pub name: String,
+	#[serde(rename = "h", with = "convert::number_like_string")]
+	pub height: usize,
 
-	/// Contains one value identical to `.tweets[].tweet.in_reply_to_screen_name`
+	/// How original was scaled, either `fit` or `crop`
 	///
-	/// URL formats;
+	/// TODO: Maybe convert to `enum` in future major version release
 	///
-	/// - Desktop: `https://twitter.com/{screen_name}`
+	/// ## Example JSON data
 	///
-	/// > Note; redirects to log-in if not logged in, and redirections may be broken.  Thanks be to
-	/// > Mr. Musk !-D
+	/// ```json
+	/// { "resize": "fit" }
+	/// ```
+	pub resize: String,
+}
+
+/// Encoding data found within `tweets[].tweet.extended_entities.media[].video_info`
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetMediaVideoInfo {
+	/// Width to height ratio, reduced to lowest terms
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "screen_name": "ThePrimeagen" }
+	/// {
+	///   "aspect_ratio": ["16", "9"]
+	/// }
 	/// ```
-	pub screen_name: String,
+	#[serde(with = "convert::indices")]
+	pub aspect_ratio: [usize; 2],
 
-	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	/// Length of video, not present for animated GIFs
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "duration_millis": "30030" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_duration_millis"
+	)]
+	pub duration_millis: Option<Duration>,
+
+	/// Each encoding of video served by Twitter
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// {
-	///   "indices": ["0", "13"]
+	///   "variants": [
+	///     {
+	///       "bitrate": "832000",
+	///       "content_type": "video/mp4",
+	///       "url": "https://video.twimg.com/ext_tw_video/1690395366854377472/pu/vid/640x360/abc.mp4"
+	///     }
+	///   ]
 	/// }
 	/// ```
-	#[serde(with = "convert::indices")]
-	pub indices: [usize; 2],
+	pub variants: Vec<TweetMediaVideoVariant>,
+}
 
-	/// URL formats;
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetMediaVideoVariant;
+///
+/// let json = r#"{
+///   "bitrate": "0",
+///   "content_type": "video/mp4",
+///   "url": "https://video.twimg.com/tweet_video/F3UNnlRWoAAQXa_.mp4"
+/// }"#;
+///
+/// let data: TweetMediaVideoVariant = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.bitrate, Some(0));
+/// assert_eq!(data.content_type, "video/mp4");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetMediaVideoVariant {
+	/// Bits per second, not present for streaming play-lists
 	///
-	/// - Desktop: `https://twitter.com/i/user/{id_str}`
+	/// ## Example JSON data
 	///
-	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	/// ```json
+	/// { "bitrate": "832000" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::number_like_string_opt"
+	)]
+	pub bitrate: Option<usize>,
+
+	/// MIME type, such as `video/mp4` or `application/x-mpegURL`
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "id_str": "291797158" }
+	/// { "content_type": "video/mp4" }
 	/// ```
-	pub id_str: String,
+	pub content_type: String,
 
-	/// URL formats;
+	/// Link to encoded video
 	///
-	/// - Desktop: `https://twitter.com/i/user/{id}`
+	/// ## Example JSON data
 	///
-	/// > Note; does **not** work if not logged-in.  Thanks be to Mr. Musk !-D
+	/// ```json
+	/// { "url": "https://video.twimg.com/tweet_video/F3UNnlRWoAAQXa_.mp4" }
+	/// ```
+	pub url: String,
+}
+
+/// Extra data found within `tweets[].tweet.extended_entities.media[].additional_media_info`
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetMediaAdditionalInfo {
+	/// Whether ads may be shown with video
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "id": "291797158" }
+	/// { "monetizable": false }
 	/// ```
-	pub id: String,
+	pub monetizable: bool,
+}
+
+string_enum! {
+	/// Extent of withholding found within `Tweet::withheld_scope`
+	pub enum WithheldScope {
+		/// Only this Tweet is withheld
+		Status => "status",
+
+		/// Whole account is withheld
+		User => "user",
+	}
 }
 
+/// Point, in latitude then longitude order, found within `tweets[].tweet.geo`
+///
 /// ## Example
 ///
 /// ```
-/// use twitter_archive::structs::tweets::TweetEntitiesUserUrl;
+/// use twitter_archive::convert::geo::GeoPoint;
+/// use twitter_archive::structs::tweets::TweetGeo;
 ///
 /// let json = r#"{
-///   "url": "https://t.co/4LBPKIGBzf",
-///   "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
-///   "display_url": "youtube.com/watch?v=J7bX5d…",
-///   "indices": [
-///     "132",
-///     "155"
+///   "type": "Point",
+///   "coordinates": [
+///     "37.7749",
+///     "-122.4194"
 ///   ]
 /// }"#;
 ///
-/// let data: TweetEntitiesUserUrl = serde_json::from_str(&json).unwrap();
+/// let data: TweetGeo = serde_json::from_str(&json).unwrap();
 ///
 /// // De-serialized properties
-/// assert_eq!(data.url, "https://t.co/4LBPKIGBzf");
-/// assert_eq!(data.expanded_url, "https://www.youtube.com/watch?v=J7bX5dPUw0g");
-/// assert_eq!(data.display_url, "youtube.com/watch?v=J7bX5d…");
-/// assert_eq!(data.indices, [132, 155]);
+/// assert_eq!(data.geo_type, "Point");
+/// assert_eq!(data.coordinates, GeoPoint::new(37.7749, -122.4194));
 ///
 /// // Re-serialize is equivalent to original data
 /// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
 /// ```
 #[derive(Deserialize, Serialize, Debug, Clone, Display)]
 #[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
-pub struct TweetEntitiesUserUrl {
-	/// Twitter shortened, and tracking, URL
+pub struct TweetGeo {
+	/// Kind of geometry, always `Point` in examples seen so far
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "url": "https://t.co/4LBPKIGBzf" }
+	/// { "type": "Point" }
+	/// ```
+	#[serde(rename = "type")]
+	pub geo_type: String,
+
+	/// Latitude then longitude, unlike `TweetCoordinates::coordinates`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "coordinates": ["37.7749", "-122.4194"] }
+	/// ```
+	#[serde(with = "convert::geo")]
+	pub coordinates: GeoPoint,
+}
+
+/// GeoJSON point, in longitude then latitude order, found within `tweets[].tweet.coordinates`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::convert::geo::GeoPoint;
+/// use twitter_archive::structs::tweets::TweetCoordinates;
+///
+/// let json = r#"{
+///   "type": "Point",
+///   "coordinates": [
+///     "-122.4194",
+///     "37.7749"
+///   ]
+/// }"#;
+///
+/// let data: TweetCoordinates = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.coordinates_type, "Point");
+/// assert_eq!(data.coordinates, GeoPoint::new(37.7749, -122.4194));
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetCoordinates {
+	/// Kind of GeoJSON geometry, always `Point` in examples seen so far
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "type": "Point" }
+	/// ```
+	#[serde(rename = "type")]
+	pub coordinates_type: String,
+
+	/// Longitude then latitude, unlike `TweetGeo::coordinates`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "coordinates": ["-122.4194", "37.7749"] }
+	/// ```
+	#[serde(with = "convert::geo::long_lat")]
+	pub coordinates: GeoPoint,
+}
+
+/// Named location Tweet was tagged with, found within `tweets[].tweet.place`
+///
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::tweets::TweetPlace;
+///
+/// let json = r#"{
+///   "id": "5a110d312052166f",
+///   "url": "https://api.twitter.com/1.1/geo/id/5a110d312052166f.json",
+///   "place_type": "city",
+///   "name": "San Francisco",
+///   "full_name": "San Francisco, CA",
+///   "country_code": "US",
+///   "country": "United States",
+///   "contained_within": [],
+///   "bounding_box": {
+///     "type": "Polygon",
+///     "coordinates": [
+///       [
+///         [
+///           "-122.514926",
+///           "37.708075"
+///         ],
+///         [
+///           "-122.357031",
+///           "37.708075"
+///         ],
+///         [
+///           "-122.357031",
+///           "37.833238"
+///         ],
+///         [
+///           "-122.514926",
+///           "37.833238"
+///         ]
+///       ]
+///     ]
+///   },
+///   "attributes": {}
+/// }"#;
+///
+/// let data: TweetPlace = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.place_type, "city");
+/// assert_eq!(data.full_name, "San Francisco, CA");
+/// assert_eq!(data.country_code, "US");
+///
+/// let bounding_box = data.bounding_box.as_ref().unwrap();
+/// assert_eq!(bounding_box.points().len(), 4);
+/// assert_eq!(bounding_box.points()[0].latitude, 37.708075);
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetPlace {
+	/// Hexadecimal identifier of place
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "5a110d312052166f" }
+	/// ```
+	pub id: String,
+
+	/// API link describing place, which no longer resolves without credentials
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://api.twitter.com/1.1/geo/id/5a110d312052166f.json" }
 	/// ```
 	pub url: String,
 
-	/// The _real_ URL
+	/// Granularity of place, such as `poi`, `neighborhood`, `city`, `admin`, or `country`
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g" }
+	/// { "place_type": "city" }
 	/// ```
-	pub expanded_url: String,
+	pub place_type: String,
 
-	/// What clients are able to view of URL within text
+	/// Short name of place
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
-	/// { "display_url": "youtube.com/watch?v=J7bX5d…" }
+	/// { "name": "San Francisco" }
 	/// ```
-	pub display_url: String,
+	pub name: String,
 
-	/// Start and stop indexes within `.tweets[].tweet.full_text`
+	/// Name of place qualified by containing region
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "full_name": "San Francisco, CA" }
+	/// ```
+	pub full_name: String,
+
+	/// ISO 3166-1 alpha-2 code of country place is within
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "country_code": "US" }
+	/// ```
+	pub country_code: String,
+
+	/// Name of country place is within
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "country": "United States" }
+	/// ```
+	pub country: String,
+
+	/// Places containing this place, seems to always be empty, kept as untyped JSON until an
+	/// example with content is found
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "contained_within": [] }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub contained_within: Option<Vec<Value>>,
+
+	/// Area covered by place
 	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// {
-	///   "indices": ["132", "155"]
+	///   "bounding_box": {
+	///     "type": "Polygon",
+	///     "coordinates": [
+	///       [
+	///         ["-122.514926", "37.708075"],
+	///         ["-122.357031", "37.708075"],
+	///         ["-122.357031", "37.833238"],
+	///         ["-122.514926", "37.833238"]
+	///       ]
+	///     ]
+	///   }
 	/// }
 	/// ```
-	#[serde(with = "convert::indices")]
-	pub indices: [usize; 2],
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub bounding_box: Option<TweetPlaceBoundingBox>,
+
+	/// Extra details such as street address, seems to always be empty, kept as untyped JSON
+	/// until an example with content is found
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "attributes": {} }
+	/// ```
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub attributes: Option<Map<String, Value>>,
+}
+
+/// GeoJSON polygon found within `tweets[].tweet.place.bounding_box`
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TweetPlaceBoundingBox {
+	/// Kind of GeoJSON geometry, always `Polygon` in examples seen so far
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "type": "Polygon" }
+	/// ```
+	#[serde(rename = "type")]
+	pub bounding_box_type: String,
+
+	/// Rings of longitude then latitude pairs, kept as strings so re-serializing is equivalent
+	/// to original data, see `TweetPlaceBoundingBox::points` for parsed form
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "coordinates": [
+	///     [
+	///       ["-122.514926", "37.708075"],
+	///       ["-122.357031", "37.708075"],
+	///       ["-122.357031", "37.833238"],
+	///       ["-122.514926", "37.833238"]
+	///     ]
+	///   ]
+	/// }
+	/// ```
+	pub coordinates: Vec<Vec<[String; 2]>>,
+}
+
+impl TweetPlaceBoundingBox {
+	/// Corners of every ring, skipping pairs that are not valid coordinates
+	pub fn points(&self) -> Vec<GeoPoint> {
+		self.coordinates
+			.iter()
+			.flatten()
+			.filter_map(|[longitude, latitude]| GeoPoint::parse(latitude, longitude))
+			.collect()
+	}
+}
+
+impl AsRef<Tweet> for Tweet {
+	fn as_ref(&self) -> &Tweet {
+		self
+	}
+}
+
+impl AsRef<Tweet> for TweetObject {
+	fn as_ref(&self) -> &Tweet {
+		&self.tweet
+	}
+}
+
+impl FromJsStr for Vec<TweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.tweets";
 }
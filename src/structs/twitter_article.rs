@@ -0,0 +1,251 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private long-form Article content found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/twitter-article.js
+//!
+//! Publishing state of each Article is described by `data/twitter-article-metadata.js`, check
+//! `structs::twitter_article_metadata` for details, and media is saved under
+//! `data/twitter_article_media` directory.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/twitter-article.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.twitter_article().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Article */
+//!         println!("Article index: {index}");
+//!         println!("Title: {}", object.article.title);
+//!         for path in object.article.media_paths() {
+//!             println!("Media path: {path}");
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/twitter-article.js` content
+//!
+//! ```javascript
+//! window.YTD.twitter_article.part0 = [
+//!   {
+//!     "article" : {
+//!       "id" : "1111111111111111111",
+//!       "title" : "Example Article",
+//!       "content" : "Long-form text, well beyond what fits within a Tweet",
+//!       "coverMediaUrl" : "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+//!       "mediaUrls" : [
+//!         "https://pbs.twimg.com/media/BBBBBBBBBBBBBBB.png"
+//!       ],
+//!       "createdAt" : "2023-01-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// Directory within archive that Article media files are saved to
+pub const MEDIA_DIRECTORY: &str = "data/twitter_article_media";
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::twitter_article::TwitterArticleObject;
+///
+/// let json = r#"{
+///   "article": {
+///     "id": "1111111111111111111",
+///     "title": "Example Article",
+///     "content": "Long-form text, well beyond what fits within a Tweet",
+///     "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+///     "mediaUrls": [
+///       "https://pbs.twimg.com/media/BBBBBBBBBBBBBBB.png"
+///     ],
+///     "createdAt": "2023-01-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: TwitterArticleObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.article.id, "1111111111111111111");
+/// assert_eq!(data.article.title, "Example Article");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterArticleObject {
+	/// Why they wrapped a list of Articles within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "article": {
+	///     "id": "1111111111111111111",
+	///     "title": "Example Article",
+	///     "content": "Long-form text, well beyond what fits within a Tweet",
+	///     "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+	///     "mediaUrls": [
+	///       "https://pbs.twimg.com/media/BBBBBBBBBBBBBBB.png"
+	///     ],
+	///     "createdAt": "2023-01-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub article: TwitterArticle,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::twitter_article::TwitterArticle;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "id": "1111111111111111111",
+///   "title": "Example Article",
+///   "content": "Long-form text, well beyond what fits within a Tweet",
+///   "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg",
+///   "mediaUrls": [
+///     "https://pbs.twimg.com/media/BBBBBBBBBBBBBBB.png"
+///   ],
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: TwitterArticle = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.id, "1111111111111111111");
+/// assert_eq!(data.title, "Example Article");
+/// assert_eq!(data.content, "Long-form text, well beyond what fits within a Tweet");
+/// assert_eq!(data.media_urls.len(), 1);
+/// assert_eq!(data.created_at, created_at_date_time);
+///
+/// // Media paths within archive
+/// assert_eq!(
+///     data.cover_media_path(),
+///     Some("data/twitter_article_media/1111111111111111111-AAAAAAAAAAAAAAA.jpg".to_string())
+/// );
+/// assert_eq!(
+///     data.media_paths(),
+///     vec!["data/twitter_article_media/1111111111111111111-BBBBBBBBBBBBBBB.png"]
+/// );
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterArticle {
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/articles/{id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "id": "1111111111111111111" }
+	/// ```
+	pub id: String,
+
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "title": "Example Article" }
+	/// ```
+	pub title: String,
+
+	/// Body text of Article
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "content": "Long-form text, well beyond what fits within a Tweet" }
+	/// ```
+	pub content: String,
+
+	/// Where cover image was hosted, check `TwitterArticle::cover_media_path` for archived copy
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "coverMediaUrl": "https://pbs.twimg.com/media/AAAAAAAAAAAAAAA.jpg" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cover_media_url: Option<String>,
+
+	/// Where inline media was hosted, check `TwitterArticle::media_paths` for archived copies
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "mediaUrls": [
+	///     "https://pbs.twimg.com/media/BBBBBBBBBBBBBBB.png"
+	///   ]
+	/// }
+	/// ```
+	#[serde(default)]
+	pub media_urls: Vec<String>,
+
+	/// Date time-stamp of when Article was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+}
+
+impl TwitterArticle {
+	/// Relative path within archive to cover media file, following
+	/// `data/twitter_article_media/<ARTICLE_ID>-<FILE_NAME>` naming convention
+	///
+	/// Returns `None` when Article has no cover media.  File may still be absent from archives
+	/// exported without media.
+	pub fn cover_media_path(&self) -> Option<String> {
+		self.media_path(self.cover_media_url.as_ref()?)
+	}
+
+	/// Relative paths within archive to inline media files, in same order as `media_urls`, and
+	/// following same naming convention as `TwitterArticle::cover_media_path`
+	pub fn media_paths(&self) -> Vec<String> {
+		self.media_urls
+			.iter()
+			.filter_map(|url| self.media_path(url))
+			.collect()
+	}
+
+	fn media_path(&self, url: &str) -> Option<String> {
+		let file_name = url.rsplit('/').next().filter(|name| !name.is_empty())?;
+		Some(format!("{MEDIA_DIRECTORY}/{}-{file_name}", self.id))
+	}
+}
+
+impl FromJsStr for Vec<TwitterArticleObject> {
+	const GLOBAL_NAME: &'static str = "YTD.twitter_article";
+}
@@ -0,0 +1,215 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private long-form Article publishing data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/twitter-article-metadata.js
+//!
+//! Content of each Article is described by `data/twitter-article.js`, check
+//! `structs::twitter_article` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/twitter-article-metadata.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.twitter_article_metadata().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Article metadata */
+//!         println!("Article metadata index: {index}");
+//!         println!("Article ID: {}", object.article_metadata.article_id);
+//!         println!("Lifecycle state: {}", object.article_metadata.lifecycle_state);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/twitter-article-metadata.js` content
+//!
+//! ```javascript
+//! window.YTD.twitter_article_metadata.part0 = [
+//!   {
+//!     "articleMetadata" : {
+//!       "articleId" : "1111111111111111111",
+//!       "lifecycleState" : "Published",
+//!       "tweetId" : "2222222222222222222",
+//!       "createdAt" : "2023-01-01T00:00:00.000Z",
+//!       "editedAt" : "2023-01-02T00:00:00.000Z",
+//!       "publishedAt" : "2023-01-03T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::twitter_article_metadata::TwitterArticleMetadataObject;
+///
+/// let json = r#"{
+///   "articleMetadata": {
+///     "articleId": "1111111111111111111",
+///     "lifecycleState": "Published",
+///     "tweetId": "2222222222222222222",
+///     "createdAt": "2023-01-01T00:00:00.000Z",
+///     "editedAt": "2023-01-02T00:00:00.000Z",
+///     "publishedAt": "2023-01-03T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: TwitterArticleMetadataObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.article_metadata.article_id, "1111111111111111111");
+/// assert_eq!(data.article_metadata.lifecycle_state, "Published");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterArticleMetadataObject {
+	/// Why they wrapped a list of Article metadata within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "articleMetadata": {
+	///     "articleId": "1111111111111111111",
+	///     "lifecycleState": "Published",
+	///     "tweetId": "2222222222222222222",
+	///     "createdAt": "2023-01-01T00:00:00.000Z",
+	///     "editedAt": "2023-01-02T00:00:00.000Z",
+	///     "publishedAt": "2023-01-03T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub article_metadata: TwitterArticleMetadata,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::twitter_article_metadata::TwitterArticleMetadata;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let created_at_string = "2023-01-01T00:00:00.000Z";
+/// let created_at_native_time = NaiveDateTime::parse_from_str(&created_at_string, FORMAT).unwrap();
+/// let created_at_date_time = DateTime::<Utc>::from_naive_utc_and_offset(created_at_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "articleId": "1111111111111111111",
+///   "lifecycleState": "Draft",
+///   "createdAt": "{created_at_string}"
+/// }}"#);
+///
+/// let data: TwitterArticleMetadata = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.article_id, "1111111111111111111");
+/// assert_eq!(data.lifecycle_state, "Draft");
+/// assert!(data.tweet_id.is_none());
+/// assert_eq!(data.created_at, created_at_date_time);
+/// assert!(data.edited_at.is_none());
+/// assert!(data.published_at.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterArticleMetadata {
+	/// ID of Article within `data/twitter-article.js`
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/articles/{article_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "articleId": "1111111111111111111" }
+	/// ```
+	pub article_id: String,
+
+	/// Publishing state, such as `Draft` or `Published`
+	///
+	/// TODO: Maybe convert to `enum` in future major version release
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "lifecycleState": "Published" }
+	/// ```
+	pub lifecycle_state: String,
+
+	/// ID of Tweet that shared Article, absent for drafts
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "tweetId": "2222222222222222222" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tweet_id: Option<String>,
+
+	/// Date time-stamp of when Article was created
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "createdAt": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub created_at: DateTime<Utc>,
+
+	/// Date time-stamp of most recent edit
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "editedAt": "2023-01-02T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub edited_at: Option<DateTime<Utc>>,
+
+	/// Date time-stamp of when Article was published, absent for drafts
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "publishedAt": "2023-01-03T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub published_at: Option<DateTime<Utc>>,
+}
+
+impl FromJsStr for Vec<TwitterArticleMetadataObject> {
+	const GLOBAL_NAME: &'static str = "YTD.twitter_article_metadata";
+}
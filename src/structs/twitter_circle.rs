@@ -54,6 +54,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::convert;
+use crate::parse::FromJsStr;
 
 /// ## Example
 ///
@@ -172,3 +173,7 @@ pub struct TwitterCircle {
 	#[serde(with = "convert::date_time_iso_8601")]
 	pub created_at: DateTime<Utc>,
 }
+
+impl FromJsStr for Vec<TwitterCircleObject> {
+	const GLOBAL_NAME: &'static str = "YTD.twitter_circle";
+}
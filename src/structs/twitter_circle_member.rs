@@ -0,0 +1,155 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private Twitter Circle membership data found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/twitter-circle-member.js
+//!
+//! Circle itself is described by `data/twitter-circle.js`, check `structs::twitter_circle` for
+//! details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/twitter-circle-member.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.twitter_circle_member().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Circle member */
+//!         println!("Index: {index}");
+//!         println!("Circle ID: {}", object.twitter_circle_member.twitter_circle_id);
+//!         println!("User ID: {}", object.twitter_circle_member.user_id);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/twitter-circle-member.js` content
+//!
+//! ```javascript
+//! window.YTD.twitter_circle_member.part0 = [
+//!   {
+//!     "twitterCircleMember" : {
+//!       "twitterCircleId" : "1564790306968592384",
+//!       "userId" : "3333333333333333333",
+//!       "addedAt" : "2022-09-01T00:00:00.000Z"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::twitter_circle_member::TwitterCircleMemberObject;
+///
+/// let json = r#"{
+///   "twitterCircleMember": {
+///     "twitterCircleId": "1564790306968592384",
+///     "userId": "3333333333333333333",
+///     "addedAt": "2022-09-01T00:00:00.000Z"
+///   }
+/// }"#;
+///
+/// let data: TwitterCircleMemberObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.twitter_circle_member.twitter_circle_id, "1564790306968592384");
+/// assert_eq!(data.twitter_circle_member.user_id, "3333333333333333333");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterCircleMemberObject {
+	/// Why they wrapped a list of members within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "twitterCircleMember": {
+	///     "twitterCircleId": "1564790306968592384",
+	///     "userId": "3333333333333333333",
+	///     "addedAt": "2022-09-01T00:00:00.000Z"
+	///   }
+	/// }
+	/// ```
+	pub twitter_circle_member: TwitterCircleMember,
+}
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::twitter_circle_member::TwitterCircleMember;
+///
+/// let json = r#"{
+///   "twitterCircleId": "1564790306968592384",
+///   "userId": "3333333333333333333"
+/// }"#;
+///
+/// let data: TwitterCircleMember = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.twitter_circle_id, "1564790306968592384");
+/// assert_eq!(data.user_id, "3333333333333333333");
+/// assert!(data.added_at.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct TwitterCircleMember {
+	/// ID of Circle within `data/twitter-circle.js`
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "twitterCircleId": "1564790306968592384" }
+	/// ```
+	pub twitter_circle_id: String,
+
+	/// Account ID of member
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/user/{user_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "userId": "3333333333333333333" }
+	/// ```
+	pub user_id: String,
+
+	/// Date time-stamp of when member was added to Circle, absent within some archives
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "addedAt": "2022-09-01T00:00:00.000Z" }
+	/// ```
+	#[serde(
+		default,
+		skip_serializing_if = "Option::is_none",
+		with = "convert::optional_date_time_iso_8601"
+	)]
+	pub added_at: Option<DateTime<Utc>>,
+}
+
+impl FromJsStr for Vec<TwitterCircleMemberObject> {
+	const GLOBAL_NAME: &'static str = "YTD.twitter_circle_member";
+}
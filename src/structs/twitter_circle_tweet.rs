@@ -0,0 +1,174 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private Tweets, shared only with Twitter Circle members,
+//! found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/twitter-circle-tweet.js
+//!
+//! Each entry is a regular `tweets::Tweet`, and Circle itself is described by
+//! `data/twitter-circle.js`, check `structs::twitter_circle` for details.
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/twitter-circle-tweet.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.twitter_circle_tweet().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each Circle Tweet */
+//!         println!("Index: {index}");
+//!         println!("Created at: {}", object.tweet.created_at);
+//!         println!("vvv Content\n{}\n^^^ Content", object.tweet.full_text);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/twitter-circle-tweet.js` content
+//!
+//! ```javascript
+//! window.YTD.twitter_circle_tweet.part0 = [
+//!   {
+//!     "tweet" : {
+//!       "edit_info" : {
+//!         "initial" : {
+//!           "editTweetIds" : [
+//!             "1111111111111111111"
+//!           ],
+//!           "editableUntil" : "2023-08-12T17:10:37.000Z",
+//!           "editsRemaining" : "5",
+//!           "isEditEligible" : true
+//!         }
+//!       },
+//!       "retweeted" : false,
+//!       "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!       "entities" : {
+//!         "hashtags" : [ ],
+//!         "symbols" : [ ],
+//!         "user_mentions" : [ ],
+//!         "urls" : [ ]
+//!       },
+//!       "display_text_range" : [
+//!         "0",
+//!         "21"
+//!       ],
+//!       "favorite_count" : "0",
+//!       "id_str" : "1111111111111111111",
+//!       "truncated" : false,
+//!       "retweet_count" : "0",
+//!       "id" : "1111111111111111111",
+//!       "created_at" : "Sat Aug 12 16:10:37 +0000 2023",
+//!       "favorited" : false,
+//!       "full_text" : "Just between friends",
+//!       "lang" : "en"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::parse::FromJsStr;
+use crate::structs::tweets;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::twitter_circle_tweet::TwitterCircleTweetObject;
+///
+/// let json = r#"{
+///   "tweet": {
+///     "edit_info": {
+///       "initial": {
+///         "editTweetIds": [
+///           "1111111111111111111"
+///         ],
+///         "editableUntil": "2023-08-12T17:10:37.000Z",
+///         "editsRemaining": "5",
+///         "isEditEligible": true
+///       }
+///     },
+///     "retweeted": false,
+///     "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+///     "entities": {
+///       "hashtags": [],
+///       "symbols": [],
+///       "user_mentions": [],
+///       "urls": []
+///     },
+///     "display_text_range": [
+///       "0",
+///       "21"
+///     ],
+///     "favorite_count": "0",
+///     "id_str": "1111111111111111111",
+///     "truncated": false,
+///     "retweet_count": "0",
+///     "id": "1111111111111111111",
+///     "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+///     "favorited": false,
+///     "full_text": "Just between friends",
+///     "lang": "en"
+///   }
+/// }"#;
+///
+/// let data: TwitterCircleTweetObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.tweet.id, "1111111111111111111");
+/// assert_eq!(data.tweet.full_text, "Just between friends");
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct TwitterCircleTweetObject {
+	/// Why they wrapped a list of Tweets within unnecessary object label is anyone's guess
+	///
+	/// Properties are shared with every other Tweet, check `tweets::Tweet` for details
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "tweet": {
+	///     "edit_info": {
+	///       "initial": {
+	///         "editTweetIds": ["1111111111111111111"],
+	///         "editableUntil": "2023-08-12T17:10:37.000Z",
+	///         "editsRemaining": "5",
+	///         "isEditEligible": true
+	///       }
+	///     },
+	///     "retweeted": false,
+	///     "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+	///     "entities": {
+	///       "hashtags": [],
+	///       "symbols": [],
+	///       "user_mentions": [],
+	///       "urls": []
+	///     },
+	///     "display_text_range": ["0", "21"],
+	///     "favorite_count": "0",
+	///     "id_str": "1111111111111111111",
+	///     "truncated": false,
+	///     "retweet_count": "0",
+	///     "id": "1111111111111111111",
+	///     "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+	///     "favorited": false,
+	///     "full_text": "Just between friends",
+	///     "lang": "en"
+	///   }
+	/// }
+	/// ```
+	pub tweet: tweets::Tweet,
+}
+
+impl FromJsStr for Vec<TwitterCircleTweetObject> {
+	const GLOBAL_NAME: &'static str = "YTD.twitter_circle_tweet";
+}
@@ -0,0 +1,155 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 describe links, each with shortened, expanded, and display
+//! forms, under differing key names found within;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/tweets.js                 -> `entities.urls[]`
+//!   twitter-<DATE>-<UID>.zip:data/note-tweet.js             -> `core.urls[]`
+//!   twitter-<DATE>-<UID>.zip:data/direct-messages.js        -> `messageCreate.urls[]`
+//!   twitter-<DATE>-<UID>.zip:data/direct-messages-group.js  -> `messageCreate.urls[]`
+//!
+//! `UrlEntity` holds the forms shared by all of them, so link analysis may treat Tweets, Notes,
+//! and Direct Messages alike.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::structs::direct_message::MessageCreateUrl;
+//! use twitter_archive::structs::tweets::TweetEntitiesUserUrl;
+//! use twitter_archive::structs::url_entity::UrlEntity;
+//!
+//! let tweet_url: TweetEntitiesUserUrl = serde_json::from_str(r#"{
+//!   "url": "https://t.co/4LBPKIGBzf",
+//!   "expanded_url": "https://www.youtube.com/watch?v=J7bX5dPUw0g",
+//!   "display_url": "youtube.com/watch?v=J7bX5d…",
+//!   "indices": [ "132", "155" ]
+//! }"#).unwrap();
+//!
+//! let message_url: MessageCreateUrl = serde_json::from_str(r#"{
+//!   "url": "https://t.co/Yot7Ijm9vG",
+//!   "expanded": "https://WWW.YouTube.com/watch?v=J7bX5dPUw0g",
+//!   "display": "youtube.com/watch?v=J7bX5d…"
+//! }"#).unwrap();
+//!
+//! let links = [UrlEntity::from(&tweet_url), UrlEntity::from(&message_url)];
+//!
+//! assert!(links.iter().all(|link| link.host() == Some("youtube.com".to_string())));
+//! ```
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::structs::{direct_message, note_tweet, tweets};
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::url_entity::UrlEntity;
+///
+/// let json = r#"{
+///   "url": "https://t.co/Yot7Ijm9vG",
+///   "expanded_url": "https://github.com/S0AndS0/",
+///   "display_url": "github.com/S0AndS0/"
+/// }"#;
+///
+/// let data: UrlEntity = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.url, "https://t.co/Yot7Ijm9vG");
+/// assert_eq!(data.expanded_url, "https://github.com/S0AndS0/");
+/// assert_eq!(data.display_url, "github.com/S0AndS0/");
+///
+/// // Re-serialize is equivalent to original data
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+pub struct UrlEntity {
+	/// Twitter shortened, and tracking, URL
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://t.co/Yot7Ijm9vG" }
+	/// ```
+	pub url: String,
+
+	/// The _real_ URL
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "expanded_url": "https://github.com/S0AndS0/" }
+	/// ```
+	pub expanded_url: String,
+
+	/// What clients are able to view of URL within text
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "display_url": "github.com/S0AndS0/" }
+	/// ```
+	pub display_url: String,
+}
+
+impl UrlEntity {
+	/// Lower-cased host name of `expanded_url`, without `www.` prefix, user information, or port
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::url_entity::UrlEntity;
+	///
+	/// let data = UrlEntity {
+	///     url: "https://t.co/Yot7Ijm9vG".to_string(),
+	///     expanded_url: "https://user@WWW.Example.com:8080/path?query".to_string(),
+	///     display_url: "example.com/path?query".to_string(),
+	/// };
+	///
+	/// assert_eq!(data.host(), Some("example.com".to_string()));
+	/// ```
+	pub fn host(&self) -> Option<String> {
+		let (_, rest) = self.expanded_url.split_once("://")?;
+		let authority = rest.split(['/', '?', '#']).next()?;
+		let authority = authority.rsplit('@').next()?;
+		let host = match authority.rsplit_once(':') {
+			Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+			_ => authority,
+		};
+
+		let host = host.to_lowercase();
+		let host = host.strip_prefix("www.").unwrap_or(&host);
+		(!host.is_empty()).then(|| host.to_string())
+	}
+}
+
+impl From<&tweets::TweetEntitiesUserUrl> for UrlEntity {
+	fn from(url: &tweets::TweetEntitiesUserUrl) -> Self {
+		Self {
+			url: url.url.clone(),
+			expanded_url: url.expanded_url.clone(),
+			display_url: url.display_url.clone(),
+		}
+	}
+}
+
+impl From<&note_tweet::Url> for UrlEntity {
+	fn from(url: &note_tweet::Url) -> Self {
+		Self {
+			url: url.short_url.clone(),
+			expanded_url: url.expanded_url.clone(),
+			display_url: url.display_url.clone(),
+		}
+	}
+}
+
+impl From<&direct_message::MessageCreateUrl> for UrlEntity {
+	fn from(url: &direct_message::MessageCreateUrl) -> Self {
+		Self {
+			url: url.url.clone(),
+			expanded_url: url.expanded.clone(),
+			display_url: url.display.clone(),
+		}
+	}
+}
@@ -0,0 +1,170 @@
+#!/usr/bin/env rust
+
+//! Tweeter archives as of 2023-08-31 have private link click telemetry found under;
+//!
+//!   twitter-<DATE>-<UID>.zip:data/user-link-clicks.js
+//!
+//! ## Example file reader for `twitter-<DATE>-<UID>.zip:data/user-link-clicks.js`
+//!
+//! ```no_build
+//! use twitter_archive::reader::ArchiveReader;
+//!
+//! fn main() {
+//!     let input_file = "~/Downloads/twitter-archive.zip";
+//!
+//!     let mut archive = ArchiveReader::open(input_file).expect("Unable to read --input-file");
+//!
+//!     for (index, object) in archive.user_link_clicks().expect("Unable to parse").iter().enumerate() {
+//!         /* Do stuff with each recorded click */
+//!         println!("Link click index: {index}");
+//!         println!("URL: {}", object.user_link_click.url);
+//!         println!("Time stamp: {}", object.user_link_click.time_stamp);
+//!     }
+//! }
+//! ```
+//!
+//! ## Example `twitter-<DATE>-<UID>.zip:data/user-link-clicks.js` content
+//!
+//! ```javascript
+//! window.YTD.user_link_clicks.part0 = [
+//!   {
+//!     "userLinkClick" : {
+//!       "url" : "https://example.com/article",
+//!       "tweetId" : "1111111111111111111",
+//!       "timeStamp" : "2023-01-01T00:00:00.000Z",
+//!       "device" : "Twitter for iPhone"
+//!     }
+//!   }
+//! ]
+//! ```
+
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+use crate::parse::FromJsStr;
+
+/// ## Example
+///
+/// ```
+/// use twitter_archive::structs::user_link_clicks::UserLinkClickObject;
+///
+/// let json = r#"{
+///   "userLinkClick": {
+///     "url": "https://example.com/article",
+///     "tweetId": "1111111111111111111",
+///     "timeStamp": "2023-01-01T00:00:00.000Z",
+///     "device": "Twitter for iPhone"
+///   }
+/// }"#;
+///
+/// let data: UserLinkClickObject = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.user_link_click.url, "https://example.com/article");
+/// assert_eq!(data.user_link_click.device, Some("Twitter for iPhone".to_string()));
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct UserLinkClickObject {
+	/// Why they wrapped a list of clicks within unnecessary object label is anyone's guess
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// {
+	///   "userLinkClick": {
+	///     "url": "https://example.com/article",
+	///     "tweetId": "1111111111111111111",
+	///     "timeStamp": "2023-01-01T00:00:00.000Z",
+	///     "device": "Twitter for iPhone"
+	///   }
+	/// }
+	/// ```
+	pub user_link_click: UserLinkClick,
+}
+
+/// ## Example
+///
+/// ```
+/// use chrono::{DateTime, NaiveDateTime, Utc};
+///
+/// use twitter_archive::structs::user_link_clicks::UserLinkClick;
+/// use twitter_archive::convert::date_time_iso_8601::FORMAT;
+///
+/// let time_stamp_string = "2023-01-01T00:00:00.000Z";
+/// let time_stamp_native_time = NaiveDateTime::parse_from_str(&time_stamp_string, FORMAT).unwrap();
+/// let time_stamp_date_time = DateTime::<Utc>::from_naive_utc_and_offset(time_stamp_native_time, Utc);
+///
+/// let json = format!(r#"{{
+///   "url": "https://example.com/article",
+///   "timeStamp": "{time_stamp_string}"
+/// }}"#);
+///
+/// let data: UserLinkClick = serde_json::from_str(&json).unwrap();
+///
+/// // De-serialized properties
+/// assert_eq!(data.url, "https://example.com/article");
+/// assert!(data.tweet_id.is_none());
+/// assert_eq!(data.time_stamp, time_stamp_date_time);
+/// assert!(data.device.is_none());
+///
+/// // Re-serialize is equivalent to original data without pretty printing
+/// assert_eq!(serde_json::to_string_pretty(&data).unwrap(), json);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[display(fmt = "{}", "serde_json::to_value(self).unwrap()")]
+#[serde(rename_all = "camelCase")]
+pub struct UserLinkClick {
+	/// Destination of clicked link, after `t.co` redirect was resolved
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "url": "https://example.com/article" }
+	/// ```
+	pub url: String,
+
+	/// ID of Tweet link was clicked from, absent when clicked elsewhere such as a profile
+	///
+	/// URL formats;
+	///
+	/// - Desktop: https://twitter.com/i/web/status/{tweet_id}
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "tweetId": "1111111111111111111" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tweet_id: Option<String>,
+
+	/// Date time-stamp of when click was recorded
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "timeStamp": "2023-01-01T00:00:00.000Z" }
+	/// ```
+	#[serde(with = "convert::date_time_iso_8601")]
+	pub time_stamp: DateTime<Utc>,
+
+	/// Client application, or device, that click was recorded from
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "device": "Twitter for iPhone" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub device: Option<String>,
+}
+
+impl FromJsStr for Vec<UserLinkClickObject> {
+	const GLOBAL_NAME: &'static str = "YTD.user_link_clicks";
+}
@@ -50,6 +50,8 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+use crate::parse::FromJsStr;
+
 /// ## Example
 ///
 /// ```
@@ -119,10 +121,88 @@ pub struct Verified {
 	/// ```
 	pub account_id: String,
 
+	/// Legacy verification flag, check `Verified::verification` for typed status
+	///
 	/// ## Example JSON data
 	///
 	/// ```json
 	/// { "verified": false }
 	/// ```
 	pub verified: bool,
+
+	/// Kind of verification, only found within newer exports
+	///
+	/// ## Example JSON data
+	///
+	/// ```json
+	/// { "verifiedType": "Blue" }
+	/// ```
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub verified_type: Option<VerifiedType>,
+}
+
+impl Verified {
+	/// Typed verification status, or `None` if account is not verified
+	///
+	/// Prefers `verified_type`, and falls back to `VerifiedType::Legacy` for older exports that
+	/// only have `verified` flag set.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::verified::{Verified, VerifiedType};
+	///
+	/// let data: Verified = serde_json::from_str(r#"{ "accountId": "435455769", "verified": true }"#).unwrap();
+	/// assert_eq!(data.verification(), Some(VerifiedType::Legacy));
+	///
+	/// let data: Verified = serde_json::from_str(r#"{ "accountId": "435455769", "verified": true, "verifiedType": "Business" }"#).unwrap();
+	/// assert_eq!(data.verification(), Some(VerifiedType::Business));
+	///
+	/// let data: Verified = serde_json::from_str(r#"{ "accountId": "435455769", "verified": false }"#).unwrap();
+	/// assert_eq!(data.verification(), None);
+	/// ```
+	pub fn verification(&self) -> Option<VerifiedType> {
+		match &self.verified_type {
+			Some(verified_type) => Some(verified_type.clone()),
+			None if self.verified => Some(VerifiedType::Legacy),
+			None => None,
+		}
+	}
+}
+
+string_enum! {
+	/// Kind of verification check mark
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use twitter_archive::structs::verified::VerifiedType;
+	///
+	/// let data: Vec<VerifiedType> = serde_json::from_str(r#"["Blue", "Government", "Notable"]"#).unwrap();
+	///
+	/// // De-serialized properties
+	/// assert_eq!(data[0], VerifiedType::Blue);
+	/// assert_eq!(data[1], VerifiedType::Government);
+	/// assert_eq!(data[2], VerifiedType::Other("Notable".to_string()));
+	///
+	/// // Both known and unknown values re-serialize to original strings
+	/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"["Blue","Government","Notable"]"#);
+	/// ```
+	pub enum VerifiedType {
+		/// Blue check mark granted before paid subscriptions existed
+		Legacy => "Legacy",
+
+		/// Blue check mark of paid subscription
+		Blue => "Blue",
+
+		/// Grey check mark of government and multilateral organizations
+		Government => "Government",
+
+		/// Gold check mark of verified organizations
+		Business => "Business",
+	}
+}
+
+impl FromJsStr for Vec<VerifiedObject> {
+	const GLOBAL_NAME: &'static str = "YTD.verified";
 }
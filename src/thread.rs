@@ -0,0 +1,127 @@
+#!/usr/bin/env rust
+
+//! Walk reply chains within an archive to find Tweet that started each thread, since archives
+//! lack the `conversation_id` Twitter's API provides
+//!
+//! Replies point at their parent via `in_reply_to_status_id_str`, so a chain is followed upward
+//! until reaching a Tweet that is not a reply, or a parent that is not within the archive, such
+//! as another account's Tweet.  In latter case ID of missing parent is the root, so replies to the
+//! same outside Tweet still group together.
+//!
+//! ## Example
+//!
+//! ```
+//! use twitter_archive::structs::tweets::Tweet;
+//! use twitter_archive::thread::ThreadIndex;
+//!
+//! let json = r#"{
+//!   "edit_info": {
+//!     "initial": {
+//!       "editTweetIds": [ "1111111111111111111" ],
+//!       "editableUntil": "2023-08-12T17:10:37.000Z",
+//!       "editsRemaining": "5",
+//!       "isEditEligible": true
+//!     }
+//!   },
+//!   "retweeted": false,
+//!   "source": "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!   "entities": {
+//!     "hashtags": [],
+//!     "symbols": [],
+//!     "user_mentions": [],
+//!     "urls": []
+//!   },
+//!   "display_text_range": [ "0", "5" ],
+//!   "favorite_count": "0",
+//!   "id_str": "1111111111111111111",
+//!   "truncated": false,
+//!   "retweet_count": "0",
+//!   "id": "1111111111111111111",
+//!   "created_at": "Sat Aug 12 16:10:37 +0000 2023",
+//!   "favorited": false,
+//!   "full_text": "1/3",
+//!   "lang": "en"
+//! }"#;
+//!
+//! let first: Tweet = serde_json::from_str(&json).unwrap();
+//!
+//! let mut second = first.clone();
+//! second.id_str = "2222222222222222222".to_string();
+//! second.in_reply_to_status_id_str = Some(first.id_str.clone());
+//!
+//! let mut third = first.clone();
+//! third.id_str = "3333333333333333333".to_string();
+//! third.in_reply_to_status_id_str = Some(second.id_str.clone());
+//!
+//! // Reply to Tweet not within archive
+//! let mut other = first.clone();
+//! other.id_str = "4444444444444444444".to_string();
+//! other.in_reply_to_status_id_str = Some("9999999999999999999".to_string());
+//!
+//! let tweets = vec![third, first, other, second];
+//! let threads = ThreadIndex::new(&tweets);
+//!
+//! assert_eq!(tweets[0].thread_root(&threads), "1111111111111111111");
+//! assert_eq!(tweets[1].thread_root(&threads), "1111111111111111111");
+//! assert_eq!(tweets[2].thread_root(&threads), "9999999999999999999");
+//!
+//! let groups = threads.group(&tweets);
+//! assert_eq!(groups["1111111111111111111"].len(), 3);
+//! assert_eq!(groups["9999999999999999999"].len(), 1);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::structs::tweets::Tweet;
+
+/// Parent of every reply within archive, keyed by ID of reply
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadIndex {
+	parents: HashMap<String, String>,
+}
+
+impl ThreadIndex {
+	/// Index replies among `tweets`, which may be `Tweet`, `TweetObject`, or references to either
+	pub fn new<I>(tweets: I) -> Self
+	where
+		I: IntoIterator,
+		I::Item: AsRef<Tweet>,
+	{
+		let parents = tweets
+			.into_iter()
+			.filter_map(|item| {
+				let tweet = item.as_ref();
+				let parent = tweet.in_reply_to_status_id_str.as_ref()?;
+				Some((tweet.id_str.clone(), parent.clone()))
+			})
+			.collect();
+
+		Self { parents }
+	}
+
+	/// ID of Tweet that started thread containing Tweet with `id`, which is `id` itself when
+	/// that Tweet is not a known reply
+	pub fn root<'a>(&'a self, id: &'a str) -> &'a str {
+		let mut root = id;
+
+		// Bounded by number of replies, so malformed data with cycles still terminates
+		for _ in 0..=self.parents.len() {
+			match self.parents.get(root) {
+				Some(parent) => root = parent,
+				None => break,
+			}
+		}
+
+		root
+	}
+
+	/// Group `tweets` by ID of Tweet that started their thread, keeping order within each group
+	pub fn group<'t, T: AsRef<Tweet>>(&self, tweets: &'t [T]) -> HashMap<String, Vec<&'t T>> {
+		let mut groups: HashMap<String, Vec<&'t T>> = HashMap::new();
+		for item in tweets {
+			let root = self.root(&item.as_ref().id_str);
+			groups.entry(root.to_string()).or_default().push(item);
+		}
+		groups
+	}
+}
@@ -0,0 +1,323 @@
+#!/usr/bin/env rust
+
+//! Cross-check archive contents against `data/manifest.js`, and Direct Message headers against
+//! full Direct Messages
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::reader::ArchiveReader;
+//! use twitter_archive::validate::Discrepancy;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/manifest.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.__THAR_CONFIG = {
+//!   "dataTypes": {
+//!     "like": {
+//!       "files": [
+//!         {
+//!           "fileName": "data/like.js",
+//!           "globalName": "YTD.like.part0",
+//!           "count": "2"
+//!         }
+//!       ]
+//!     },
+//!     "tweets": {
+//!       "files": [
+//!         {
+//!           "fileName": "data/tweets.js",
+//!           "globalName": "YTD.tweets.part0",
+//!           "count": "10"
+//!         }
+//!       ]
+//!     },
+//!     "tweetsMedia": {
+//!       "mediaDirectory": "data/tweets_media"
+//!     }
+//!   }
+//! }"#).unwrap();
+//! zip_writer.start_file("data/like.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.like.part0 = [ { "like" : { } } ]"#).unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! let discrepancies = archive.validate().unwrap();
+//!
+//! assert_eq!(discrepancies.len(), 3);
+//! assert!(matches!(
+//!     &discrepancies[0],
+//!     Discrepancy::CountMismatch { file_name, expected: 2, actual: 1, .. } if file_name == "data/like.js"
+//! ));
+//! assert!(matches!(
+//!     &discrepancies[1],
+//!     Discrepancy::MissingFile { file_name, .. } if file_name == "data/tweets.js"
+//! ));
+//! assert!(matches!(
+//!     &discrepancies[2],
+//!     Discrepancy::MissingMediaDirectory { directory, .. } if directory == "data/tweets_media"
+//! ));
+//! ```
+
+use std::collections::BTreeSet;
+
+use derive_more::Display;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::structs::{
+	direct_message_group_headers, direct_message_headers, direct_messages, direct_messages_group,
+};
+use crate::Result;
+
+/// Difference between what `data/manifest.js` describes and what archive contains
+#[derive(Deserialize, Serialize, Debug, Clone, Display)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum Discrepancy {
+	/// Manifest lists a data file that does not exist
+	#[display(fmt = "{data_type}: missing {file_name}")]
+	#[serde(rename_all = "camelCase")]
+	MissingFile {
+		/// Manifest `dataTypes` name, such as `"tweets"`
+		data_type: String,
+
+		/// Relative path to file within archive
+		file_name: String,
+	},
+
+	/// Manifest lists a media directory that contains no files
+	///
+	/// Note; accounts that never attached media may legitimately have empty directories
+	#[display(fmt = "{data_type}: no files within {directory}")]
+	#[serde(rename_all = "camelCase")]
+	MissingMediaDirectory {
+		/// Manifest `dataTypes` name, such as `"tweetsMedia"`
+		data_type: String,
+
+		/// Relative path to directory within archive
+		directory: String,
+	},
+
+	/// Number of items within data file differs from manifest `count`
+	#[display(fmt = "{data_type}: {file_name} has {actual} items, manifest claims {expected}")]
+	#[serde(rename_all = "camelCase")]
+	CountMismatch {
+		/// Manifest `dataTypes` name, such as `"tweets"`
+		data_type: String,
+
+		/// Relative path to file within archive
+		file_name: String,
+
+		/// Count claimed by manifest
+		expected: usize,
+
+		/// Count of items actually found
+		actual: usize,
+	},
+
+	/// Data file exists but is not a JavaScript array assigned to the expected global name
+	#[display(fmt = "{data_type}: {error}")]
+	#[serde(rename_all = "camelCase")]
+	Unreadable {
+		/// Manifest `dataTypes` name, such as `"tweets"`
+		data_type: String,
+
+		/// Relative path to file within archive
+		file_name: String,
+
+		/// Description of parse failure
+		error: String,
+	},
+
+	/// Direct Message conversation listed by headers, or full messages, lacks its counterpart
+	/// within the other data type, as happens with partial archives
+	#[display(fmt = "{data_type}: conversation {conversation_id} missing from {missing_from}")]
+	#[serde(rename_all = "camelCase")]
+	UnmatchedConversation {
+		/// Manifest `dataTypes` name that lists conversation, such as `"directMessageHeaders"`
+		data_type: String,
+
+		/// ID of conversation
+		conversation_id: String,
+
+		/// Manifest `dataTypes` name that lacks conversation, such as `"directMessages"`
+		missing_from: String,
+	},
+}
+
+impl<S: Source> ArchiveReader<S> {
+	/// Check each file listed by manifest exists and holds `count` items, and that each media
+	/// directory holds at least one file
+	///
+	/// Items are counted without deserializing them into data structures, so data types this crate
+	/// does not model are validated too.  Archives without a manifest have nothing to validate.
+	pub fn validate(&mut self) -> Result<Vec<Discrepancy>> {
+		if self.manifest_raw()?.is_null() {
+			return Ok(Vec::new());
+		}
+		let capabilities = self.capabilities()?;
+
+		let mut discrepancies = Vec::new();
+		for (data_type, capability) in capabilities.data_types {
+			for file in capability.files {
+				if !file.present {
+					discrepancies.push(Discrepancy::MissingFile {
+						data_type: data_type.clone(),
+						file_name: file.file_name,
+					});
+					continue;
+				}
+
+				match self.read_array_length(&file.file_name, &file.global_name) {
+					Ok(actual) if actual != file.count => {
+						discrepancies.push(Discrepancy::CountMismatch {
+							data_type: data_type.clone(),
+							file_name: file.file_name,
+							expected: file.count,
+							actual,
+						});
+					}
+					Ok(_) => {}
+					Err(error) => discrepancies.push(Discrepancy::Unreadable {
+						data_type: data_type.clone(),
+						error: error.to_string(),
+						file_name: file.file_name,
+					}),
+				}
+			}
+
+			if let Some(directory) = capability.media_directory {
+				if !capability.media_directory_present {
+					discrepancies.push(Discrepancy::MissingMediaDirectory {
+						data_type: data_type.clone(),
+						directory,
+					});
+				}
+			}
+		}
+
+		Ok(discrepancies)
+	}
+
+	/// Check each conversation within `data/direct-message-headers.js` has a counterpart within
+	/// `data/direct-messages.js`, and vice versa, likewise for group conversations within
+	/// `data/direct-message-group-headers.js` and `data/direct-messages-group.js`
+	///
+	/// Data types the archive has no files for are treated as empty, so archives lacking both
+	/// headers and messages have nothing to report.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use std::io::{Cursor, Write};
+	/// use zip::write::{FileOptions, ZipWriter};
+	///
+	/// use twitter_archive::reader::ArchiveReader;
+	/// use twitter_archive::validate::Discrepancy;
+	///
+	/// let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+	/// zip_writer.start_file("data/direct-message-headers.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.direct_message_headers.part0 = [
+	///   { "dmConversation": { "conversationId": "1111-2222", "messages": [] } },
+	///   { "dmConversation": { "conversationId": "1111-3333", "messages": [] } }
+	/// ]"#).unwrap();
+	/// zip_writer.start_file("data/direct-messages.js", FileOptions::default()).unwrap();
+	/// zip_writer.write_all(br#"window.YTD.direct_messages.part0 = [
+	///   { "dmConversation": { "conversationId": "1111-2222", "messages": [] } },
+	///   { "dmConversation": { "conversationId": "1111-4444", "messages": [] } }
+	/// ]"#).unwrap();
+	///
+	/// let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+	///
+	/// let discrepancies = archive.validate_dm_headers().unwrap();
+	///
+	/// assert_eq!(discrepancies.len(), 2);
+	/// assert!(matches!(
+	///     &discrepancies[0],
+	///     Discrepancy::UnmatchedConversation { conversation_id, missing_from, .. }
+	///         if conversation_id == "1111-3333" && missing_from == "directMessages"
+	/// ));
+	/// assert!(matches!(
+	///     &discrepancies[1],
+	///     Discrepancy::UnmatchedConversation { conversation_id, missing_from, .. }
+	///         if conversation_id == "1111-4444" && missing_from == "directMessageHeaders"
+	/// ));
+	/// ```
+	pub fn validate_dm_headers(&mut self) -> Result<Vec<Discrepancy>> {
+		let mut discrepancies = Vec::new();
+
+		let headers = if self.has_data_files::<direct_message_headers::DmConversationObject>()? {
+			self.direct_message_headers()?
+				.into_iter()
+				.map(|object| object.dm_conversation.conversation_id)
+				.collect()
+		} else {
+			BTreeSet::new()
+		};
+		let messages = if self.has_data_files::<direct_messages::DmConversationObject>()? {
+			self.direct_messages()?
+				.into_iter()
+				.map(|object| object.dm_conversation.conversation_id)
+				.collect()
+		} else {
+			BTreeSet::new()
+		};
+		unmatched_conversations(
+			&mut discrepancies,
+			("directMessageHeaders", &headers),
+			("directMessages", &messages),
+		);
+
+		let headers =
+			if self.has_data_files::<direct_message_group_headers::DmConversationObject>()? {
+				self.direct_message_group_headers()?
+					.into_iter()
+					.map(|object| object.dm_conversation.conversation_id)
+					.collect()
+			} else {
+				BTreeSet::new()
+			};
+		let messages = if self.has_data_files::<direct_messages_group::DmConversationObject>()? {
+			self.direct_messages_group()?
+				.into_iter()
+				.map(|object| object.dm_conversation.conversation_id)
+				.collect()
+		} else {
+			BTreeSet::new()
+		};
+		unmatched_conversations(
+			&mut discrepancies,
+			("directMessageGroupHeaders", &headers),
+			("directMessagesGroup", &messages),
+		);
+
+		Ok(discrepancies)
+	}
+
+	/// Count items of JavaScript array without keeping them
+	fn read_array_length(&mut self, file_name: &str, global_name: &str) -> Result<usize> {
+		let items: Vec<IgnoredAny> = self.read_data(file_name, global_name)?;
+		Ok(items.len())
+	}
+}
+
+/// Report conversation IDs of either data type that the other lacks, those of `a` first
+fn unmatched_conversations(
+	discrepancies: &mut Vec<Discrepancy>,
+	a: (&str, &BTreeSet<String>),
+	b: (&str, &BTreeSet<String>),
+) {
+	for ((data_type, ids), (missing_from, others)) in [(a, b), (b, a)] {
+		discrepancies.extend(ids.difference(others).map(|conversation_id| {
+			Discrepancy::UnmatchedConversation {
+				data_type: data_type.to_string(),
+				conversation_id: conversation_id.clone(),
+				missing_from: missing_from.to_string(),
+			}
+		}));
+	}
+}
@@ -0,0 +1,162 @@
+#!/usr/bin/env rust
+
+//! Detect which generation of archive export is being read, so older exports may be parsed
+//! instead of failing deserialization
+//!
+//! Known differences between generations;
+//!
+//! - 2019 era exports have no `data/manifest.js`, Tweets are found within `data/tweet.js` assigned
+//!   to `YTD.tweet.part0`, and each Tweet is not wrapped within a `{ "tweet": { } }` object
+//! - 2021 era exports have a manifest, but Tweets are still found within `data/tweet.js`
+//! - 2023 era exports, and later, name the file `data/tweets.js` and add `edit_info` to Tweets
+//!
+//! ## Example
+//!
+//! ```
+//! use std::io::{Cursor, Write};
+//! use zip::write::{FileOptions, ZipWriter};
+//!
+//! use twitter_archive::reader::ArchiveReader;
+//! use twitter_archive::version::ArchiveVersion;
+//!
+//! let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+//! zip_writer.start_file("data/tweet.js", FileOptions::default()).unwrap();
+//! zip_writer.write_all(br#"window.YTD.tweet.part0 = [
+//!   {
+//!     "retweeted" : false,
+//!     "source" : "<a href=\"https://mobile.twitter.com\" rel=\"nofollow\">Twitter Web App</a>",
+//!     "entities" : {
+//!       "hashtags" : [ ],
+//!       "symbols" : [ ],
+//!       "user_mentions" : [ ],
+//!       "urls" : [ ]
+//!     },
+//!     "display_text_range" : [ "0", "6" ],
+//!     "favorite_count" : "0",
+//!     "id_str" : "1111111111111111111",
+//!     "truncated" : false,
+//!     "retweet_count" : "0",
+//!     "id" : "1111111111111111111",
+//!     "created_at" : "Tue Jan 01 00:00:00 +0000 2019",
+//!     "favorited" : false,
+//!     "full_text" : "Hello!",
+//!     "lang" : "en"
+//!   }
+//! ]"#).unwrap();
+//!
+//! let mut archive = ArchiveReader::new(zip_writer.finish().unwrap()).unwrap();
+//!
+//! assert_eq!(archive.version().unwrap(), ArchiveVersion::V2019);
+//!
+//! let data = archive.tweets().unwrap();
+//!
+//! assert_eq!(data.len(), 1);
+//! assert_eq!(data[0].tweet.full_text, "Hello!");
+//! assert_eq!(data[0].tweet.edit_info.initial.edits_remaining, 0);
+//! assert_eq!(data[0].tweet.edit_info.initial.editable_until, data[0].tweet.created_at);
+//! ```
+
+use chrono::DateTime;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::convert;
+use crate::reader::ArchiveReader;
+use crate::source::Source;
+use crate::structs::tweets;
+use crate::{Error, Result};
+
+/// Generation of archive export, named after the year its shape was first observed
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum ArchiveVersion {
+	/// No manifest, Tweets within `data/tweet.js` not wrapped in `{ "tweet": { } }` objects
+	V2019,
+
+	/// Manifest describes `tweet` data type found within `data/tweet.js`
+	V2021,
+
+	/// Manifest describes `tweets` data type found within `data/tweets.js`
+	V2023,
+
+	/// Shape did not match any known generation
+	Unknown,
+}
+
+impl<S: Source> ArchiveReader<S> {
+	/// Detect archive generation based on manifest shape and presence of marker files
+	pub fn version(&mut self) -> Result<ArchiveVersion> {
+		let data_types = self.manifest_raw()?.get("dataTypes").cloned();
+
+		let version = match data_types {
+			Some(data_types) if data_types.get("tweets").is_some() => ArchiveVersion::V2023,
+			Some(data_types) if data_types.get("tweet").is_some() => ArchiveVersion::V2021,
+			_ if self.contains("data/tweets.js") => ArchiveVersion::V2023,
+			None if self.contains("data/tweet.js") => ArchiveVersion::V2019,
+			Some(_) if self.contains("data/tweet.js") => ArchiveVersion::V2021,
+			_ => ArchiveVersion::Unknown,
+		};
+
+		Ok(version)
+	}
+
+	/// Read Tweets from `data/tweet.js` of older exports, wrapping bare Tweets and filling in
+	/// `edit_info` that did not yet exist
+	///
+	/// Tweets from before editing was possible are given an `edit_info` describing them as never
+	/// editable; `editable_until` equal to `created_at` and zero `edits_remaining`.
+	pub(crate) fn legacy_tweets(&mut self) -> Result<Vec<tweets::TweetObject>> {
+		let values: Vec<Value> = self.read_parts("YTD.tweet")?;
+
+		values
+			.into_iter()
+			.map(|value| {
+				tweets::TweetObject::deserialize(normalize_legacy_tweet(value))
+					.map_err(|error| Error::from(error).with_file_name("data/tweet.js"))
+			})
+			.collect()
+	}
+}
+
+/// Reshape one element of `data/tweet.js` into what `tweets::TweetObject` expects
+fn normalize_legacy_tweet(value: Value) -> Value {
+	let mut tweet = match value {
+		Value::Object(mut object) if object.len() == 1 && object.contains_key("tweet") => {
+			object.remove("tweet").unwrap_or_default()
+		}
+		value => value,
+	};
+
+	if let Value::Object(object) = &mut tweet {
+		if !object.contains_key("edit_info") {
+			let editable_until = object
+				.get("created_at")
+				.and_then(Value::as_str)
+				.and_then(|created_at| {
+					DateTime::parse_from_str(created_at, convert::created_at::FORMAT).ok()
+				})
+				.map(|created_at| {
+					created_at
+						.format(convert::date_time_iso_8601::FORMAT)
+						.to_string()
+				});
+
+			if let Some(editable_until) = editable_until {
+				let id = object.get("id_str").cloned().unwrap_or_default();
+				object.insert(
+					"edit_info".to_string(),
+					json!({
+						"initial": {
+							"editTweetIds": [id],
+							"editableUntil": editable_until,
+							"editsRemaining": "0",
+							"isEditEligible": false,
+						}
+					}),
+				);
+			}
+		}
+	}
+
+	json!({ "tweet": tweet })
+}